@@ -263,6 +263,40 @@ impl<E: PairingEngine> Proof<E> {
         &self.batch_sizes
     }
 
+    /// Returns the compressed size in bytes of a proof with the given batch sizes, without constructing the proof.
+    ///
+    /// The polynomials are opened at three points (`alpha`, `beta`, and `gamma`), and in hiding mode,
+    /// only the opening at `beta` is hiding, as it is the only one that involves hiding polynomials.
+    pub fn compressed_size_for(batch_sizes: &[usize], is_hiding: bool) -> usize {
+        const NUM_OPENINGS: usize = 3;
+
+        let num_circuits = batch_sizes.len();
+        let num_instances = batch_sizes.iter().sum::<usize>();
+
+        let length_size = 0usize.compressed_size();
+        let flag_size = false.compressed_size();
+        let commitment_size = E::G1Affine::default().compressed_size();
+        let field_size = E::Fr::default().compressed_size();
+
+        // The batch sizes.
+        let batch_sizes_size = length_size * (1 + num_circuits);
+        // The witness commitments, the `h_0`, `g_1`, `h_1`, and `h_2` commitments,
+        // the `g_a`, `g_b`, and `g_c` commitments, and the optional mask commitment.
+        let commitments_size = commitment_size * (num_instances + 4 + 3 * num_circuits)
+            + flag_size
+            + if is_hiding { commitment_size } else { 0 };
+        // The evaluations of `g_1`, and of the `g_a`, `g_b`, and `g_c` polynomials.
+        let evaluations_size = field_size * (1 + 3 * num_circuits);
+        // The matrix sums in the third message (per instance) and the fourth message (per circuit).
+        let messages_size = field_size * 3 * (num_instances + num_circuits);
+        // The opening proofs, each with an optional evaluation of the random polynomial.
+        let pc_proof_size = length_size
+            + (commitment_size + flag_size) * NUM_OPENINGS
+            + if is_hiding { field_size } else { 0 };
+
+        batch_sizes_size + commitments_size + evaluations_size + messages_size + pc_proof_size
+    }
+
     /// Check that the number of messages is consistent with our batch size
     pub fn check_batch_sizes(&self) -> Result<(), SNARKError> {
         let total_instances = self
@@ -488,6 +522,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_compressed_size_for() {
+        let rng = &mut snarkvm_utilities::rand::TestRng::default();
+
+        for i in 1..11 {
+            for j in 1..11 {
+                let is_hiding = i * j % 2 == 0;
+                let batch_sizes = vec![j; i];
+                let commitments = rand_commitments(j, i, !is_hiding);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); j]; i] };
+                let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); i] };
+                // Only the opening at `beta` is hiding.
+                let openings =
+                    vec![rand_kzg_proof(rng, true), rand_kzg_proof(rng, !is_hiding), rand_kzg_proof(rng, true)];
+                let pc_proof = sonic_pc::BatchLCProof { proof: BatchProof(openings) };
+                let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+                assert_eq!(proof.is_hiding(), is_hiding);
+                assert_eq!(
+                    proof.compressed_size(),
+                    Proof::<Bls12_377>::compressed_size_for(proof.batch_sizes(), proof.is_hiding())
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_serializing_proof() {
         let rng = &mut snarkvm_utilities::rand::TestRng::default();
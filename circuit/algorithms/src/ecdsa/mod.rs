@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use crate::{
+    foreign::{inverse, ForeignField, Term},
+    Hash,
+    Keccak256,
+};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, U8};
+
+use num_bigint::{BigInt, BigUint};
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used by Ethereum.
+///
+/// A signature is the 64-byte compact encoding from EIP-2098, namely `r || (yParity * 2^255 + s)`,
+/// where `r` and `s` are 32-byte big-endian integers and `yParity` is the parity of the y-coordinate of `R`.
+/// The signer is identified by its 20-byte Ethereum address, which is recovered from the signature
+/// and the Keccak-256 digest of the message.
+///
+/// The curve arithmetic is performed in affine coordinates over a foreign field. To avoid the point at
+/// infinity, the scalar multiplication starts from a fixed offset point `H` with an unknown discrete log,
+/// which is removed at the end. If an addition is exceptional (i.e. both points share an x-coordinate),
+/// the signature is treated as invalid, which only happens for honest signatures with negligible probability.
+#[derive(Clone)]
+pub struct ECDSASecp256k1<E: Environment> {
+    /// The Keccak-256 hash function.
+    keccak: Keccak256<E>,
+    /// The offset point `H`.
+    offset: console::Secp256k1Point,
+}
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// The number of bytes in an address.
+    pub const ADDRESS_SIZE_IN_BYTES: usize = console::ECDSASecp256k1::ADDRESS_SIZE_IN_BYTES;
+    /// The number of bytes in a signature.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = console::ECDSASecp256k1::SIGNATURE_SIZE_IN_BYTES;
+
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub fn new() -> Self {
+        Self { keccak: Keccak256::new(), offset: Self::offset_point() }
+    }
+
+    /// Returns the offset point `H`, which is the first point whose x-coordinate is at least
+    /// the Keccak-256 digest of a fixed domain separator, and whose y-coordinate is even.
+    fn offset_point() -> console::Secp256k1Point {
+        let domain = b"AleoECDSASecp256k1OffsetPoint";
+        let domain_bits = domain.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let digest = console::Hash::hash(&console::Keccak256::default(), &domain_bits)
+            .unwrap_or_else(|_| E::halt("Failed to hash the offset point domain"));
+        let digest_bytes = digest.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8));
+        let mut x = BigUint::from_bytes_be(&digest_bytes.collect::<Vec<_>>());
+        loop {
+            if let Some(point) = console::Secp256k1Point::from_x(&x, false) {
+                return point;
+            }
+            x += 1u32;
+        }
+    }
+}
+
+impl<E: Environment> Default for ECDSASecp256k1<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A point on secp256k1 in affine coordinates.
+#[derive(Clone)]
+struct Point<E: Environment> {
+    x: ForeignField<E>,
+    y: ForeignField<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point.
+    fn constant(point: &console::Secp256k1Point) -> Self {
+        Self { x: ForeignField::constant(point.x()), y: ForeignField::constant(point.y()) }
+    }
+
+    /// Returns `first` if `condition` is `true`, and `second` otherwise.
+    fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: ForeignField::ternary(condition, &first.x, &second.x),
+            y: ForeignField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `true` if the point is a constant.
+    fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+}
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// Returns the sum of the given points, and `true` if the addition is not exceptional.
+    /// If both points share an x-coordinate, the generator and `false` are returned instead.
+    fn add(first: &Point<E>, second: &Point<E>) -> (Point<E>, Boolean<E>) {
+        let p = console::Secp256k1Point::base_field_modulus();
+        let zero = BigInt::default();
+
+        // Compute `x2 - x1`, and determine if the addition is exceptional.
+        let dx = ForeignField::reduce(&[Term::Linear(1, &second.x), Term::Linear(-1, &first.x)], &zero, &p);
+        let is_exceptional = dx.is_zero_mod(&p);
+        // If the addition is exceptional, divide by one instead, so that the constraints are satisfiable.
+        let dx = ForeignField::ternary(&is_exceptional, &ForeignField::constant(&BigUint::from(1u32)), &dx);
+
+        // Compute the slope `λ = (y2 - y1) / (x2 - x1)`.
+        let lambda = ForeignField::witness(dx.is_constant() && first.is_constant() && second.is_constant(), || {
+            let dy = (second.y.eject_value() + &p - first.y.eject_value() % &p) % &p;
+            dy * inverse(&dx.eject_value(), &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[Term::Product(1, &lambda, &dx), Term::Linear(-1, &second.y), Term::Linear(1, &first.y)],
+            &zero,
+            &p,
+        );
+
+        // Compute `x3 = λ^2 - x1 - x2` and `y3 = λ * (x1 - x3) - y1`.
+        let x = ForeignField::reduce(
+            &[Term::Product(1, &lambda, &lambda), Term::Linear(-1, &first.x), Term::Linear(-1, &second.x)],
+            &zero,
+            &p,
+        );
+        let y = ForeignField::reduce(
+            &[Term::Product(1, &lambda, &first.x), Term::Product(-1, &lambda, &x), Term::Linear(-1, &first.y)],
+            &zero,
+            &p,
+        );
+
+        let generator = Point::constant(&console::Secp256k1Point::generator());
+        (Point::ternary(&is_exceptional, &generator, &Point { x, y }), !is_exceptional)
+    }
+
+    /// Returns the double of the given point, which must be on the curve.
+    fn double(point: &Point<E>) -> Point<E> {
+        let p = console::Secp256k1Point::base_field_modulus();
+        let zero = BigInt::default();
+
+        // Compute the slope `λ = 3 * x^2 / (2 * y)`.
+        let lambda = ForeignField::witness(point.is_constant(), || {
+            let (x, y) = (point.x.eject_value(), point.y.eject_value());
+            BigUint::from(3u32) * &x * &x % &p * inverse(&(BigUint::from(2u32) * y), &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[Term::Product(2, &lambda, &point.y), Term::Product(-3, &point.x, &point.x)],
+            &zero,
+            &p,
+        );
+
+        // Compute `x3 = λ^2 - 2 * x` and `y3 = λ * (x - x3) - y`.
+        let x = ForeignField::reduce(&[Term::Product(1, &lambda, &lambda), Term::Linear(-2, &point.x)], &zero, &p);
+        let y = ForeignField::reduce(
+            &[Term::Product(1, &lambda, &point.x), Term::Product(-1, &lambda, &x), Term::Linear(-1, &point.y)],
+            &zero,
+            &p,
+        );
+        Point { x, y }
+    }
+
+    /// Returns the point with the given x-coordinate and y-parity, and `true` if it exists.
+    /// If the point does not exist, the returned y-coordinate satisfies `y^2 = -(x^3 + 7)` instead.
+    fn decompress(x: &ForeignField<E>, is_odd: &Boolean<E>) -> (Point<E>, Boolean<E>) {
+        let p = console::Secp256k1Point::base_field_modulus();
+        let zero = BigInt::default();
+
+        // Compute `x^3 + 7` and its negation.
+        let xx = ForeignField::reduce(&[Term::Product(1, x, x)], &zero, &p);
+        let yy = ForeignField::reduce(&[Term::Product(1, &xx, x)], &BigInt::from(7), &p);
+        let neg_yy = ForeignField::reduce(&[Term::Linear(-1, &yy)], &zero, &p);
+
+        // Compute the square root of `x^3 + 7` if it exists, and of `-(x^3 + 7)` otherwise.
+        // Note: As `p = 3 mod 4`, `-1` is a non-residue, so exactly one of the two is a square.
+        let (is_square, y) = {
+            let yy = yy.eject_value() % &p;
+            let exponent = (&p + 1u32) >> 2;
+            let y = yy.modpow(&exponent, &p);
+            match &y * &y % &p == yy {
+                true => (true, y),
+                false => (false, ((&p - &yy) % &p).modpow(&exponent, &p)),
+            }
+        };
+        // Select the square root with the given parity.
+        let y = match y.bit(0) == is_odd.eject_value() {
+            true => y,
+            false => (&p - y) % &p,
+        };
+
+        // Witness the square root, and ensure it is canonical and has the given parity.
+        let is_constant = x.is_constant() && is_odd.is_constant();
+        let mode = if is_constant { Mode::Constant } else { Mode::Private };
+        let is_square = Boolean::new(mode, is_square);
+        let y = ForeignField::witness(is_constant, || y);
+        y.enforce_canonical(&p);
+        E::assert_eq(&y.to_bits_le()[0], is_odd);
+
+        // Enforce `y^2 = x^3 + 7` if it is a square, and `y^2 = -(x^3 + 7)` otherwise.
+        let target = ForeignField::ternary(&is_square, &yy, &neg_yy);
+        ForeignField::assert_zero(&[Term::Product(1, &y, &y), Term::Linear(-1, &target)], &zero, &p);
+
+        (Point { x: x.clone(), y }, is_square)
+    }
+}
+
+/// Returns the given bytes as arrays of little-endian bits.
+fn to_bits_le_bytes<E: Environment>(bytes: &[U8<E>]) -> Vec<[Boolean<E>; 8]> {
+    bytes
+        .iter()
+        .map(|byte| {
+            let bits_le = byte.to_bits_le();
+            core::array::from_fn(|i| bits_le[i].clone())
+        })
+        .collect()
+}
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// Returns `true` if the signature is valid for the given address and message.
+    ///
+    /// A signature `(r, s, yParity)` is valid if `1 <= r < n`, `1 <= s <= (n - 1) / 2`, `R` is the point
+    /// with x-coordinate `r` and y-parity `yParity`, and the public key `Q = r^(-1) * (s * R - z * G)`
+    /// hashes to the given address, where `z` is the Keccak-256 digest of the message.
+    /// In addition, `r` must not be the x-coordinate of the generator, as this case is not provable.
+    pub fn verify(&self, signature: &[U8<E>], address: &[U8<E>], message: &[U8<E>]) -> Boolean<E> {
+        // Ensure the signature and address are well-formed.
+        if signature.len() != Self::SIGNATURE_SIZE_IN_BYTES {
+            E::halt(format!("Expected a {}-byte signature", Self::SIGNATURE_SIZE_IN_BYTES))
+        }
+        if address.len() != Self::ADDRESS_SIZE_IN_BYTES {
+            E::halt(format!("Expected a {}-byte address", Self::ADDRESS_SIZE_IN_BYTES))
+        }
+
+        let p = console::Secp256k1Point::base_field_modulus();
+        let n = console::Secp256k1Point::scalar_field_modulus();
+        let zero = BigInt::default();
+        let generator = console::Secp256k1Point::generator();
+        // Note: The fallback point `2G` is used in place of `R` when the signature is malformed.
+        let fallback = generator.double();
+
+        // Parse `r`, `s`, and the y-parity of `R`.
+        let r = ForeignField::from_bytes_be(&to_bits_le_bytes(&signature[..32]));
+        let mut s_bytes = to_bits_le_bytes(&signature[32..]);
+        let is_odd = s_bytes[0][7].clone();
+        s_bytes[0][7] = Boolean::constant(false);
+        let s = ForeignField::from_bytes_be(&s_bytes);
+
+        // Ensure `r` is in range, and is not the x-coordinate of the generator.
+        let is_r_valid = !r.is_equal_constant(&BigUint::default())
+            & r.is_less_than_or_equal_constant(&(&n - 1u32))
+            & !r.is_equal_constant(generator.x());
+        // Ensure `s` is in the lower half of the range.
+        let is_s_valid = !s.is_equal_constant(&BigUint::default()) & s.is_less_than_or_equal_constant(&(&n >> 1));
+        let r = ForeignField::ternary(&is_r_valid, &r, &ForeignField::constant(fallback.x()));
+
+        // Recover `R` from `r` and its y-parity.
+        let (point_r, is_on_curve) = Self::decompress(&r, &is_odd);
+        let mut is_valid = is_r_valid & is_s_valid & is_on_curve;
+        let point_r = Point::ternary(&is_valid, &point_r, &Point::constant(&fallback));
+
+        // Compute the digest `z` of the message.
+        let message_bits = message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let digest = self.keccak.hash(&message_bits);
+        let digest_bytes = digest.chunks(8).map(|bits| core::array::from_fn(|i| bits[i].clone())).collect::<Vec<_>>();
+        let z = ForeignField::from_bytes_be(&digest_bytes);
+
+        // Compute `u1 = -z * r^(-1)` and `u2 = s * r^(-1)`.
+        let r_inv = ForeignField::witness(r.is_constant(), || inverse(&r.eject_value(), &n));
+        ForeignField::assert_zero(&[Term::Product(1, &r, &r_inv)], &BigInt::from(-1), &n);
+        let u1 = ForeignField::reduce(&[Term::Product(-1, &z, &r_inv)], &zero, &n);
+        let u2 = ForeignField::reduce(&[Term::Product(1, &s, &r_inv)], &zero, &n);
+
+        // Prepare the table `[H, G + H, R + H, G + R + H]`.
+        let offset = Point::constant(&self.offset);
+        let (g_plus_r, is_not_exceptional) = Self::add(&Point::constant(&generator), &point_r);
+        is_valid &= is_not_exceptional;
+        let (r_plus_h, is_not_exceptional) = Self::add(&point_r, &offset);
+        is_valid &= is_not_exceptional;
+        let (g_plus_r_plus_h, is_not_exceptional) = Self::add(&g_plus_r, &offset);
+        is_valid &= is_not_exceptional;
+        let table = [offset.clone(), Point::constant(&generator.add(&self.offset)), r_plus_h, g_plus_r_plus_h];
+
+        // Compute `u1 * G + u2 * R + (2^257 - 1) * H`, with Shamir's trick.
+        let mut accumulator = offset;
+        let (u1_bits, u2_bits) = (u1.to_bits_le(), u2.to_bits_le());
+        for (bit_1, bit_2) in u1_bits.iter().zip(u2_bits.iter()).rev() {
+            accumulator = Self::double(&accumulator);
+            let lower = Point::ternary(bit_1, &table[1], &table[0]);
+            let upper = Point::ternary(bit_1, &table[3], &table[2]);
+            let (sum, is_not_exceptional) = Self::add(&accumulator, &Point::ternary(bit_2, &upper, &lower));
+            accumulator = sum;
+            is_valid &= is_not_exceptional;
+        }
+
+        // Remove the offset, to compute the public key `Q = u1 * G + u2 * R`.
+        // Note: If `Q` is the point at infinity, the addition is exceptional, and the signature is invalid.
+        let correction = self.offset.mul(&((BigUint::from(1u32) << 257) - 1u32)).neg();
+        let (public_key, is_not_exceptional) = Self::add(&accumulator, &Point::constant(&correction));
+        is_valid &= is_not_exceptional;
+
+        // Compute the address of the public key, namely the last 20 bytes of `Keccak256(Q.x || Q.y)`.
+        let preimage = [public_key.x.to_canonical(&p), public_key.y.to_canonical(&p)]
+            .iter()
+            .flat_map(|coordinate| {
+                // Note: The coordinates are encoded as big-endian bytes, with little-endian bits.
+                coordinate.to_bits_le().chunks(8).rev().flatten().cloned().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let candidate = self.keccak.hash(&preimage);
+
+        // Ensure the address matches.
+        let address_bits = address.iter().flat_map(|byte| byte.to_bits_le());
+        candidate[(32 - Self::ADDRESS_SIZE_IN_BYTES) * 8..]
+            .iter()
+            .zip_eq(address_bits)
+            .fold(is_valid, |is_valid, (candidate, expected)| is_valid & candidate.is_equal(&expected))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::{Rng, TestRng};
+    use snarkvm_circuit_types::environment::{assert_scope, Circuit};
+
+    /// Returns the given bytes as circuit bytes in the given mode.
+    fn inject(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect()
+    }
+
+    fn check_verify(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        // Sample a signature.
+        let private_key = console::ECDSASecp256k1::sample_private_key(rng);
+        let address = console::ECDSASecp256k1::address(&private_key).unwrap();
+        let message = (0..32).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let signature = console::ECDSASecp256k1::sign(&private_key, &message, rng).unwrap();
+        assert!(console::ECDSASecp256k1::verify(&signature, &address, &message).unwrap());
+
+        let (signature, address, message) = (inject(mode, &signature), inject(mode, &address), inject(mode, &message));
+
+        let ecdsa = ECDSASecp256k1::<Circuit>::new();
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate = ecdsa.verify(&signature, &address, &message);
+            assert!(candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Constant, 670264, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_public() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Public, 260704, 0, 1530476, 1538001, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Private, 260704, 0, 1530476, 1538001, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_invalid() {
+        let rng = &mut TestRng::default();
+
+        let private_key = console::ECDSASecp256k1::sample_private_key(rng);
+        let address = console::ECDSASecp256k1::address(&private_key).unwrap();
+        let message = (0..32).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let signature = console::ECDSASecp256k1::sign(&private_key, &message, rng).unwrap();
+
+        // Flip the y-parity, and sample a signature with an `r` that is not the x-coordinate of a point.
+        let mut flipped = signature;
+        flipped[32] ^= 0x80;
+        let mut off_curve = signature;
+        off_curve[..32].copy_from_slice(&[0u8; 32]);
+        off_curve[31] = 5;
+
+        for signature in [flipped, off_curve, [0u8; 64], [0xffu8; 64]] {
+            assert!(!console::ECDSASecp256k1::verify(&signature, &address, &message).unwrap());
+
+            let candidate = ECDSASecp256k1::<Circuit>::new().verify(
+                &inject(Mode::Private, &signature),
+                &inject(Mode::Private, &address),
+                &inject(Mode::Private, &message),
+            );
+            assert!(!candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
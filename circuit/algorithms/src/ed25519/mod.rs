@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use crate::{
+    foreign::{inverse, ForeignField, Term},
+    Hash,
+    Sha512,
+};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, U8};
+
+use num_bigint::{BigInt, BigUint};
+
+/// The Ed25519 signature scheme, as specified in RFC 8032.
+///
+/// A signature is the 64-byte encoding `R || S`, where `R` is an encoded point and `S` is a 32-byte
+/// little-endian integer, and a public key is an encoded point.
+///
+/// Verification uses the cofactorless equation `[S]B = R + [k]A`, where `k = SHA-512(R || A || M) mod L`,
+/// and rejects non-canonical encodings of `S` and `A`. The curve arithmetic is performed in affine
+/// coordinates over a foreign field, with the complete addition law of the twisted Edwards curve.
+#[derive(Clone, Debug, Default)]
+pub struct Ed25519<E: Environment> {
+    /// The SHA-512 hash function.
+    sha512: Sha512<E>,
+}
+
+impl<E: Environment> Ed25519<E> {
+    /// The number of bytes in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BYTES: usize = console::Ed25519::PUBLIC_KEY_SIZE_IN_BYTES;
+    /// The number of bytes in a signature.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = console::Ed25519::SIGNATURE_SIZE_IN_BYTES;
+
+    /// Initializes a new instance of Ed25519.
+    pub fn new() -> Self {
+        Self { sha512: Sha512::new() }
+    }
+}
+
+/// A point on edwards25519 in affine coordinates.
+#[derive(Clone)]
+struct Point<E: Environment> {
+    x: ForeignField<E>,
+    y: ForeignField<E>,
+}
+
+/// A point on edwards25519 in affine coordinates, with the product `t = d * x * y` for the addition law.
+#[derive(Clone)]
+struct TablePoint<E: Environment> {
+    point: Point<E>,
+    t: ForeignField<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point.
+    fn constant(point: &console::Ed25519Point) -> Self {
+        Self { x: ForeignField::constant(point.x()), y: ForeignField::constant(point.y()) }
+    }
+
+    /// Returns `first` if `condition` is `true`, and `second` otherwise.
+    fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: ForeignField::ternary(condition, &first.x, &second.x),
+            y: ForeignField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `true` if the point is a constant.
+    fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+
+    /// Returns the point with the product `t = d * x * y`.
+    fn to_table_point(&self) -> TablePoint<E> {
+        let p = console::Ed25519Point::base_field_modulus();
+        let d = ForeignField::constant(&console::Ed25519Point::coefficient_d());
+        let xy = ForeignField::reduce(&[Term::Product(1, &self.x, &self.y)], &BigInt::default(), &p);
+        let t = ForeignField::reduce(&[Term::Product(1, &d, &xy)], &BigInt::default(), &p);
+        TablePoint { point: self.clone(), t }
+    }
+}
+
+impl<E: Environment> TablePoint<E> {
+    /// Returns `first` if `condition` is `true`, and `second` otherwise.
+    fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            point: Point::ternary(condition, &first.point, &second.point),
+            t: ForeignField::ternary(condition, &first.t, &second.t),
+        }
+    }
+}
+
+impl<E: Environment> Ed25519<E> {
+    /// Returns the sum of the given points, which must be on the curve.
+    ///
+    /// The addition law `(x3, y3) = ((x1 y2 + y1 x2) / (1 + d x1 x2 y1 y2), (y1 y2 + x1 x2) / (1 - d x1 x2 y1 y2))`
+    /// is complete, as `d` is a non-square.
+    fn add(first: &Point<E>, second: &TablePoint<E>) -> Point<E> {
+        let p = console::Ed25519Point::base_field_modulus();
+        let zero = BigInt::default();
+        let (second, second_t) = (&second.point, &second.t);
+
+        // Compute `d x1 x2 y1 y2`.
+        let xy = ForeignField::reduce(&[Term::Product(1, &first.x, &first.y)], &zero, &p);
+        let dxy = ForeignField::reduce(&[Term::Product(1, &xy, second_t)], &zero, &p);
+
+        // Compute `x3`, and enforce `x3 * (1 + d x1 x2 y1 y2) = x1 y2 + y1 x2`.
+        let is_constant = first.is_constant() && second.is_constant() && second_t.is_constant();
+        let x = ForeignField::witness(is_constant, || {
+            let (x1, y1, x2, y2) =
+                (first.x.eject_value(), first.y.eject_value(), second.x.eject_value(), second.y.eject_value());
+            (x1 * &y2 + y1 * &x2) % &p * inverse(&(dxy.eject_value() + 1u32), &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[
+                Term::Linear(1, &x),
+                Term::Product(1, &x, &dxy),
+                Term::Product(-1, &first.x, &second.y),
+                Term::Product(-1, &first.y, &second.x),
+            ],
+            &zero,
+            &p,
+        );
+
+        // Compute `y3`, and enforce `y3 * (1 - d x1 x2 y1 y2) = y1 y2 + x1 x2`.
+        let y = ForeignField::witness(is_constant, || {
+            let (x1, y1, x2, y2) =
+                (first.x.eject_value(), first.y.eject_value(), second.x.eject_value(), second.y.eject_value());
+            (y1 * &y2 + x1 * &x2) % &p * inverse(&((&p + 1u32 - dxy.eject_value() % &p) % &p), &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[
+                Term::Linear(1, &y),
+                Term::Product(-1, &y, &dxy),
+                Term::Product(-1, &first.y, &second.y),
+                Term::Product(-1, &first.x, &second.x),
+            ],
+            &zero,
+            &p,
+        );
+
+        Point { x, y }
+    }
+
+    /// Returns the double of the given point, which must be on the curve.
+    ///
+    /// Using the curve equation, the doubling law simplifies to
+    /// `(x3, y3) = (2 x y / (y^2 - x^2), (y^2 + x^2) / (2 - y^2 + x^2))`.
+    fn double(point: &Point<E>) -> Point<E> {
+        let p = console::Ed25519Point::base_field_modulus();
+        let zero = BigInt::default();
+
+        let xx = ForeignField::reduce(&[Term::Product(1, &point.x, &point.x)], &zero, &p);
+        let yy = ForeignField::reduce(&[Term::Product(1, &point.y, &point.y)], &zero, &p);
+
+        // Compute `x3`, and enforce `x3 * (y^2 - x^2) = 2 x y`.
+        let x = ForeignField::witness(point.is_constant(), || {
+            let (x, y) = (point.x.eject_value(), point.y.eject_value());
+            let denominator = (yy.eject_value() + &p - xx.eject_value() % &p) % &p;
+            BigUint::from(2u32) * x * y % &p * inverse(&denominator, &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[Term::Product(1, &x, &yy), Term::Product(-1, &x, &xx), Term::Product(-2, &point.x, &point.y)],
+            &zero,
+            &p,
+        );
+
+        // Compute `y3`, and enforce `y3 * (2 - y^2 + x^2) = y^2 + x^2`.
+        let y = ForeignField::witness(point.is_constant(), || {
+            let (xx, yy) = (xx.eject_value() % &p, yy.eject_value() % &p);
+            let denominator = (BigUint::from(2u32) + &p - &yy + &xx) % &p;
+            (yy + xx) % &p * inverse(&denominator, &p) % &p
+        });
+        ForeignField::assert_zero(
+            &[
+                Term::Linear(2, &y),
+                Term::Product(-1, &y, &yy),
+                Term::Product(1, &y, &xx),
+                Term::Linear(-1, &yy),
+                Term::Linear(-1, &xx),
+            ],
+            &zero,
+            &p,
+        );
+
+        Point { x, y }
+    }
+
+    /// Returns the point encoded by the given 256 little-endian bits, and `true` if the encoding is canonical and valid.
+    /// If the encoding is invalid, the returned point is not necessarily on the curve.
+    fn decode(bits_le: &[Boolean<E>]) -> (Point<E>, Boolean<E>) {
+        debug_assert_eq!(bits_le.len(), 256, "An encoded point must have 256 bits");
+
+        let p = console::Ed25519Point::base_field_modulus();
+        let zero = BigInt::default();
+        let d = ForeignField::constant(&console::Ed25519Point::coefficient_d());
+
+        // Parse the sign bit and the y-coordinate, and ensure the y-coordinate is canonical.
+        let is_odd = &bits_le[255];
+        let y = ForeignField::from_bits_le(&bits_le[..255]);
+        let is_y_valid = y.is_less_than_or_equal_constant(&(&p - 1u32));
+
+        // Compute `u = y^2 - 1` and `v = d y^2 + 1`, such that `x^2 = u / v`.
+        // Note: `v` is never zero, as `-1 / d` is a non-square.
+        let yy = ForeignField::reduce(&[Term::Product(1, &y, &y)], &zero, &p);
+        let u = ForeignField::reduce(&[Term::Linear(1, &yy)], &BigInt::from(-1), &p);
+        let v = ForeignField::reduce(&[Term::Product(1, &d, &yy)], &BigInt::from(1), &p);
+        let double_u = ForeignField::reduce(&[Term::Linear(2, &u)], &zero, &p);
+
+        // Compute the square root of `u / v` if it exists, and of `2 u / v` otherwise.
+        // Note: As `p = 5 mod 8`, `2` is a non-residue, so exactly one of the two is a square.
+        let (is_square, x) = {
+            let ratio = u.eject_value() * inverse(&v.eject_value(), &p) % &p;
+            match sqrt(&ratio, &p) {
+                Some(x) => (true, x),
+                None => (false, sqrt(&(BigUint::from(2u32) * ratio % &p), &p).unwrap_or_default()),
+            }
+        };
+        // Select the square root with the given sign.
+        let x = match x.bit(0) == is_odd.eject_value() {
+            true => x,
+            false => (&p - x) % &p,
+        };
+
+        // Witness the square root, and ensure it is canonical.
+        let is_constant = y.is_constant() && is_odd.is_constant();
+        let mode = if is_constant { Mode::Constant } else { Mode::Private };
+        let is_square = Boolean::new(mode, is_square);
+        let x = ForeignField::witness(is_constant, || x);
+        x.enforce_canonical(&p);
+        let is_x_zero = x.is_equal_constant(&BigUint::default());
+
+        // Enforce `x^2 v = u` if it is a square, and `x^2 v = 2 u` otherwise.
+        let xx = ForeignField::reduce(&[Term::Product(1, &x, &x)], &zero, &p);
+        let target = ForeignField::ternary(&is_square, &u, &double_u);
+        ForeignField::assert_zero(&[Term::Product(1, &xx, &v), Term::Linear(-1, &target)], &zero, &p);
+        // Ensure `x` is non-zero if `u / v` is not a square, as otherwise `u = 0` would satisfy both cases.
+        E::assert(&is_square | &!&is_x_zero);
+        // Ensure `x` has the given sign, unless it is zero.
+        E::assert(&is_x_zero | &x.to_bits_le()[0].is_equal(is_odd));
+
+        // Reject the non-canonical encoding of `x = 0`.
+        let is_valid = is_y_valid & is_square & !(&is_x_zero & is_odd);
+        (Point { x, y }, is_valid)
+    }
+}
+
+/// Returns the square root of the given value modulo `p = 2^255 - 19`, if it exists.
+fn sqrt(value: &BigUint, p: &BigUint) -> Option<BigUint> {
+    // Compute the candidate square root `x = value^((p + 3) / 8)`, as `p = 5 mod 8`.
+    let x = value.modpow(&((p + 3u32) >> 3), p);
+    if &x * &x % p == *value {
+        return Some(x);
+    }
+    // Multiply by `sqrt(-1) = 2^((p - 1) / 4)`.
+    let x = x * BigUint::from(2u32).modpow(&((p - 1u32) >> 2), p) % p;
+    match &x * &x % p == *value {
+        true => Some(x),
+        false => None,
+    }
+}
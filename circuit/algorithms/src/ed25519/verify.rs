@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Ed25519<E> {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    ///
+    /// A signature `R || S` is valid if `S < L`, the public key decodes to a point `A`,
+    /// and `[S]B - [k]A` encodes to `R`, where `k = SHA-512(R || A || M) mod L`.
+    pub fn verify(&self, signature: &[U8<E>], public_key: &[U8<E>], message: &[U8<E>]) -> Boolean<E> {
+        // Ensure the signature and public key are well-formed.
+        if signature.len() != Self::SIGNATURE_SIZE_IN_BYTES {
+            E::halt(format!("Expected a {}-byte signature", Self::SIGNATURE_SIZE_IN_BYTES))
+        }
+        if public_key.len() != Self::PUBLIC_KEY_SIZE_IN_BYTES {
+            E::halt(format!("Expected a {}-byte public key", Self::PUBLIC_KEY_SIZE_IN_BYTES))
+        }
+
+        let p = console::Ed25519Point::base_field_modulus();
+        let l = console::Ed25519Point::group_order();
+        let zero = BigInt::default();
+        let generator = Point::constant(&console::Ed25519Point::generator());
+
+        let signature_bits = signature.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let public_key_bits = public_key.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let message_bits = message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+
+        // Decode the public key.
+        // Note: If the public key is invalid, the base point is used in its place.
+        let (point_a, is_a_valid) = Self::decode(&public_key_bits);
+        let point_a = Point::ternary(&is_a_valid, &point_a, &generator);
+
+        // Ensure `S` is canonical.
+        let s = ForeignField::from_bits_le(&signature_bits[256..]);
+        let is_s_valid = s.is_less_than_or_equal_constant(&(&l - 1u32));
+
+        // Compute the challenge `k`, where the digest is reduced from its 256-bit halves as `lo + hi * (2^256 mod L)`.
+        let digest = self.sha512.hash(&[&signature_bits[..256], &public_key_bits, &message_bits].concat());
+        let (lower, upper) = (ForeignField::from_bits_le(&digest[..256]), ForeignField::from_bits_le(&digest[256..]));
+        let shift = ForeignField::constant(&((BigUint::from(1u32) << 256) % &l));
+        let k = ForeignField::reduce(&[Term::Linear(1, &lower), Term::Product(1, &upper, &shift)], &zero, &l);
+        // Note: The challenge must be canonical, as `A` may have a small-order component.
+        k.enforce_canonical(&l);
+
+        // Prepare the table `[O, B, -A, B - A]`.
+        let neg_a = Point { x: ForeignField::reduce(&[Term::Linear(-1, &point_a.x)], &zero, &p), y: point_a.y };
+        let b_minus_a = Self::add(&generator, &neg_a.to_table_point());
+        let table = [Point::constant(&console::Ed25519Point::zero()), generator, neg_a, b_minus_a]
+            .map(|point| point.to_table_point());
+
+        // Compute `[S]B - [k]A`, with Shamir's trick.
+        // Note: As `L < 2^253`, only the lower 253 bits of the scalars are used.
+        let num_bits = l.bits() as usize;
+        let (s_bits, k_bits) = (s.to_bits_le(), k.to_bits_le());
+        let mut candidate = table[0].point.clone();
+        for (bit_s, bit_k) in s_bits[..num_bits].iter().zip(k_bits[..num_bits].iter()).rev() {
+            candidate = Self::double(&candidate);
+            let lower = TablePoint::ternary(bit_s, &table[1], &table[0]);
+            let upper = TablePoint::ternary(bit_s, &table[3], &table[2]);
+            candidate = Self::add(&candidate, &TablePoint::ternary(bit_k, &upper, &lower));
+        }
+
+        // Encode the candidate, as the 255-bit y-coordinate with the least significant bit of the x-coordinate.
+        let (x, y) = (candidate.x.to_canonical(&p), candidate.y.to_canonical(&p));
+        let encoding = y.to_bits_le().into_iter().take(255).chain(core::iter::once(x.to_bits_le()[0].clone()));
+
+        // Ensure the encoding matches `R`.
+        encoding
+            .zip_eq(&signature_bits[..256])
+            .fold(is_a_valid & is_s_valid, |is_valid, (candidate, expected)| is_valid & candidate.is_equal(expected))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::{Rng, TestRng};
+    use snarkvm_circuit_types::environment::{assert_scope, Circuit};
+
+    /// Returns the given bytes as circuit bytes in the given mode.
+    fn inject(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect()
+    }
+
+    fn check_verify(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        // Sample a signature.
+        let secret_key = rng.gen::<[u8; 32]>();
+        let public_key = console::Ed25519::public_key(&secret_key);
+        let message = (0..32).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let signature = console::Ed25519::sign(&secret_key, &message);
+        assert!(console::Ed25519::verify(&signature, &public_key, &message).unwrap());
+
+        let (signature, public_key, message) =
+            (inject(mode, &signature), inject(mode, &public_key), inject(mode, &message));
+
+        let ed25519 = Ed25519::<Circuit>::new();
+        Circuit::scope(format!("Ed25519 {mode}"), || {
+            let candidate = ed25519.verify(&signature, &public_key, &message);
+            assert!(candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Constant, 542654, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_public() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Public, 69446, 0, 1593625, 1603039, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Private, 69446, 0, 1593625, 1603039, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_invalid() {
+        let rng = &mut TestRng::default();
+
+        let secret_key = rng.gen::<[u8; 32]>();
+        let public_key = console::Ed25519::public_key(&secret_key);
+        let message = (0..32).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let signature = console::Ed25519::sign(&secret_key, &message);
+
+        // Prepare a signature for a different message, a signature with a non-canonical `S`,
+        // and public keys with a non-canonical `y`, a non-square `x^2`, and a negative zero `x`.
+        let mut other_message = message.clone();
+        other_message[0] ^= 1;
+        let mut non_canonical_s = signature;
+        non_canonical_s[63] |= 0xf0;
+        let non_canonical_y = [0xffu8; 32];
+        let mut non_square = [0u8; 32];
+        non_square[0] = 2;
+        let mut negative_zero = [0u8; 32];
+        negative_zero[0] = 1;
+        negative_zero[31] = 0x80;
+
+        let cases = [
+            (signature, public_key, other_message),
+            (non_canonical_s, public_key, message.clone()),
+            (signature, non_canonical_y, message.clone()),
+            (signature, non_square, message.clone()),
+            (signature, negative_zero, message.clone()),
+        ];
+        for (signature, public_key, message) in cases {
+            assert!(!console::Ed25519::verify(&signature, &public_key, &message).unwrap());
+
+            let candidate = Ed25519::<Circuit>::new().verify(
+                &inject(Mode::Private, &signature),
+                &inject(Mode::Private, &public_key),
+                &inject(Mode::Private, &message),
+            );
+            assert!(!candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
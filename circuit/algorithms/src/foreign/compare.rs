@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ForeignField<E> {
+    /// Returns `true` if `self` is less than or equal to the given constant.
+    pub(crate) fn is_less_than_or_equal_constant(&self, constant: &BigUint) -> Boolean<E> {
+        if constant.bits() > SIZE_IN_BITS as u64 {
+            E::halt(format!("The constant must be less than 2^{SIZE_IN_BITS}"))
+        }
+        // If `self` is a constant, compare the values directly.
+        if self.is_constant() {
+            return Boolean::constant(self.eject_value() <= *constant);
+        }
+
+        let half_size_in_bits = SIZE_IN_BITS / 2;
+        let half_mask = (BigUint::from(1u32) << half_size_in_bits) - 1u32;
+        let shift = Field::constant(to_field::<E>(&(BigInt::from(1u32) << half_size_in_bits)));
+
+        // Split `self` and the constant into halves.
+        let (self_lo, self_hi) = self.to_halves();
+        let constant_lo = Field::constant(to_field::<E>(&BigInt::from(constant & &half_mask)));
+        let constant_hi = Field::constant(to_field::<E>(&BigInt::from(constant >> half_size_in_bits)));
+
+        // Compute `constant - self` with borrows, such that `constant - self = t_lo + t_hi * 2^128 - borrow_hi * 2^256`,
+        // where `t_lo` and `t_hi` are range-checked to 128 bits.
+        let mut borrow = Boolean::constant(false);
+        for (self_half, constant_half) in [(self_lo, constant_lo), (self_hi, constant_hi)] {
+            let difference = constant_half - self_half - Field::from_boolean(&borrow);
+            // Compute the borrow and the (shifted) difference.
+            let difference_value = to_bigint::<E>(&difference.eject_value());
+            borrow = Boolean::new(Mode::Private, difference_value < BigInt::default());
+            let shifted = match borrow.eject_value() {
+                true => difference_value + (BigInt::from(1u32) << half_size_in_bits),
+                false => difference_value,
+            };
+            let shifted = shifted.to_biguint().unwrap_or_default();
+            let shifted_bits_le = (0..half_size_in_bits as u64)
+                .map(|i| Boolean::<E>::new(Mode::Private, shifted.bit(i)))
+                .collect::<Vec<_>>();
+            // Enforce `difference + borrow * 2^128 = shifted`.
+            E::assert_eq(difference + Field::from_boolean(&borrow) * &shift, Field::from_bits_le(&shifted_bits_le));
+        }
+        // Return `true` if there is no final borrow.
+        !borrow
+    }
+
+    /// Enforces that `self` is less than the given modulus.
+    pub(crate) fn enforce_canonical(&self, modulus: &BigUint) {
+        E::assert(self.is_less_than_or_equal_constant(&(modulus - 1u32)))
+    }
+
+    /// Returns the canonical representative of `self` modulo the given modulus.
+    pub(crate) fn to_canonical(&self, modulus: &BigUint) -> Self {
+        let output = Self::reduce(&[Term::Linear(1, self)], &BigInt::default(), modulus);
+        output.enforce_canonical(modulus);
+        output
+    }
+
+    /// Returns `true` if `self` is equal to the given constant.
+    pub(crate) fn is_equal_constant(&self, constant: &BigUint) -> Boolean<E> {
+        if constant.bits() > SIZE_IN_BITS as u64 {
+            return Boolean::constant(false);
+        }
+        let half_size_in_bits = SIZE_IN_BITS / 2;
+        let half_mask = (BigUint::from(1u32) << half_size_in_bits) - 1u32;
+        let (self_lo, self_hi) = self.to_halves();
+        let constant_lo = Field::constant(to_field::<E>(&BigInt::from(constant & &half_mask)));
+        let constant_hi = Field::constant(to_field::<E>(&BigInt::from(constant >> half_size_in_bits)));
+        self_lo.is_equal(&constant_lo) & self_hi.is_equal(&constant_hi)
+    }
+
+    /// Returns `true` if `self` is congruent to zero modulo the given modulus.
+    pub(crate) fn is_zero_mod(&self, modulus: &BigUint) -> Boolean<E> {
+        // Check each multiple of the modulus below `2^256`.
+        let bound = BigUint::from(1u32) << SIZE_IN_BITS;
+        let mut multiple = BigUint::default();
+        let mut output = Boolean::constant(false);
+        while multiple < bound {
+            output |= self.is_equal_constant(&multiple);
+            multiple += modulus;
+        }
+        output
+    }
+
+    /// Returns the lower and upper 128-bit halves of `self`.
+    fn to_halves(&self) -> (Field<E>, Field<E>) {
+        let shift = Field::constant(console::Field::from_u128(1u128 << LIMB_SIZE_IN_BITS));
+        (&self.limbs[0] + &self.limbs[1] * &shift, &self.limbs[2] + &self.limbs[3] * &shift)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::{Rng, TestRng};
+    use snarkvm_circuit_types::environment::{assert_scope, Circuit};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_is_less_than_or_equal_constant() {
+        let rng = &mut TestRng::default();
+        let modulus = console::Secp256k1Point::base_field_modulus();
+
+        for i in 0..ITERATIONS {
+            // Sample values around the modulus.
+            let offset = BigUint::from(rng.gen::<u32>());
+            for value in [&modulus - &offset - 1u32, modulus.clone() - 1u32, modulus.clone(), &modulus + &offset] {
+                for mode in [Mode::Constant, Mode::Private] {
+                    let candidate = ForeignField::<Circuit>::new(mode, &value);
+                    Circuit::scope(format!("{mode} {i}"), || {
+                        let output = candidate.is_less_than_or_equal_constant(&(&modulus - 1u32));
+                        assert_eq!(value < modulus, output.eject_value());
+                        match mode {
+                            Mode::Constant => assert_scope!(0, 0, 0, 0),
+                            _ => assert_scope!(4, 0, 258, 260),
+                        }
+                    });
+                    assert!(Circuit::is_satisfied());
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_zero_mod() {
+        let modulus = console::Secp256k1Point::base_field_modulus();
+
+        for (value, expected) in [
+            (BigUint::default(), true),
+            (modulus.clone(), true),
+            (BigUint::from(1u32), false),
+            (&modulus + 1u32, false),
+            (&modulus - 1u32, false),
+        ] {
+            let candidate = ForeignField::<Circuit>::new(Mode::Private, &value);
+            assert_eq!(expected, candidate.is_zero_mod(&modulus).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
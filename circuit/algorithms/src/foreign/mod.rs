@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod compare;
+mod reduce;
+
+pub(crate) use reduce::Term;
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+use num_bigint::{BigInt, BigUint};
+
+/// The number of limbs in a foreign field element.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in a limb.
+const LIMB_SIZE_IN_BITS: usize = 64;
+/// The number of bits in a foreign field element.
+const SIZE_IN_BITS: usize = NUM_LIMBS * LIMB_SIZE_IN_BITS;
+
+/// An element of a foreign (i.e. non-native) prime field, represented as four 64-bit limbs in little-endian order.
+///
+/// The represented integer is always in `[0, 2^256)`, but is not necessarily reduced modulo the foreign modulus.
+/// Arithmetic is performed with `ForeignField::reduce` and `ForeignField::assert_zero`, which enforce an identity
+/// over the integers, and canonical representatives are enforced with `ForeignField::enforce_canonical`.
+#[derive(Clone)]
+pub(crate) struct ForeignField<E: Environment> {
+    /// The limbs, in little-endian order.
+    limbs: [Field<E>; NUM_LIMBS],
+    /// The little-endian bits of the limbs, if they are known.
+    bits_le: Option<Vec<Boolean<E>>>,
+}
+
+impl<E: Environment> ForeignField<E> {
+    /// Initializes a constant foreign field element.
+    pub(crate) fn constant(value: &BigUint) -> Self {
+        Self::new(Mode::Constant, value)
+    }
+
+    /// Initializes a new foreign field element, range-checking its limbs if it is not a constant.
+    pub(crate) fn new(mode: Mode, value: &BigUint) -> Self {
+        if value.bits() > SIZE_IN_BITS as u64 {
+            E::halt(format!("Foreign field elements must be less than 2^{SIZE_IN_BITS}"))
+        }
+        let bits_le = (0..SIZE_IN_BITS).map(|i| Boolean::new(mode, value.bit(i as u64))).collect::<Vec<_>>();
+        Self::from_bits_le(&bits_le)
+    }
+
+    /// Initializes a foreign field element from the given value, which is a constant if `is_constant` is set.
+    pub(crate) fn witness(is_constant: bool, value: impl FnOnce() -> BigUint) -> Self {
+        match is_constant {
+            true => Self::new(Mode::Constant, &value()),
+            false => Self::new(Mode::Private, &value()),
+        }
+    }
+
+    /// Initializes a foreign field element from at most 256 little-endian bits.
+    pub(crate) fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        if bits_le.len() > SIZE_IN_BITS {
+            E::halt(format!("Foreign field elements must have at most {SIZE_IN_BITS} bits"))
+        }
+        let mut bits_le = bits_le.to_vec();
+        bits_le.resize(SIZE_IN_BITS, Boolean::constant(false));
+        let limbs =
+            core::array::from_fn(|i| Field::from_bits_le(&bits_le[i * LIMB_SIZE_IN_BITS..(i + 1) * LIMB_SIZE_IN_BITS]));
+        Self { limbs, bits_le: Some(bits_le) }
+    }
+
+    /// Initializes a foreign field element from at most 256 big-endian bytes.
+    pub(crate) fn from_bytes_be(bytes_be: &[[Boolean<E>; 8]]) -> Self {
+        // Note: The bits of each byte are in little-endian order.
+        Self::from_bits_le(&bytes_be.iter().rev().flatten().cloned().collect::<Vec<_>>())
+    }
+
+    /// Returns the 256 little-endian bits of the foreign field element.
+    pub(crate) fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        match &self.bits_le {
+            Some(bits_le) => bits_le.clone(),
+            None => self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect(),
+        }
+    }
+
+    /// Returns `true` if the foreign field element is a constant.
+    pub(crate) fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns the value of the foreign field element.
+    pub(crate) fn eject_value(&self) -> BigUint {
+        self.limbs
+            .iter()
+            .rev()
+            .fold(BigUint::default(), |acc, limb| (acc << LIMB_SIZE_IN_BITS) + to_biguint::<E>(&limb.eject_value()))
+    }
+
+    /// Returns `first` if `condition` is `true`, and `second` otherwise.
+    pub(crate) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        let limbs = core::array::from_fn(|i| Field::ternary(condition, &first.limbs[i], &second.limbs[i]));
+        Self { limbs, bits_le: None }
+    }
+}
+
+/// Returns the inverse of the given value modulo the given prime, or zero if the value is zero.
+pub(crate) fn inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+    value.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the value of the given native field element as an unsigned integer.
+fn to_biguint<E: Environment>(value: &console::Field<E::Network>) -> BigUint {
+    let bits_le = value.to_bits_le().into_iter().map(u8::from).collect::<Vec<_>>();
+    BigUint::from_radix_le(&bits_le, 2).unwrap_or_default()
+}
+
+/// Returns the value of the given native field element as a signed integer in `(-(p - 1) / 2, (p - 1) / 2]`.
+fn to_bigint<E: Environment>(value: &console::Field<E::Network>) -> BigInt {
+    let value = to_biguint::<E>(value);
+    let modulus = modulus::<E>();
+    match value > (&modulus >> 1) {
+        true => BigInt::from(value) - BigInt::from(modulus),
+        false => BigInt::from(value),
+    }
+}
+
+/// Returns the native field element for the given signed integer, which must be less than the modulus in magnitude.
+fn to_field<E: Environment>(value: &BigInt) -> console::Field<E::Network> {
+    let (sign, magnitude) = value.clone().into_parts();
+    let bits_le = (0..magnitude.bits()).map(|i| magnitude.bit(i)).collect::<Vec<_>>();
+    let field =
+        console::Field::<E::Network>::from_bits_le(&bits_le).unwrap_or_else(|_| E::halt("Invalid native field value"));
+    match sign {
+        num_bigint::Sign::Minus => -field,
+        _ => field,
+    }
+}
+
+/// Returns the modulus of the native field.
+fn modulus<E: Environment>() -> BigUint {
+    to_biguint::<E>(&-console::Field::<E::Network>::one()) + 1u32
+}
@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A term in an expression over foreign field elements.
+pub(crate) enum Term<'a, E: Environment> {
+    /// The term `c * a * b`, for a small coefficient `c`.
+    Product(i64, &'a ForeignField<E>, &'a ForeignField<E>),
+    /// The term `c * a`, for a small coefficient `c`.
+    Linear(i64, &'a ForeignField<E>),
+}
+
+impl<E: Environment> Term<'_, E> {
+    /// Returns `true` if the operands of the term are constants.
+    fn is_constant(&self) -> bool {
+        match self {
+            Self::Product(_, a, b) => a.is_constant() && b.is_constant(),
+            Self::Linear(_, a) => a.is_constant(),
+        }
+    }
+
+    /// Returns the value of the term.
+    fn eject_value(&self) -> BigInt {
+        match self {
+            Self::Product(c, a, b) => BigInt::from(*c) * BigInt::from(a.eject_value() * b.eject_value()),
+            Self::Linear(c, a) => BigInt::from(*c) * BigInt::from(a.eject_value()),
+        }
+    }
+}
+
+impl<E: Environment> ForeignField<E> {
+    /// Returns `r = (sum(terms) + constant) mod modulus`.
+    ///
+    /// The output is only guaranteed to be congruent to the expression, and in `[0, 2^256)`.
+    pub(crate) fn reduce(terms: &[Term<E>], constant: &BigInt, modulus: &BigUint) -> Self {
+        // Compute the reduced value of the expression.
+        let value = Self::evaluate(terms, constant, modulus) % modulus;
+        // If all of the terms are constant, return a constant.
+        if terms.iter().all(|term| term.is_constant()) {
+            return Self::constant(&value);
+        }
+        // Initialize the remainder, and enforce the expression is congruent to it.
+        let remainder = Self::new(Mode::Private, &value);
+        Self::enforce(terms, constant, Some(&remainder), modulus);
+        remainder
+    }
+
+    /// Enforces `sum(terms) + constant = 0 mod modulus`.
+    pub(crate) fn assert_zero(terms: &[Term<E>], constant: &BigInt, modulus: &BigUint) {
+        // If all of the terms are constant, check the expression directly.
+        if terms.iter().all(|term| term.is_constant()) {
+            if Self::evaluate(terms, constant, modulus) % modulus != BigUint::default() {
+                E::halt("Foreign field expression is not equal to zero")
+            }
+            return;
+        }
+        Self::enforce(terms, constant, None, modulus);
+    }
+
+    /// Returns the value of `sum(terms) + constant + offset`, where the offset is the
+    /// multiple of the modulus from `Self::offset` that makes the expression non-negative.
+    fn evaluate(terms: &[Term<E>], constant: &BigInt, modulus: &BigUint) -> BigUint {
+        let value = terms.iter().map(|term| term.eject_value()).sum::<BigInt>()
+            + BigInt::from(Self::offset(terms, constant, modulus));
+        value.to_biguint().unwrap_or_else(|| E::halt("Foreign field expression is negative"))
+    }
+
+    /// Returns `constant mod modulus`, plus the least multiple of the modulus that is
+    /// at least the magnitude of the negative terms.
+    fn offset(terms: &[Term<E>], constant: &BigInt, modulus: &BigUint) -> BigUint {
+        let (_, negative) = Self::bounds(terms);
+        let constant =
+            (constant % BigInt::from(modulus.clone()) + BigInt::from(modulus.clone())) % BigInt::from(modulus.clone());
+        constant.to_biguint().unwrap_or_default() + (negative + modulus - 1u32) / modulus * modulus
+    }
+
+    /// Returns the bounds on the positive and negative parts of the terms.
+    fn bounds(terms: &[Term<E>]) -> (BigUint, BigUint) {
+        let max = (BigUint::from(1u32) << SIZE_IN_BITS) - 1u32;
+        let (mut positive, mut negative) = (BigUint::default(), BigUint::default());
+        for term in terms {
+            let (coefficient, bound) = match term {
+                Term::Product(c, _, _) => (*c, &max * &max),
+                Term::Linear(c, _) => (*c, max.clone()),
+            };
+            match coefficient >= 0 {
+                true => positive += bound * coefficient.unsigned_abs(),
+                false => negative += bound * coefficient.unsigned_abs(),
+            }
+        }
+        (positive, negative)
+    }
+
+    /// Enforces `sum(terms) + constant = quotient * modulus + remainder` over the integers,
+    /// where the remainder is zero if it is not given.
+    ///
+    /// The identity is checked limb-wise: the coefficient of each power of `2^64` is computed as
+    /// a native field element, and pairs of coefficients are combined with a signed carry that is
+    /// range-checked, such that no native field element can wrap around the native modulus.
+    fn enforce(terms: &[Term<E>], constant: &BigInt, remainder: Option<&Self>, modulus: &BigUint) {
+        let limb_max = BigInt::from(u64::MAX);
+        let modulus_limbs = to_limbs(modulus);
+
+        // Compute the constant, and the bound on the quotient.
+        let offset = Self::offset(terms, constant, modulus);
+        let (positive, _) = Self::bounds(terms);
+        let quotient_bits = ((positive + &offset) / modulus).bits().max(1) as usize;
+
+        // Compute the quotient.
+        let quotient = {
+            let value = Self::evaluate(terms, constant, modulus);
+            let remainder = remainder.map(|remainder| remainder.eject_value()).unwrap_or_default();
+            match value >= remainder {
+                true => (value - remainder) / modulus,
+                false => BigUint::default(),
+            }
+        };
+        let quotient_bits_le =
+            (0..quotient_bits).map(|i| Boolean::new(Mode::Private, quotient.bit(i as u64))).collect::<Vec<_>>();
+        let quotient_limbs = quotient_bits_le.chunks(LIMB_SIZE_IN_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        let quotient_limb_bounds = quotient_bits_le
+            .chunks(LIMB_SIZE_IN_BITS)
+            .map(|chunk| (BigInt::from(1u32) << chunk.len()) - 1u32)
+            .collect::<Vec<_>>();
+
+        // Initialize the coefficients, and their positive and negative bounds.
+        let offset_limbs = to_limbs(&offset);
+        let num_coefficients =
+            (2 * NUM_LIMBS - 1).max(quotient_limbs.len() + NUM_LIMBS - 1).max(offset_limbs.len()).max(NUM_LIMBS);
+        let mut coefficients = vec![Field::<E>::zero(); num_coefficients];
+        let mut positive = vec![BigInt::default(); num_coefficients];
+        let mut negative = vec![BigInt::default(); num_coefficients];
+
+        // Add the terms.
+        for term in terms {
+            match term {
+                Term::Product(c, a, b) => {
+                    let c_field = Field::constant(to_field::<E>(&BigInt::from(*c)));
+                    let bound = &limb_max * &limb_max * c.unsigned_abs();
+                    for (i, a_i) in a.limbs.iter().enumerate() {
+                        for (j, b_j) in b.limbs.iter().enumerate() {
+                            coefficients[i + j] += a_i * b_j * &c_field;
+                            match *c >= 0 {
+                                true => positive[i + j] += &bound,
+                                false => negative[i + j] += &bound,
+                            }
+                        }
+                    }
+                }
+                Term::Linear(c, a) => {
+                    let c_field = Field::constant(to_field::<E>(&BigInt::from(*c)));
+                    let bound = &limb_max * c.unsigned_abs();
+                    for (i, a_i) in a.limbs.iter().enumerate() {
+                        coefficients[i] += a_i * &c_field;
+                        match *c >= 0 {
+                            true => positive[i] += &bound,
+                            false => negative[i] += &bound,
+                        }
+                    }
+                }
+            }
+        }
+        // Add the constant.
+        for (i, limb) in offset_limbs.iter().enumerate() {
+            coefficients[i] += Field::constant(console::Field::from_u64(*limb));
+            positive[i] += BigInt::from(*limb);
+        }
+        // Subtract the product of the quotient and the modulus.
+        for (i, (q_i, q_bound)) in quotient_limbs.iter().zip(&quotient_limb_bounds).enumerate() {
+            for (j, p_j) in modulus_limbs.iter().enumerate() {
+                coefficients[i + j] -= q_i * Field::constant(console::Field::from_u64(*p_j));
+                negative[i + j] += q_bound * BigInt::from(*p_j);
+            }
+        }
+        // Subtract the remainder.
+        if let Some(remainder) = remainder {
+            for (i, r_i) in remainder.limbs.iter().enumerate() {
+                coefficients[i] -= r_i;
+                negative[i] += &limb_max;
+            }
+        }
+
+        // Check the coefficients sum to zero, two at a time.
+        let shift = Field::constant(console::Field::from_u128(1u128 << LIMB_SIZE_IN_BITS));
+        let (mut carry, mut carry_positive, mut carry_negative) = (Field::zero(), BigInt::default(), BigInt::default());
+        let max_bits = E::BaseField::size_in_data_bits() as u64 - 2;
+        let num_chunks = (num_coefficients + 1) / 2;
+        for (index, ((coefficients, positive), negative)) in
+            coefficients.chunks(2).zip(positive.chunks(2)).zip(negative.chunks(2)).enumerate()
+        {
+            // Compute the sum of the coefficients with the incoming carry.
+            let mut sum = &coefficients[0] + &carry;
+            let mut sum_positive = &positive[0] + &carry_positive;
+            let mut sum_negative = &negative[0] + &carry_negative;
+            if coefficients.len() == 2 {
+                sum += &coefficients[1] * &shift;
+                sum_positive += &positive[1] << LIMB_SIZE_IN_BITS;
+                sum_negative += &negative[1] << LIMB_SIZE_IN_BITS;
+            }
+            // Ensure the sum cannot wrap around the native modulus.
+            if sum_positive.bits().max(sum_negative.bits()) > max_bits {
+                E::halt("Foreign field expression exceeds the native field capacity")
+            }
+            // If this is the last chunk, enforce the sum is zero.
+            if index == num_chunks - 1 {
+                E::assert_eq(&sum, Field::<E>::zero());
+                break;
+            }
+            // Compute the outgoing carry, shifted to be non-negative.
+            carry_positive = sum_positive >> (2 * LIMB_SIZE_IN_BITS);
+            carry_negative = sum_negative >> (2 * LIMB_SIZE_IN_BITS);
+            let carry_bits = (&carry_positive + &carry_negative).bits().max(1);
+            let shifted = ((to_bigint::<E>(&sum.eject_value()) >> (2 * LIMB_SIZE_IN_BITS)) + &carry_negative)
+                .to_biguint()
+                .unwrap_or_default();
+            let shifted_bits_le =
+                (0..carry_bits).map(|i| Boolean::new(Mode::Private, shifted.bit(i))).collect::<Vec<_>>();
+            carry = Field::from_bits_le(&shifted_bits_le) - Field::constant(to_field::<E>(&carry_negative));
+            // Enforce the sum is equal to the carry times `2^128`.
+            E::assert_eq(&sum, &carry * &shift * &shift);
+        }
+    }
+}
+
+/// Returns the little-endian 64-bit limbs of the given integer.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    value.to_u64_digits()
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::{Rng, TestRng};
+    use snarkvm_circuit_types::environment::{assert_scope, Circuit};
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the secp256k1 base field modulus.
+    fn modulus() -> BigUint {
+        console::Secp256k1Point::base_field_modulus()
+    }
+
+    /// Samples a random integer below `2^256`.
+    fn sample(rng: &mut TestRng) -> BigUint {
+        BigUint::from_bytes_le(&rng.gen::<[u8; 32]>())
+    }
+
+    fn check_mul(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let rng = &mut TestRng::default();
+        let p = modulus();
+
+        for i in 0..ITERATIONS {
+            let (a, b) = (sample(rng), sample(rng));
+            let expected = (&a * &b) % &p;
+
+            let a = ForeignField::<Circuit>::new(mode_a, &a);
+            let b = ForeignField::<Circuit>::new(mode_b, &b);
+
+            Circuit::scope(format!("Mul {mode_a} {mode_b} {i}"), || {
+                let candidate = ForeignField::reduce(&[Term::Product(1, &a, &b)], &BigInt::default(), &p);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        check_mul(Mode::Constant, Mode::Constant, 256, 0, 0, 0);
+        check_mul(Mode::Constant, Mode::Private, 25, 0, 712, 716);
+        check_mul(Mode::Private, Mode::Private, 25, 0, 728, 732);
+    }
+
+    #[test]
+    fn test_expression() {
+        let rng = &mut TestRng::default();
+        let p = modulus();
+
+        for _ in 0..ITERATIONS {
+            let values = (0..4).map(|_| sample(rng)).collect::<Vec<_>>();
+            let [a, b, c, d] = [0, 1, 2, 3].map(|i| ForeignField::<Circuit>::new(Mode::Private, &values[i]));
+            let constant = BigInt::from(rng.gen::<i64>());
+
+            // Compute `3ab - 2cd + 5a - c + constant`.
+            let expected = BigInt::from(3u32) * BigInt::from(&values[0] * &values[1])
+                - BigInt::from(2u32) * BigInt::from(&values[2] * &values[3])
+                + BigInt::from(5u32) * BigInt::from(values[0].clone())
+                - BigInt::from(values[2].clone())
+                + &constant;
+            let p_int = BigInt::from(p.clone());
+            let expected = ((expected % &p_int + &p_int) % &p_int).to_biguint().unwrap();
+
+            let terms =
+                [Term::Product(3, &a, &b), Term::Product(-2, &c, &d), Term::Linear(5, &a), Term::Linear(-1, &c)];
+            let candidate = ForeignField::reduce(&terms, &constant, &p);
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure the difference of the expression and the result is zero.
+            let terms = [
+                Term::Product(3, &a, &b),
+                Term::Product(-2, &c, &d),
+                Term::Linear(5, &a),
+                Term::Linear(-1, &c),
+                Term::Linear(-1, &candidate),
+            ];
+            ForeignField::assert_zero(&terms, &constant, &p);
+            assert!(Circuit::is_satisfied());
+
+            // Ensure an incorrect result is rejected.
+            let wrong = ForeignField::new(Mode::Private, &((expected + 1u32) % &p));
+            let terms = [
+                Term::Product(3, &a, &b),
+                Term::Product(-2, &c, &d),
+                Term::Linear(5, &a),
+                Term::Linear(-1, &c),
+                Term::Linear(-1, &wrong),
+            ];
+            ForeignField::assert_zero(&terms, &constant, &p);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -18,9 +18,17 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod ecdsa;
+pub use ecdsa::ECDSASecp256k1;
+
+pub mod ed25519;
+pub use ed25519::Ed25519;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+mod foreign;
+
 pub mod keccak;
 pub use keccak::*;
 
@@ -30,5 +38,8 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha512;
+pub use sha512::*;
+
 pub mod traits;
 pub use traits::*;
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha512<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-512 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Initialize the hash value `H^(0)`.
+        let mut state = self.initial_hash.clone();
+
+        // Process each of the padded message blocks `M^(i)`.
+        for block in Self::pad(input) {
+            state = self.compress(&state, block);
+        }
+
+        // Output the hash value `H^(N)`, with each word encoded in big-endian byte order.
+        state.iter().flat_map(|word| Self::to_bits_be_bytes(word)).collect()
+    }
+}
+
+impl<E: Environment> Sha512<E> {
+    /// Pads the message to a multiple of the block size, defined as `M || 0x80 || 0x00…0x00 || ℓ`,
+    /// where `M` is the input data, and `ℓ` is the length of `M` in bits, encoded as a 128-bit big-endian integer.
+    /// The padded message is returned as a sequence of blocks, each consisting of 16 words.
+    fn pad(input: &[Boolean<E>]) -> Vec<Vec<U64<E>>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Record the message length `ℓ` in bits.
+        let length = padded_input.len() as u128;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend((0..8).map(|i| Boolean::constant(i == 7)));
+
+        // Step 2: Append "0" bytes until the length of the message is congruent to 112 mod 128 bytes.
+        while (padded_input.len() / 8) % BLOCK_SIZE_IN_BYTES != BLOCK_SIZE_IN_BYTES - 16 {
+            padded_input.push(Boolean::constant(false));
+        }
+
+        // Step 3: Append the message length `ℓ`, as a 128-bit big-endian integer.
+        for byte in length.to_be_bytes() {
+            padded_input.extend((0..8).map(|i| Boolean::constant(byte >> i & 1 == 1)));
+        }
+
+        // Construct the padded blocks, where each word is read in big-endian byte order.
+        padded_input
+            .chunks(BLOCK_SIZE_IN_BYTES * 8)
+            .map(|block| block.chunks(WORD_SIZE_IN_BYTES * 8).map(Self::from_bits_be_bytes).collect())
+            .collect()
+    }
+
+    /// The compression function, which updates the hash value `H^(i-1)` with the message block `M^(i)`.
+    fn compress(&self, state: &[U64<E>], block: Vec<U64<E>>) -> Vec<U64<E>> {
+        debug_assert_eq!(state.len(), 8, "The state must have 8 words");
+        debug_assert_eq!(block.len(), 16, "The block must have 16 words");
+
+        // Prepare the message schedule `W_t`.
+        let mut w = block;
+        for t in 16..NUM_ROUNDS {
+            // W_t = σ1(W_{t-2}) + W_{t-7} + σ0(W_{t-15}) + W_{t-16}
+            let sigma_1 = Self::sigma(&w[t - 2], 19, 61, 6);
+            let sigma_0 = Self::sigma(&w[t - 15], 1, 8, 7);
+            w.push(Self::sum(&[&sigma_1, &w[t - 7], &sigma_0, &w[t - 16]]));
+        }
+
+        // Initialize the working variables with the hash value `H^(i-1)`.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] =
+            [0, 1, 2, 3, 4, 5, 6, 7].map(|i| state[i].clone());
+
+        for (round_constant, w_t) in self.round_constants.iter().zip_eq(&w) {
+            // T1 = h + Σ1(e) + Ch(e, f, g) + K_t + W_t
+            let big_sigma_1 = Self::big_sigma(&e, 14, 18, 41);
+            let ch = Self::ch(&e, &f, &g);
+            // T2 = Σ0(a) + Maj(a, b, c)
+            let big_sigma_0 = Self::big_sigma(&a, 28, 34, 39);
+            let maj = Self::maj(&a, &b, &c);
+
+            // The additions are combined, so that each new word is only decomposed into bits once.
+            let t1 = [&h, &big_sigma_1, &ch, round_constant, w_t];
+            // e = d + T1
+            let new_e = Self::sum(&[&[&d][..], &t1].concat());
+            // a = T1 + T2
+            let new_a = Self::sum(&[&t1[..], &[&big_sigma_0, &maj]].concat());
+
+            h = g;
+            g = f;
+            f = e;
+            e = new_e;
+            d = c;
+            c = b;
+            b = a;
+            a = new_a;
+        }
+
+        // Compute the hash value `H^(i)`.
+        [a, b, c, d, e, f, g, h].iter().zip_eq(state).map(|(word, previous)| Self::sum(&[previous, word])).collect()
+    }
+
+    /// Returns `Σ(x) = ROTR^m(x) ⊕ ROTR^n(x) ⊕ ROTR^o(x)`.
+    fn big_sigma(x: &U64<E>, m: usize, n: usize, o: usize) -> U64<E> {
+        &(&Self::rotate_right(x, m) ^ &Self::rotate_right(x, n)) ^ &Self::rotate_right(x, o)
+    }
+
+    /// Returns `σ(x) = ROTR^m(x) ⊕ ROTR^n(x) ⊕ SHR^o(x)`.
+    fn sigma(x: &U64<E>, m: usize, n: usize, o: usize) -> U64<E> {
+        &(&Self::rotate_right(x, m) ^ &Self::rotate_right(x, n)) ^ &Self::shift_right(x, o)
+    }
+
+    /// Returns `Ch(x, y, z) = (x ∧ y) ⊕ (¬x ∧ z)`, which selects the bit of `y` if `x` is set, and of `z` otherwise.
+    fn ch(x: &U64<E>, y: &U64<E>, z: &U64<E>) -> U64<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le().iter().zip_eq(z.to_bits_le()))
+            .map(|(x, (y, z))| Boolean::ternary(x, y, &z))
+            .collect::<Vec<_>>();
+        U64::from_bits_le(&bits_le)
+    }
+
+    /// Returns `Maj(x, y, z) = (x ∧ y) ⊕ (x ∧ z) ⊕ (y ∧ z)`, which selects the bit of `z` if `x` and `y` differ, and of `x` otherwise.
+    fn maj(x: &U64<E>, y: &U64<E>, z: &U64<E>) -> U64<E> {
+        let bits_le = x
+            .to_bits_le()
+            .iter()
+            .zip_eq(y.to_bits_le().iter().zip_eq(z.to_bits_le()))
+            .map(|(x, (y, z))| Boolean::ternary(&(x ^ y), &z, x))
+            .collect::<Vec<_>>();
+        U64::from_bits_le(&bits_le)
+    }
+
+    /// Returns the sum of the given words, modulo `2^64`.
+    fn sum(words: &[&U64<E>]) -> U64<E> {
+        debug_assert!(words.len() > 1 && words.len() <= 8, "The number of words must be in [2, 8]");
+
+        // Instead of adding the words pairwise, the words are converted into field elements, and summed,
+        // before converting back to a word once. This is safe as the field is larger than the sum.
+        let sum = words.iter().map(|word| word.to_field()).fold(Field::zero(), |acc, word| acc + word);
+        // Extract the word bits from the field element, with up to 3 carry bits.
+        let bits_le = sum.to_lower_bits_le(64 + 3);
+        // Drop the carry bits as the operation is wrapped addition.
+        U64::from_bits_le(&bits_le[..64])
+    }
+
+    /// Performs a rotate right operation on the given `u64` value.
+    fn rotate_right(value: &U64<E>, n: usize) -> U64<E> {
+        // Perform the rotation on the little-endian bits.
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        // Return the rotated value.
+        U64::from_bits_le(&bits_le)
+    }
+
+    /// Performs a shift right operation on the given `u64` value.
+    fn shift_right(value: &U64<E>, n: usize) -> U64<E> {
+        // Perform the shift on the little-endian bits.
+        let mut bits_le = value.to_bits_le();
+        bits_le.drain(..n);
+        bits_le.resize(64, Boolean::constant(false));
+        // Return the shifted value.
+        U64::from_bits_le(&bits_le)
+    }
+
+    /// Returns the word from the given bits, which encode the word in big-endian byte order (with little-endian bits).
+    fn from_bits_be_bytes(bits: &[Boolean<E>]) -> U64<E> {
+        debug_assert_eq!(bits.len(), 64, "The word must have 64 bits");
+        U64::from_bits_le(&bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+    }
+
+    /// Returns the bits of the given word, which encode the word in big-endian byte order (with little-endian bits).
+    fn to_bits_be_bytes(word: &U64<E>) -> Vec<Boolean<E>> {
+        word.to_bits_le().chunks(8).rev().flatten().cloned().collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        use console::Hash as H;
+
+        let native = console::Sha512;
+        let sha512 = Sha512::<Circuit>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Sha512 {mode} {i}"), || {
+                let candidate = sha512.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sha512_hash_constant() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Constant, 0, 15544, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 1, 15544, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 8, 15544, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 512, 15544, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 1024, 31088, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_sha512_hash_public() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Public, 1, 201, 0, 63371, 63600, &mut rng);
+        check_hash(Mode::Public, 8, 201, 0, 63371, 63600, &mut rng);
+        check_hash(Mode::Public, 512, 0, 0, 65148, 65380, &mut rng);
+        check_hash(Mode::Public, 1024, 4288, 0, 113456, 113856, &mut rng);
+    }
+
+    #[test]
+    fn test_sha512_hash_private() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Private, 1, 201, 0, 63371, 63600, &mut rng);
+        check_hash(Mode::Private, 8, 201, 0, 63371, 63600, &mut rng);
+        check_hash(Mode::Private, 512, 0, 0, 65148, 65380, &mut rng);
+        check_hash(Mode::Private, 1024, 4288, 0, 113456, 113856, &mut rng);
+    }
+
+    #[test]
+    fn test_sha512_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        for num_inputs in [0, 1, 7, 8, 440, 888, 896, 1016, 1024, 1032, 2048] {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let expected = console::Sha512.hash(&native_input).expect("Failed to hash console input");
+
+            // Compute the circuit hash.
+            let candidate = Sha512::<Circuit>::new().hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            Circuit::reset();
+        }
+    }
+}
@@ -16,6 +16,8 @@ use crate::Aleo;
 use snarkvm_circuit_algorithms::{
     Commit,
     CommitUncompressed,
+    ECDSASecp256k1,
+    Ed25519,
     Hash,
     HashMany,
     HashToGroup,
@@ -44,6 +46,7 @@ use snarkvm_circuit_types::{
     Field,
     Group,
     Scalar,
+    U8,
 };
 
 use core::fmt;
@@ -95,6 +98,11 @@ thread_local! {
     static SHA3_384: Sha3_384<AleoCanaryV0> = Sha3_384::<AleoCanaryV0>::new();
     /// The SHA-3 hash function, which outputs 512 bits.
     static SHA3_512: Sha3_512<AleoCanaryV0> = Sha3_512::<AleoCanaryV0>::new();
+
+    /// The ECDSA signature scheme over the secp256k1 curve.
+    static ECDSA_SECP256K1: ECDSASecp256k1<AleoCanaryV0> = ECDSASecp256k1::<AleoCanaryV0>::new();
+    /// The Ed25519 signature scheme.
+    static ED25519: Ed25519<AleoCanaryV0> = Ed25519::<AleoCanaryV0>::new();
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -377,6 +385,16 @@ impl Aleo for AleoCanaryV0 {
     ) -> Boolean<Self> {
         POSEIDON_4.with(|psd4| POSEIDON_2.with(|psd2| path.verify(psd4, psd2, root, leaf)))
     }
+
+    /// Returns `true` if the given ECDSA secp256k1 signature is valid for the given Ethereum address and message.
+    fn verify_ecdsa_secp256k1(signature: &[U8<Self>], address: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self> {
+        ECDSA_SECP256K1.with(|ecdsa| ecdsa.verify(signature, address, message))
+    }
+
+    /// Returns `true` if the given Ed25519 signature is valid for the given public key and message.
+    fn verify_ed25519(signature: &[U8<Self>], public_key: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self> {
+        ED25519.with(|ed25519| ed25519.verify(signature, public_key, message))
+    }
 }
 
 impl Environment for AleoCanaryV0 {
@@ -25,7 +25,7 @@ pub mod v0;
 pub use v0::*;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar, U8};
 
 /// Attention: Do not use `Send + Sync` on this trait, as it is not thread-safe.
 pub trait Aleo: Environment {
@@ -188,4 +188,10 @@ pub trait Aleo: Environment {
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
+
+    /// Returns `true` if the given ECDSA secp256k1 signature is valid for the given Ethereum address and message.
+    fn verify_ecdsa_secp256k1(signature: &[U8<Self>], address: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self>;
+
+    /// Returns `true` if the given Ed25519 signature is valid for the given public key and message.
+    fn verify_ed25519(signature: &[U8<Self>], public_key: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self>;
 }
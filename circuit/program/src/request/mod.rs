@@ -34,6 +34,8 @@ pub enum InputID<A: Aleo> {
     Record(Field<A>, Box<Group<A>>, Field<A>, Field<A>),
     /// The hash of the external record input.
     ExternalRecord(Field<A>),
+    /// The `(commitment, gamma, serial_number, tag)` tuple of the program-owned record input.
+    ProgramRecord(Field<A>, Box<Group<A>>, Field<A>, Field<A>),
 }
 
 #[cfg(console)]
@@ -58,6 +60,13 @@ impl<A: Aleo> Inject for InputID<A> {
             ),
             // Inject the commitment as `Mode::Public`.
             console::InputID::ExternalRecord(field) => Self::ExternalRecord(Field::new(Mode::Public, field)),
+            // Inject commitment and gamma as `Mode::Private`, and the expected serial number and tag as `Mode::Public`.
+            console::InputID::ProgramRecord(commitment, gamma, serial_number, tag) => Self::ProgramRecord(
+                Field::new(Mode::Private, commitment),
+                Box::new(Group::new(Mode::Private, gamma)),
+                Field::new(Mode::Public, serial_number),
+                Field::new(Mode::Public, tag),
+            ),
         }
     }
 }
@@ -78,6 +87,11 @@ impl<A: Aleo> Eject for InputID<A> {
                 tag.eject_mode(),
             ]),
             Self::ExternalRecord(field) => field.eject_mode(),
+            Self::ProgramRecord(commitment, gamma, serial_number, tag) => Mode::combine(commitment.eject_mode(), [
+                gamma.eject_mode(),
+                serial_number.eject_mode(),
+                tag.eject_mode(),
+            ]),
         }
     }
 
@@ -94,6 +108,12 @@ impl<A: Aleo> Eject for InputID<A> {
                 tag.eject_value(),
             ),
             Self::ExternalRecord(field) => console::InputID::ExternalRecord(field.eject_value()),
+            Self::ProgramRecord(commitment, gamma, serial_number, tag) => console::InputID::ProgramRecord(
+                commitment.eject_value(),
+                gamma.eject_value(),
+                serial_number.eject_value(),
+                tag.eject_value(),
+            ),
         }
     }
 }
@@ -111,6 +131,9 @@ impl<A: Aleo> ToFields for InputID<A> {
                 vec![commitment.clone(), gamma.to_x_coordinate(), serial_number.clone(), tag.clone()]
             }
             InputID::ExternalRecord(field) => vec![field.clone()],
+            InputID::ProgramRecord(commitment, gamma, serial_number, tag) => {
+                vec![commitment.clone(), gamma.to_x_coordinate(), serial_number.clone(), tag.clone()]
+            }
         }
     }
 }
@@ -204,6 +227,15 @@ impl<A: Aleo> Inject for Request<A> {
                         // Return the input.
                         Ok(input)
                     }
+                    // A program record input is injected as `Mode::Private`.
+                    console::InputID::ProgramRecord(..) => {
+                        // Inject the input as `Mode::Private`.
+                        let input = Value::new(Mode::Private, input.clone());
+                        // Ensure the input is a record.
+                        ensure!(matches!(input, Value::Record(..)), "Expected a program record input");
+                        // Return the input.
+                        Ok(input)
+                    }
                 }
             })
             .collect::<Result<Vec<_>, _>>()
@@ -20,6 +20,9 @@ impl<A: Aleo> Request<A> {
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// Note: A program record input is a *public* spend, as its `gamma` is fixed to `H`.
+    /// Its serial number and tag are computable by anyone who knows the record.
     pub fn verify(
         &self,
         input_types: &[console::ValueType<A::Network>],
@@ -118,6 +121,39 @@ impl<A: Aleo> Request<A> {
         tvk: &Field<A>,
         tcm: &Field<A>,
         signature: Option<&Signature<A>>,
+    ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
+        // Compute the function ID.
+        let function_id = compute_function_id(network_id, program_id, function_name);
+
+        // Check the input IDs.
+        Self::check_input_ids_with_function_id::<CREATE_MESSAGE>(
+            &function_id,
+            Some(program_id),
+            input_ids,
+            inputs,
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            signature,
+        )
+    }
+
+    /// Returns `true` if the inputs match their input IDs, given the function ID.
+    /// If the program ID is not given, the inputs must not contain records.
+    /// Note: This method does **not** perform signature checks.
+    pub fn check_input_ids_with_function_id<const CREATE_MESSAGE: bool>(
+        function_id: &Field<A>,
+        program_id: Option<&ProgramID<A>>,
+        input_ids: &[InputID<A>],
+        inputs: &[Value<A>],
+        input_types: &[console::ValueType<A::Network>],
+        signer: &Address<A>,
+        sk_tag: &Field<A>,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        signature: Option<&Signature<A>>,
     ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
         // Ensure the signature response matches the `CREATE_MESSAGE` flag.
         match CREATE_MESSAGE {
@@ -125,9 +161,6 @@ impl<A: Aleo> Request<A> {
             false => assert!(signature.is_none()),
         }
 
-        // Compute the function ID.
-        let function_id = compute_function_id(network_id, program_id, function_name);
-
         // Initialize a vector for a message.
         let mut message = Vec::new();
 
@@ -224,6 +257,11 @@ impl<A: Aleo> Request<A> {
                             // Ensure the input is a record.
                             _ => A::halt(format!("Expected a record input at input {index}")),
                         };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt("Expected a program ID to check a record input"),
+                        };
                         // Compute the record commitment.
                         let candidate_commitment = record.to_commitment(program_id, &record_name);
                         // Compute the `candidate_serial_number` from `gamma`.
@@ -292,6 +330,55 @@ impl<A: Aleo> Request<A> {
                         // Ensure the expected hash matches the computed hash.
                         input_hash.is_equal(&A::hash_psd8(&preimage))
                     }
+                    // A program record input is computed to its serial number.
+                    // Note: Ownership of a program record is checked against the parent program by the caller.
+                    InputID::ProgramRecord(commitment, gamma, serial_number, tag) => {
+                        // Retrieve the record.
+                        let record = match &input {
+                            Value::Record(record) => record,
+                            // Ensure the input is a record.
+                            Value::Plaintext(..) => A::halt("Expected a record input, found a plaintext input"),
+                            Value::Future(..) => A::halt("Expected a record input, found a future input"),
+                        };
+                        // Retrieve the record name as a `Mode::Constant`.
+                        let record_name = match input_type {
+                            console::ValueType::ProgramRecord(record_name) => Identifier::constant(*record_name),
+                            // Ensure the input is a program record.
+                            _ => A::halt(format!("Expected a program record input at input {index}")),
+                        };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt("Expected a program ID to check a program record input"),
+                        };
+                        // Compute the record commitment.
+                        let candidate_commitment = record.to_commitment(program_id, &record_name);
+                        // Compute the generator `H` as `HashToGroup(commitment)`.
+                        let h = A::hash_to_group_psd2(&[A::serial_number_domain(), candidate_commitment.clone()]);
+                        // Compute the `candidate_serial_number` from `gamma`.
+                        let candidate_serial_number =
+                            Record::<A, Plaintext<A>>::serial_number_from_gamma(gamma, candidate_commitment.clone());
+                        // Compute the tag, using the owner address in place of `sk_tag`.
+                        let candidate_tag = Record::<A, Plaintext<A>>::tag(
+                            record.owner().deref().to_field(),
+                            candidate_commitment.clone(),
+                        );
+
+                        if CREATE_MESSAGE {
+                            // Add (`serial_number`, `tag`) to the message.
+                            message.push(serial_number.clone());
+                            message.push(candidate_tag.clone());
+                        }
+
+                        // Ensure the candidate serial number matches the expected serial number.
+                        serial_number.is_equal(&candidate_serial_number)
+                            // Ensure the candidate commitment matches the expected commitment.
+                            & commitment.is_equal(&candidate_commitment)
+                            // Ensure `gamma` is the generator `H`.
+                            & gamma.is_equal(&h)
+                            // Ensure the candidate tag matches the expected tag.
+                            & tag.is_equal(&candidate_tag)
+                    }
                 }
             })
             .fold(Boolean::constant(true), |acc, x| acc & x);
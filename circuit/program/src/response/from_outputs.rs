@@ -165,6 +165,8 @@ impl<A: Aleo> Response<A> {
                             Value::Record(..) => A::halt("Expected a future output, found a record output"),
                         }
                     }
+                    // A program record is not a valid output.
+                    console::ValueType::ProgramRecord(..) => A::halt("A program record is not a valid output"),
                 }
             })
             .collect();
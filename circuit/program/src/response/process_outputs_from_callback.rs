@@ -29,6 +29,29 @@ impl<A: Aleo> Response<A> {
         // Compute the function ID.
         let function_id = compute_function_id(network_id, program_id, function_name);
 
+        // Process the outputs.
+        Self::process_outputs_from_callback_with_function_id(
+            &function_id,
+            Some(program_id),
+            num_inputs,
+            tvk,
+            tcm,
+            outputs,
+            output_types,
+        )
+    }
+
+    /// Returns the injected circuit outputs, given the function ID, number of inputs, tvk, tcm, outputs, and output types.
+    /// If the program ID is not given, the outputs must not contain records.
+    pub fn process_outputs_from_callback_with_function_id(
+        function_id: &Field<A>,
+        program_id: Option<&ProgramID<A>>,
+        num_inputs: usize,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        outputs: Vec<console::Value<A::Network>>,        // Note: Console type
+        output_types: &[console::ValueType<A::Network>], // Note: Console type
+    ) -> Vec<Value<A>> {
         match outputs
             .iter()
             .zip_eq(output_types)
@@ -118,6 +141,11 @@ impl<A: Aleo> Response<A> {
                             Value::Plaintext(..) => A::halt("Expected a record output, found a plaintext output"),
                             Value::Future(..) => A::halt("Expected a record output, found a future output"),
                         };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt("Expected a program ID to process a record output"),
+                        };
                         // Compute the record commitment.
                         let commitment = record.to_commitment(program_id, &Identifier::constant(*record_name));
 
@@ -174,6 +202,8 @@ impl<A: Aleo> Response<A> {
                             Value::Record(..) => A::halt("Expected a future output, found a record output"),
                         }
                     }
+                    // A program record is not a valid output.
+                    console::ValueType::ProgramRecord(..) => A::halt("A program record is not a valid output"),
                 }
             })
             .collect::<Result<Vec<_>>>()
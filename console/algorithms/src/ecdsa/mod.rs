@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+pub use point::Secp256k1Point;
+
+mod sign;
+mod verify;
+
+use crate::{keccak_256_native, modular};
+use snarkvm_console_types::environment::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used by Ethereum.
+///
+/// A signature is the 64-byte compact encoding from EIP-2098, namely `r || (yParity * 2^255 + s)`,
+/// where `r` and `s` are 32-byte big-endian integers and `yParity` is the parity of the y-coordinate of `R`.
+/// The signer is identified by its 20-byte Ethereum address, which is recovered from the signature
+/// and the Keccak-256 digest of the message.
+///
+/// Following Ethereum, only signatures with a low `s` (i.e. `s <= (n - 1) / 2`) are accepted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSASecp256k1;
+
+impl ECDSASecp256k1 {
+    /// The number of bytes in an address.
+    pub const ADDRESS_SIZE_IN_BYTES: usize = 20;
+    /// The number of bytes in a private key.
+    pub const PRIVATE_KEY_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in a signature.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 64;
+
+    /// Returns the digest of the given message, as an integer modulo the group order.
+    fn digest(message: &[u8]) -> BigUint {
+        BigUint::from_bytes_be(&keccak_256_native(message)) % Secp256k1Point::scalar_field_modulus()
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The base field modulus `p = 2^256 - 2^32 - 977` of secp256k1.
+const BASE_FIELD_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F";
+/// The order `n` of the secp256k1 group.
+const SCALAR_FIELD_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+/// The x-coordinate of the secp256k1 generator.
+const GENERATOR_X: &str = "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798";
+/// The y-coordinate of the secp256k1 generator.
+const GENERATOR_Y: &str = "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8";
+/// The coefficient `b` in the curve equation `y^2 = x^3 + b`.
+const COEFFICIENT_B: u32 = 7;
+
+/// An affine point on the secp256k1 curve `y^2 = x^3 + 7`.
+///
+/// Note: This implementation is not constant-time, and is intended for public values only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Point {
+    /// The x-coordinate, or zero for the point at infinity.
+    x: BigUint,
+    /// The y-coordinate, or zero for the point at infinity.
+    y: BigUint,
+    /// A flag indicating whether this is the point at infinity.
+    is_zero: bool,
+}
+
+impl Secp256k1Point {
+    /// Returns the base field modulus `p`.
+    pub fn base_field_modulus() -> BigUint {
+        parse_hex(BASE_FIELD_MODULUS)
+    }
+
+    /// Returns the group order `n`.
+    pub fn scalar_field_modulus() -> BigUint {
+        parse_hex(SCALAR_FIELD_MODULUS)
+    }
+
+    /// Returns the coefficient `b` in the curve equation `y^2 = x^3 + b`.
+    pub fn coefficient_b() -> BigUint {
+        BigUint::from(COEFFICIENT_B)
+    }
+
+    /// Returns the point at infinity.
+    pub fn zero() -> Self {
+        Self { x: BigUint::default(), y: BigUint::default(), is_zero: true }
+    }
+
+    /// Returns the generator.
+    pub fn generator() -> Self {
+        Self { x: parse_hex(GENERATOR_X), y: parse_hex(GENERATOR_Y), is_zero: false }
+    }
+
+    /// Returns the point with the given coordinates, if it is on the curve.
+    pub fn new(x: BigUint, y: BigUint) -> Option<Self> {
+        let p = Self::base_field_modulus();
+        match x < p && y < p && Self::is_on_curve(&x, &y, &p) {
+            true => Some(Self { x, y, is_zero: false }),
+            false => None,
+        }
+    }
+
+    /// Returns the point with the given x-coordinate and y-coordinate parity, if it exists.
+    pub fn from_x(x: &BigUint, is_odd: bool) -> Option<Self> {
+        let p = Self::base_field_modulus();
+        if *x >= p {
+            return None;
+        }
+        // Compute `y^2 = x^3 + b`.
+        let y2 = modular::add(&modular::mul(&modular::mul(x, x, &p), x, &p), &Self::coefficient_b(), &p);
+        // Compute the candidate square root `y = (y^2)^((p + 1) / 4)`, as `p = 3 mod 4`.
+        let y = y2.modpow(&((&p + 1u32) >> 2), &p);
+        if modular::mul(&y, &y, &p) != y2 {
+            return None;
+        }
+        // Select the root with the requested parity.
+        let y = match y.bit(0) == is_odd {
+            true => y,
+            false => modular::neg(&y, &p),
+        };
+        Some(Self { x: x.clone(), y, is_zero: false })
+    }
+
+    /// Returns the x-coordinate.
+    pub fn x(&self) -> &BigUint {
+        &self.x
+    }
+
+    /// Returns the y-coordinate.
+    pub fn y(&self) -> &BigUint {
+        &self.y
+    }
+
+    /// Returns `true` if this is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.is_zero
+    }
+
+    /// Returns the negation of this point.
+    pub fn neg(&self) -> Self {
+        match self.is_zero {
+            true => self.clone(),
+            false => Self { x: self.x.clone(), y: modular::neg(&self.y, &Self::base_field_modulus()), is_zero: false },
+        }
+    }
+
+    /// Returns the sum of this point and the given point.
+    pub fn add(&self, other: &Self) -> Self {
+        let p = Self::base_field_modulus();
+        Jacobian::from_affine(self).add(&Jacobian::from_affine(other), &p).to_affine(&p)
+    }
+
+    /// Returns the double of this point.
+    pub fn double(&self) -> Self {
+        let p = Self::base_field_modulus();
+        Jacobian::from_affine(self).double(&p).to_affine(&p)
+    }
+
+    /// Returns the product of this point with the given scalar.
+    pub fn mul(&self, scalar: &BigUint) -> Self {
+        let p = Self::base_field_modulus();
+        let base = Jacobian::from_affine(self);
+        let mut output = Jacobian::zero();
+        for i in (0..scalar.bits()).rev() {
+            output = output.double(&p);
+            if scalar.bit(i) {
+                output = output.add(&base, &p);
+            }
+        }
+        output.to_affine(&p)
+    }
+
+    /// Returns the Ethereum address of this point, namely the last 20 bytes of `Keccak256(x || y)`.
+    pub fn to_address(&self) -> [u8; 20] {
+        let mut preimage = Vec::with_capacity(64);
+        preimage.extend_from_slice(&modular::to_bytes_be_32(&self.x));
+        preimage.extend_from_slice(&modular::to_bytes_be_32(&self.y));
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&keccak_256_native(&preimage)[12..]);
+        address
+    }
+
+    /// Returns `true` if `y^2 = x^3 + b`.
+    fn is_on_curve(x: &BigUint, y: &BigUint, p: &BigUint) -> bool {
+        let x3 = modular::mul(&modular::mul(x, x, p), x, p);
+        modular::mul(y, y, p) == modular::add(&x3, &Self::coefficient_b(), p)
+    }
+}
+
+/// Returns the integer encoded by the given hexadecimal string.
+fn parse_hex(string: &str) -> BigUint {
+    BigUint::parse_bytes(string.as_bytes(), 16).expect("Invalid hexadecimal constant")
+}
+
+/// A point in Jacobian coordinates `(X : Y : Z)`, representing the affine point `(X / Z^2, Y / Z^3)`.
+struct Jacobian {
+    x: BigUint,
+    y: BigUint,
+    z: BigUint,
+}
+
+impl Jacobian {
+    /// Returns the point at infinity.
+    fn zero() -> Self {
+        Self { x: BigUint::from(1u32), y: BigUint::from(1u32), z: BigUint::default() }
+    }
+
+    /// Returns the given affine point in Jacobian coordinates.
+    fn from_affine(point: &Secp256k1Point) -> Self {
+        match point.is_zero {
+            true => Self::zero(),
+            false => Self { x: point.x.clone(), y: point.y.clone(), z: BigUint::from(1u32) },
+        }
+    }
+
+    /// Returns this point in affine coordinates.
+    fn to_affine(&self, p: &BigUint) -> Secp256k1Point {
+        if self.is_zero() {
+            return Secp256k1Point::zero();
+        }
+        let z_inv = modular::inv(&self.z, p);
+        let z_inv2 = modular::mul(&z_inv, &z_inv, p);
+        let x = modular::mul(&self.x, &z_inv2, p);
+        let y = modular::mul(&self.y, &modular::mul(&z_inv2, &z_inv, p), p);
+        Secp256k1Point { x, y, is_zero: false }
+    }
+
+    /// Returns `true` if this is the point at infinity.
+    fn is_zero(&self) -> bool {
+        self.z == BigUint::default()
+    }
+
+    /// Returns the double of this point, using the "dbl-2009-l" formulas.
+    fn double(&self, p: &BigUint) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let a = modular::mul(&self.x, &self.x, p);
+        let b = modular::mul(&self.y, &self.y, p);
+        let c = modular::mul(&b, &b, p);
+        let x_plus_b = modular::add(&self.x, &b, p);
+        let d = modular::sub(&modular::sub(&modular::mul(&x_plus_b, &x_plus_b, p), &a, p), &c, p);
+        let d = modular::add(&d, &d, p);
+        let e = modular::mul(&a, &BigUint::from(3u32), p);
+        let f = modular::mul(&e, &e, p);
+        let x3 = modular::sub(&f, &modular::add(&d, &d, p), p);
+        let y3 = modular::sub(
+            &modular::mul(&e, &modular::sub(&d, &x3, p), p),
+            &modular::mul(&c, &BigUint::from(8u32), p),
+            p,
+        );
+        let z3 = modular::mul(&modular::add(&self.y, &self.y, p), &self.z, p);
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns the sum of this point and the given point, using the "add-2007-bl" formulas.
+    fn add(&self, other: &Self, p: &BigUint) -> Self {
+        if self.is_zero() {
+            return Self { x: other.x.clone(), y: other.y.clone(), z: other.z.clone() };
+        }
+        if other.is_zero() {
+            return Self { x: self.x.clone(), y: self.y.clone(), z: self.z.clone() };
+        }
+        let z1z1 = modular::mul(&self.z, &self.z, p);
+        let z2z2 = modular::mul(&other.z, &other.z, p);
+        let u1 = modular::mul(&self.x, &z2z2, p);
+        let u2 = modular::mul(&other.x, &z1z1, p);
+        let s1 = modular::mul(&modular::mul(&self.y, &other.z, p), &z2z2, p);
+        let s2 = modular::mul(&modular::mul(&other.y, &self.z, p), &z1z1, p);
+        let h = modular::sub(&u2, &u1, p);
+        let r = modular::sub(&s2, &s1, p);
+        // Handle the exceptional cases, where the x-coordinates are equal.
+        if h == BigUint::default() {
+            return match r == BigUint::default() {
+                true => self.double(p),
+                false => Self::zero(),
+            };
+        }
+        let r = modular::add(&r, &r, p);
+        let h2 = modular::add(&h, &h, p);
+        let i = modular::mul(&h2, &h2, p);
+        let j = modular::mul(&h, &i, p);
+        let v = modular::mul(&u1, &i, p);
+        let x3 = modular::sub(&modular::sub(&modular::mul(&r, &r, p), &j, p), &modular::add(&v, &v, p), p);
+        let s1j = modular::mul(&s1, &j, p);
+        let y3 = modular::sub(&modular::mul(&r, &modular::sub(&v, &x3, p), p), &modular::add(&s1j, &s1j, p), p);
+        let z1_plus_z2 = modular::add(&self.z, &other.z, p);
+        let z3 = modular::mul(
+            &modular::sub(&modular::sub(&modular::mul(&z1_plus_z2, &z1_plus_z2, p), &z1z1, p), &z2z2, p),
+            &h,
+            p,
+        );
+        Self { x: x3, y: y3, z: z3 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let generator = Secp256k1Point::generator();
+        assert!(Secp256k1Point::new(generator.x().clone(), generator.y().clone()).is_some());
+        assert_eq!(Secp256k1Point::from_x(generator.x(), generator.y().bit(0)), Some(generator.clone()));
+        // Ensure the generator has order `n`.
+        assert!(generator.mul(&Secp256k1Point::scalar_field_modulus()).is_zero());
+    }
+
+    #[test]
+    fn test_group_law() {
+        let rng = &mut TestRng::default();
+        let generator = Secp256k1Point::generator();
+
+        for _ in 0..10 {
+            let a = BigUint::from(rng.gen::<u64>());
+            let b = BigUint::from(rng.gen::<u64>());
+            let pa = generator.mul(&a);
+            let pb = generator.mul(&b);
+            // Check addition and doubling against scalar multiplication.
+            assert_eq!(pa.add(&pb), generator.mul(&(&a + &b)));
+            assert_eq!(pa.double(), generator.mul(&(&a * 2u32)));
+            assert_eq!(pa.add(&pa), pa.double());
+            assert!(pa.add(&pa.neg()).is_zero());
+            assert_eq!(pa.add(&Secp256k1Point::zero()), pa);
+        }
+    }
+}
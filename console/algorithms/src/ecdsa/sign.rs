@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl ECDSASecp256k1 {
+    /// Samples a random private key.
+    pub fn sample_private_key<R: Rng + CryptoRng>(rng: &mut R) -> [u8; 32] {
+        let n = Secp256k1Point::scalar_field_modulus();
+        loop {
+            let private_key: [u8; 32] = rng.gen();
+            let scalar = BigUint::from_bytes_be(&private_key);
+            if scalar != BigUint::default() && scalar < n {
+                return private_key;
+            }
+        }
+    }
+
+    /// Returns the Ethereum address of the given private key.
+    pub fn address(private_key: &[u8; 32]) -> Result<[u8; 20]> {
+        Ok(Secp256k1Point::generator().mul(&Self::private_key_to_scalar(private_key)?).to_address())
+    }
+
+    /// Returns a signature for the given message under the given private key.
+    ///
+    /// Note: This implementation is not constant-time, and should not be used with valuable keys.
+    pub fn sign<R: Rng + CryptoRng>(private_key: &[u8; 32], message: &[u8], rng: &mut R) -> Result<[u8; 64]> {
+        let n = Secp256k1Point::scalar_field_modulus();
+        let d = Self::private_key_to_scalar(private_key)?;
+        let z = Self::digest(message);
+
+        loop {
+            // Sample a random nonce `k`, and compute `R = k * G`.
+            let k = BigUint::from_bytes_be(&Self::sample_private_key(rng));
+            let point_r = Secp256k1Point::generator().mul(&k);
+            // Ensure `r` can be recovered from `R`.
+            let r = point_r.x().clone();
+            if r >= n || r == *Secp256k1Point::generator().x() {
+                continue;
+            }
+            // Compute `s = k^(-1) * (z + r * d)`.
+            let s = modular::mul(&modular::inv(&k, &n), &modular::add(&z, &modular::mul(&r, &d, &n), &n), &n);
+            if s == BigUint::default() {
+                continue;
+            }
+            // Normalize `s` to the lower half of the range, flipping the y-parity of `R` accordingly.
+            let (s, is_odd) = match s > (&n >> 1) {
+                true => (modular::neg(&s, &n), !point_r.y().bit(0)),
+                false => (s, point_r.y().bit(0)),
+            };
+            // Encode the signature.
+            let mut signature = [0u8; 64];
+            signature[..32].copy_from_slice(&modular::to_bytes_be_32(&r));
+            signature[32..].copy_from_slice(&modular::to_bytes_be_32(&s));
+            signature[32] |= (is_odd as u8) << 7;
+            return Ok(signature);
+        }
+    }
+
+    /// Returns the scalar of the given private key.
+    fn private_key_to_scalar(private_key: &[u8; 32]) -> Result<BigUint> {
+        let scalar = BigUint::from_bytes_be(private_key);
+        ensure!(
+            scalar != BigUint::default() && scalar < Secp256k1Point::scalar_field_modulus(),
+            "Invalid secp256k1 private key"
+        );
+        Ok(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address() -> Result<()> {
+        // The private key `1` corresponds to the generator.
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let expected = [
+            0x7e, 0x5f, 0x45, 0x52, 0x09, 0x1a, 0x69, 0x12, 0x5d, 0x5d, 0xfc, 0xb7, 0xb8, 0xc2, 0x65, 0x90, 0x29, 0x39,
+            0x5b, 0xdf,
+        ];
+        assert_eq!(ECDSASecp256k1::address(&private_key)?, expected);
+
+        // Ensure invalid private keys are rejected.
+        assert!(ECDSASecp256k1::address(&[0u8; 32]).is_err());
+        assert!(ECDSASecp256k1::address(&[0xffu8; 32]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl ECDSASecp256k1 {
+    /// Returns `true` if the signature is valid for the given address and message.
+    ///
+    /// A signature `(r, s, yParity)` is valid if `1 <= r < n`, `1 <= s <= (n - 1) / 2`, `R` is the point
+    /// with x-coordinate `r` and y-parity `yParity`, and the public key `Q = r^(-1) * (s * R - z * G)`
+    /// hashes to the given address, where `z` is the Keccak-256 digest of the message.
+    /// In addition, `r` must not be the x-coordinate of the generator, as this case is not provable.
+    pub fn verify(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool> {
+        // Ensure the address is well-formed.
+        ensure!(
+            address.len() == Self::ADDRESS_SIZE_IN_BYTES,
+            "Expected a {}-byte address, found {} bytes",
+            Self::ADDRESS_SIZE_IN_BYTES,
+            address.len()
+        );
+        // Recover the address of the signer, and compare it to the given address.
+        Ok(Self::recover(signature, message)?.map_or(false, |recovered| recovered[..] == address[..]))
+    }
+
+    /// Returns the address of the signer of the given message, if the signature is valid.
+    pub fn recover(signature: &[u8], message: &[u8]) -> Result<Option<[u8; 20]>> {
+        // Ensure the signature is well-formed.
+        ensure!(
+            signature.len() == Self::SIGNATURE_SIZE_IN_BYTES,
+            "Expected a {}-byte signature, found {} bytes",
+            Self::SIGNATURE_SIZE_IN_BYTES,
+            signature.len()
+        );
+
+        let n = Secp256k1Point::scalar_field_modulus();
+        let zero = BigUint::default();
+
+        // Parse `r`, `s`, and the y-parity of `R`.
+        let r = BigUint::from_bytes_be(&signature[..32]);
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&signature[32..]);
+        let is_odd = s_bytes[0] >> 7 == 1;
+        s_bytes[0] &= 0x7f;
+        let s = BigUint::from_bytes_be(&s_bytes);
+
+        // Ensure `r` is in range, and is not the x-coordinate of the generator.
+        if r == zero || r >= n || r == *Secp256k1Point::generator().x() {
+            return Ok(None);
+        }
+        // Ensure `s` is in the lower half of the range.
+        if s == zero || s > (&n >> 1) {
+            return Ok(None);
+        }
+        // Recover `R` from `r` and its y-parity.
+        let Some(point_r) = Secp256k1Point::from_x(&r, is_odd) else {
+            return Ok(None);
+        };
+
+        // Compute `u1 = -z * r^(-1)` and `u2 = s * r^(-1)`.
+        let r_inv = modular::inv(&r, &n);
+        let u1 = modular::neg(&modular::mul(&Self::digest(message), &r_inv, &n), &n);
+        let u2 = modular::mul(&s, &r_inv, &n);
+
+        // Compute the public key `Q = u1 * G + u2 * R`.
+        let public_key = Secp256k1Point::generator().mul(&u1).add(&point_r.mul(&u2));
+        match public_key.is_zero() {
+            true => Ok(None),
+            false => Ok(Some(public_key.to_address())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let private_key = ECDSASecp256k1::sample_private_key(rng);
+            let address = ECDSASecp256k1::address(&private_key)?;
+            let message = (0..i * 17).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let signature = ECDSASecp256k1::sign(&private_key, &message, rng)?;
+
+            // Ensure the signature is valid.
+            assert!(ECDSASecp256k1::verify(&signature, &address, &message)?);
+            assert_eq!(ECDSASecp256k1::recover(&signature, &message)?, Some(address));
+
+            // Ensure the signature is invalid for a different message.
+            let mut other_message = message.clone();
+            other_message.push(rng.gen());
+            assert!(!ECDSASecp256k1::verify(&signature, &address, &other_message)?);
+
+            // Ensure the signature is invalid for a different address.
+            let mut other_address = address;
+            other_address[0] ^= 1;
+            assert!(!ECDSASecp256k1::verify(&signature, &other_address, &message)?);
+
+            // Ensure the signature is invalid with the opposite y-parity.
+            let mut other_signature = signature;
+            other_signature[32] ^= 0x80;
+            assert!(!ECDSASecp256k1::verify(&other_signature, &address, &message)?);
+
+            // Ensure the signature is invalid with a high `s`.
+            let n = Secp256k1Point::scalar_field_modulus();
+            let s = BigUint::from_bytes_be(&signature[32..]) & ((BigUint::from(1u32) << 255) - 1u32);
+            let mut high_s_signature = signature;
+            high_s_signature[32..].copy_from_slice(&modular::to_bytes_be_32(&(n - s)));
+            high_s_signature[32] |= (signature[32] & 0x80) ^ 0x80;
+            assert!(!ECDSASecp256k1::verify(&high_s_signature, &address, &message)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_malformed() {
+        assert!(ECDSASecp256k1::verify(&[0u8; 63], &[0u8; 20], &[]).is_err());
+        assert!(ECDSASecp256k1::verify(&[0u8; 64], &[0u8; 21], &[]).is_err());
+        assert!(!ECDSASecp256k1::verify(&[0u8; 64], &[0u8; 20], &[]).unwrap());
+        assert!(!ECDSASecp256k1::verify(&[0xffu8; 64], &[0u8; 20], &[]).unwrap());
+    }
+}
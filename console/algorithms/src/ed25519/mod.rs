@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+pub use point::Ed25519Point;
+
+mod sign;
+mod verify;
+
+use crate::{modular, sha512_native};
+use snarkvm_console_types::environment::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The Ed25519 signature scheme, as specified in RFC 8032.
+///
+/// A signature is the 64-byte encoding `R || S`, where `R` is an encoded point and `S` is a 32-byte
+/// little-endian integer, and a public key is an encoded point.
+///
+/// Verification uses the cofactorless equation `[S]B = R + [k]A`, where `k = SHA-512(R || A || M) mod L`,
+/// and rejects non-canonical encodings of `S` and `A`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    /// The number of bytes in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in a secret key.
+    pub const SECRET_KEY_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in a signature.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 64;
+
+    /// Returns the challenge `k = SHA-512(R || A || M) mod L`.
+    fn challenge(encoded_r: &[u8], public_key: &[u8], message: &[u8]) -> BigUint {
+        let mut preimage = Vec::with_capacity(encoded_r.len() + public_key.len() + message.len());
+        preimage.extend_from_slice(encoded_r);
+        preimage.extend_from_slice(public_key);
+        preimage.extend_from_slice(message);
+        BigUint::from_bytes_le(&sha512_native(&preimage)) % Ed25519Point::group_order()
+    }
+}
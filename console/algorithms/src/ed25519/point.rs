@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The order `L = 2^252 + 27742317777372353535851937790883648493` of the prime-order subgroup.
+const GROUP_ORDER: &str = "1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED";
+/// The coefficient `d = -121665 / 121666` in the curve equation.
+const COEFFICIENT_D: &str = "52036CEE2B6FFE738CC740797779E89800700A4D4141D8AB75EB4DCA135978A3";
+/// The x-coordinate of the base point.
+const GENERATOR_X: &str = "216936D3CD6E53FEC0A4E231FDD6DC5C692CC7609525A7B2C9562D608F25D51A";
+/// The y-coordinate of the base point.
+const GENERATOR_Y: &str = "6666666666666666666666666666666666666666666666666666666666666658";
+
+/// An affine point on the twisted Edwards curve `-x^2 + y^2 = 1 + d x^2 y^2` (edwards25519).
+///
+/// Note: This implementation is not constant-time, and is intended for public values only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519Point {
+    /// The x-coordinate.
+    x: BigUint,
+    /// The y-coordinate.
+    y: BigUint,
+}
+
+impl Ed25519Point {
+    /// Returns the base field modulus `p = 2^255 - 19`.
+    pub fn base_field_modulus() -> BigUint {
+        (BigUint::from(1u32) << 255) - 19u32
+    }
+
+    /// Returns the order `L` of the prime-order subgroup.
+    pub fn group_order() -> BigUint {
+        parse_hex(GROUP_ORDER)
+    }
+
+    /// Returns the coefficient `d` in the curve equation.
+    pub fn coefficient_d() -> BigUint {
+        parse_hex(COEFFICIENT_D)
+    }
+
+    /// Returns the identity point `(0, 1)`.
+    pub fn zero() -> Self {
+        Self { x: BigUint::default(), y: BigUint::from(1u32) }
+    }
+
+    /// Returns the base point `B`.
+    pub fn generator() -> Self {
+        Self { x: parse_hex(GENERATOR_X), y: parse_hex(GENERATOR_Y) }
+    }
+
+    /// Returns the point with the given coordinates, if it is on the curve.
+    pub fn new(x: BigUint, y: BigUint) -> Option<Self> {
+        let p = Self::base_field_modulus();
+        match x < p && y < p && Self::is_on_curve(&x, &y, &p) {
+            true => Some(Self { x, y }),
+            false => None,
+        }
+    }
+
+    /// Returns the x-coordinate.
+    pub fn x(&self) -> &BigUint {
+        &self.x
+    }
+
+    /// Returns the y-coordinate.
+    pub fn y(&self) -> &BigUint {
+        &self.y
+    }
+
+    /// Returns `true` if this is the identity point.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Returns the point encoded by the given bytes, if the encoding is canonical and valid.
+    ///
+    /// The encoding is the 255-bit little-endian y-coordinate, with the most significant bit
+    /// set to the least significant bit of the x-coordinate.
+    pub fn decode(bytes: &[u8; 32]) -> Option<Self> {
+        let p = Self::base_field_modulus();
+        let one = BigUint::from(1u32);
+
+        // Parse the sign bit and the y-coordinate.
+        let is_odd = bytes[31] >> 7 == 1;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        let y = BigUint::from_bytes_le(&y_bytes);
+        if y >= p {
+            return None;
+        }
+
+        // Compute `x^2 = (y^2 - 1) / (d y^2 + 1)`.
+        let y2 = modular::mul(&y, &y, &p);
+        let u = modular::sub(&y2, &one, &p);
+        let v = modular::add(&modular::mul(&Self::coefficient_d(), &y2, &p), &one, &p);
+        let x2 = modular::mul(&u, &modular::inv(&v, &p), &p);
+
+        // Compute the candidate square root `x = (x^2)^((p + 3) / 8)`, as `p = 5 mod 8`.
+        let mut x = x2.modpow(&((&p + 3u32) >> 3), &p);
+        if modular::mul(&x, &x, &p) != x2 {
+            // Multiply by `sqrt(-1) = 2^((p - 1) / 4)`.
+            x = modular::mul(&x, &BigUint::from(2u32).modpow(&((&p - 1u32) >> 2), &p), &p);
+            if modular::mul(&x, &x, &p) != x2 {
+                return None;
+            }
+        }
+        // Reject the non-canonical encoding of `x = 0`.
+        if x == BigUint::default() && is_odd {
+            return None;
+        }
+        // Select the root with the requested sign.
+        if x.bit(0) != is_odd {
+            x = modular::neg(&x, &p);
+        }
+        Some(Self { x, y })
+    }
+
+    /// Returns the encoding of this point.
+    pub fn encode(&self) -> [u8; 32] {
+        let mut bytes = modular::to_bytes_le_32(&self.y);
+        bytes[31] |= (self.x.bit(0) as u8) << 7;
+        bytes
+    }
+
+    /// Returns the negation of this point.
+    pub fn neg(&self) -> Self {
+        Self { x: modular::neg(&self.x, &Self::base_field_modulus()), y: self.y.clone() }
+    }
+
+    /// Returns the sum of this point and the given point.
+    pub fn add(&self, other: &Self) -> Self {
+        let p = Self::base_field_modulus();
+        let d2 = modular::add(&Self::coefficient_d(), &Self::coefficient_d(), &p);
+        Extended::from_affine(self, &p).add(&Extended::from_affine(other, &p), &d2, &p).to_affine(&p)
+    }
+
+    /// Returns the double of this point.
+    pub fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Returns the product of this point with the given scalar.
+    pub fn mul(&self, scalar: &BigUint) -> Self {
+        let p = Self::base_field_modulus();
+        let d2 = modular::add(&Self::coefficient_d(), &Self::coefficient_d(), &p);
+        let base = Extended::from_affine(self, &p);
+        let mut output = Extended::from_affine(&Self::zero(), &p);
+        for i in (0..scalar.bits()).rev() {
+            output = output.add(&output, &d2, &p);
+            if scalar.bit(i) {
+                output = output.add(&base, &d2, &p);
+            }
+        }
+        output.to_affine(&p)
+    }
+
+    /// Returns `true` if `-x^2 + y^2 = 1 + d x^2 y^2`.
+    fn is_on_curve(x: &BigUint, y: &BigUint, p: &BigUint) -> bool {
+        let x2 = modular::mul(x, x, p);
+        let y2 = modular::mul(y, y, p);
+        let lhs = modular::sub(&y2, &x2, p);
+        let rhs =
+            modular::add(&modular::mul(&Self::coefficient_d(), &modular::mul(&x2, &y2, p), p), &BigUint::from(1u32), p);
+        lhs == rhs
+    }
+}
+
+/// Returns the integer encoded by the given hexadecimal string.
+fn parse_hex(string: &str) -> BigUint {
+    BigUint::parse_bytes(string.as_bytes(), 16).expect("Invalid hexadecimal constant")
+}
+
+/// A point in extended coordinates `(X : Y : Z : T)`, representing the affine point `(X / Z, Y / Z)` with `T = XY / Z`.
+struct Extended {
+    x: BigUint,
+    y: BigUint,
+    z: BigUint,
+    t: BigUint,
+}
+
+impl Extended {
+    /// Returns the given affine point in extended coordinates.
+    fn from_affine(point: &Ed25519Point, p: &BigUint) -> Self {
+        Self { x: point.x.clone(), y: point.y.clone(), z: BigUint::from(1u32), t: modular::mul(&point.x, &point.y, p) }
+    }
+
+    /// Returns this point in affine coordinates.
+    fn to_affine(&self, p: &BigUint) -> Ed25519Point {
+        let z_inv = modular::inv(&self.z, p);
+        Ed25519Point { x: modular::mul(&self.x, &z_inv, p), y: modular::mul(&self.y, &z_inv, p) }
+    }
+
+    /// Returns the sum of this point and the given point, using the complete "add-2008-hwcd-3" formulas.
+    fn add(&self, other: &Self, d2: &BigUint, p: &BigUint) -> Self {
+        let a = modular::mul(&modular::sub(&self.y, &self.x, p), &modular::sub(&other.y, &other.x, p), p);
+        let b = modular::mul(&modular::add(&self.y, &self.x, p), &modular::add(&other.y, &other.x, p), p);
+        let c = modular::mul(&modular::mul(&self.t, d2, p), &other.t, p);
+        let d = modular::mul(&modular::add(&self.z, &self.z, p), &other.z, p);
+        let e = modular::sub(&b, &a, p);
+        let f = modular::sub(&d, &c, p);
+        let g = modular::add(&d, &c, p);
+        let h = modular::add(&b, &a, p);
+        Self {
+            x: modular::mul(&e, &f, p),
+            y: modular::mul(&g, &h, p),
+            z: modular::mul(&f, &g, p),
+            t: modular::mul(&e, &h, p),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let generator = Ed25519Point::generator();
+        assert!(Ed25519Point::new(generator.x().clone(), generator.y().clone()).is_some());
+        assert_eq!(Ed25519Point::decode(&generator.encode()), Some(generator.clone()));
+        // Ensure the base point has order `L`.
+        assert!(generator.mul(&Ed25519Point::group_order()).is_zero());
+    }
+
+    #[test]
+    fn test_group_law() {
+        let rng = &mut TestRng::default();
+        let generator = Ed25519Point::generator();
+
+        for _ in 0..10 {
+            let a = BigUint::from(rng.gen::<u64>());
+            let b = BigUint::from(rng.gen::<u64>());
+            let pa = generator.mul(&a);
+            let pb = generator.mul(&b);
+            // Check addition and doubling against scalar multiplication.
+            assert_eq!(pa.add(&pb), generator.mul(&(&a + &b)));
+            assert_eq!(pa.double(), generator.mul(&(&a * 2u32)));
+            assert!(pa.add(&pa.neg()).is_zero());
+            assert_eq!(pa.add(&Ed25519Point::zero()), pa);
+            // Check the encoding.
+            assert_eq!(Ed25519Point::decode(&pa.encode()), Some(pa));
+        }
+    }
+
+    #[test]
+    fn test_decode_non_canonical() {
+        // Ensure a y-coordinate that is not reduced is rejected.
+        let mut bytes = [0xffu8; 32];
+        bytes[0] = 0xee;
+        bytes[31] = 0x7f;
+        assert!(Ed25519Point::decode(&bytes).is_none());
+        // Ensure the encoding of `x = 0` with the sign bit set is rejected.
+        let mut bytes = Ed25519Point::zero().encode();
+        bytes[31] |= 0x80;
+        assert!(Ed25519Point::decode(&bytes).is_none());
+    }
+}
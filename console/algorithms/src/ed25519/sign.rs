@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Ed25519 {
+    /// Returns the public key of the given secret key.
+    pub fn public_key(secret_key: &[u8; 32]) -> [u8; 32] {
+        let (scalar, _) = Self::expand_secret_key(secret_key);
+        Ed25519Point::generator().mul(&scalar).encode()
+    }
+
+    /// Returns the deterministic signature for the given message under the given secret key.
+    ///
+    /// Note: This implementation is not constant-time, and should not be used with valuable keys.
+    pub fn sign(secret_key: &[u8; 32], message: &[u8]) -> [u8; 64] {
+        let order = Ed25519Point::group_order();
+        let (scalar, prefix) = Self::expand_secret_key(secret_key);
+        let public_key = Ed25519Point::generator().mul(&scalar).encode();
+
+        // Compute the nonce `r = SHA-512(prefix || M) mod L`, and `R = [r]B`.
+        let mut preimage = prefix.to_vec();
+        preimage.extend_from_slice(message);
+        let nonce = BigUint::from_bytes_le(&sha512_native(&preimage)) % &order;
+        let encoded_r = Ed25519Point::generator().mul(&nonce).encode();
+
+        // Compute `S = (r + k * s) mod L`.
+        let challenge = Self::challenge(&encoded_r, &public_key, message);
+        let s = modular::add(&nonce, &modular::mul(&challenge, &(scalar % &order), &order), &order);
+
+        // Encode the signature.
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&encoded_r);
+        signature[32..].copy_from_slice(&modular::to_bytes_le_32(&s));
+        signature
+    }
+
+    /// Returns the clamped secret scalar and the nonce prefix of the given secret key.
+    fn expand_secret_key(secret_key: &[u8; 32]) -> (BigUint, [u8; 32]) {
+        let hash = sha512_native(secret_key);
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&hash[..32]);
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&hash[32..]);
+        (BigUint::from_bytes_le(&scalar_bytes), prefix)
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Ed25519 {
+    /// Returns `true` if the signature is valid for the given public key and message.
+    ///
+    /// A signature `R || S` is valid if `S < L`, the public key decodes to a point `A`,
+    /// and `[S]B - [k]A` encodes to `R`, where `k = SHA-512(R || A || M) mod L`.
+    pub fn verify(signature: &[u8], public_key: &[u8], message: &[u8]) -> Result<bool> {
+        // Ensure the signature and public key are well-formed.
+        ensure!(
+            signature.len() == Self::SIGNATURE_SIZE_IN_BYTES,
+            "Expected a {}-byte signature, found {} bytes",
+            Self::SIGNATURE_SIZE_IN_BYTES,
+            signature.len()
+        );
+        ensure!(
+            public_key.len() == Self::PUBLIC_KEY_SIZE_IN_BYTES,
+            "Expected a {}-byte public key, found {} bytes",
+            Self::PUBLIC_KEY_SIZE_IN_BYTES,
+            public_key.len()
+        );
+
+        // Decode the public key.
+        let mut public_key_bytes = [0u8; 32];
+        public_key_bytes.copy_from_slice(public_key);
+        let Some(point_a) = Ed25519Point::decode(&public_key_bytes) else {
+            return Ok(false);
+        };
+        // Ensure `S` is canonical.
+        let s = BigUint::from_bytes_le(&signature[32..]);
+        if s >= Ed25519Point::group_order() {
+            return Ok(false);
+        }
+        // Compute the challenge `k`.
+        let challenge = Self::challenge(&signature[..32], public_key, message);
+        // Compute `[S]B - [k]A`, and compare its encoding to `R`.
+        let candidate = Ed25519Point::generator().mul(&s).add(&point_a.neg().mul(&challenge));
+        Ok(candidate.encode()[..] == signature[..32])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the bytes of the given hexadecimal string.
+    fn from_hex<const N: usize>(string: &str) -> [u8; N] {
+        hex::decode(string).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_rfc8032_vectors() -> Result<()> {
+        // The test vectors from RFC 8032, Section 7.1.
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+        ];
+        for (secret_key, public_key, message, signature) in vectors {
+            let secret_key = from_hex::<32>(secret_key);
+            let public_key = from_hex::<32>(public_key);
+            let message = hex::decode(message)?;
+            let signature = from_hex::<64>(signature);
+
+            assert_eq!(Ed25519::public_key(&secret_key), public_key);
+            assert_eq!(Ed25519::sign(&secret_key, &message), signature);
+            assert!(Ed25519::verify(&signature, &public_key, &message)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let secret_key: [u8; 32] = rng.gen();
+            let public_key = Ed25519::public_key(&secret_key);
+            let message = (0..i * 17).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let signature = Ed25519::sign(&secret_key, &message);
+
+            // Ensure the signature is valid.
+            assert!(Ed25519::verify(&signature, &public_key, &message)?);
+
+            // Ensure the signature is invalid for a different message.
+            let mut other_message = message.clone();
+            other_message.push(rng.gen());
+            assert!(!Ed25519::verify(&signature, &public_key, &other_message)?);
+
+            // Ensure the signature is invalid for a different public key.
+            let other_public_key = Ed25519::public_key(&rng.gen());
+            assert!(!Ed25519::verify(&signature, &other_public_key, &message)?);
+
+            // Ensure the signature is invalid with a non-canonical `S + L`.
+            let s = BigUint::from_bytes_le(&signature[32..]) + Ed25519Point::group_order();
+            let mut other_signature = signature;
+            other_signature[32..].copy_from_slice(&modular::to_bytes_le_32(&s));
+            assert!(!Ed25519::verify(&other_signature, &public_key, &message)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_malformed() {
+        assert!(Ed25519::verify(&[0u8; 63], &[0u8; 32], &[]).is_err());
+        assert!(Ed25519::verify(&[0u8; 64], &[0u8; 31], &[]).is_err());
+        assert!(!Ed25519::verify(&[0u8; 64], &[0xffu8; 32], &[]).unwrap());
+    }
+}
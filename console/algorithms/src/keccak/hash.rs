@@ -48,7 +48,7 @@ fn keccak_224_native(preimage: &[u8]) -> [u8; 28] {
 }
 
 /// Computes the Keccak-256 hash of the given preimage as bytes.
-fn keccak_256_native(preimage: &[u8]) -> [u8; 32] {
+pub(crate) fn keccak_256_native(preimage: &[u8]) -> [u8; 32] {
     let mut keccak = TinyKeccak::v256();
     keccak.update(preimage);
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod hash;
+pub(crate) use hash::keccak_256_native;
 
 #[cfg(test)]
 use snarkvm_utilities::Uniform;
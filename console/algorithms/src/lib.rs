@@ -25,14 +25,25 @@ pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::{ECDSASecp256k1, Secp256k1Point};
+
+mod ed25519;
+pub use ed25519::{Ed25519, Ed25519Point};
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
 mod keccak;
 pub use keccak::*;
 
+mod modular;
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod sha512;
+pub use sha512::*;
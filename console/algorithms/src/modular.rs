@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for arithmetic modulo an arbitrary (non-native) prime.
+
+use num_bigint::BigUint;
+
+/// Returns `(a + b) mod m`.
+pub(crate) fn add(a: &BigUint, b: &BigUint, m: &BigUint) -> BigUint {
+    (a + b) % m
+}
+
+/// Returns `(a - b) mod m`, for `a, b < m`.
+pub(crate) fn sub(a: &BigUint, b: &BigUint, m: &BigUint) -> BigUint {
+    (a + m - b) % m
+}
+
+/// Returns `(a * b) mod m`.
+pub(crate) fn mul(a: &BigUint, b: &BigUint, m: &BigUint) -> BigUint {
+    (a * b) % m
+}
+
+/// Returns `-a mod m`, for `a < m`.
+pub(crate) fn neg(a: &BigUint, m: &BigUint) -> BigUint {
+    (m - a) % m
+}
+
+/// Returns `a^(-1) mod m`, for a prime `m`. Note that `0` is mapped to `0`.
+pub(crate) fn inv(a: &BigUint, m: &BigUint) -> BigUint {
+    a.modpow(&(m - 2u32), m)
+}
+
+/// Returns the big-endian encoding of `a` as 32 bytes, for `a < 2^256`.
+pub(crate) fn to_bytes_be_32(a: &BigUint) -> [u8; 32] {
+    let bytes = a.to_bytes_be();
+    let mut output = [0u8; 32];
+    output[32 - bytes.len()..].copy_from_slice(&bytes);
+    output
+}
+
+/// Returns the little-endian encoding of `a` as 32 bytes, for `a < 2^256`.
+pub(crate) fn to_bytes_le_32(a: &BigUint) -> [u8; 32] {
+    let bytes = a.to_bytes_le();
+    let mut output = [0u8; 32];
+    output[..bytes.len()].copy_from_slice(&bytes);
+    output
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Sha512 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-512 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&sha512_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+/// Computes the SHA-512 hash of the given preimage as bytes.
+pub(crate) fn sha512_native(preimage: &[u8]) -> [u8; 64] {
+    let mut hash = [0u8; 64];
+    hash.copy_from_slice(&Sha512Native::digest(preimage));
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha512_vectors() {
+        // The test vectors from FIPS 180-4.
+        let expected = "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                        2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+        assert_eq!(hex::encode(sha512_native(b"abc")), expected);
+
+        let expected = "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+                        47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e";
+        assert_eq!(hex::encode(sha512_native(b"")), expected);
+    }
+
+    #[test]
+    fn test_sha512_equivalence() {
+        let rng = &mut TestRng::default();
+
+        for num_bytes in [0, 1, 2, 55, 111, 112, 127, 128, 129, 255, 256, 1024] {
+            // Prepare the preimage.
+            let input = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let input_bits = bits_from_bytes_le(&input).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = bits_from_bytes_le(&sha512_native(&input)).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let candidate = Sha512.hash(&input_bits).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+}
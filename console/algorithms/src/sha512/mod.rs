@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+pub(crate) use hash::sha512_native;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+use sha2::{Digest, Sha512 as Sha512Native};
+
+/// The SHA-512 hash function, as specified in FIPS 180-4.
+///
+/// The input bits are interpreted as a little-endian encoding of the message bytes,
+/// and the output is the little-endian encoding of the 64-byte digest.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sha512;
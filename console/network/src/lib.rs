@@ -183,6 +183,8 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum number of iterations of a loop (including its enclosing loops) in finalize.
+    const MAX_LOOP_ITERATIONS: u32 = 1024;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
@@ -398,4 +400,10 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given ECDSA secp256k1 signature is valid for the given Ethereum address and message.
+    fn verify_ecdsa_secp256k1(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool>;
+
+    /// Returns `true` if the given Ed25519 signature is valid for the given public key and message.
+    fn verify_ed25519(signature: &[u8], public_key: &[u8], message: &[u8]) -> Result<bool>;
 }
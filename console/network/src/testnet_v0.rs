@@ -16,6 +16,8 @@ use super::*;
 use crate::TRANSACTION_PREFIX;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    ECDSASecp256k1,
+    Ed25519,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -474,6 +476,16 @@ impl Network for TestnetV0 {
     ) -> bool {
         path.verify(&*TESTNET_POSEIDON_4, &*TESTNET_POSEIDON_2, root, leaf)
     }
+
+    /// Returns `true` if the given ECDSA secp256k1 signature is valid for the given Ethereum address and message.
+    fn verify_ecdsa_secp256k1(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool> {
+        ECDSASecp256k1::verify(signature, address, message)
+    }
+
+    /// Returns `true` if the given Ed25519 signature is valid for the given public key and message.
+    fn verify_ed25519(signature: &[u8], public_key: &[u8], message: &[u8]) -> Result<bool> {
+        Ed25519::verify(signature, public_key, message)
+    }
 }
 
 #[cfg(test)]
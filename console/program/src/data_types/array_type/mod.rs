@@ -16,7 +16,7 @@ mod bytes;
 mod parse;
 pub(crate) mod serialize;
 
-use crate::{PlaintextType, U32};
+use crate::{PlaintextType, ProgramID, U32};
 use snarkvm_console_network::prelude::*;
 
 use core::fmt::{Debug, Display};
@@ -85,6 +85,11 @@ impl<N: Network> ArrayType<N> {
     pub const fn length(&self) -> &U32<N> {
         &self.length
     }
+
+    /// Returns the array type, with any local struct element types qualified by the given program ID.
+    pub fn qualify(&self, program_id: &ProgramID<N>) -> Self {
+        Self { element_type: Box::new(self.element_type.qualify(program_id)), length: self.length }
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +162,13 @@ mod tests {
         assert_eq!(array.length(), &U32::new(3));
         assert!(!array.is_empty());
 
+        // Test qualifying struct array types.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[foo; 2u32]; 3u32]")?;
+        let program_id = ProgramID::from_str("token.aleo")?;
+        assert_eq!(array.qualify(&program_id).to_string(), "[[token.aleo/foo; 2u32]; 3u32]");
+        let array = ArrayType::<CurrentNetwork>::from_str("[field; 4u32]")?;
+        assert_eq!(array.qualify(&program_id), array);
+
         Ok(())
     }
 
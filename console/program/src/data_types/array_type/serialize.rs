@@ -58,6 +58,8 @@ pub(crate) mod tests {
         "[signature; 1u32]",
         "[foo; 4u32]",
         "[bar; 4u32]",
+        "[token.aleo/foo; 4u32]",
+        "[[token.aleo/bar; 2u32]; 3u32]",
         "[[u8; 1u32]; 2u32]",
         "[[[u8; 1u32]; 2u32]; 3u32]",
         "[[[[u8; 1u32]; 2u32]; 3u32]; 4u32]",
@@ -16,7 +16,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{ArrayType, Identifier, LiteralType};
+use crate::{ArrayType, Identifier, LiteralType, Locator, ProgramID};
 use snarkvm_console_network::prelude::*;
 
 /// A `PlaintextType` defines the type parameter for a literal, struct, or array.
@@ -31,6 +31,21 @@ pub enum PlaintextType<N: Network> {
     /// An array type contains its element type and length.
     /// The format of the type is `[<element_type>; <length>]`.
     Array(ArrayType<N>),
+    /// An external struct type contains its locator.
+    /// The format of the type is `<program_id>/<identifier>`.
+    ExternalStruct(Locator<N>),
+}
+
+impl<N: Network> PlaintextType<N> {
+    /// Returns the plaintext type, with any local struct types qualified by the given program ID.
+    /// This is used to refer to a type declared in `program_id` from the context of another program.
+    pub fn qualify(&self, program_id: &ProgramID<N>) -> Self {
+        match self {
+            Self::Literal(..) | Self::ExternalStruct(..) => self.clone(),
+            Self::Struct(struct_name) => Self::ExternalStruct(Locator::new(*program_id, *struct_name)),
+            Self::Array(array_type) => Self::Array(array_type.qualify(program_id)),
+        }
+    }
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
@@ -47,6 +62,13 @@ impl<N: Network> From<Identifier<N>> for PlaintextType<N> {
     }
 }
 
+impl<N: Network> From<Locator<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from an external struct type.
+    fn from(locator: Locator<N>) -> Self {
+        PlaintextType::ExternalStruct(locator)
+    }
+}
+
 impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
     /// Initializes a plaintext type from an array type.
     fn from(array: ArrayType<N>) -> Self {
@@ -67,6 +67,9 @@ mod tests {
         "passport",
         "object",
         "array",
+        // External struct
+        "token.aleo/metadata",
+        "credits.aleo/bond_state",
     ];
 
     fn check_serde_json<
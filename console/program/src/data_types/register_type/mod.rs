@@ -40,7 +40,7 @@ impl<N: Network> From<ValueType<N>> for RegisterType<N> {
             ValueType::Constant(plaintext_type)
             | ValueType::Public(plaintext_type)
             | ValueType::Private(plaintext_type) => Self::Plaintext(plaintext_type),
-            ValueType::Record(record_name) => Self::Record(record_name),
+            ValueType::Record(record_name) | ValueType::ProgramRecord(record_name) => Self::Record(record_name),
             ValueType::ExternalRecord(locator) => Self::ExternalRecord(locator),
             ValueType::Future(locator) => Self::Future(locator),
         }
@@ -35,6 +35,10 @@ pub enum ValueType<N: Network> {
     ExternalRecord(Locator<N>),
     /// A publicly-visible future.
     Future(Locator<N>),
+    /// A record type owned by a program address, which is spent on the authority of its parent program.
+    /// Note: A program record is a *public* spend. As a program address has no secret key, anyone who knows
+    /// the record can compute its serial number and tag, and link the spend back to the record.
+    ProgramRecord(Identifier<N>),
 }
 
 impl<N: Network> From<EntryType<N>> for ValueType<N> {
@@ -24,6 +24,8 @@ impl<N: Network> Parser for ValueType<N> {
             map(pair(PlaintextType::parse, tag(".constant")), |(plaintext_type, _)| Self::Constant(plaintext_type)),
             map(pair(PlaintextType::parse, tag(".public")), |(plaintext_type, _)| Self::Public(plaintext_type)),
             map(pair(PlaintextType::parse, tag(".private")), |(plaintext_type, _)| Self::Private(plaintext_type)),
+            // Note: A program record (`name.record.program`) is a public spend, as its serial number is linkable.
+            map(pair(Identifier::parse, tag(".record.program")), |(identifier, _)| Self::ProgramRecord(identifier)),
             map(pair(Identifier::parse, tag(".record")), |(identifier, _)| Self::Record(identifier)),
             map(pair(Locator::parse, tag(".record")), |(locator, _)| Self::ExternalRecord(locator)),
             map(pair(Locator::parse, tag(".future")), |(locator, _)| Self::Future(locator)),
@@ -65,6 +67,7 @@ impl<N: Network> Display for ValueType<N> {
             Self::Record(identifier) => write!(f, "{identifier}.record"),
             Self::ExternalRecord(locator) => write!(f, "{locator}.record"),
             Self::Future(locator) => write!(f, "{locator}.future"),
+            Self::ProgramRecord(identifier) => write!(f, "{identifier}.record.program"),
         }
     }
 }
@@ -120,6 +123,16 @@ mod tests {
             ValueType::<CurrentNetwork>::parse("message.record")?.1
         );
 
+        // ProgramRecord type.
+        assert_eq!(
+            Ok(("", ValueType::<CurrentNetwork>::from_str("credits.record.program")?)),
+            ValueType::<CurrentNetwork>::parse("credits.record.program")
+        );
+        assert_eq!(
+            ValueType::<CurrentNetwork>::ProgramRecord(Identifier::from_str("credits")?),
+            ValueType::<CurrentNetwork>::parse("credits.record.program")?.1
+        );
+
         // ExternalRecord type.
         assert_eq!(
             Ok(("", ValueType::<CurrentNetwork>::from_str("howard.aleo/message.record")?)),
@@ -195,6 +208,7 @@ mod tests {
         assert_eq!(ValueType::<CurrentNetwork>::from_str("signature.private")?.to_string(), "signature.private");
 
         assert_eq!(ValueType::<CurrentNetwork>::from_str("token.record")?.to_string(), "token.record");
+        assert_eq!(ValueType::<CurrentNetwork>::from_str("token.record.program")?.to_string(), "token.record.program");
 
         assert_eq!(
             ValueType::<CurrentNetwork>::from_str("howard.aleo/message.record")?.to_string(),
@@ -111,6 +111,7 @@ mod tests {
         check_serde_json(ValueType::<CurrentNetwork>::from_str("token.record").unwrap());
         check_serde_json(ValueType::<CurrentNetwork>::from_str("hello_world.record").unwrap());
         check_serde_json(ValueType::<CurrentNetwork>::from_str("hello_world.aleo/new.record").unwrap());
+        check_serde_json(ValueType::<CurrentNetwork>::from_str("token.record.program").unwrap());
     }
 
     #[test]
@@ -123,5 +124,6 @@ mod tests {
         check_bincode(ValueType::<CurrentNetwork>::from_str("token.record").unwrap());
         check_bincode(ValueType::<CurrentNetwork>::from_str("hello_world.record").unwrap());
         check_bincode(ValueType::<CurrentNetwork>::from_str("hello_world.aleo/new.record").unwrap());
+        check_bincode(ValueType::<CurrentNetwork>::from_str("token.record.program").unwrap());
     }
 }
@@ -42,6 +42,19 @@ impl<N: Network> FromBytes for InputID<N> {
             }
             // External record input.
             4 => Ok(Self::ExternalRecord(Field::read_le(&mut reader)?)),
+            // Program record input.
+            5 => {
+                // Read the commitment.
+                let commitment = Field::read_le(&mut reader)?;
+                // Read the gamma value.
+                let gamma = Group::read_le(&mut reader)?;
+                // Read the serial number.
+                let serial_number = Field::read_le(&mut reader)?;
+                // Read the tag value.
+                let tag = Field::read_le(&mut reader)?;
+                // Return the program record input.
+                Ok(Self::ProgramRecord(commitment, gamma, serial_number, tag))
+            }
             // Invalid input.
             _ => Err(error("Invalid input ID variant")),
         }
@@ -93,6 +106,19 @@ impl<N: Network> ToBytes for InputID<N> {
                 // Write the value.
                 value.write_le(&mut writer)
             }
+            // Program record input.
+            Self::ProgramRecord(commitment, gamma, serial_number, tag) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the commitment.
+                commitment.write_le(&mut writer)?;
+                // Write the gamma value.
+                gamma.write_le(&mut writer)?;
+                // Write the serial number.
+                serial_number.write_le(&mut writer)?;
+                // Write the tag value.
+                tag.write_le(&mut writer)
+            }
         }
     }
 }
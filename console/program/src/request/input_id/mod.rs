@@ -31,6 +31,9 @@ pub enum InputID<N: Network> {
     Record(Field<N>, Group<N>, Field<N>, Field<N>),
     /// The hash of the external record input.
     ExternalRecord(Field<N>),
+    /// The commitment, gamma, serial number, and tag of the program-owned record input.
+    /// Note: These are publicly computable from the record, so the spend is linkable.
+    ProgramRecord(Field<N>, Group<N>, Field<N>, Field<N>),
 }
 
 impl<N: Network> InputID<N> {
@@ -42,6 +45,7 @@ impl<N: Network> InputID<N> {
             InputID::Private(id) => id,
             InputID::Record(id, ..) => id,
             InputID::ExternalRecord(id) => id,
+            InputID::ProgramRecord(id, ..) => id,
         }
     }
 }
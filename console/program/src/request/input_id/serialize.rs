@@ -54,6 +54,15 @@ impl<N: Network> Serialize for InputID<N> {
                     input.serialize_field("id", &id)?;
                     input.end()
                 }
+                Self::ProgramRecord(commitment, gamma, serial_number, tag) => {
+                    let mut input = serializer.serialize_struct("InputID", 5)?;
+                    input.serialize_field("type", "program_record")?;
+                    input.serialize_field("commitment", &commitment)?;
+                    input.serialize_field("gamma", &gamma)?;
+                    input.serialize_field("serial_number", &serial_number)?;
+                    input.serialize_field("tag", &tag)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -81,6 +90,12 @@ impl<'de, N: Network> Deserialize<'de> for InputID<N> {
                     Some("external_record") => {
                         InputID::ExternalRecord(DeserializeExt::take_from_value::<D>(&mut input, "id")?)
                     }
+                    Some("program_record") => InputID::ProgramRecord(
+                        DeserializeExt::take_from_value::<D>(&mut input, "commitment")?,
+                        DeserializeExt::take_from_value::<D>(&mut input, "gamma")?,
+                        DeserializeExt::take_from_value::<D>(&mut input, "serial_number")?,
+                        DeserializeExt::take_from_value::<D>(&mut input, "tag")?,
+                    ),
                     _ => return Err(de::Error::custom("Invalid input type")),
                 };
                 Ok(input_id)
@@ -104,6 +119,7 @@ mod tests {
         "{\"type\":\"private\",\"id\":\"123field\"}",
         "{\"type\":\"record\",\"commitment\":\"123123field\",\"tag\":\"0field\",\"serial_number\":\"123456789field\",\"gamma\":\"0group\"}",
         "{\"type\":\"external_record\",\"id\":\"123456789field\"}",
+        "{\"type\":\"program_record\",\"commitment\":\"123123field\",\"tag\":\"0field\",\"serial_number\":\"123456789field\",\"gamma\":\"0group\"}",
     ];
 
     fn check_serde_json<
@@ -18,6 +18,11 @@ impl<N: Network> Request<N> {
     /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG, where:
     ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     ///     response := r - challenge * sk_sig
+    ///
+    /// Note: A program record input (`name.record.program`) is a *public* spend. Its serial number and tag are
+    /// derived from the program address that owns the record, not from the signer. A program address has no
+    /// secret key, so anyone who knows the record can compute its serial number and tag, and link the spend back
+    /// to the record. Program records must not be used where the spend of a record needs to remain private.
     pub fn sign<R: Rng + CryptoRng>(
         private_key: &PrivateKey<N>,
         program_id: ProgramID<N>,
@@ -219,6 +224,35 @@ impl<N: Network> Request<N> {
                 }
                 // A future is not a valid input.
                 ValueType::Future(..) => bail!("A future is not a valid input"),
+                // A program record input is computed to its serial number, using the public generator as `gamma`.
+                ValueType::ProgramRecord(record_name) => {
+                    // Retrieve the record.
+                    let record = match &input {
+                        Value::Record(record) => record,
+                        // Ensure the input is a record.
+                        Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                        Value::Future(..) => bail!("Expected a record input, found a future input"),
+                    };
+
+                    // Compute the record commitment.
+                    let commitment = record.to_commitment(&program_id, record_name)?;
+
+                    // Compute the generator `H` as `HashToGroup(commitment)`.
+                    // Note: A program address has no secret key, so `gamma` is `H` itself and the serial number
+                    // of a program-owned record is publicly computable from its commitment.
+                    let gamma = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+
+                    // Compute the `serial_number` from `gamma`.
+                    let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+                    // Compute the tag, using the owner address in place of `sk_tag`.
+                    let tag = Record::<N, Plaintext<N>>::tag(record.owner().to_x_coordinate(), commitment)?;
+
+                    // Add (`serial_number`, `tag`) to the preimage.
+                    message.extend([serial_number, tag]);
+
+                    // Add the input ID.
+                    input_ids.push(InputID::ProgramRecord(commitment, gamma, serial_number, tag));
+                }
             }
         }
 
@@ -19,6 +19,10 @@ impl<N: Network> Request<N> {
     ///
     /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///
+    /// Note: A program record input (`name.record.program`) is a *public* spend. Its serial number and tag are
+    /// recomputed from the record commitment and the owning program address, with `gamma` fixed to `H`.
+    /// As a program address has no secret key, these values are public to anyone who knows the record.
     pub fn verify(&self, input_types: &[ValueType<N>], is_root: bool) -> bool {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
@@ -194,6 +198,45 @@ impl<N: Network> Request<N> {
                         // Add the input hash to the message.
                         message.push(candidate_hash);
                     }
+                    // A program record input is computed to its serial number.
+                    InputID::ProgramRecord(commitment, gamma, serial_number, tag) => {
+                        // Retrieve the record.
+                        let record = match &input {
+                            Value::Record(record) => record,
+                            // Ensure the input is a record.
+                            Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                            Value::Future(..) => bail!("Expected a record input, found a future input"),
+                        };
+                        // Retrieve the record name.
+                        let record_name = match input_type {
+                            ValueType::ProgramRecord(record_name) => record_name,
+                            // Ensure the input type is a program record.
+                            _ => bail!("Expected a program record type at input {index}"),
+                        };
+
+                        // Compute the record commitment.
+                        let candidate_cm = record.to_commitment(&self.program_id, record_name)?;
+                        // Ensure the commitment matches.
+                        ensure!(*commitment == candidate_cm, "Expected a record input with the same commitment");
+
+                        // Compute the generator `H` as `HashToGroup(commitment)`.
+                        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+                        // Ensure `gamma` is the generator `H`.
+                        ensure!(*gamma == h, "Expected a program record input with gamma equal to H");
+
+                        // Compute the `candidate_sn` from `gamma`.
+                        let candidate_sn = Record::<N, Plaintext<N>>::serial_number_from_gamma(gamma, *commitment)?;
+                        // Ensure the serial number matches.
+                        ensure!(*serial_number == candidate_sn, "Expected a record input with the same serial number");
+
+                        // Compute the tag as `Hash(owner || commitment)`.
+                        let candidate_tag = N::hash_psd2(&[record.owner().to_x_coordinate(), *commitment])?;
+                        // Ensure the tag matches.
+                        ensure!(*tag == candidate_tag, "Expected a record input with the same tag");
+
+                        // Add (`serial_number`, `tag`) to the message.
+                        message.extend([*serial_number, *tag]);
+                    }
                 }
                 Ok(())
             },
@@ -272,4 +315,64 @@ mod tests {
             assert!(request.verify(&input_types, is_root));
         }
     }
+
+    #[test]
+    fn test_sign_and_verify_program_record() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Construct a program ID and function name.
+            let program_id = ProgramID::from_str("escrow.aleo").unwrap();
+            let function_name = Identifier::from_str("release").unwrap();
+
+            // Prepare a record belonging to the program address.
+            let program_address = program_id.to_address().unwrap();
+            let record_string = format!(
+                "{{ owner: {program_address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+            );
+
+            // Construct the inputs and input types.
+            let inputs = [Value::<CurrentNetwork>::from_str(&record_string).unwrap()];
+            let input_types = vec![ValueType::from_str("token.record.program").unwrap()];
+
+            // Sample 'is_root'.
+            let is_root = Uniform::rand(rng);
+
+            // Compute the signed request.
+            let request = Request::sign(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.clone().into_iter(),
+                &input_types,
+                None,
+                is_root,
+                rng,
+            )
+            .unwrap();
+            assert!(request.verify(&input_types, is_root));
+
+            // Ensure the serial number is the same for any signer.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let other_request = Request::sign(
+                &other_private_key,
+                program_id,
+                function_name,
+                inputs.into_iter(),
+                &input_types,
+                None,
+                is_root,
+                rng,
+            )
+            .unwrap();
+            assert_eq!(request.input_ids(), other_request.input_ids());
+
+            // Ensure the request does not verify as a regular record input.
+            let record_types = vec![ValueType::from_str("token.record").unwrap()];
+            assert!(!request.verify(&record_types, is_root));
+        }
+    }
 }
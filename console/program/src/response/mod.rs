@@ -209,6 +209,8 @@ impl<N: Network> Response<N> {
                         // Return the output ID.
                         Ok(OutputID::Future(output_hash))
                     }
+                    // A program record is not a valid output.
+                    ValueType::ProgramRecord(..) => bail!("A program record is not a valid output"),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
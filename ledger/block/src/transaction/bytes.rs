@@ -75,7 +75,27 @@ impl<N: Network> FromBytes for Transaction<N> {
                 // Return the ID and the transaction.
                 (id, transaction)
             }
-            3.. => return Err(error("Invalid transaction variant")),
+            3 => {
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the number of executions.
+                let num_executions = u16::read_le(&mut reader)?;
+                // Ensure the number of executions is within bounds.
+                if num_executions as usize > Self::MAX_BUNDLE_EXECUTIONS {
+                    return Err(error("Invalid number of executions in the bundle"));
+                }
+                // Read the executions.
+                let executions =
+                    (0..num_executions).map(|_| Execution::read_le(&mut reader)).collect::<IoResult<_>>()?;
+                // Read the fee.
+                let fee = Fee::read_le(&mut reader)?;
+
+                // Initialize the transaction.
+                let transaction = Self::from_bundle(executions, fee).map_err(|e| error(e.to_string()))?;
+                // Return the ID and the transaction.
+                (id, transaction)
+            }
+            4.. => return Err(error("Invalid transaction variant")),
         };
 
         // Ensure the transaction ID matches.
@@ -132,6 +152,20 @@ impl<N: Network> ToBytes for Transaction<N> {
                 // Write the fee.
                 fee.write_le(&mut writer)
             }
+            Self::Bundle(id, executions, fee) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+                // Write the number of executions.
+                u16::try_from(executions.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the executions.
+                for execution in executions {
+                    execution.write_le(&mut writer)?;
+                }
+                // Write the fee.
+                fee.write_le(&mut writer)
+            }
         }
     }
 }
@@ -149,6 +183,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(true, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(false, rng),
         ]
         .into_iter()
         {
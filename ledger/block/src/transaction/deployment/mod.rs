@@ -56,10 +56,11 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is valid.
+        // Note: The initial deployment of a program is at `N::EDITION`, and each upgrade increments the edition.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
@@ -109,6 +110,11 @@ impl<N: Network> Deployment<N> {
         self.edition
     }
 
+    /// Returns `true` if the deployment is an upgrade of a previously-deployed program.
+    pub const fn is_upgrade(&self) -> bool {
+        self.edition > N::EDITION
+    }
+
     /// Returns the program.
     pub const fn program(&self) -> &Program<N> {
         &self.program
@@ -15,6 +15,8 @@
 use super::*;
 
 impl<N: Network> Transaction<N> {
+    /// The maximum number of executions allowed in a bundle transaction.
+    pub const MAX_BUNDLE_EXECUTIONS: usize = 8;
     /// The maximum number of transitions allowed in a transaction.
     pub const MAX_TRANSITIONS: usize = usize::pow(2, TRANSACTION_DEPTH as u32);
 
@@ -78,6 +80,29 @@ impl<N: Network> Transaction<N> {
                 // Error if the transition ID was not found.
                 bail!("Transition ID not found in fee transaction");
             }
+            Self::Bundle(_, executions, fee) => {
+                // Retrieve the number of transitions in the bundle.
+                let num_transitions = executions.iter().map(Execution::len).sum::<usize>();
+                // Check if the ID is the transition ID for the fee.
+                if *id == **fee.id() {
+                    // Return the transaction leaf.
+                    return Ok(TransactionLeaf::new_execution(
+                        u16::try_from(num_transitions + 1)?, // The last index.
+                        *id,
+                    ));
+                }
+
+                // Iterate through the transitions in the bundle.
+                for (index, transition) in executions.iter().flat_map(Execution::transitions).enumerate() {
+                    // Check if the transition ID matches the given ID.
+                    if *id == **transition.id() {
+                        // Return the transaction leaf.
+                        return Ok(TransactionLeaf::new_execution(u16::try_from(index)?, *id));
+                    }
+                }
+                // Error if the transition ID was not found.
+                bail!("Transition ID not found in bundle transaction");
+            }
         }
     }
 
@@ -96,6 +121,8 @@ impl<N: Network> Transaction<N> {
             Transaction::Execute(_, execution, fee) => Self::execution_tree(execution, fee),
             // Compute the fee tree.
             Transaction::Fee(_, fee) => Self::fee_tree(fee),
+            // Compute the bundle tree.
+            Transaction::Bundle(_, executions, fee) => Self::bundle_tree(executions, Some(fee)),
         }
     }
 }
@@ -109,12 +136,14 @@ impl<N: Network> Transaction<N> {
         let program = deployment.program();
         // Prepare the leaves.
         let leaves = program.functions().values().enumerate().map(|(index, function)| {
+            // Compute the function hash.
+            // Note: For an upgrade, the edition is bound to each leaf, so the deployment ID commits to the edition.
+            let function_hash = match deployment.is_upgrade() {
+                true => N::hash_bhp1024(&to_bits_le![program.id(), deployment.edition(), function.to_bytes_le()?])?,
+                false => N::hash_bhp1024(&to_bits_le![program.id(), function.to_bytes_le()?])?,
+            };
             // Construct the transaction leaf.
-            Ok(TransactionLeaf::new_deployment(
-                u16::try_from(index)?,
-                N::hash_bhp1024(&to_bits_le![program.id(), function.to_bytes_le()?])?,
-            )
-            .to_bits_le())
+            Ok(TransactionLeaf::new_deployment(u16::try_from(index)?, function_hash).to_bits_le())
         });
         // If the fee is present, add it to the leaves.
         let leaves = match fee {
@@ -180,6 +209,47 @@ impl<N: Network> Transaction<N> {
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[leaf])
     }
 
+    /// Returns the Merkle tree for the given bundle.
+    ///
+    /// The leaves are composed of:
+    /// | execution_0 transitions, ..., execution_n transitions | bundle leaf | fee leaf (optional) |
+    /// where the bundle leaf commits to the number of transitions in each execution.
+    pub fn bundle_tree(executions: &[Execution<N>], fee: Option<&Fee<N>>) -> Result<TransactionTree<N>> {
+        // Ensure the number of leaves is within the Merkle tree size.
+        Self::check_bundle_size(executions)?;
+        // Prepare the leaves.
+        let mut leaves = executions
+            .iter()
+            .flat_map(Execution::transitions)
+            .enumerate()
+            .map(|(index, transition)| {
+                // Construct the transaction leaf.
+                Ok(TransactionLeaf::new_execution(u16::try_from(index)?, **transition.id()).to_bits_le())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the number of transitions in each execution.
+        let lengths =
+            executions.iter().map(|execution| u16::try_from(execution.len())).collect::<Result<Vec<_>, _>>()?;
+        // Construct the bundle leaf.
+        let bundle_leaf =
+            TransactionLeaf::new_bundle(u16::try_from(leaves.len())?, N::hash_bhp1024(&lengths.to_bits_le())?);
+        leaves.push(bundle_leaf.to_bits_le());
+
+        // If the fee is present, add it to the leaves.
+        if let Some(fee) = fee {
+            // Construct the transaction leaf.
+            let leaf = TransactionLeaf::new_fee(
+                u16::try_from(leaves.len())?, // The last index.
+                **fee.transition_id(),
+            );
+            leaves.push(leaf.to_bits_le());
+        }
+
+        // Compute the bundle tree.
+        N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves)
+    }
+
     /// Returns `true` if the deployment is within the size bounds.
     pub fn check_deployment_size(deployment: &Deployment<N>) -> Result<()> {
         // Retrieve the program.
@@ -218,4 +288,78 @@ impl<N: Network> Transaction<N> {
         );
         Ok(())
     }
+
+    /// Returns `true` if the bundle is within the size bounds.
+    pub fn check_bundle_size(executions: &[Execution<N>]) -> Result<()> {
+        // Ensure there are at least two executions.
+        ensure!(executions.len() > 1, "Bundle must contain at least two executions, found {}", executions.len());
+        // Ensure the number of executions is within the allowed range.
+        ensure!(
+            executions.len() <= Self::MAX_BUNDLE_EXECUTIONS,
+            "Bundle must contain at most {} executions, found {}",
+            Self::MAX_BUNDLE_EXECUTIONS,
+            executions.len()
+        );
+        // Ensure each execution contains transitions.
+        ensure!(executions.iter().all(|execution| !execution.is_empty()), "Bundle contains an empty execution");
+        // Retrieve the number of transitions in the bundle.
+        let num_transitions = executions.iter().map(Execution::len).sum::<usize>();
+        // Ensure the number of transitions is within the allowed range.
+        ensure!(
+            num_transitions < Self::MAX_TRANSITIONS - 1, // Note: Observe we hold back 2 for the bundle leaf and the fee.
+            "Bundle must contain less than {} transitions, found {num_transitions}",
+            Self::MAX_TRANSITIONS - 1,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bundle_tree() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for is_fee_private in [true, false] {
+            // Sample a bundle transaction.
+            let transaction = crate::transaction::test_helpers::sample_bundle_transaction(is_fee_private, rng);
+            let executions = transaction.bundle().unwrap();
+            let fee = transaction.fee_transition().unwrap();
+
+            // Ensure every transition has a valid execution leaf and path.
+            let root = transaction.to_root()?;
+            for transition_id in transaction.transition_ids() {
+                let leaf = transaction.to_leaf(transition_id)?;
+                assert_eq!(leaf.variant(), 1);
+                let path = transaction.to_path(&leaf)?;
+                assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &root, &leaf.to_bits_le()));
+            }
+
+            // Ensure the bundle ID differs from an execution of the same transitions.
+            let transitions = executions.iter().flat_map(Execution::transitions);
+            let execution = Execution::from(transitions.cloned(), executions[0].global_state_root(), None)?;
+            assert_ne!(Transaction::execution_tree(&execution, &Some(fee.clone()))?.root(), &root);
+
+            // Ensure the bundle ID commits to the boundaries between executions.
+            let mut shifted = executions.to_vec();
+            let transition = shifted[0].pop()?;
+            if !shifted[0].is_empty() {
+                shifted[1] = Execution::from(
+                    std::iter::once(transition).chain(shifted[1].transitions().cloned()),
+                    shifted[1].global_state_root(),
+                    None,
+                )?;
+                assert_ne!(Transaction::bundle_tree(&shifted, Some(&fee))?.root(), &root);
+            }
+
+            // Ensure a bundle must contain at least two executions.
+            assert!(Transaction::from_bundle(executions[..1].to_vec(), fee).is_err());
+        }
+        Ok(())
+    }
 }
@@ -41,6 +41,8 @@ pub enum Transaction<N: Network> {
     Execute(N::TransactionID, Execution<N>, Option<Fee<N>>),
     /// The fee transaction represents a fee paid to the network, used for rejected transactions.
     Fee(N::TransactionID, Fee<N>),
+    /// The bundle transaction represents independent calls to Aleo programs, finalized atomically under one fee.
+    Bundle(N::TransactionID, Vec<Execution<N>>, Fee<N>),
 }
 
 impl<N: Network> Transaction<N> {
@@ -77,6 +79,15 @@ impl<N: Network> Transaction<N> {
         // Construct the execution transaction.
         Ok(Self::Fee(id.into(), fee))
     }
+
+    /// Initializes a new bundle transaction.
+    pub fn from_bundle(executions: Vec<Execution<N>>, fee: Fee<N>) -> Result<Self> {
+        // Compute the transaction ID.
+        // Note: This call will ensure the bundle is within the size bounds.
+        let id = *Self::bundle_tree(&executions, Some(&fee))?.root();
+        // Construct the bundle transaction.
+        Ok(Self::Bundle(id.into(), executions, fee))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -97,6 +108,12 @@ impl<N: Network> Transaction<N> {
     pub const fn is_fee(&self) -> bool {
         matches!(self, Self::Fee(..))
     }
+
+    /// Returns `true` if the transaction is a bundle transaction.
+    #[inline]
+    pub const fn is_bundle(&self) -> bool {
+        matches!(self, Self::Bundle(..))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -106,6 +123,10 @@ impl<N: Network> Transaction<N> {
         match self {
             // Case 1 - The transaction contains a transition that calls 'credits.aleo/split'.
             Transaction::Execute(_, execution, _) => execution.transitions().any(|transition| transition.is_split()),
+            // Case 2 - The bundle contains a transition that calls 'credits.aleo/split'.
+            Transaction::Bundle(_, executions, _) => {
+                executions.iter().flat_map(Execution::transitions).any(|transition| transition.is_split())
+            }
             // Otherwise, return 'false'.
             _ => false,
         }
@@ -139,16 +160,28 @@ impl<N: Network> Transaction<N> {
             _ => None,
         }
     }
+
+    /// Returns `Some(executions)` if the transaction is a bundle. Otherwise, returns `None`.
+    #[inline]
+    pub fn bundle(&self) -> Option<&[Execution<N>]> {
+        match self {
+            Self::Bundle(_, executions, _) => Some(executions),
+            _ => None,
+        }
+    }
 }
 
 /// A helper enum for iterators and consuming iterators over a transaction.
-enum IterWrap<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>> {
+enum IterWrap<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>, I4: Iterator<Item = T>> {
     Deploy(I1),
     Execute(I2),
     Fee(I3),
+    Bundle(I4),
 }
 
-impl<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>> Iterator for IterWrap<T, I1, I2, I3> {
+impl<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>, I4: Iterator<Item = T>> Iterator
+    for IterWrap<T, I1, I2, I3, I4>
+{
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -156,18 +189,25 @@ impl<T, I1: Iterator<Item = T>, I2: Iterator<Item = T>, I3: Iterator<Item = T>>
             Self::Deploy(iter) => iter.next(),
             Self::Execute(iter) => iter.next(),
             Self::Fee(iter) => iter.next(),
+            Self::Bundle(iter) => iter.next(),
         }
     }
 }
 
-impl<T, I1: DoubleEndedIterator<Item = T>, I2: DoubleEndedIterator<Item = T>, I3: DoubleEndedIterator<Item = T>>
-    DoubleEndedIterator for IterWrap<T, I1, I2, I3>
+impl<
+        T,
+        I1: DoubleEndedIterator<Item = T>,
+        I2: DoubleEndedIterator<Item = T>,
+        I3: DoubleEndedIterator<Item = T>,
+        I4: DoubleEndedIterator<Item = T>,
+    > DoubleEndedIterator for IterWrap<T, I1, I2, I3, I4>
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         match self {
             Self::Deploy(iter) => iter.next_back(),
             Self::Execute(iter) => iter.next_back(),
             Self::Fee(iter) => iter.next_back(),
+            Self::Bundle(iter) => iter.next_back(),
         }
     }
 }
@@ -179,6 +219,7 @@ impl<N: Network> Transaction<N> {
             Self::Deploy(id, ..) => *id,
             Self::Execute(id, ..) => *id,
             Self::Fee(id, ..) => *id,
+            Self::Bundle(id, ..) => *id,
         }
     }
 
@@ -189,6 +230,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.amount(),
+            Self::Bundle(_, _, fee) => fee.amount(),
         }
     }
 
@@ -199,6 +241,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.base_amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.base_amount(),
+            Self::Bundle(_, _, fee) => fee.base_amount(),
         }
     }
 
@@ -209,6 +252,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.priority_amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.priority_amount(),
+            Self::Bundle(_, _, fee) => fee.priority_amount(),
         }
     }
 
@@ -218,6 +262,7 @@ impl<N: Network> Transaction<N> {
             Self::Deploy(_, _, _, fee) => Some(fee.clone()),
             Self::Execute(_, _, fee) => fee.clone(),
             Self::Fee(_, fee) => Some(fee.clone()),
+            Self::Bundle(_, _, fee) => Some(fee.clone()),
         }
     }
 }
@@ -235,6 +280,11 @@ impl<N: Network> Transaction<N> {
             }
             // Check the fee.
             Self::Fee(_, fee) => fee.id() == transition_id,
+            // Check the executions and fee.
+            Self::Bundle(_, executions, fee) => {
+                executions.iter().any(|execution| execution.contains_transition(transition_id))
+                    || fee.id() == transition_id
+            }
         }
     }
 
@@ -270,6 +320,14 @@ impl<N: Network> Transaction<N> {
                 true => Some(fee.transition()),
                 false => None,
             },
+            // Check the executions and fee.
+            Self::Bundle(_, executions, fee) => executions
+                .iter()
+                .find_map(|execution| execution.get_transition(transition_id))
+                .or_else(|| match fee.id() == transition_id {
+                    true => Some(fee.transition()),
+                    false => None,
+                }),
         }
     }
 
@@ -303,6 +361,9 @@ impl<N: Network> Transaction<N> {
                 IterWrap::Execute(execution.transitions().chain(fee.as_ref().map(|fee| fee.transition())))
             }
             Self::Fee(_, fee) => IterWrap::Fee(Some(fee.transition()).into_iter()),
+            Self::Bundle(_, executions, fee) => IterWrap::Bundle(
+                executions.iter().flat_map(Execution::transitions).chain(std::iter::once(fee.transition())),
+            ),
         }
     }
 
@@ -370,6 +431,12 @@ impl<N: Network> Transaction<N> {
                 IterWrap::Execute(execution.into_transitions().chain(fee.map(|fee| fee.into_transition())))
             }
             Self::Fee(_, fee) => IterWrap::Fee(Some(fee.into_transition()).into_iter()),
+            Self::Bundle(_, executions, fee) => IterWrap::Bundle(
+                executions
+                    .into_iter()
+                    .flat_map(Execution::into_transitions)
+                    .chain(std::iter::once(fee.into_transition())),
+            ),
         }
     }
 
@@ -453,6 +520,25 @@ pub mod test_helpers {
         Transaction::from_execution(execution, Some(fee)).unwrap()
     }
 
+    /// Samples a random bundle transaction with a private or public fee.
+    pub fn sample_bundle_transaction(is_fee_private: bool, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        // Sample the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        // Retrieve the executions.
+        let executions: Vec<_> = block.transactions().iter().filter_map(|tx| tx.execution().cloned()).take(2).collect();
+        // Compute the bundle ID.
+        let bundle_id = *Transaction::bundle_tree(&executions, None).unwrap().root();
+
+        // Sample the fee.
+        let fee = match is_fee_private {
+            true => crate::transaction::fee::test_helpers::sample_fee_private(bundle_id, rng),
+            false => crate::transaction::fee::test_helpers::sample_fee_public(bundle_id, rng),
+        };
+
+        // Construct a bundle transaction.
+        Transaction::from_bundle(executions, fee).unwrap()
+    }
+
     /// Samples a random fee transaction.
     pub fn sample_private_fee_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
         // Sample a fee.
@@ -45,6 +45,14 @@ impl<N: Network> Serialize for Transaction<N> {
                     transaction.serialize_field("fee", &fee)?;
                     transaction.end()
                 }
+                Self::Bundle(id, executions, fee) => {
+                    let mut transaction = serializer.serialize_struct("Transaction", 4)?;
+                    transaction.serialize_field("type", "bundle")?;
+                    transaction.serialize_field("id", &id)?;
+                    transaction.serialize_field("executions", &executions)?;
+                    transaction.serialize_field("fee", &fee)?;
+                    transaction.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -94,6 +102,14 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
                         // Construct the transaction.
                         Transaction::from_fee(fee).map_err(de::Error::custom)?
                     }
+                    Some("bundle") => {
+                        // Retrieve the executions.
+                        let executions = DeserializeExt::take_from_value::<D>(&mut transaction, "executions")?;
+                        // Retrieve the fee.
+                        let fee = DeserializeExt::take_from_value::<D>(&mut transaction, "fee")?;
+                        // Construct the transaction.
+                        Transaction::from_bundle(executions, fee).map_err(de::Error::custom)?
+                    }
                     _ => return Err(de::Error::custom("Invalid transaction type")),
                 };
 
@@ -121,6 +137,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(true, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(false, rng),
         ]
         .into_iter()
         {
@@ -144,6 +162,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(true, rng),
+            crate::transaction::test_helpers::sample_bundle_transaction(false, rng),
         ]
         .into_iter()
         {
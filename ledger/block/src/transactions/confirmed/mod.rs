@@ -27,11 +27,11 @@ pub type NumFinalizeSize = u16;
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
+    /// The accepted execute transaction is composed of `(index, execute_or_bundle_transaction, finalize_operations)`.
     AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
     /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, finalize_operations)`.
     RejectedDeploy(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
-    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize_operations)`.
+    /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution_or_bundle, finalize_operations)`.
     RejectedExecute(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
 }
 
@@ -45,7 +45,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         // Retrieve the program and fee from the deployment transaction, and ensure the transaction is a deploy transaction.
         let (program, fee) = match &transaction {
             Transaction::Deploy(_, _, deployment, fee) => (deployment.program(), fee),
-            Transaction::Execute(..) | Transaction::Fee(..) => {
+            Transaction::Execute(..) | Transaction::Fee(..) | Transaction::Bundle(..) => {
                 bail!("Transaction '{}' is not a deploy transaction", transaction.id())
             }
         };
@@ -112,8 +112,8 @@ impl<N: Network> ConfirmedTransaction<N> {
                 }
             }
         }
-        // Ensure the transaction is an execute transaction.
-        match transaction.is_execute() {
+        // Ensure the transaction is an execute or bundle transaction.
+        match transaction.is_execute() || transaction.is_bundle() {
             true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations)),
             false => bail!("Transaction '{}' is not an execute transaction", transaction.id()),
         }
@@ -156,8 +156,8 @@ impl<N: Network> ConfirmedTransaction<N> {
         rejected: Rejected<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
-        // Ensure the rejected object is an execution.
-        ensure!(rejected.is_execution(), "Rejected execution is not an execution");
+        // Ensure the rejected object is an execution or a bundle.
+        ensure!(rejected.is_execution() || rejected.is_bundle(), "Rejected execution is not an execution");
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
             // Ensure the finalize operation is an insert or update key-value operation.
@@ -318,10 +318,19 @@ impl<N: Network> ConfirmedTransaction<N> {
                 rejected.deployment().cloned().ok_or_else(|| anyhow!("Missing deployment for rejected transaction"))?,
                 fee_transaction.fee_transition().ok_or_else(|| anyhow!("Missing fee for rejected deployment"))?,
             ),
-            Self::RejectedExecute(_, fee_transaction, rejected, _) => Transaction::from_execution(
-                rejected.execution().cloned().ok_or_else(|| anyhow!("Missing execution for rejected transaction"))?,
-                fee_transaction.fee_transition(),
-            ),
+            Self::RejectedExecute(_, fee_transaction, rejected, _) => match rejected.bundle() {
+                Some(executions) => Transaction::from_bundle(
+                    executions.to_vec(),
+                    fee_transaction.fee_transition().ok_or_else(|| anyhow!("Missing fee for rejected bundle"))?,
+                ),
+                None => Transaction::from_execution(
+                    rejected
+                        .execution()
+                        .cloned()
+                        .ok_or_else(|| anyhow!("Missing execution for rejected transaction"))?,
+                    fee_transaction.fee_transition(),
+                ),
+            },
         }
     }
 }
@@ -595,5 +604,16 @@ mod test {
             ConfirmedTransaction::rejected_execute(Uniform::rand(rng), fee, rejected, vec![]).unwrap();
         assert_eq!(rejected_execute.to_unconfirmed_transaction_id().unwrap(), execution_transaction.id());
         assert_eq!(rejected_execute.to_unconfirmed_transaction().unwrap(), execution_transaction);
+
+        // Ensure that the unconfirmed transaction of a rejected bundle is not equivalent to its confirmed transaction.
+        for is_fee_private in [true, false] {
+            let bundle_transaction = crate::transaction::test_helpers::sample_bundle_transaction(is_fee_private, rng);
+            let rejected = Rejected::new_bundle(bundle_transaction.bundle().unwrap().to_vec());
+            let fee = Transaction::from_fee(bundle_transaction.fee_transition().unwrap()).unwrap();
+            let rejected_bundle =
+                ConfirmedTransaction::rejected_execute(Uniform::rand(rng), fee, rejected, vec![]).unwrap();
+            assert_eq!(rejected_bundle.to_unconfirmed_transaction_id().unwrap(), bundle_transaction.id());
+            assert_eq!(rejected_bundle.to_unconfirmed_transaction().unwrap(), bundle_transaction);
+        }
     }
 }
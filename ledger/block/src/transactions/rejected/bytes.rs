@@ -33,7 +33,20 @@ impl<N: Network> FromBytes for Rejected<N> {
                 // Return the rejected execution.
                 Ok(Self::new_execution(execution))
             }
-            2.. => Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
+            2 => {
+                // Read the number of executions.
+                let num_executions = u16::read_le(&mut reader)?;
+                // Ensure the number of executions is within bounds.
+                if num_executions as usize > Transaction::<N>::MAX_BUNDLE_EXECUTIONS {
+                    return Err(error("Invalid number of executions in the rejected bundle"));
+                }
+                // Read the executions.
+                let executions =
+                    (0..num_executions).map(|_| Execution::read_le(&mut reader)).collect::<IoResult<_>>()?;
+                // Return the rejected bundle.
+                Ok(Self::new_bundle(executions))
+            }
+            3.. => Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
         }
     }
 }
@@ -56,6 +69,14 @@ impl<N: Network> ToBytes for Rejected<N> {
                 // Write the execution.
                 execution.write_le(&mut writer)
             }
+            Self::Bundle(executions) => {
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the number of executions.
+                u16::try_from(executions.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the executions.
+                executions.iter().try_for_each(|execution| execution.write_le(&mut writer))
+            }
         }
     }
 }
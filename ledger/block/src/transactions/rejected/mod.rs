@@ -25,6 +25,7 @@ use crate::{Deployment, Execution, Fee};
 pub enum Rejected<N: Network> {
     Deployment(ProgramOwner<N>, Box<Deployment<N>>),
     Execution(Execution<N>),
+    Bundle(Vec<Execution<N>>),
 }
 
 impl<N: Network> Rejected<N> {
@@ -38,6 +39,11 @@ impl<N: Network> Rejected<N> {
         Self::Execution(execution)
     }
 
+    /// Initializes a rejected bundle.
+    pub fn new_bundle(executions: Vec<Execution<N>>) -> Self {
+        Self::Bundle(executions)
+    }
+
     /// Returns true if the rejected transaction is a deployment.
     pub fn is_deployment(&self) -> bool {
         matches!(self, Self::Deployment(..))
@@ -48,11 +54,16 @@ impl<N: Network> Rejected<N> {
         matches!(self, Self::Execution(..))
     }
 
+    /// Returns true if the rejected transaction is a bundle.
+    pub fn is_bundle(&self) -> bool {
+        matches!(self, Self::Bundle(..))
+    }
+
     /// Returns the program owner of the rejected deployment.
     pub fn program_owner(&self) -> Option<&ProgramOwner<N>> {
         match self {
             Self::Deployment(program_owner, _) => Some(program_owner),
-            Self::Execution(_) => None,
+            Self::Execution(_) | Self::Bundle(_) => None,
         }
     }
 
@@ -60,23 +71,32 @@ impl<N: Network> Rejected<N> {
     pub fn deployment(&self) -> Option<&Deployment<N>> {
         match self {
             Self::Deployment(_, deployment) => Some(deployment),
-            Self::Execution(_) => None,
+            Self::Execution(_) | Self::Bundle(_) => None,
         }
     }
 
     /// Returns the rejected execution.
     pub fn execution(&self) -> Option<&Execution<N>> {
         match self {
-            Self::Deployment(_, _) => None,
+            Self::Deployment(_, _) | Self::Bundle(_) => None,
             Self::Execution(execution) => Some(execution),
         }
     }
 
+    /// Returns the rejected bundle.
+    pub fn bundle(&self) -> Option<&[Execution<N>]> {
+        match self {
+            Self::Deployment(_, _) | Self::Execution(_) => None,
+            Self::Bundle(executions) => Some(executions),
+        }
+    }
+
     /// Returns the rejected ID.
     pub fn to_id(&self) -> Result<Field<N>> {
         match self {
             Self::Deployment(_, deployment) => deployment.to_deployment_id(),
             Self::Execution(execution) => execution.to_execution_id(),
+            Self::Bundle(executions) => Ok(*Transaction::bundle_tree(executions, None)?.root()),
         }
     }
 
@@ -87,6 +107,7 @@ impl<N: Network> Rejected<N> {
         match self {
             Self::Deployment(_, deployment) => Ok(*Transaction::deployment_tree(deployment, fee.as_ref())?.root()),
             Self::Execution(execution) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
+            Self::Bundle(executions) => Ok(*Transaction::bundle_tree(executions, fee.as_ref())?.root()),
         }
     }
 }
@@ -128,6 +149,18 @@ pub mod test_helpers {
         Rejected::new_execution(execution)
     }
 
+    /// Samples a rejected bundle.
+    pub(crate) fn sample_rejected_bundle(is_fee_private: bool, rng: &mut TestRng) -> Rejected<CurrentNetwork> {
+        // Sample a bundle transaction.
+        let executions = match crate::transaction::test_helpers::sample_bundle_transaction(is_fee_private, rng) {
+            Transaction::Bundle(_, executions, _) => executions,
+            _ => unreachable!(),
+        };
+
+        // Return the rejected bundle.
+        Rejected::new_bundle(executions)
+    }
+
     /// Sample a list of randomly rejected transactions.
     pub(crate) fn sample_rejected_transactions() -> Vec<Rejected<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -137,6 +170,8 @@ pub mod test_helpers {
             sample_rejected_deployment(false, rng),
             sample_rejected_execution(true, rng),
             sample_rejected_execution(false, rng),
+            sample_rejected_bundle(true, rng),
+            sample_rejected_bundle(false, rng),
         ]
     }
 }
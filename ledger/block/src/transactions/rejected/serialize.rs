@@ -32,6 +32,12 @@ impl<N: Network> Serialize for Rejected<N> {
                     object.serialize_field("execution", execution)?;
                     object.end()
                 }
+                Self::Bundle(executions) => {
+                    let mut object = serializer.serialize_struct("Rejected", 2)?;
+                    object.serialize_field("type", "bundle")?;
+                    object.serialize_field("executions", executions)?;
+                    object.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -67,6 +73,13 @@ impl<'de, N: Network> Deserialize<'de> for Rejected<N> {
                         // Return the rejected execution.
                         Ok(Self::new_execution(execution))
                     }
+                    Some("bundle") => {
+                        // Parse the executions.
+                        let executions: Vec<Execution<N>> =
+                            DeserializeExt::take_from_value::<D>(&mut object, "executions")?;
+                        // Return the rejected bundle.
+                        Ok(Self::new_bundle(executions))
+                    }
                     _ => Err(de::Error::custom("Invalid rejected transaction type")),
                 }
             }
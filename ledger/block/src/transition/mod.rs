@@ -143,6 +143,11 @@ impl<N: Network> Transition<N> {
                         // Return the input record.
                         Ok(Input::Record(*serial_number, *tag))
                     }
+                    (InputID::ProgramRecord(_, _, serial_number, tag), Value::Record(..)) => {
+                        // Return the input record.
+                        // Note: A program record is consumed on-chain exactly as a record input.
+                        Ok(Input::Record(*serial_number, *tag))
+                    }
                     (InputID::ExternalRecord(input_hash), Value::Record(..)) => Ok(Input::ExternalRecord(*input_hash)),
                     _ => bail!("Malformed request input: {:?}, {input}", input_id),
                 }
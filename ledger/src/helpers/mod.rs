@@ -17,3 +17,9 @@ pub use bft::*;
 
 mod supply;
 pub use supply::*;
+
+mod snapshot;
+pub use snapshot::*;
+
+mod record_scanner;
+pub use record_scanner::*;
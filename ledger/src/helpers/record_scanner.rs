@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::RecordMap;
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+use ledger_block::Block;
+
+use indexmap::IndexMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A record found by the scanner, as `(commitment, record)`.
+type ScannedRecord<N> = (Field<N>, Record<N, Plaintext<N>>);
+
+/// The scan state of a single view key.
+#[derive(Clone, PartialEq, Eq)]
+struct ScannedAccount<N: Network> {
+    /// The view key.
+    view_key: ViewKey<N>,
+    /// The `sk_tag` of the graph key, used to derive the tags of the owned records.
+    sk_tag: Field<N>,
+    /// The next block height to scan.
+    next_height: u32,
+    /// The hash of the last scanned block, if any.
+    last_block_hash: Option<N::BlockHash>,
+    /// The unspent records, as a map of `commitment` to `record`.
+    unspent: RecordMap<N>,
+    /// The tags of the unspent records, as a map of `tag` to `commitment`.
+    tags: IndexMap<Field<N>, Field<N>>,
+}
+
+impl<N: Network> ScannedAccount<N> {
+    /// Initializes a new scan state, starting from the given block height.
+    fn new(view_key: ViewKey<N>, next_height: u32, last_block_hash: Option<N::BlockHash>) -> Result<Self> {
+        let sk_tag = GraphKey::try_from(&view_key)?.sk_tag();
+        Ok(Self {
+            view_key,
+            sk_tag,
+            next_height,
+            last_block_hash,
+            unspent: Default::default(),
+            tags: Default::default(),
+        })
+    }
+
+    /// Inserts the given unspent record.
+    fn insert(&mut self, commitment: Field<N>, record: Record<N, Plaintext<N>>) -> Result<()> {
+        self.tags.insert(Record::<N, Plaintext<N>>::tag(self.sk_tag, commitment)?, commitment);
+        self.unspent.insert(commitment, record);
+        Ok(())
+    }
+
+    /// Scans the given block, which must be the next block for this view key,
+    /// and returns the `(spent tags, new records)` to apply to the scan state.
+    #[allow(clippy::type_complexity)]
+    fn scan_block(&self, block: &Block<N>) -> Result<(Vec<Field<N>>, IndexMap<Field<N>, ScannedRecord<N>>)> {
+        // Ensure the block is the next block to scan.
+        ensure!(
+            block.height() == self.next_height,
+            "Expected to scan block {} for '{}', found block {}",
+            self.next_height,
+            self.view_key.to_address(),
+            block.height()
+        );
+        // Ensure the block extends the last scanned block, to detect a rollback of the ledger.
+        if let Some(last_block_hash) = self.last_block_hash {
+            ensure!(
+                block.previous_hash() == last_block_hash,
+                "Block {} does not extend the last scanned block for '{}' (the ledger was rolled back)",
+                block.height(),
+                self.view_key.to_address()
+            );
+        }
+
+        let mut spent = Vec::new();
+        // The new records, as a map of `tag` to `(commitment, record)`.
+        let mut created = IndexMap::new();
+        // Process the transitions in order, so that a record spent in the same block as it was created is skipped.
+        for transition in block.transitions() {
+            // Collect the records spent by this transition.
+            for tag in transition.inputs().iter().filter_map(|input| input.tag()) {
+                if created.shift_remove(tag).is_none() && self.tags.contains_key(tag) {
+                    spent.push(*tag);
+                }
+            }
+            // Collect the records owned by this view key.
+            for (commitment, record) in transition.outputs().iter().filter_map(|output| output.record()) {
+                if record.is_owner(&self.view_key) {
+                    let tag = Record::<N, Plaintext<N>>::tag(self.sk_tag, *commitment)?;
+                    created.insert(tag, (*commitment, record.decrypt(&self.view_key)?));
+                }
+            }
+        }
+        Ok((spent, created))
+    }
+
+    /// Applies the result of scanning the given block to the scan state.
+    fn apply(&mut self, block: &Block<N>, spent: Vec<Field<N>>, created: IndexMap<Field<N>, ScannedRecord<N>>) {
+        // Remove the spent records.
+        for tag in spent {
+            if let Some(commitment) = self.tags.shift_remove(&tag) {
+                self.unspent.shift_remove(&commitment);
+            }
+        }
+        // Insert the new records.
+        for (tag, (commitment, record)) in created {
+            self.tags.insert(tag, commitment);
+            self.unspent.insert(commitment, record);
+        }
+        // Advance the cursor.
+        self.next_height = block.height().saturating_add(1);
+        self.last_block_hash = Some(block.hash());
+    }
+}
+
+/// An incremental scanner of the records owned by a set of view keys.
+///
+/// The scanner tracks a cursor for each view key, so that each block is only scanned once,
+/// and maintains the unspent records of each view key by watching the tags of the spent records.
+/// The scanner may be persisted with `ToBytes`, and resumed with `FromBytes`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecordScanner<N: Network> {
+    /// The scan state of each view key, keyed by address.
+    accounts: IndexMap<Address<N>, ScannedAccount<N>>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner, without any view keys.
+    pub fn new() -> Self {
+        Self { accounts: Default::default() }
+    }
+
+    /// Adds the given view key to the scanner, to be scanned from the genesis block.
+    /// If the view key was already added, this is a no-op.
+    pub fn add_view_key(&mut self, view_key: ViewKey<N>) -> Result<()> {
+        let address = view_key.to_address();
+        if !self.accounts.contains_key(&address) {
+            self.accounts.insert(address, ScannedAccount::new(view_key, 0, None)?);
+        }
+        Ok(())
+    }
+
+    /// Adds the given view key to the scanner, to be scanned from the block after the given `block`.
+    /// This skips the records created before the given block, for accounts that are known to be new.
+    pub fn add_view_key_from(&mut self, view_key: ViewKey<N>, block: &Block<N>) -> Result<()> {
+        let address = view_key.to_address();
+        ensure!(!self.accounts.contains_key(&address), "The view key for '{address}' is already in the scanner");
+        let next_height = block.height().saturating_add(1);
+        self.accounts.insert(address, ScannedAccount::new(view_key, next_height, Some(block.hash()))?);
+        Ok(())
+    }
+
+    /// Removes the view key for the given address from the scanner, returning `true` if it was present.
+    pub fn remove_view_key(&mut self, address: &Address<N>) -> bool {
+        self.accounts.shift_remove(address).is_some()
+    }
+
+    /// Returns the addresses of the view keys in the scanner.
+    pub fn addresses(&self) -> impl '_ + Iterator<Item = &Address<N>> {
+        self.accounts.keys()
+    }
+
+    /// Returns the next block height to scan for the given address, or `None` if the address is not in the scanner.
+    pub fn cursor(&self, address: &Address<N>) -> Option<u32> {
+        self.accounts.get(address).map(|account| account.next_height)
+    }
+
+    /// Returns the next block height to scan, across all view keys, or `None` if the scanner is empty.
+    pub fn min_cursor(&self) -> Option<u32> {
+        self.accounts.values().map(|account| account.next_height).min()
+    }
+
+    /// Returns the unspent records for the given address, as of its cursor.
+    pub fn unspent_records(&self, address: &Address<N>) -> Option<&RecordMap<N>> {
+        self.accounts.get(address).map(|account| &account.unspent)
+    }
+
+    /// Scans the given block for each view key whose cursor is at the block height, in parallel.
+    /// The view keys whose cursor is ahead of the block height are skipped.
+    ///
+    /// If an error occurs, the scan state of the view keys is left unchanged.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure no view key has a cursor behind the block height, as its blocks would be skipped.
+        if let Some(min_cursor) = self.min_cursor() {
+            ensure!(min_cursor >= block.height(), "The scanner must scan block {min_cursor} first");
+        }
+        // Scan the block for each view key.
+        let updates = cfg_iter!(self.accounts)
+            .filter(|(_, account)| account.next_height == block.height())
+            .map(|(address, account)| Ok((*address, account.scan_block(block)?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Apply the updates to the scan state.
+        for (address, (spent, created)) in updates {
+            if let Some(account) = self.accounts.get_mut(&address) {
+                account.apply(block, spent, created);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Default for RecordScanner<N> {
+    /// Initializes a new record scanner, without any view keys.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FromBytes for RecordScanner<N> {
+    /// Reads the record scanner from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid record scanner version"));
+        }
+
+        // Read the scan state of each view key.
+        let num_accounts = u32::read_le(&mut reader)?;
+        let mut accounts = IndexMap::with_capacity(num_accounts as usize);
+        for _ in 0..num_accounts {
+            // Read the view key and cursor.
+            let view_key = ViewKey::<N>::read_le(&mut reader)?;
+            let next_height = u32::read_le(&mut reader)?;
+            let last_block_hash = match bool::read_le(&mut reader)? {
+                true => Some(FromBytes::read_le(&mut reader)?),
+                false => None,
+            };
+            let mut account = ScannedAccount::new(view_key, next_height, last_block_hash).map_err(error)?;
+            // Read the unspent records.
+            let num_unspent = u32::read_le(&mut reader)?;
+            for _ in 0..num_unspent {
+                let commitment = Field::read_le(&mut reader)?;
+                let record = Record::read_le(&mut reader)?;
+                account.insert(commitment, record).map_err(error)?;
+            }
+            accounts.insert(view_key.to_address(), account);
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<N: Network> ToBytes for RecordScanner<N> {
+    /// Writes the record scanner to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the scan state of each view key.
+        u32::try_from(self.accounts.len()).map_err(error)?.write_le(&mut writer)?;
+        for account in self.accounts.values() {
+            // Write the view key and cursor.
+            account.view_key.write_le(&mut writer)?;
+            account.next_height.write_le(&mut writer)?;
+            match account.last_block_hash {
+                Some(last_block_hash) => {
+                    true.write_le(&mut writer)?;
+                    last_block_hash.write_le(&mut writer)?;
+                }
+                None => false.write_le(&mut writer)?,
+            }
+            // Write the unspent records.
+            u32::try_from(account.unspent.len()).map_err(error)?.write_le(&mut writer)?;
+            for (commitment, record) in &account.unspent {
+                commitment.write_le(&mut writer)?;
+                record.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+use ledger_block::{Block, Transaction};
+use ledger_committee::Committee;
+
+/// The finalize entries of a mapping, as `(program ID, mapping name, entries)`.
+pub type SnapshotMapping<N> = (ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>);
+
+/// A snapshot of the ledger state at a given block height, used to bootstrap a ledger without replaying every block.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Snapshot<N: Network> {
+    /// The block at the snapshot height.
+    block: Block<N>,
+    /// The hashes of the blocks preceding the snapshot block, in ascending order of height.
+    block_hashes: Vec<N::BlockHash>,
+    /// The current round of the committee store.
+    current_round: u64,
+    /// The `(round, height)` entries of the committee store, within the committee lookback range.
+    rounds: Vec<(u64, u32)>,
+    /// The `(height, committee)` entries of the committee store, within the committee lookback range.
+    committees: Vec<(u32, Committee<N>)>,
+    /// The deployment transactions for the latest edition of each program, excluding those in the snapshot block.
+    deployments: Vec<Transaction<N>>,
+    /// The finalize mappings.
+    mappings: Vec<SnapshotMapping<N>>,
+    /// The confirmed checksum of the finalize store.
+    /// Note: The checksum only detects corruption of the mappings, and does not authenticate them.
+    checksum: Field<N>,
+}
+
+impl<N: Network> Snapshot<N> {
+    /// Initializes a new snapshot.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        block: Block<N>,
+        block_hashes: Vec<N::BlockHash>,
+        current_round: u64,
+        rounds: Vec<(u64, u32)>,
+        committees: Vec<(u32, Committee<N>)>,
+        deployments: Vec<Transaction<N>>,
+        mappings: Vec<SnapshotMapping<N>>,
+        checksum: Field<N>,
+    ) -> Result<Self> {
+        // Ensure the block hashes precede the snapshot block.
+        ensure!(
+            u32::try_from(block_hashes.len())? == block.height(),
+            "Expected {} block hashes in the snapshot, found {}",
+            block.height(),
+            block_hashes.len()
+        );
+        // Ensure the deployments are all deployment transactions.
+        ensure!(deployments.iter().all(Transaction::is_deploy), "The snapshot deployments must be deployments");
+        // Return the snapshot.
+        Ok(Self { block, block_hashes, current_round, rounds, committees, deployments, mappings, checksum })
+    }
+
+    /// Returns the snapshot height.
+    pub const fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the block at the snapshot height.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the hashes of the blocks preceding the snapshot block.
+    pub fn block_hashes(&self) -> &[N::BlockHash] {
+        &self.block_hashes
+    }
+
+    /// Returns the current round of the committee store.
+    pub const fn current_round(&self) -> u64 {
+        self.current_round
+    }
+
+    /// Returns the `(round, height)` entries of the committee store.
+    pub fn rounds(&self) -> &[(u64, u32)] {
+        &self.rounds
+    }
+
+    /// Returns the `(height, committee)` entries of the committee store.
+    pub fn committees(&self) -> &[(u32, Committee<N>)] {
+        &self.committees
+    }
+
+    /// Returns the deployment transactions.
+    pub fn deployments(&self) -> &[Transaction<N>] {
+        &self.deployments
+    }
+
+    /// Returns the finalize mappings.
+    pub fn mappings(&self) -> &[SnapshotMapping<N>] {
+        &self.mappings
+    }
+
+    /// Returns the confirmed checksum of the finalize store.
+    pub const fn checksum(&self) -> Field<N> {
+        self.checksum
+    }
+}
+
+impl<N: Network> FromBytes for Snapshot<N> {
+    /// Reads the snapshot from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid snapshot version"));
+        }
+
+        // Read the block.
+        let block = Block::read_le(&mut reader)?;
+        // Read the block hashes.
+        let num_block_hashes = u32::read_le(&mut reader)?;
+        let block_hashes =
+            (0..num_block_hashes).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the current round.
+        let current_round = u64::read_le(&mut reader)?;
+        // Read the rounds.
+        let num_rounds = u32::read_le(&mut reader)?;
+        let rounds = (0..num_rounds).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the committees.
+        let num_committees = u32::read_le(&mut reader)?;
+        let committees = (0..num_committees).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the deployments.
+        let num_deployments = u32::read_le(&mut reader)?;
+        let deployments =
+            (0..num_deployments).map(|_| Transaction::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the mappings.
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mappings = (0..num_mappings)
+            .map(|_| {
+                let program_id = ProgramID::read_le(&mut reader)?;
+                let mapping_name = Identifier::read_le(&mut reader)?;
+                let num_entries = u32::read_le(&mut reader)?;
+                let entries =
+                    (0..num_entries).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                Ok((program_id, mapping_name, entries))
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        // Read the checksum.
+        let checksum = Field::read_le(&mut reader)?;
+
+        // Return the snapshot.
+        Self::new(block, block_hashes, current_round, rounds, committees, deployments, mappings, checksum)
+            .map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Snapshot<N> {
+    /// Writes the snapshot to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block.
+        self.block.write_le(&mut writer)?;
+        // Write the block hashes.
+        u32::try_from(self.block_hashes.len()).map_err(error)?.write_le(&mut writer)?;
+        self.block_hashes.write_le(&mut writer)?;
+
+        // Write the current round.
+        self.current_round.write_le(&mut writer)?;
+        // Write the rounds.
+        u32::try_from(self.rounds.len()).map_err(error)?.write_le(&mut writer)?;
+        self.rounds.write_le(&mut writer)?;
+        // Write the committees.
+        u32::try_from(self.committees.len()).map_err(error)?.write_le(&mut writer)?;
+        self.committees.write_le(&mut writer)?;
+
+        // Write the deployments.
+        u32::try_from(self.deployments.len()).map_err(error)?.write_le(&mut writer)?;
+        self.deployments.write_le(&mut writer)?;
+
+        // Write the mappings.
+        u32::try_from(self.mappings.len()).map_err(error)?.write_le(&mut writer)?;
+        for (program_id, mapping_name, entries) in &self.mappings {
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            u32::try_from(entries.len()).map_err(error)?.write_le(&mut writer)?;
+            entries.write_le(&mut writer)?;
+        }
+
+        // Write the checksum.
+        self.checksum.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Enables the mapping history from the latest block height onwards,
+    /// allowing the mappings to be queried as of any block height that is subsequently added.
+    pub fn enable_history(&self) -> Result<()> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let current_block = self.current_block.write();
+        self.vm.finalize_store().enable_history(current_block.height())
+    }
+
+    /// Disables the mapping history, removing all recorded history from storage.
+    pub fn disable_history(&self) -> Result<()> {
+        self.vm.finalize_store().disable_history()
+    }
+
+    /// Prunes the mapping history up to the given `block height`,
+    /// such that the earliest block height that may be queried becomes `block height`.
+    pub fn prune_history(&self, block_height: u32) -> Result<()> {
+        self.vm.finalize_store().prune_history(block_height)
+    }
+
+    /// Returns the `(earliest, latest)` block heights that may be queried in the mapping history,
+    /// or `None` if the history is not enabled.
+    pub fn get_history_range(&self) -> Result<Option<(u32, u32)>> {
+        self.vm.finalize_store().get_history_range()
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.vm.finalize_store().get_value_at_height(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns the mapping entries for the given `program ID` and `mapping name`, as of the given `block height`.
+    pub fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        block_height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.vm.finalize_store().get_mapping_at_height(program_id, mapping_name, block_height)
+    }
+}
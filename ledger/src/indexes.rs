@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Enables the secondary indexes, indexing the transactions and transitions by program ID,
+    /// function name, and the addresses in their public inputs and outputs.
+    pub fn enable_indexes(&self) -> Result<()> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let _current_block = self.current_block.write();
+        self.vm.transaction_store().enable_indexes()
+    }
+
+    /// Disables the secondary indexes, removing them from storage.
+    pub fn disable_indexes(&self) -> Result<()> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let _current_block = self.current_block.write();
+        self.vm.transaction_store().disable_indexes()
+    }
+
+    /// Returns `true` if the secondary indexes are enabled.
+    pub fn indexes_enabled(&self) -> Result<bool> {
+        self.vm.transaction_store().indexes_enabled()
+    }
+
+    /// Returns an iterator over the IDs of the transactions that deploy or execute the given `program ID`.
+    pub fn find_transaction_ids_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransactionID>> {
+        self.vm.transaction_store().find_transaction_ids_for_program(program_id)
+    }
+
+    /// Returns an iterator over the IDs of the transitions that call the given `program ID` and `function name`.
+    pub fn find_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+        self.vm.transition_store().find_transition_ids_for_function(program_id, function_name)
+    }
+
+    /// Returns an iterator over the IDs of the transitions with the given `address` in their public inputs or outputs.
+    pub fn find_transition_ids_for_address(
+        &self,
+        address: &Address<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+        self.vm.transition_store().find_transition_ids_for_address(address)
+    }
+
+    /// Returns an iterator over the records owned by the given `view key`, from the outputs of
+    /// the transitions that call the given `program ID` and `function name`.
+    ///
+    /// Unlike `find_records`, this only decrypts the records output by the indexed transitions.
+    pub fn find_records_for_function<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        let transition_ids = self.find_transition_ids_for_function(program_id, function_name)?;
+        Ok(transition_ids.flat_map(move |transition_id| {
+            // Retrieve the records output by the transition.
+            let outputs = match self.vm.transition_store().get_outputs(&transition_id) {
+                Ok(outputs) => outputs,
+                Err(e) => {
+                    warn!("Failed to get the outputs of transition '{transition_id}': {e}");
+                    Vec::new()
+                }
+            };
+            outputs.into_iter().flat_map(Output::into_record).filter_map(|(commitment, record)| {
+                match record.is_owner(view_key) {
+                    true => match record.decrypt(view_key) {
+                        Ok(record) => Some((commitment, record)),
+                        Err(e) => {
+                            warn!("Failed to decrypt the record: {e}");
+                            None
+                        }
+                    },
+                    false => None,
+                }
+            })
+        }))
+    }
+}
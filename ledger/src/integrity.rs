@@ -0,0 +1,25 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the cross-references between all maps in the ledger storage, and returns the inconsistencies found.
+    /// If `repair` is `true`, the repairable inconsistencies are removed from storage.
+    pub fn check_integrity(&self, repair: bool) -> Result<Vec<Inconsistency<N>>> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let _current_block = self.current_block.write();
+        self.vm.check_integrity(repair)
+    }
+}
@@ -40,7 +40,14 @@ mod check_transaction_basic;
 mod contains;
 mod find;
 mod get;
+mod history;
+mod indexes;
+mod integrity;
 mod iterators;
+mod prune;
+mod rollback;
+mod scan;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -56,10 +63,10 @@ use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_puzzle::{Puzzle, PuzzleSolutions, Solution, SolutionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{ConsensusStorage, ConsensusStore, Inconsistency};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
-    vm::VM,
+    vm::{BlockEvent, VM},
 };
 
 use aleo_std::{
@@ -71,7 +78,10 @@ use core::ops::Range;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    sync::{mpsc::Receiver, Arc},
+};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -127,9 +137,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the latest height.
         let latest_height = ledger.current_block.read().height();
         debug_assert_eq!(latest_height, *ledger.vm.block_store().heights().max().unwrap(), "Mismatch in latest height");
+        // Retrieve the earliest height with a full block (this is non-zero if the ledger was restored or pruned).
+        let earliest_height = ledger.earliest_block_height()?;
         // Sample random block heights.
-        let block_heights: Vec<u32> =
-            (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
+        let block_heights: Vec<u32> = (earliest_height..=latest_height)
+            .choose_multiple(&mut OsRng, ((latest_height - earliest_height) as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
             ledger.get_block(height)?;
             Ok::<_, Error>(())
@@ -152,6 +164,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, without performing integrity checks.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
@@ -190,7 +213,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current epoch hash.
         ledger.current_epoch_hash = Arc::new(RwLock::new(Some(ledger.get_epoch_hash(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
+        finish!(timer);
         Ok(ledger)
     }
 
@@ -199,6 +222,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.vm
     }
 
+    /// Subscribes to the events emitted upon advancing to each subsequent block.
+    /// The subscription ends when the returned receiver is dropped, or when the subscriber lags behind.
+    /// See [`VM::subscribe`] for details.
+    pub fn subscribe(&self) -> Receiver<BlockEvent<N>> {
+        self.vm.subscribe()
+    }
+
     /// Returns the puzzle.
     pub const fn puzzle(&self) -> &Puzzle<N> {
         self.vm.puzzle()
@@ -395,18 +425,23 @@ pub(crate) mod test_helpers {
 
     pub(crate) type CurrentNetwork = MainnetV0;
 
-    #[cfg(not(feature = "rocks"))]
+    #[cfg(not(any(feature = "rocks", feature = "redb")))]
     pub(crate) type CurrentLedger =
         Ledger<CurrentNetwork, ledger_store::helpers::memory::ConsensusMemory<CurrentNetwork>>;
     #[cfg(feature = "rocks")]
     pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ledger_store::helpers::rocksdb::ConsensusDB<CurrentNetwork>>;
+    #[cfg(all(feature = "redb", not(feature = "rocks")))]
+    pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ledger_store::helpers::redb::ConsensusDB<CurrentNetwork>>;
 
-    #[cfg(not(feature = "rocks"))]
+    #[cfg(not(any(feature = "rocks", feature = "redb")))]
     pub(crate) type CurrentConsensusStore =
         ConsensusStore<CurrentNetwork, ledger_store::helpers::memory::ConsensusMemory<CurrentNetwork>>;
     #[cfg(feature = "rocks")]
     pub(crate) type CurrentConsensusStore =
         ConsensusStore<CurrentNetwork, ledger_store::helpers::rocksdb::ConsensusDB<CurrentNetwork>>;
+    #[cfg(all(feature = "redb", not(feature = "rocks")))]
+    pub(crate) type CurrentConsensusStore =
+        ConsensusStore<CurrentNetwork, ledger_store::helpers::redb::ConsensusDB<CurrentNetwork>>;
 
     #[allow(dead_code)]
     pub(crate) struct TestEnv {
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Sets the pruning depth, such that blocks older than `depth` blocks are pruned as new blocks are added.
+    /// The block headers, state roots, and finalize state are retained, along with the state paths for records.
+    /// If `depth` is `None`, blocks are no longer pruned, though previously-pruned blocks remain pruned.
+    pub fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let _current_block = self.current_block.write();
+        self.vm.block_store().set_pruning_depth(depth)
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    pub fn pruning_depth(&self) -> Result<Option<u32>> {
+        self.vm.block_store().pruning_depth()
+    }
+
+    /// Returns the latest pruned block height, or `None` if no blocks have been pruned.
+    pub fn pruned_height(&self) -> Result<Option<u32>> {
+        self.vm.block_store().pruned_height()
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Rolls back the ledger to the given block height, removing the subsequent blocks.
+    /// The mapping entries, committees, and programs are reverted to their state as of the given block height,
+    /// and the puzzle and coinbase state follow from the block at the given height.
+    ///
+    /// The mapping history must be enabled, and cover the given block height, as it is used to revert the blocks.
+    /// See [`VM::rollback_to`] for details.
+    pub fn rollback_to(&self, block_height: u32) -> Result<()> {
+        // Acquire the write lock on the current block, to prevent the ledger from advancing.
+        let mut current_block = self.current_block.write();
+        // Roll back the VM.
+        self.vm.rollback_to(block_height)?;
+
+        // Update the current block.
+        *current_block = self.get_block(block_height)?;
+        // Update the cached committee from storage.
+        *self.current_committee.write() = Some(self.vm.finalize_store().committee_store().current_committee()?);
+        // Update the current epoch hash.
+        *self.current_epoch_hash.write() = Some(self.get_epoch_hash(block_height)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Scans the blocks after the cursor of each view key in the given `scanner`, up to the latest block,
+    /// updating the unspent records of each view key.
+    ///
+    /// Each block is fetched once, and scanned for all of the view keys in parallel.
+    pub fn scan_records(&self, scanner: &mut RecordScanner<N>) -> Result<()> {
+        // Retrieve the next block height to scan.
+        let Some(start_height) = scanner.min_cursor() else {
+            return Ok(());
+        };
+        // Ensure the blocks to scan are in storage.
+        let earliest_height = self.earliest_block_height()?;
+        ensure!(
+            start_height >= earliest_height,
+            "Cannot scan from block {start_height}, as the blocks before {earliest_height} are not in storage"
+        );
+
+        // Scan the blocks up to the latest block.
+        for height in start_height..=self.latest_height() {
+            scanner.scan_block(&self.get_block(height)?)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::{atomic_batch_scope, helpers::memory::FinalizeMemory, FinalizeStore};
+
+use indexmap::IndexSet;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a snapshot of the ledger state at the latest block height.
+    ///
+    /// The snapshot contains the finalize mappings, the block hashes for the state tree,
+    /// the committees within the committee lookback range, and the deployments of every program.
+    pub fn snapshot(&self) -> Result<Snapshot<N>> {
+        // Retrieve the latest block.
+        let block = self.latest_block();
+        // Retrieve the state root after the latest block.
+        let state_root = self.vm.block_store().current_state_root();
+        // Ensure the state root belongs to the latest block.
+        ensure!(
+            self.vm.block_store().get_state_root(block.height())? == Some(state_root),
+            "The ledger is advancing, try taking the snapshot again"
+        );
+
+        // Retrieve the hashes of the blocks preceding the latest block.
+        let block_hashes = (0..block.height()).map(|height| self.get_hash(height)).collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the committee store.
+        let committee_store = self.vm.finalize_store().committee_store();
+        // Retrieve the current round.
+        let current_round = committee_store.current_round()?;
+        // Determine the earliest round that may be looked up when advancing past the latest block.
+        let start_round = current_round.saturating_sub(2 * Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        // Retrieve the committee entries from the start round.
+        let mut rounds = Vec::new();
+        let mut committees: Vec<(u32, Committee<N>)> = Vec::new();
+        for round in start_round..=current_round {
+            if let Some(height) = committee_store.get_height_for_round(round)? {
+                rounds.push((round, height));
+                // Retrieve the committee for the height, if it has not been retrieved yet.
+                if committees.last().map(|(last_height, _)| *last_height) != Some(height) {
+                    match committee_store.get_committee(height)? {
+                        Some(committee) => committees.push((height, committee)),
+                        None => bail!("Missing committee for block {height}"),
+                    }
+                }
+            }
+        }
+
+        // Retrieve the program IDs of the deployments.
+        let transaction_store = self.vm.transaction_store();
+        let program_ids = transaction_store.program_ids().map(|program_id| *program_id).collect::<IndexSet<_>>();
+        // Retrieve the latest deployment of each program, excluding those in the latest block.
+        let block_transaction_ids = block.transaction_ids().collect::<IndexSet<_>>();
+        let mut deployments = Vec::with_capacity(program_ids.len());
+        for program_id in &program_ids {
+            let Some(transaction_id) = transaction_store.find_transaction_id_from_program_id(program_id)? else {
+                bail!("Missing the deployment transaction ID for '{program_id}'")
+            };
+            if !block_transaction_ids.contains(&transaction_id) {
+                match transaction_store.get_transaction(&transaction_id)? {
+                    Some(transaction) => deployments.push(transaction),
+                    None => bail!("Missing the deployment transaction '{transaction_id}'"),
+                }
+            }
+        }
+
+        // Retrieve the finalize mappings of each program.
+        let finalize_store = self.vm.finalize_store();
+        let mut mappings = Vec::new();
+        for program_id in std::iter::once(ProgramID::from_str("credits.aleo")?).chain(program_ids) {
+            if let Some(mapping_names) = finalize_store.get_mapping_names_confirmed(&program_id)? {
+                for mapping_name in mapping_names {
+                    let entries = finalize_store.get_mapping_confirmed(program_id, mapping_name)?;
+                    mappings.push((program_id, mapping_name, entries));
+                }
+            }
+        }
+        // Compute the checksum of the finalize store.
+        let checksum = finalize_store.get_checksum_confirmed()?;
+
+        // Ensure the ledger did not advance while taking the snapshot.
+        ensure!(
+            self.vm.block_store().current_state_root() == state_root,
+            "The ledger advanced while taking the snapshot, try again"
+        );
+
+        // Return the snapshot.
+        Snapshot::new(block, block_hashes, current_round, rounds, committees, deployments, mappings, checksum)
+    }
+
+    /// Writes a snapshot of the ledger state at the latest block height to the given path.
+    pub fn export_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        // Take the snapshot.
+        let snapshot = self.snapshot()?;
+        // Write the snapshot to the file.
+        let mut writer = BufWriter::new(File::create(path)?);
+        snapshot.write_le(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Loads the ledger from the snapshot at the given path, restoring it into empty storage.
+    ///
+    /// **Warning:** The snapshot file must be obtained from a trusted source. Only the snapshot block and the
+    /// preceding block hashes are checked against the given `trusted_block_hash`. The finalize mappings, committees,
+    /// and deployments cannot be verified against the block, and are restored as provided. See [`Self::from_snapshot`].
+    pub fn load_from_snapshot<P: AsRef<Path>>(
+        genesis_block: Block<N>,
+        path: P,
+        trusted_block_hash: N::BlockHash,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        // Read the snapshot from the file.
+        let snapshot = Snapshot::read_le(BufReader::new(File::open(path)?))?;
+        // Restore the ledger from the snapshot.
+        Self::from_snapshot(genesis_block, &snapshot, trusted_block_hash, storage_mode)
+    }
+
+    /// Restores the ledger from the given snapshot into empty storage.
+    ///
+    /// The snapshot block must have the given `trusted_block_hash`, which the caller obtains out-of-band
+    /// (e.g. from a checkpoint it trusts). The block hashes preceding the snapshot block are then checked
+    /// against the previous state root of the snapshot block.
+    ///
+    /// **Warning:** The finalize mappings, committees, and deployments in the snapshot are not committed to by the
+    /// snapshot block, and are restored as provided. The snapshot checksum only detects a corrupted snapshot,
+    /// as anyone producing a snapshot can compute it. A snapshot must therefore come from a trusted source.
+    ///
+    /// Note: The blocks preceding the snapshot height are not restored, only their block hashes and state roots.
+    pub fn from_snapshot(
+        genesis_block: Block<N>,
+        snapshot: &Snapshot<N>,
+        trusted_block_hash: N::BlockHash,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::from_snapshot");
+
+        // Ensure the snapshot block is the trusted block.
+        ensure!(
+            snapshot.block().hash() == trusted_block_hash,
+            "The snapshot block at height {} does not match the trusted block hash '{trusted_block_hash}'",
+            snapshot.height()
+        );
+
+        // Ensure the snapshot descends from the given genesis block.
+        match snapshot.block_hashes().first() {
+            Some(genesis_hash) => {
+                ensure!(*genesis_hash == genesis_block.hash(), "The snapshot has a different genesis")
+            }
+            None => ensure!(*snapshot.block() == genesis_block, "The snapshot has a different genesis"),
+        }
+
+        // Ensure the finalize mappings are consistent with the snapshot checksum, before writing to storage.
+        // Note: This only detects a corrupted snapshot, and does not authenticate the finalize mappings.
+        let finalize_store = FinalizeStore::<N, FinalizeMemory<N>>::open(None)?;
+        for (program_id, mapping_name, entries) in snapshot.mappings() {
+            finalize_store.initialize_mapping(*program_id, *mapping_name)?;
+            finalize_store.replace_mapping(*program_id, *mapping_name, entries.clone())?;
+        }
+        ensure!(
+            finalize_store.get_checksum_confirmed()? == snapshot.checksum(),
+            "The snapshot mappings are inconsistent with the snapshot checksum"
+        );
+        drop(finalize_store);
+        lap!(timer, "Check the snapshot checksum");
+
+        info!("Restoring the ledger from the snapshot at block {}...", snapshot.height());
+        // Initialize the consensus store.
+        let store = match ConsensusStore::<N, C>::open(storage_mode) {
+            Ok(store) => store,
+            Err(e) => bail!("Failed to load ledger (run 'snarkos clean' and try again)\n\n{e}\n"),
+        };
+        // Ensure the consensus store is empty.
+        ensure!(store.block_store().get_block_hash(0)?.is_none(), "Cannot restore a snapshot into a non-empty ledger");
+
+        atomic_batch_scope!(store, {
+            // Restore the finalize mappings.
+            for (program_id, mapping_name, entries) in snapshot.mappings() {
+                store.finalize_store().initialize_mapping(*program_id, *mapping_name)?;
+                store.finalize_store().replace_mapping(*program_id, *mapping_name, entries.clone())?;
+            }
+            // Restore the committees.
+            store.finalize_store().committee_store().restore(
+                snapshot.current_round(),
+                snapshot.rounds(),
+                snapshot.committees(),
+            )?;
+            // Restore the deployments.
+            for deployment in snapshot.deployments() {
+                store.transaction_store().insert(deployment)?;
+            }
+            // Restore the block tree and the snapshot block.
+            store.block_store().restore(snapshot.block_hashes(), snapshot.block())
+        })?;
+        lap!(timer, "Restore the consensus store");
+
+        // Initialize the ledger.
+        let ledger = Self::from_store(genesis_block, store)?;
+        // Ensure the ledger is at the snapshot block.
+        ensure!(ledger.latest_hash() == snapshot.block().hash(), "Failed to restore the ledger from the snapshot");
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Returns the earliest block height for which the full block is in storage.
+    /// This is non-zero for a ledger restored from a snapshot, as only the block hashes precede the snapshot height,
+    /// and for a pruned ledger, as the pruned blocks cannot be retrieved.
+    pub(crate) fn earliest_block_height(&self) -> Result<u32> {
+        // Returns `true` if the header for the given block height is in storage.
+        let contains_header = |height: u32| -> Result<bool> {
+            match self.vm.block_store().get_block_hash(height)? {
+                Some(hash) => Ok(self.vm.block_store().get_block_header(&hash)?.is_some()),
+                None => Ok(false),
+            }
+        };
+        // Binary search for the earliest block height, as the full blocks are contiguous up to the latest height.
+        let (mut low, mut high) = (0, self.latest_height());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match contains_header(middle)? {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+        // Skip the pruned blocks.
+        match self.vm.block_store().pruned_height()? {
+            Some(pruned_height) => Ok(low.max(pruned_height.saturating_add(1)).min(self.latest_height())),
+            None => Ok(low),
+        }
+    }
+}
@@ -16,19 +16,25 @@ use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentLedger, CurrentNetwork},
     Ledger,
+    RecordScanner,
     RecordsFilter,
+    Snapshot,
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
     types::U16,
 };
 use ledger_block::{ConfirmedTransaction, Ratify, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
-use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-use synthesizer::{program::Program, vm::VM, Stack};
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore, Inconsistency};
+use synthesizer::{
+    program::Program,
+    vm::{BlockEvent, VM},
+    Stack,
+};
 
 use indexmap::IndexMap;
 use rand::seq::SliceRandom;
@@ -2160,6 +2166,129 @@ finalize is_id:
     ledger.advance_to_next_block(&block_3).unwrap();
 }
 
+#[test]
+fn test_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("snapshot.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping entries:
+    key as u8.public;
+    value as u8.public;
+function store:
+    input r0 as u8.public;
+    async store r0 into r1;
+    output r1 as {program_id}/store.future;
+finalize store:
+    input r0 as u8.public;
+    set r0 into entries[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Store a value in the mapping.
+    let inputs = [Value::<CurrentNetwork>::from_str("7u8").unwrap()];
+    let transaction =
+        ledger.vm.execute(&private_key, (&program_id, "store"), inputs.iter(), None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Advance by an empty block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 3);
+
+    // Take the snapshot, and check its byte representation.
+    let snapshot = ledger.snapshot().unwrap();
+    assert_eq!(snapshot.height(), 3);
+    assert_eq!(snapshot.deployments().len(), 1);
+    let snapshot_bytes = snapshot.to_bytes_le().unwrap();
+    assert!(snapshot == Snapshot::read_le(&snapshot_bytes[..]).unwrap());
+
+    // Ensure a tampered snapshot is rejected.
+    let tampered = Snapshot::new(
+        snapshot.block().clone(),
+        snapshot.block_hashes().to_vec(),
+        snapshot.current_round(),
+        snapshot.rounds().to_vec(),
+        snapshot.committees().to_vec(),
+        snapshot.deployments().to_vec(),
+        snapshot.mappings()[1..].to_vec(),
+        snapshot.checksum(),
+    )
+    .unwrap();
+    let genesis = ledger.get_block(0).unwrap();
+    let trusted_hash = ledger.latest_hash();
+    assert!(
+        Ledger::<_, ConsensusMemory<_>>::from_snapshot(
+            genesis.clone(),
+            &tampered,
+            trusted_hash,
+            StorageMode::Production
+        )
+        .is_err()
+    );
+
+    // Ensure a snapshot of an untrusted block is rejected.
+    let untrusted_hash = ledger.get_hash(2).unwrap();
+    assert!(
+        Ledger::<_, ConsensusMemory<_>>::from_snapshot(
+            genesis.clone(),
+            &snapshot,
+            untrusted_hash,
+            StorageMode::Production
+        )
+        .is_err()
+    );
+
+    // Restore a new ledger from the snapshot.
+    let restored =
+        Ledger::<_, ConsensusMemory<_>>::from_snapshot(genesis, &snapshot, trusted_hash, StorageMode::Production)
+            .unwrap();
+    assert_eq!(restored.latest_hash(), ledger.latest_hash());
+    assert_eq!(restored.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(restored.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(restored.get_program(program_id).unwrap(), program);
+    assert_eq!(
+        restored.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+    // Ensure the historical state roots are restored, but the preceding blocks are not.
+    assert!(restored.vm().block_store().contains_state_root(&ledger.get_state_root(1).unwrap().unwrap()).unwrap());
+    assert!(restored.get_block(1).is_err());
+    assert_eq!(restored.earliest_block_height().unwrap(), 3);
+    assert_eq!(ledger.earliest_block_height().unwrap(), 0);
+
+    // Ensure the restored ledger is consistent, and that repairing it keeps the restored deployment.
+    assert!(restored.check_integrity(true).unwrap().is_empty());
+    assert_eq!(restored.get_program(program_id).unwrap(), program);
+
+    // Ensure the restored ledger advances with the original ledger.
+    let inputs = [Value::<CurrentNetwork>::from_str("8u8").unwrap()];
+    let transaction =
+        ledger.vm.execute(&private_key, (&program_id, "store"), inputs.iter(), None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    restored.check_next_block(&block, rng).unwrap();
+    restored.advance_to_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(restored.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(
+        restored.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+}
+
 // These tests require the proof targets to be low enough to be able to generate **valid** solutions.
 // This requires the 'test' feature to be enabled for the `console` dependency.
 #[cfg(feature = "test")]
@@ -2483,3 +2612,483 @@ mod valid_solutions {
         assert_eq!(block_aborted_solution_ids, expected_aborted_solutions, "Aborted solutions do not match");
     }
 }
+
+#[test]
+fn test_mapping_history() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("history.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping entries:
+    key as u8.public;
+    value as u8.public;
+function store:
+    input r0 as u8.public;
+    async store r0 into r1;
+    output r1 as {program_id}/store.future;
+finalize store:
+    input r0 as u8.public;
+    set r0 into entries[0u8];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the history is disabled by default.
+    assert!(ledger.get_history_range().unwrap().is_none());
+    // Enable the history at block 1.
+    ledger.enable_history().unwrap();
+    assert_eq!(ledger.get_history_range().unwrap(), Some((1, 1)));
+
+    // Store a value in the mapping at blocks 2 and 3.
+    for value in ["1u8", "2u8"] {
+        let inputs = [Value::<CurrentNetwork>::from_str(value).unwrap()];
+        let transaction =
+            ledger.vm.execute(&private_key, (&program_id, "store"), inputs.iter(), None, 0, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.get_history_range().unwrap(), Some((1, 3)));
+
+    // Ensure the values are correct at each block height.
+    let mapping_name = Identifier::from_str("entries").unwrap();
+    let key = Plaintext::from_str("0u8").unwrap();
+    assert_eq!(ledger.get_value_at_height(program_id, mapping_name, &key, 1).unwrap(), None);
+    for (height, value) in [(2, "1u8"), (3, "2u8")] {
+        let expected = Value::from_str(value).unwrap();
+        assert_eq!(ledger.get_value_at_height(program_id, mapping_name, &key, height).unwrap(), Some(expected.clone()));
+        assert_eq!(ledger.get_mapping_at_height(program_id, mapping_name, height).unwrap(), vec![(
+            key.clone(),
+            expected
+        )]);
+    }
+    assert!(ledger.get_mapping_at_height(program_id, mapping_name, 1).unwrap().is_empty());
+
+    // Ensure the public balance reflects the fees paid in each block.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+    let address = Plaintext::from(Literal::Address(address));
+    let balance_1 = ledger.get_value_at_height(credits, account, &address, 1).unwrap().unwrap();
+    let balance_3 = ledger.get_value_at_height(credits, account, &address, 3).unwrap().unwrap();
+    assert_ne!(balance_1, balance_3);
+    assert_eq!(balance_3, ledger.vm.finalize_store().get_value_confirmed(credits, account, &address).unwrap().unwrap());
+
+    // Prune the history up to block 2, and ensure block 1 can no longer be queried.
+    ledger.prune_history(2).unwrap();
+    assert!(ledger.get_value_at_height(program_id, mapping_name, &key, 1).is_err());
+    assert_eq!(
+        ledger.get_value_at_height(program_id, mapping_name, &key, 2).unwrap(),
+        Some(Value::from_str("1u8").unwrap())
+    );
+
+    // Disable the history.
+    ledger.disable_history().unwrap();
+    assert!(ledger.get_history_range().unwrap().is_none());
+}
+
+#[test]
+fn test_pruning() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Create a transfer in block 1, to produce a record for the account.
+    let transfer = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let pruned_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer.clone()], rng).unwrap();
+    ledger.advance_to_next_block(&pruned_block).unwrap();
+    // Advance by an empty block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Enable pruning with a depth of 1, which prunes block 1.
+    assert!(ledger.set_pruning_depth(Some(0)).is_err());
+    assert_eq!(ledger.pruned_height().unwrap(), None);
+    ledger.set_pruning_depth(Some(1)).unwrap();
+    assert_eq!(ledger.pruning_depth().unwrap(), Some(1));
+    assert_eq!(ledger.pruned_height().unwrap(), Some(1));
+
+    // Ensure the pruned block and its transactions can no longer be retrieved, but its header can.
+    let error = ledger.get_block(1).unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+    assert!(ledger.get_transactions(1).is_err());
+    assert!(ledger.get_transaction(transfer.id()).is_err());
+    assert!(ledger.get_solutions(1).is_err());
+    assert_eq!(ledger.get_header(1).unwrap(), *pruned_block.header());
+    // Ensure the genesis block and the latest block are retained.
+    assert_eq!(ledger.get_block(0).unwrap().height(), 0);
+    assert_eq!(ledger.get_block(2).unwrap(), block);
+
+    // Ensure the record from the pruned block can still be spent.
+    let (commitment, record) = transfer.records().next().unwrap();
+    ledger.get_state_path_for_commitment(commitment).unwrap();
+    let inputs = [
+        Value::Record(record.decrypt(&view_key).unwrap()),
+        Value::from_str(&format!("{address}")).unwrap(),
+        Value::from_str("50u64").unwrap(),
+    ];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    ledger.vm().check_transaction(&transaction, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure block 2 is pruned as the ledger advances.
+    assert_eq!(ledger.pruned_height().unwrap(), Some(2));
+    assert!(ledger.get_block(2).is_err());
+    assert_eq!(ledger.get_block(3).unwrap(), block);
+    // Ensure the pruned blocks cannot be removed.
+    assert!(ledger.vm.block_store().remove_last_n(2).is_err());
+}
+
+#[test]
+fn test_rollback_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure the ledger cannot be rolled back without the mapping history.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(ledger.rollback_to(0).is_err());
+    // Enable the history at block 1.
+    ledger.enable_history().unwrap();
+
+    // Deploy a test program to the ledger in block 2.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("rollback.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping entries:
+    key as u8.public;
+    value as u8.public;
+function store:
+    input r0 as u8.public;
+    async store r0 into r1;
+    output r1 as {program_id}/store.future;
+finalize store:
+    input r0 as u8.public;
+    set r0 into entries[0u8];",
+    ))
+    .unwrap();
+    let deployment = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    // Deploy a test program without mappings in block 2 as well.
+    let plain_program_id = ProgramID::<CurrentNetwork>::from_str("rollback_plain.aleo").unwrap();
+    let plain_program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {plain_program_id};
+function compute:
+    input r0 as u8.public;
+    add r0 r0 into r1;
+    output r1 as u8.public;",
+    ))
+    .unwrap();
+    let plain_deployment = ledger.vm.deploy(&private_key, &plain_program, None, 0, None, rng).unwrap();
+    let transactions = vec![deployment.clone(), plain_deployment];
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+    assert_eq!(block.transactions().num_accepted(), 2);
+    ledger.advance_to_next_block(&block).unwrap();
+    let block_2 = block;
+
+    // Store a value in the mapping at blocks 3 and 4.
+    for value in ["1u8", "2u8"] {
+        let inputs = [Value::<CurrentNetwork>::from_str(value).unwrap()];
+        let transaction =
+            ledger.vm.execute(&private_key, (&program_id, "store"), inputs.iter(), None, 0, None, rng).unwrap();
+        // Ensure the transaction pays a public fee, which updates the account balance.
+        assert!(transaction.fee_transition().unwrap().is_fee_public());
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        // Ensure the block pays a block reward, which updates the staker balances.
+        let mut ratifications = block.ratifications().iter();
+        assert!(ratifications.any(|ratify| matches!(ratify, Ratify::BlockReward(reward) if *reward > 0)));
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 4);
+
+    // Retrieve the public balance at block 2.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+    let address = Plaintext::from(Literal::Address(address));
+    let balance_1 = ledger.get_value_at_height(credits, account, &address, 1).unwrap();
+    let balance_2 = ledger.get_value_at_height(credits, account, &address, 2).unwrap();
+
+    // Ensure the ledger cannot be rolled back beyond the history, or forwards.
+    assert!(ledger.rollback_to(0).is_err());
+    assert!(ledger.rollback_to(4).is_err());
+
+    // Roll back to block 2.
+    ledger.rollback_to(2).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert_eq!(ledger.latest_block(), block_2);
+    assert_eq!(ledger.latest_committee().unwrap(), ledger.get_committee(2).unwrap().unwrap());
+    assert_eq!(ledger.latest_epoch_hash().unwrap(), ledger.get_epoch_hash(2).unwrap());
+    assert_eq!(ledger.get_history_range().unwrap(), Some((1, 2)));
+    assert!(ledger.get_block(3).is_err());
+    // Ensure the mapping entries are reverted.
+    let mapping_name = Identifier::from_str("entries").unwrap();
+    let key = Plaintext::from_str("0u8").unwrap();
+    let mapping_names = ledger.vm.finalize_store().get_mapping_names_confirmed(&program_id).unwrap().unwrap();
+    assert!(mapping_names.contains(&mapping_name));
+    assert_eq!(ledger.vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(), None);
+    assert_eq!(ledger.vm.finalize_store().get_value_confirmed(credits, account, &address).unwrap(), balance_2);
+
+    // Roll back to block 1, which removes the programs.
+    assert!(ledger.vm.process().read().contains_program(&plain_program_id));
+    ledger.rollback_to(1).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert!(!ledger.vm.process().read().contains_program(&program_id));
+    assert!(!ledger.vm.process().read().contains_program(&plain_program_id));
+    assert!(!ledger.vm.transaction_store().contains_program_id(&plain_program_id).unwrap());
+    assert!(!ledger.vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+    assert!(!ledger.vm.transaction_store().contains_program_id(&program_id).unwrap());
+    assert_eq!(ledger.vm.finalize_store().get_value_confirmed(credits, account, &address).unwrap(), balance_1);
+
+    // Ensure the ledger advances from the rolled back block, including a redeployment of the program.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![deployment], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert!(ledger.vm.process().read().contains_program(&program_id));
+    assert_eq!(ledger.get_history_range().unwrap(), Some((1, 2)));
+}
+
+#[test]
+fn test_secondary_indexes() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+    // Ensure the secondary indexes are disabled by default, and may not be queried.
+    let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+    let transfer_public = Identifier::from_str("transfer_public").unwrap();
+    assert!(!ledger.indexes_enabled().unwrap());
+    assert!(ledger.find_transition_ids_for_function(&credits, &transfer_public).is_err());
+
+    // Transfer credits publicly to the recipient, before the secondary indexes are enabled.
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction_0 = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_0.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Enable the secondary indexes, and ensure the existing transaction is indexed.
+    ledger.enable_indexes().unwrap();
+    assert!(ledger.indexes_enabled().unwrap());
+    let transition_0 = *transaction_0.transitions().next().unwrap().id();
+    assert!(ledger.find_transition_ids_for_function(&credits, &transfer_public).unwrap().any(|id| id == transition_0));
+    assert!(ledger.find_transition_ids_for_address(&recipient).unwrap().any(|id| id == transition_0));
+    assert!(ledger.find_transaction_ids_for_program(&credits).unwrap().any(|id| id == transaction_0.id()));
+
+    // Transfer credits privately to the recipient, after the secondary indexes are enabled.
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("20u64").unwrap()];
+    let transaction_1 = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_1.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the new transaction is indexed, and its record is found for the recipient.
+    let transfer_public_to_private = Identifier::from_str("transfer_public_to_private").unwrap();
+    let transition_1 = *transaction_1.transitions().next().unwrap().id();
+    assert_eq!(
+        ledger.find_transition_ids_for_function(&credits, &transfer_public_to_private).unwrap().collect::<Vec<_>>(),
+        vec![transition_1]
+    );
+    assert!(ledger.find_transition_ids_for_address(&address).unwrap().any(|id| id == transition_1));
+    let records = ledger
+        .find_records_for_function(&recipient_view_key, &credits, &transfer_public_to_private)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(records.len(), 1);
+    assert_eq!(**records[0].1.owner(), recipient);
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    assert_eq!(ledger.find_records_for_function(&view_key, &credits, &transfer_public_to_private).unwrap().count(), 0);
+
+    // Disable the secondary indexes, and ensure they may not be queried.
+    ledger.disable_indexes().unwrap();
+    assert!(!ledger.indexes_enabled().unwrap());
+    assert!(ledger.find_transaction_ids_for_program(&credits).is_err());
+}
+
+#[test]
+fn test_record_scanner() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address, .. } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to find the unspent commitments, by scanning the full ledger.
+    let find_unspent = || {
+        let mut commitments = ledger
+            .find_records(&view_key, RecordsFilter::Unspent)
+            .unwrap()
+            .map(|(commitment, _)| commitment)
+            .collect::<Vec<_>>();
+        commitments.sort();
+        commitments
+    };
+    // A helper function to find the unspent commitments in the scanner.
+    let scanned_unspent = |scanner: &RecordScanner<CurrentNetwork>| {
+        let mut commitments = scanner.unspent_records(&address).unwrap().keys().copied().collect::<Vec<_>>();
+        commitments.sort();
+        commitments
+    };
+
+    // Scan the ledger for the view key.
+    let mut scanner = RecordScanner::new();
+    scanner.add_view_key(view_key).unwrap();
+    ledger.scan_records(&mut scanner).unwrap();
+    assert_eq!(scanner.cursor(&address), Some(ledger.latest_height() + 1));
+    assert!(!scanned_unspent(&scanner).is_empty());
+    assert_eq!(scanned_unspent(&scanner), find_unspent());
+
+    // Spend a record, paying the fee publicly.
+    let (commitment, record) =
+        scanner.unspent_records(&address).unwrap().first().map(|(c, r)| (*c, r.clone())).unwrap();
+    let inputs =
+        [Value::Record(record), Value::from_str(&format!("{address}")).unwrap(), Value::from_str("100u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private_to_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Persist the scanner, and ensure it resumes from its cursor.
+    let mut scanner = RecordScanner::<CurrentNetwork>::from_bytes_le(&scanner.to_bytes_le().unwrap()).unwrap();
+    assert_eq!(scanner.cursor(&address), Some(ledger.latest_height()));
+    ledger.scan_records(&mut scanner).unwrap();
+    assert_eq!(scanner.cursor(&address), Some(ledger.latest_height() + 1));
+
+    // Ensure the spent record is removed, and the change record is added.
+    assert!(!scanner.unspent_records(&address).unwrap().contains_key(&commitment));
+    assert_eq!(scanned_unspent(&scanner), find_unspent());
+
+    // Ensure a new view key may be scanned from the latest block, without rescanning the ledger.
+    let new_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    scanner.add_view_key_from(new_view_key, &block).unwrap();
+    assert_eq!(scanner.cursor(&new_view_key.to_address()), Some(ledger.latest_height() + 1));
+    assert!(scanner.unspent_records(&new_view_key.to_address()).unwrap().is_empty());
+    assert!(scanner.remove_view_key(&new_view_key.to_address()));
+}
+
+#[test]
+fn test_subscribe() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Subscribe to the block events.
+    let receiver = ledger.subscribe();
+
+    // Transfer credits publicly to a recipient.
+    let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the events for the block were emitted.
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert!(events.iter().all(|event| event.height() == block.height()));
+    assert_eq!(
+        events.first(),
+        Some(&BlockEvent::TransactionAccepted { height: block.height(), transaction_id: transaction.id() })
+    );
+    assert_eq!(events.last(), Some(&BlockEvent::BlockCommitted { height: block.height(), block_hash: block.hash() }));
+
+    // Ensure the public balances of the sender and recipient were updated.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+    for address in [address, recipient] {
+        let key = Plaintext::from(Literal::Address(address));
+        let value = ledger.vm.finalize_store().get_value_confirmed(credits, account, &key).unwrap().unwrap();
+        assert!(events.contains(&BlockEvent::MappingKeySet {
+            height: block.height(),
+            program_id: credits,
+            mapping_name: account,
+            key,
+            value
+        }));
+    }
+
+    // Ensure the ledger advances after unsubscribing, and a new subscriber only receives the new events.
+    drop(receiver);
+    let receiver = ledger.subscribe();
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(receiver.try_iter().all(|event| event.height() == block.height()));
+}
+
+#[test]
+fn test_check_integrity() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance the ledger with a public transfer.
+    let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the ledger is consistent.
+    assert!(ledger.check_integrity(false).unwrap().is_empty());
+
+    // Insert a transaction that does not belong to any block.
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    ledger.vm.transaction_store().insert(&transaction).unwrap();
+
+    // Ensure the dangling transaction is reported, and then repaired.
+    let expected = vec![Inconsistency::DanglingTransaction { transaction_id: transaction.id() }];
+    assert_eq!(ledger.check_integrity(true).unwrap(), expected);
+    assert!(!ledger.vm.transaction_store().contains_transaction_id(&transaction.id()).unwrap());
+    assert!(ledger.check_integrity(false).unwrap().is_empty());
+}
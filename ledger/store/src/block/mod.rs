@@ -53,6 +53,11 @@ use std::{borrow::Cow, sync::Arc};
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The pruning metadata key for the pruning depth.
+const PRUNING_DEPTH_KEY: u8 = 0;
+/// The pruning metadata key for the latest pruned block height.
+const PRUNED_HEIGHT_KEY: u8 = 1;
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
@@ -136,9 +141,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of rejected or aborted `transaction ID` to `block hash`.
     type RejectedOrAbortedTransactionIDMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
     /// The mapping of `transaction ID` to `(block hash, confirmed tx type, finalize operations)`.
-    type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
+    type ConfirmedTransactionsMap: for<'a> Map<
+        'a,
+        N::TransactionID,
+        (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>),
+    >;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `pruning metadata key` to `block height`.
+    type PruningMap: for<'a> Map<'a, u8, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +192,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the pruning map.
+    fn pruning_map(&self) -> &Self::PruningMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -213,6 +226,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.pruning_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +249,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.pruning_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +272,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.pruning_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +295,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.pruning_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +318,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.pruning_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +341,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.pruning_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +364,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.pruning_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -590,6 +610,97 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Sets the pruning depth, such that blocks older than `depth` blocks are pruned as new blocks are stored.
+    /// If `depth` is `None`, blocks are no longer pruned, though previously-pruned blocks remain pruned.
+    fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // Ensure the latest block is retained.
+        if depth == Some(0) {
+            bail!("The pruning depth must be at least 1")
+        }
+
+        atomic_batch_scope!(self, {
+            match depth {
+                Some(depth) => self.pruning_map().insert(PRUNING_DEPTH_KEY, depth)?,
+                None => self.pruning_map().remove(&PRUNING_DEPTH_KEY)?,
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    fn get_pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(self.pruning_map().get_confirmed(&PRUNING_DEPTH_KEY)?.map(|depth| cow_to_copied!(depth)))
+    }
+
+    /// Returns the latest pruned block height, or `None` if no blocks have been pruned.
+    fn get_pruned_height(&self) -> Result<Option<u32>> {
+        Ok(self.pruning_map().get_confirmed(&PRUNED_HEIGHT_KEY)?.map(|height| cow_to_copied!(height)))
+    }
+
+    /// Prunes the blocks up to and including the given `block height`, removing the proofs and transition values
+    /// of their transactions, and their solutions. The block headers, state roots, and the IDs, serial numbers,
+    /// tags, and commitments of their transitions are retained, so the state paths for their records can still
+    /// be computed. The solution IDs and aborted solution IDs are retained, so duplicate solutions are still rejected.
+    ///
+    /// Note: The genesis block is never pruned.
+    fn prune(&self, block_height: u32) -> Result<()> {
+        // Determine the earliest block height to prune.
+        let start_height = match self.pruning_map().get_speculative(&PRUNED_HEIGHT_KEY)? {
+            Some(height) => cow_to_copied!(height).saturating_add(1),
+            None => 1,
+        };
+        // If the blocks are already pruned, return early.
+        if block_height < start_height {
+            return Ok(());
+        }
+
+        // Retrieve the block hashes and transaction IDs of the blocks to prune.
+        let blocks = (start_height..=block_height)
+            .map(|height| {
+                let block_hash = match self.get_block_hash(height)? {
+                    Some(block_hash) => block_hash,
+                    None => bail!("Failed to prune block {height}: missing block hash"),
+                };
+                // Note: The transactions are missing for blocks preceding a restored snapshot.
+                let transaction_ids = self
+                    .transactions_map()
+                    .get_confirmed(&block_hash)?
+                    .map(|ids| cow_to_cloned!(ids))
+                    .unwrap_or_default();
+                Ok((block_hash, transaction_ids))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_batch_scope!(self, {
+            for (block_hash, transaction_ids) in &blocks {
+                // Prune the transactions.
+                for transaction_id in transaction_ids {
+                    self.transaction_store().prune(transaction_id)?;
+                }
+                // Prune the solutions.
+                self.solutions_map().remove(block_hash)?;
+            }
+            // Update the latest pruned block height.
+            self.pruning_map().insert(PRUNED_HEIGHT_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the blocks older than the pruning depth, relative to the given `latest height`.
+    /// If pruning is disabled, this method is a no-op.
+    fn prune_to_depth(&self, latest_height: u32) -> Result<()> {
+        // Retrieve the pruning depth.
+        let Some(depth) = self.pruning_map().get_speculative(&PRUNING_DEPTH_KEY)? else {
+            return Ok(());
+        };
+        // Prune the blocks older than the pruning depth.
+        match latest_height.checked_sub(cow_to_copied!(depth)) {
+            Some(block_height) if block_height > 0 => self.prune(block_height),
+            _ => Ok(()),
+        }
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -1044,8 +1155,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Prune the blocks older than the pruning depth, if pruning is enabled.
+            self.storage.prune_to_depth(block.height())
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1085,6 +1200,10 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
                     .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
                 // Ensure the block height matches the number of leaves in the Merkle tree.
                 ensure!(end_height == u32::try_from(tree.number_of_leaves())? - 1, "Block height mismatch");
+                // Ensure the blocks to remove have not been pruned.
+                if let Some(pruned_height) = self.storage.get_pruned_height()? {
+                    ensure!(start_height > pruned_height, "Failed to remove last '{n}' blocks: the blocks are pruned");
+                }
                 // Output the block heights.
                 start_height..=end_height
             }
@@ -1115,6 +1234,106 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Sets the pruning depth, such that blocks older than `depth` blocks are pruned as new blocks are stored.
+    /// If `depth` is `None`, blocks are no longer pruned, though previously-pruned blocks remain pruned.
+    pub fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // Acquire the read lock on the block tree, to prevent blocks from being stored concurrently.
+        let tree = self.tree.read();
+        atomic_batch_scope!(self, {
+            // Set the pruning depth.
+            self.storage.set_pruning_depth(depth)?;
+            // Prune the blocks older than the pruning depth.
+            match tree.number_of_leaves().checked_sub(1) {
+                Some(latest_height) => self.storage.prune_to_depth(u32::try_from(latest_height)?),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    pub fn pruning_depth(&self) -> Result<Option<u32>> {
+        self.storage.get_pruning_depth()
+    }
+
+    /// Returns the latest pruned block height, or `None` if no blocks have been pruned.
+    pub fn pruned_height(&self) -> Result<Option<u32>> {
+        self.storage.get_pruned_height()
+    }
+
+    /// Prunes the blocks up to and including the given `block height`. The genesis block is never pruned.
+    pub fn prune(&self, block_height: u32) -> Result<()> {
+        // Acquire the read lock on the block tree, to prevent blocks from being stored concurrently.
+        let tree = self.tree.read();
+        // Ensure the block height is in storage.
+        ensure!(
+            u32::try_from(tree.number_of_leaves())? > block_height,
+            "Cannot prune block {block_height}, as it does not exist in storage"
+        );
+        self.storage.prune(block_height)
+    }
+
+    /// Restores an empty block store from the given block hashes, and stores the given block as the latest block.
+    /// The block hashes must be in ascending order of height, and precede the given block.
+    ///
+    /// Note: Only the block hashes and state roots are stored for the blocks preceding the given block.
+    pub fn restore(&self, block_hashes: &[N::BlockHash], block: &Block<N>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the block store is empty.
+        ensure!(tree.number_of_leaves() == 0, "Cannot restore into a non-empty block store");
+        // Ensure the block hashes precede the given block.
+        ensure!(
+            u32::try_from(block_hashes.len())? == block.height(),
+            "Expected {} block hashes to precede block {}, found {}",
+            block.height(),
+            block.height(),
+            block_hashes.len()
+        );
+        if let Some(previous_hash) = block_hashes.last() {
+            ensure!(
+                *previous_hash == block.previous_hash(),
+                "The block hashes do not precede block {}",
+                block.height()
+            );
+        }
+
+        // Compute the state root after each of the preceding blocks.
+        let mut updated_tree = N::merkle_tree_bhp(&[])?;
+        let mut state_roots = Vec::with_capacity(block_hashes.len());
+        for block_hash in block_hashes {
+            updated_tree = updated_tree.prepare_append(&[block_hash.to_bits_le()])?;
+            state_roots.push(N::StateRoot::from(*updated_tree.root()));
+        }
+        // Ensure the state root matches the previous state root of the given block.
+        if let Some(state_root) = state_roots.last() {
+            ensure!(
+                *state_root == block.previous_state_root(),
+                "The block hashes do not match the previous state root of block {}",
+                block.height()
+            );
+        }
+        // Prepare an updated Merkle tree containing the given block hash.
+        let updated_tree = updated_tree.prepare_append(&[block.hash().to_bits_le()])?;
+
+        atomic_batch_scope!(self, {
+            // Store the block hashes and state roots of the preceding blocks.
+            for (height, (block_hash, state_root)) in block_hashes.iter().zip(state_roots).enumerate() {
+                let height = u32::try_from(height)?;
+                self.storage.state_root_map().insert(height, state_root)?;
+                self.storage.reverse_state_root_map().insert(state_root, height)?;
+                self.storage.id_map().insert(height, *block_hash)?;
+                self.storage.reverse_id_map().insert(*block_hash, height)?;
+            }
+            // Store the given block.
+            self.storage.insert((*updated_tree.root()).into(), block)
+        })?;
+
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1241,19 +1460,36 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the block solutions for the given `block hash`.
     pub fn get_block_solutions(&self, block_hash: &N::BlockHash) -> Result<Solutions<N>> {
+        self.ensure_block_not_pruned(block_hash)?;
         self.storage.get_block_solutions(block_hash)
     }
 
     /// Returns the prover solution for the given solution ID.
     pub fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>> {
+        // Ensure the block containing the solution has not been pruned.
+        if let Some(pruned_height) = self.storage.get_pruned_height()? {
+            if let Some(height) = self.storage.find_block_height_from_solution_id(solution_id)? {
+                ensure!(height == 0 || height > pruned_height, "Block {height} has been pruned");
+            }
+        }
         self.storage.get_solution(solution_id)
     }
 
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
+        self.ensure_block_not_pruned(block_hash)?;
         self.storage.get_block_transactions(block_hash)
     }
 
+    /// Returns the block transaction IDs for the given `block hash`.
+    /// Note: The transaction IDs are retained for pruned blocks.
+    pub fn get_block_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
+        match self.storage.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => Ok(Some(cow_to_cloned!(transaction_ids))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     pub fn get_block_aborted_transaction_ids(
         &self,
@@ -1264,6 +1500,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_transaction(transaction_id)
     }
 
@@ -1272,19 +1509,42 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         &self,
         transaction_id: &N::TransactionID,
     ) -> Result<Option<ConfirmedTransaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_confirmed_transaction(*transaction_id)
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_unconfirmed_transaction(transaction_id)
     }
 
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
+        self.ensure_block_not_pruned(block_hash)?;
         self.storage.get_block(block_hash)
     }
 
+    /// Ensures the block for the given `block hash` has not been pruned.
+    fn ensure_block_not_pruned(&self, block_hash: &N::BlockHash) -> Result<()> {
+        if let Some(pruned_height) = self.storage.get_pruned_height()? {
+            if let Some(height) = self.storage.get_block_height(block_hash)? {
+                ensure!(height == 0 || height > pruned_height, "Block {height} has been pruned");
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the block containing the given `transaction ID` has not been pruned.
+    fn ensure_transaction_not_pruned(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        if self.storage.get_pruned_height()?.is_some() {
+            if let Some(block_hash) = self.storage.find_block_hash(transaction_id)? {
+                self.ensure_block_not_pruned(&block_hash)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
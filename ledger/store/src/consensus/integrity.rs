@@ -0,0 +1,419 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::atomic_batch_scope;
+
+use console::{
+    program::{Identifier, ProgramID},
+    types::Field,
+};
+
+use std::fmt;
+
+/// An inconsistency found in the consensus storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inconsistency<N: Network> {
+    /// The stored block at the given height does not match its cross-references.
+    InvalidBlock { height: u32, reason: String },
+    /// A transaction listed in a block is missing from the transaction store.
+    MissingTransaction { height: u32, transaction_id: N::TransactionID },
+    /// A transaction in the transaction store does not belong to any block.
+    DanglingTransaction { transaction_id: N::TransactionID },
+    /// A transaction in the transaction store could not be reconstructed.
+    InvalidTransaction { transaction_id: N::TransactionID, reason: String },
+    /// A transition in the transition store does not belong to any stored transaction.
+    DanglingTransition { transition_id: N::TransitionID },
+    /// An input or output ID of a transition does not map back to the transition.
+    InvalidTransitionIndex { transition_id: N::TransitionID, id: Field<N> },
+    /// The finalize state of the given program is inconsistent with the deployed program.
+    InvalidFinalizeState { program_id: ProgramID<N>, reason: String },
+    /// The storage metadata (i.e. the history range or the committee height) is inconsistent.
+    InvalidMetadata { reason: String },
+}
+
+impl<N: Network> Inconsistency<N> {
+    /// Returns `true` if the inconsistency can be repaired without external data.
+    pub const fn is_repairable(&self) -> bool {
+        matches!(self, Self::DanglingTransaction { .. } | Self::DanglingTransition { .. })
+    }
+}
+
+impl<N: Network> fmt::Display for Inconsistency<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidBlock { height, reason } => write!(f, "Block {height} is invalid - {reason}"),
+            Self::MissingTransaction { height, transaction_id } => {
+                write!(f, "Transaction '{transaction_id}' in block {height} is missing")
+            }
+            Self::DanglingTransaction { transaction_id } => {
+                write!(f, "Transaction '{transaction_id}' does not belong to any block")
+            }
+            Self::InvalidTransaction { transaction_id, reason } => {
+                write!(f, "Transaction '{transaction_id}' is invalid - {reason}")
+            }
+            Self::DanglingTransition { transition_id } => {
+                write!(f, "Transition '{transition_id}' does not belong to any transaction")
+            }
+            Self::InvalidTransitionIndex { transition_id, id } => {
+                write!(f, "Input or output '{id}' does not map to transition '{transition_id}'")
+            }
+            Self::InvalidFinalizeState { program_id, reason } => {
+                write!(f, "Finalize state of '{program_id}' is invalid - {reason}")
+            }
+            Self::InvalidMetadata { reason } => write!(f, "Storage metadata is invalid - {reason}"),
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Checks the cross-references between all maps in the consensus storage, and returns the inconsistencies found.
+    /// If `repair` is `true`, the repairable inconsistencies (see [`Inconsistency::is_repairable`]) are removed
+    /// from storage, and are still included in the returned list.
+    ///
+    /// Note: The block headers commit to the finalize operations, not to the finalize state.
+    /// As such, the finalize state is checked against the deployed programs, and not against the headers.
+    pub fn check_integrity(&self, repair: bool) -> Result<Vec<Inconsistency<N>>> {
+        let mut inconsistencies = Vec::new();
+        // Check the blocks.
+        self.check_blocks(&mut inconsistencies)?;
+        // Check the transactions, and repair the dangling ones, before checking the transitions.
+        // Note: Removing a dangling transaction also removes its transitions.
+        let start = inconsistencies.len();
+        self.check_transactions(&mut inconsistencies)?;
+        if repair {
+            self.repair(&inconsistencies[start..])?;
+        }
+        // Check the transitions.
+        let start = inconsistencies.len();
+        self.check_transitions(&mut inconsistencies)?;
+        if repair {
+            self.repair(&inconsistencies[start..])?;
+        }
+        // Check the finalize state and the storage metadata.
+        self.check_finalize(&mut inconsistencies)?;
+        Ok(inconsistencies)
+    }
+
+    /// Checks the block hashes, state roots, headers, and transaction IDs of every block.
+    fn check_blocks(&self, inconsistencies: &mut Vec<Inconsistency<N>>) -> Result<()> {
+        let block_store = self.block_store();
+        // Retrieve the latest block height.
+        let Some(latest_height) = block_store.heights().max().map(|height| *height) else {
+            return Ok(());
+        };
+        // Retrieve the pruned height.
+        let pruned_height = block_store.pruned_height()?;
+
+        let mut previous_hash = N::BlockHash::default();
+        // Blocks restored from a snapshot only retain their hash and state root, and always form a prefix of the chain.
+        let mut has_seen_header = false;
+        for height in 0..=latest_height {
+            let invalid = |reason: String| Inconsistency::InvalidBlock { height, reason };
+
+            // Check the block hash and its reverse mapping.
+            let Some(block_hash) = block_store.get_block_hash(height)? else {
+                inconsistencies.push(invalid("missing block hash".to_string()));
+                continue;
+            };
+            if block_store.get_block_height(&block_hash)? != Some(height) {
+                inconsistencies.push(invalid(format!("block hash '{block_hash}' does not map back to the height")));
+            }
+            // Check the state root and its reverse mapping.
+            let state_root = block_store.get_state_root(height)?;
+            match state_root {
+                Some(state_root) => {
+                    if block_store.find_block_height_from_state_root(state_root)? != Some(height) {
+                        inconsistencies
+                            .push(invalid(format!("state root '{state_root}' does not map back to the height")));
+                    }
+                }
+                None => inconsistencies.push(invalid("missing state root".to_string())),
+            }
+
+            // Check the block header.
+            match block_store.get_block_header(&block_hash)? {
+                Some(header) => {
+                    has_seen_header = true;
+                    if header.height() != height {
+                        inconsistencies.push(invalid(format!("header height {} does not match", header.height())));
+                    }
+                    // Check the previous state root in the header.
+                    if height > 0 && block_store.get_state_root(height - 1)? != Some(header.previous_state_root()) {
+                        inconsistencies.push(invalid("header does not match the previous state root".to_string()));
+                    }
+                    // Recompute the block hash.
+                    let candidate_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+                    if N::BlockHash::from(candidate_hash) != block_hash {
+                        inconsistencies
+                            .push(invalid("block hash does not match the header and previous block hash".to_string()));
+                    }
+                }
+                None if has_seen_header => inconsistencies.push(invalid("missing block header".to_string())),
+                None => (),
+            }
+
+            // Check the transaction IDs, unless the block was restored from a snapshot.
+            if has_seen_header {
+                let is_pruned = height > 0 && pruned_height.map_or(false, |pruned_height| height <= pruned_height);
+                for transaction_id in block_store.get_block_transaction_ids(&block_hash)?.unwrap_or_default() {
+                    if block_store.find_block_hash(&transaction_id)? != Some(block_hash) {
+                        inconsistencies
+                            .push(invalid(format!("transaction '{transaction_id}' does not map back to the block")));
+                    }
+                    // Note: Pruned transactions retain their IDs, but not their contents.
+                    if !self.transaction_store().contains_transaction_id(&transaction_id)? {
+                        inconsistencies.push(Inconsistency::MissingTransaction { height, transaction_id });
+                    } else if !is_pruned {
+                        if let Err(error) = self.transaction_store().get_transaction(&transaction_id) {
+                            inconsistencies
+                                .push(Inconsistency::InvalidTransaction { transaction_id, reason: error.to_string() });
+                        }
+                    }
+                }
+            }
+
+            previous_hash = block_hash;
+        }
+
+        // Check the block tree against the latest state root.
+        if let Some(state_root) = block_store.get_state_root(latest_height)? {
+            if block_store.current_state_root() != state_root {
+                inconsistencies.push(Inconsistency::InvalidBlock {
+                    height: latest_height,
+                    reason: "the block tree does not match the latest state root".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that every stored transaction belongs to a block.
+    ///
+    /// Note: A ledger restored from a snapshot holds the latest deployment of each program, without the block
+    /// that contains it, as the blocks preceding the snapshot are not restored. These deployments are not dangling.
+    fn check_transactions(&self, inconsistencies: &mut Vec<Inconsistency<N>>) -> Result<()> {
+        let is_restored = self.is_restored_from_snapshot()?;
+        for transaction_id in self.transaction_store().transaction_ids() {
+            if self.block_store().find_block_hash(&transaction_id)?.is_some() {
+                continue;
+            }
+            if is_restored && self.is_latest_deployment(&transaction_id)? {
+                continue;
+            }
+            inconsistencies.push(Inconsistency::DanglingTransaction { transaction_id: *transaction_id });
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the ledger was restored from a snapshot, i.e. the genesis block only retains its hash.
+    fn is_restored_from_snapshot(&self) -> Result<bool> {
+        match self.block_store().get_block_hash(0)? {
+            Some(block_hash) => Ok(self.block_store().get_block_header(&block_hash)?.is_none()),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns `true` if the given transaction is the latest deployment of its program.
+    fn is_latest_deployment(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        match self.transaction_store().get_program_id(transaction_id)? {
+            Some(program_id) => {
+                Ok(self.transaction_store().find_transaction_id_from_program_id(&program_id)? == Some(*transaction_id))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Checks that every stored transition belongs to a transaction, and that its input and output IDs map back to it.
+    fn check_transitions(&self, inconsistencies: &mut Vec<Inconsistency<N>>) -> Result<()> {
+        let transition_store = self.transition_store();
+        for transition_id in transition_store.transition_ids() {
+            let transition_id = *transition_id;
+            // Check the transition belongs to a stored transaction.
+            let is_dangling = match self.transaction_store().find_transaction_id_from_transition_id(&transition_id)? {
+                Some(transaction_id) => !self.transaction_store().contains_transaction_id(&transaction_id)?,
+                None => true,
+            };
+            if is_dangling {
+                inconsistencies.push(Inconsistency::DanglingTransition { transition_id });
+                continue;
+            }
+            // Check the input and output IDs map back to the transition.
+            let input_ids = transition_store.get_input_ids(&transition_id)?;
+            let output_ids = transition_store.get_output_ids(&transition_id)?;
+            for id in input_ids.into_iter().chain(output_ids) {
+                if transition_store.find_transition_id(&id).ok() != Some(transition_id) {
+                    inconsistencies.push(Inconsistency::InvalidTransitionIndex { transition_id, id });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the finalize state against the deployed programs, and the storage metadata against the latest block.
+    fn check_finalize(&self, inconsistencies: &mut Vec<Inconsistency<N>>) -> Result<()> {
+        let finalize_store = self.finalize_store();
+        let transaction_store = self.transaction_store();
+
+        // Check that every program in the finalize state was deployed.
+        // Note: 'credits.aleo' is initialized in the genesis block, without a deployment.
+        let credits = ProgramID::<N>::from_str("credits.aleo")?;
+        for program_id in finalize_store.program_ids() {
+            if *program_id != credits && !transaction_store.contains_program_id(&program_id)? {
+                inconsistencies.push(Inconsistency::InvalidFinalizeState {
+                    program_id: *program_id,
+                    reason: "the program is not deployed".to_string(),
+                });
+            }
+        }
+        // Check that every mapping of a deployed program is initialized.
+        for program in transaction_store.programs() {
+            let mapping_names = finalize_store.get_mapping_names_confirmed(program.id())?.unwrap_or_default();
+            let missing = program.mappings().keys().filter(|name| !mapping_names.contains(*name)).collect::<Vec<_>>();
+            if !missing.is_empty() {
+                inconsistencies.push(Inconsistency::InvalidFinalizeState {
+                    program_id: *program.id(),
+                    reason: format!("missing mappings {}", display_names(&missing)),
+                });
+            }
+        }
+
+        // Check the storage metadata against the latest block height.
+        let latest_height = self.block_store().heights().max().map(|height| *height).unwrap_or_default();
+        if let Some((_, end)) = finalize_store.get_history_range()? {
+            if end > latest_height {
+                inconsistencies.push(Inconsistency::InvalidMetadata {
+                    reason: format!("the mapping history ends at block {end}, after the latest block {latest_height}"),
+                });
+            }
+        }
+        if let Ok(height) = finalize_store.committee_store().current_height() {
+            if height > latest_height {
+                inconsistencies.push(Inconsistency::InvalidMetadata {
+                    reason: format!("the committee is at block {height}, after the latest block {latest_height}"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the repairable inconsistencies from storage.
+    fn repair(&self, inconsistencies: &[Inconsistency<N>]) -> Result<()> {
+        if !inconsistencies.iter().any(Inconsistency::is_repairable) {
+            return Ok(());
+        }
+        atomic_batch_scope!(self.block_store(), {
+            for inconsistency in inconsistencies {
+                match inconsistency {
+                    Inconsistency::DanglingTransaction { transaction_id } => {
+                        self.transaction_store().remove(transaction_id)?
+                    }
+                    Inconsistency::DanglingTransition { transition_id } => {
+                        self.transition_store().remove(transition_id)?
+                    }
+                    _ => (),
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Returns the given mapping names as a comma-separated string.
+fn display_names<N: Network>(names: &[&Identifier<N>]) -> String {
+    names.iter().map(|name| name.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::ConsensusMemory;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_check_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let transaction = block.transactions().iter().next().unwrap().transaction().clone();
+
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        assert!(store.check_integrity(false).unwrap().is_empty());
+
+        // Insert the block, and ensure the store is consistent.
+        store.block_store().insert(&block).unwrap();
+        assert!(store.check_integrity(false).unwrap().is_empty());
+
+        // Remove a transition that belongs to the block.
+        let transition_id = *transaction.transition_ids().next().unwrap();
+        store.transition_store().remove(&transition_id).unwrap();
+        let inconsistencies = store.check_integrity(true).unwrap();
+        assert!(!inconsistencies.is_empty());
+        assert!(inconsistencies.iter().all(|inconsistency| !inconsistency.is_repairable()));
+    }
+
+    #[test]
+    fn test_check_finalize_state() {
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        assert!(store.check_integrity(false).unwrap().is_empty());
+
+        // Initialize a mapping for 'credits.aleo', which does not require a deployment.
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        store.finalize_store().initialize_mapping(credits, mapping_name).unwrap();
+        assert!(store.check_integrity(false).unwrap().is_empty());
+
+        // Initialize a mapping for a program that was never deployed.
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        store.finalize_store().initialize_mapping(program_id, mapping_name).unwrap();
+        let inconsistencies = store.check_integrity(true).unwrap();
+        assert_eq!(inconsistencies, vec![Inconsistency::InvalidFinalizeState {
+            program_id,
+            reason: "the program is not deployed".to_string()
+        }]);
+        // Ensure the inconsistency is not repairable.
+        assert!(!inconsistencies[0].is_repairable());
+        assert_eq!(store.check_integrity(false).unwrap(), inconsistencies);
+    }
+
+    #[test]
+    fn test_repair_dangling_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction that does not belong to any block.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = transaction.id();
+
+        // Initialize a new consensus store, and insert the transaction.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        store.transaction_store().insert(&transaction).unwrap();
+
+        // Ensure the transaction is reported as dangling.
+        let inconsistencies = store.check_integrity(false).unwrap();
+        assert_eq!(inconsistencies, vec![Inconsistency::DanglingTransaction { transaction_id }]);
+        assert!(store.transaction_store().contains_transaction_id(&transaction_id).unwrap());
+
+        // Repair the store, and ensure the transaction and its transitions are removed.
+        assert_eq!(store.check_integrity(true).unwrap(), inconsistencies);
+        assert!(!store.transaction_store().contains_transaction_id(&transaction_id).unwrap());
+        for transition_id in transaction.transition_ids() {
+            assert!(!store.transition_store().contains_transition_id(transition_id).unwrap());
+        }
+        assert!(store.check_integrity(false).unwrap().is_empty());
+    }
+}
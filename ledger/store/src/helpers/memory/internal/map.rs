@@ -14,11 +14,11 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{KeyRange, Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::Bound};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
@@ -366,6 +366,43 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         self.map.read().clone().into_values().map(Cow::Owned)
     }
+
+    ///
+    /// Returns the confirmed key-value pairs whose keys are within the given bounds, up to `limit` of them.
+    /// The keys are ordered by their serialized bytes, which is the iteration order of every backend.
+    ///
+    fn range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: Option<usize>) -> Result<Vec<(K, V)>> {
+        // Serialize the bounds.
+        let range = KeyRange::new(start, end)?;
+        // Note: `BTreeMap::range` panics on an empty range.
+        if range.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.map
+            .read()
+            .range::<[u8], _>(range.as_bounds())
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(k, v)| Ok((bincode::deserialize(k)?, v.clone())))
+            .collect()
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs whose serialized keys start with the serialized `prefix`,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn prefix_confirmed<P: Serialize + ?Sized>(&'a self, prefix: &P, limit: Option<usize>) -> Result<Vec<(K, V)>> {
+        // Serialize the prefix.
+        let prefix = bincode::serialize(prefix)?;
+
+        self.map
+            .read()
+            .range::<[u8], _>((Bound::Included(prefix.as_slice()), Bound::Unbounded))
+            .take_while(|(k, _)| k.starts_with(&prefix))
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(k, v)| Ok((bincode::deserialize(k)?, v.clone())))
+            .collect()
+    }
 }
 
 impl<
@@ -436,6 +473,14 @@ mod tests {
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
+    #[test]
+    fn test_range_iteration() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        crate::helpers::test_helpers::map::check_range_iteration(map);
+    }
+
     #[test]
     fn test_atomic_writes_are_batched() {
         // Initialize a map.
@@ -14,10 +14,10 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{KeyRange, NestedMap, NestedMapRead};
 use console::network::prelude::*;
 
-use core::hash::Hash;
+use core::{hash::Hash, ops::Bound};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
@@ -500,6 +500,70 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         self.map_inner.read().clone().into_values().map(Cow::Owned)
     }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose keys are within the given bounds,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize 'm' and the bounds.
+        let m = bincode::serialize(map)?;
+        let range = KeyRange::new(start, end)?;
+        // Note: `BTreeSet::range` panics on an empty range.
+        if range.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Acquire the read locks, in the same order as the writers.
+        let map_keys = self.map.read();
+        let map_inner = self.map_inner.read();
+        // Retrieve the keys for the serialized map.
+        let Some(keys) = map_keys.get(&m) else {
+            return Ok(Vec::new());
+        };
+
+        // Return the key-value pairs within the range.
+        keys.range::<[u8], _>(range.as_bounds())
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|k| to_key_value(&map_inner, &m, k))
+            .collect()
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose serialized keys start with
+    /// the serialized `prefix`, up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_prefix_confirmed<P: Serialize + ?Sized>(
+        &'a self,
+        map: &M,
+        prefix: &P,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize 'm' and the prefix.
+        let m = bincode::serialize(map)?;
+        let prefix = bincode::serialize(prefix)?;
+
+        // Acquire the read locks, in the same order as the writers.
+        let map_keys = self.map.read();
+        let map_inner = self.map_inner.read();
+        // Retrieve the keys for the serialized map.
+        let Some(keys) = map_keys.get(&m) else {
+            return Ok(Vec::new());
+        };
+
+        // Return the key-value pairs with the given prefix.
+        keys.range::<[u8], _>((Bound::Included(prefix.as_slice()), Bound::Unbounded))
+            .take_while(|k| k.starts_with(&prefix))
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|k| to_key_value(&map_inner, &m, k))
+            .collect()
+    }
 }
 
 /// Inserts the given map-key-value pair.
@@ -576,6 +640,20 @@ fn remove_key<
     map_inner.remove(&mk);
 }
 
+/// Returns the deserialized key and its value, for the given serialized map and key.
+fn to_key_value<K: for<'de> Deserialize<'de>, V: Clone>(
+    map_inner: &BTreeMap<Vec<u8>, V>,
+    m: &[u8],
+    k: &[u8],
+) -> Result<(K, V)> {
+    // Concatenate 'm' and 'k' with a 0-byte separator.
+    let mk = to_map_key(m, k);
+    // Retrieve the value.
+    let value = map_inner.get(&mk).ok_or_else(|| anyhow!("Missing the value of a nested map key"))?;
+    // Return the key-value pair.
+    Ok((bincode::deserialize(k)?, value.clone()))
+}
+
 /// Returns the concatenated map-key.
 fn to_map_key(m: &[u8], k: &[u8]) -> Vec<u8> {
     // Concatenate 'm' and 'k' with a 0-byte separator.
@@ -655,6 +733,14 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_iterators_match(map);
     }
 
+    #[test]
+    fn test_range_iteration() {
+        // Initialize a map.
+        let map: NestedMemoryMap<usize, usize, String> = Default::default();
+
+        crate::helpers::test_helpers::nested_map::check_range_iteration(map);
+    }
+
     #[test]
     fn test_atomic_writes_are_batched() {
         // Initialize a map.
@@ -38,6 +38,14 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The history map.
+    history_map: NestedMemoryMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>,
+    /// The history metadata map.
+    history_metadata_map: MemoryMap<u8, u32>,
+    /// The mapping length map.
+    mapping_length_map: MemoryMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The history index map.
+    history_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Vec<u32>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +55,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type HistoryMap = NestedMemoryMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    type HistoryMetadataMap = MemoryMap<u8, u32>;
+    type MappingLengthMap = MemoryMap<(ProgramID<N>, Identifier<N>), u64>;
+    type HistoryIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Vec<u32>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +69,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            history_metadata_map: MemoryMap::default(),
+            mapping_length_map: MemoryMap::default(),
+            history_index_map: NestedMemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -82,6 +98,26 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history metadata map.
+    fn history_metadata_map(&self) -> &Self::HistoryMetadataMap {
+        &self.history_metadata_map
+    }
+
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the history index map.
+    fn history_index_map(&self) -> &Self::HistoryIndexMap {
+        &self.history_index_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
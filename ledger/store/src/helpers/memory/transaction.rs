@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransitionMemory},
     DeploymentStorage,
     DeploymentStore,
     ExecutionStorage,
@@ -42,6 +42,8 @@ pub struct TransactionMemory<N: Network> {
     execution_store: ExecutionStore<N, ExecutionMemory<N>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
+    /// The mapping of `program ID` to `transaction ID`.
+    program_index_map: NestedMemoryMap<ProgramID<N>, N::TransactionID, ()>,
 }
 
 #[rustfmt::skip]
@@ -51,6 +53,7 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type ExecutionStorage = ExecutionMemory<N>;
     type FeeStorage = FeeMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
+    type ProgramIndexMap = NestedMemoryMap<ProgramID<N>, N::TransactionID, ()>;
 
     /// Initializes the transaction storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
@@ -61,7 +64,7 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store, fee_store })
+        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store, fee_store, program_index_map: NestedMemoryMap::default() })
     }
 
     /// Returns the ID map.
@@ -83,6 +86,11 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
     }
+
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
 }
 
 /// An in-memory deployment storage.
@@ -183,6 +191,8 @@ pub struct ExecutionMemory<N: Network> {
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The bundle map.
+    bundle_map: MemoryMap<N::TransactionID, Vec<(u16, N::StateRoot, Option<Proof<N>>)>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -192,6 +202,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type BundleMap = MemoryMap<N::TransactionID, Vec<(u16, N::StateRoot, Option<Proof<N>>)>>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the execution storage.
@@ -200,6 +211,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             inclusion_map: MemoryMap::default(),
+            bundle_map: MemoryMap::default(),
             fee_store
         })
     }
@@ -219,6 +231,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.inclusion_map
     }
 
+    /// Returns the bundle map.
+    fn bundle_map(&self) -> &Self::BundleMap {
+        &self.bundle_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
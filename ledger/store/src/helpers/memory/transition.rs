@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -40,6 +47,12 @@ pub struct TransitionMemory<N: Network> {
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: MemoryMap<N::TransitionID, Field<N>>,
+    /// The function index map.
+    function_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), N::TransitionID, ()>,
+    /// The address index map.
+    address_index_map: NestedMemoryMap<Address<N>, N::TransitionID, ()>,
+    /// The index metadata map.
+    index_metadata_map: MemoryMap<u8, bool>,
 }
 
 #[rustfmt::skip]
@@ -52,6 +65,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
     type SCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type FunctionIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), N::TransitionID, ()>;
+    type AddressIndexMap = NestedMemoryMap<Address<N>, N::TransitionID, ()>;
+    type IndexMetadataMap = MemoryMap<u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -64,6 +80,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
             scm_map: MemoryMap::default(),
+            function_index_map: NestedMemoryMap::default(),
+            address_index_map: NestedMemoryMap::default(),
+            index_metadata_map: MemoryMap::default(),
         })
     }
 
@@ -106,6 +125,21 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the index metadata map.
+    fn index_metadata_map(&self) -> &Self::IndexMetadataMap {
+        &self.index_metadata_map
+    }
 }
 
 /// An in-memory transition input storage.
@@ -12,12 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The storage backends.
+//!
+//! A backend provides implementations of the [`Map`] and [`NestedMap`] traits, and plugs them into
+//! the `*Storage` traits of this crate (e.g. `BlockStorage`, `FinalizeStorage`, `ConsensusStorage`).
+//! The following backends are available:
+//! - [`memory`], an in-memory backend that is always enabled;
+//! - `redb`, a persistent backend written in pure Rust (enabled with the `redb` feature);
+//! - `rocksdb`, a persistent backend based on RocksDB (enabled with the `rocks` feature).
+//!
+//! Each backend exposes the same set of storage types (e.g. `ConsensusDB` for both persistent ones),
+//! so switching between them only requires a change of the module path.
+//!
+//! In order to be interchangeable, a backend must uphold the following contract:
+//! - writes made outside of an atomic batch are applied immediately;
+//! - writes made between `start_atomic` and `finish_atomic` are only visible through the `*_speculative`
+//!   and `*_pending` methods until the outermost batch is finished, at which point all of them are
+//!   applied together, or not at all;
+//! - `atomic_checkpoint` and `atomic_rewind` discard the queued writes back to the latest checkpoint,
+//!   and `abort_atomic` discards all of them;
+//! - while the atomic writes are paused, finished batches are queued until `unpause_atomic_writes`
+//!   either executes them as a single batch or discards them.
+//!
+//! The `test_helpers` module (enabled with the `test` feature) contains the conformance suite
+//! that every backend is expected to pass.
+
 pub mod memory;
+#[cfg(feature = "redb")]
+pub mod redb;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
-#[cfg(test)]
-pub(crate) mod test_helpers;
+#[cfg(any(feature = "redb", feature = "rocks"))]
+mod map_id;
+
+#[cfg(any(test, feature = "test"))]
+pub mod test_helpers;
+
+mod range;
+pub(crate) use range::*;
 
 mod traits;
 pub use traits::*;
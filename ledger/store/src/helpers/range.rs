@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::{Result, Serialize};
+
+use core::ops::Bound;
+
+/// The bounds of a key range, in their serialized form.
+///
+/// All of the backends order the keys by their serialized bytes, so a range over
+/// the serialized keys selects the same entries in every one of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct KeyRange {
+    /// The serialized lower bound.
+    pub(crate) start: Bound<Vec<u8>>,
+    /// The serialized upper bound.
+    pub(crate) end: Bound<Vec<u8>>,
+}
+
+impl KeyRange {
+    /// Serializes the given bounds.
+    pub(crate) fn new<K: Serialize>(start: Bound<&K>, end: Bound<&K>) -> Result<Self> {
+        Ok(Self { start: serialize_bound(start)?, end: serialize_bound(end)? })
+    }
+
+    /// Returns a range that contains every key.
+    pub(crate) fn unbounded() -> Self {
+        Self { start: Bound::Unbounded, end: Bound::Unbounded }
+    }
+
+    /// Returns `true` if no key can be within the range.
+    pub(crate) fn is_empty(&self) -> bool {
+        match (&self.start, &self.end) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Included(end))
+            | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+            _ => false,
+        }
+    }
+
+    /// Returns the serialized key from which a forward iteration should start.
+    pub(crate) fn seek_key(&self) -> &[u8] {
+        match &self.start {
+            Bound::Included(start) | Bound::Excluded(start) => start,
+            Bound::Unbounded => &[],
+        }
+    }
+
+    /// Returns `true` if the given serialized key is below the lower bound.
+    pub(crate) fn is_before_start(&self, key: &[u8]) -> bool {
+        match &self.start {
+            Bound::Included(start) => key < start.as_slice(),
+            Bound::Excluded(start) => key <= start.as_slice(),
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Returns `true` if the given serialized key is above the upper bound.
+    pub(crate) fn is_past_end(&self, key: &[u8]) -> bool {
+        match &self.end {
+            Bound::Included(end) => key > end.as_slice(),
+            Bound::Excluded(end) => key >= end.as_slice(),
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Returns the bounds as a pair, which can be used with `BTreeMap::range`.
+    ///
+    /// Note: The range must not be empty, otherwise `BTreeMap::range` panics.
+    pub(crate) fn as_bounds(&self) -> (Bound<&[u8]>, Bound<&[u8]>) {
+        (as_slice_bound(&self.start), as_slice_bound(&self.end))
+    }
+}
+
+/// Serializes the key in the given bound.
+fn serialize_bound<K: Serialize>(bound: Bound<&K>) -> Result<Bound<Vec<u8>>> {
+    Ok(match bound {
+        Bound::Included(key) => Bound::Included(bincode::serialize(key)?),
+        Bound::Excluded(key) => Bound::Excluded(bincode::serialize(key)?),
+        Bound::Unbounded => Bound::Unbounded,
+    })
+}
+
+/// Borrows the serialized key in the given bound.
+fn as_slice_bound(bound: &Bound<Vec<u8>>) -> Bound<&[u8]> {
+    match bound {
+        Bound::Included(key) => Bound::Included(key.as_slice()),
+        Bound::Excluded(key) => Bound::Excluded(key.as_slice()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_range() {
+        let range = KeyRange::new(Bound::Excluded(&1u8), Bound::Included(&3u8)).unwrap();
+        assert!(!range.is_empty());
+        assert_eq!(range.seek_key(), &[1u8]);
+        assert!(range.is_before_start(&[1u8]));
+        assert!(!range.is_before_start(&[2u8]));
+        assert!(!range.is_past_end(&[3u8]));
+        assert!(range.is_past_end(&[4u8]));
+
+        // Check the empty ranges.
+        assert!(KeyRange::new(Bound::Included(&2u8), Bound::Excluded(&2u8)).unwrap().is_empty());
+        assert!(KeyRange::new(Bound::Included(&3u8), Bound::Included(&2u8)).unwrap().is_empty());
+        assert!(!KeyRange::new(Bound::Included(&2u8), Bound::Included(&2u8)).unwrap().is_empty());
+        assert!(!KeyRange::new::<u8>(Bound::Unbounded, Bound::Unbounded).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod map;
+pub use map::*;
+
+mod nested_map;
+pub use nested_map::*;
+
+pub use crate::helpers::map_id::*;
+use crate::helpers::KeyRange;
+
+#[cfg(test)]
+mod tests;
+
+use aleo_std_storage::StorageMode;
+use anyhow::{bail, ensure, Result};
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    mem,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+
+/// The name of the database file, within the ledger directory.
+const DATABASE_FILE: &str = "ledger.redb";
+
+/// The single table holding all of the maps, keyed by their prefixed keys.
+const TABLE: ::redb::TableDefinition<&[u8], &[u8]> = ::redb::TableDefinition::new("ledger");
+
+pub trait Database {
+    /// Opens the database.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<DataMap<K, V>>;
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>>;
+}
+
+/// A low-level write operation on the database.
+#[derive(Clone, Debug)]
+pub(super) enum Operation {
+    /// Inserts the given key-value pair.
+    Put(Vec<u8>, Vec<u8>),
+    /// Removes the given key.
+    Delete(Vec<u8>),
+    /// Removes all of the keys starting with the given prefix.
+    DeletePrefix(Vec<u8>),
+}
+
+/// An instance of a redb database.
+///
+/// The maps use the same key layout as the RocksDB backend: every key is prefixed with
+/// the network ID and the map ID, and all of the maps are stored in a single table.
+#[derive(Clone)]
+pub struct Redb {
+    /// The redb instance.
+    redb: Arc<::redb::Database>,
+    /// The network ID.
+    network_id: u16,
+    /// The storage mode.
+    storage_mode: StorageMode,
+    /// The low-level database transaction that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    pub(super) atomic_batch: Arc<Mutex<Vec<Operation>>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    pub(super) atomic_depth: Arc<AtomicUsize>,
+    /// A flag indicating whether the atomic writes are currently paused.
+    pub(super) atomic_writes_paused: Arc<AtomicBool>,
+}
+
+impl Database for Redb {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens file `~/.aleo/storage/ledger-{network}/ledger.redb`.
+    /// In development mode, the database opens file `/path/to/repo/.ledger-{network}-{id}/ledger.redb`.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
+        static DB: OnceCell<Redb> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
+                Self::open_file(primary, network_id, storage.clone().into())
+            })?
+            .clone();
+
+        // Ensure the database network ID and storage mode match.
+        match database.network_id == network_id && database.storage_mode == storage.into() {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or storage mode in the database"),
+        }
+    }
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<DataMap<K, V>> {
+        // Open the redb database.
+        let database = Self::open(network_id, storage)?;
+        // Return the DataMap.
+        Ok(DataMap::new(database, map_id.into()))
+    }
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>> {
+        // Open the redb database.
+        let database = Self::open(network_id, storage)?;
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id.into()))
+    }
+}
+
+impl Redb {
+    /// Opens (or creates) the database file in the given directory.
+    fn open_file(directory: std::path::PathBuf, network_id: u16, storage_mode: StorageMode) -> Result<Self> {
+        // Ensure the directory exists.
+        std::fs::create_dir_all(&directory)?;
+        // Open the database file.
+        let redb = ::redb::Database::create(directory.join(DATABASE_FILE))?;
+
+        // Ensure the table exists, so that the read transactions can open it.
+        let transaction = redb.begin_write()?;
+        transaction.open_table(TABLE)?;
+        transaction.commit()?;
+
+        Ok(Redb {
+            redb: Arc::new(redb),
+            network_id,
+            storage_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        })
+    }
+
+    /// Returns the value for the given raw key, if it exists.
+    pub(super) fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let table = self.redb.begin_read()?.open_table(TABLE)?;
+        Ok(table.get(key)?.map(|value| value.value().to_vec()))
+    }
+
+    /// Returns an iterator over the raw key-value pairs whose keys start with the given prefix.
+    pub(super) fn prefix_iterator(&self, prefix: &[u8]) -> Result<PrefixIter> {
+        let table = self.redb.begin_read()?.open_table(TABLE)?;
+        Ok(PrefixIter { range: table.range::<&[u8]>(prefix..)?, prefix: prefix.to_vec() })
+    }
+
+    /// Returns the raw entries whose keys start with the given `prefix`, and whose remaining key bytes
+    /// are within the given `range`, up to `limit` of them; the keys are returned in full.
+    pub(super) fn prefix_range_entries(
+        &self,
+        prefix: &[u8],
+        range: &KeyRange,
+        limit: Option<usize>,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut entries = Vec::new();
+        if range.is_empty() || limit == 0 {
+            return Ok(entries);
+        }
+
+        // Seek to the lower bound of the range, right after the prefix.
+        let mut seek_key = prefix.to_vec();
+        seek_key.extend_from_slice(range.seek_key());
+
+        let table = self.redb.begin_read()?.open_table(TABLE)?;
+        let iterator = PrefixIter { range: table.range::<&[u8]>(seek_key.as_slice()..)?, prefix: prefix.to_vec() };
+
+        for entry in iterator {
+            let (key, value) = entry?;
+            // Skip the key matching an excluded lower bound, and stop past the upper bound.
+            let suffix = &key[prefix.len()..];
+            if range.is_before_start(suffix) {
+                continue;
+            }
+            if range.is_past_end(suffix) {
+                break;
+            }
+
+            entries.push((key, value));
+            if entries.len() == limit {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Executes the given operations atomically.
+    pub(super) fn write(&self, operations: Vec<Operation>) -> Result<()> {
+        use ::redb::ReadableTable;
+
+        if operations.is_empty() {
+            return Ok(());
+        }
+
+        let transaction = self.redb.begin_write()?;
+        {
+            let mut table = transaction.open_table(TABLE)?;
+            for operation in operations {
+                match operation {
+                    Operation::Put(key, value) => {
+                        table.insert(key.as_slice(), value.as_slice())?;
+                    }
+                    Operation::Delete(key) => {
+                        table.remove(key.as_slice())?;
+                    }
+                    Operation::DeletePrefix(prefix) => {
+                        // Collect the keys first, as the table cannot be mutated while iterating.
+                        let mut keys = Vec::new();
+                        for entry in table.range::<&[u8]>(prefix.as_slice()..)? {
+                            let (key, _) = entry?;
+                            if !key.value().starts_with(&prefix) {
+                                break;
+                            }
+                            keys.push(key.value().to_vec());
+                        }
+                        for key in keys {
+                            table.remove(key.as_slice())?;
+                        }
+                    }
+                }
+            }
+        }
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    /// Pause the execution of atomic writes for the entire database.
+    fn pause_atomic_writes(&self) -> Result<()> {
+        // This operation is only intended to be performed before or after
+        // atomic batches - never in the middle of them.
+        assert_eq!(self.atomic_depth.load(Ordering::SeqCst), 0);
+
+        // Set the flag indicating that the pause is in effect.
+        let already_paused = self.atomic_writes_paused.swap(true, Ordering::SeqCst);
+        // Make sure that we haven't already paused atomic writes (which would
+        // indicate a logic bug).
+        assert!(!already_paused);
+
+        Ok(())
+    }
+
+    /// Unpause the execution of atomic writes for the entire database; this
+    /// executes all the writes that have been queued since they were paused.
+    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
+        // Ensure the call to unpause is only performed before or after an atomic batch scope
+        // - and never in the middle of one (otherwise there is a fundamental logic bug).
+        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
+
+        let currently_paused = self.atomic_writes_paused.load(Ordering::SeqCst);
+        // Ensure the database is paused (otherwise there is a fundamental logic bug).
+        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
+        ensure!(currently_paused, "Atomic writes must be paused to unpause them");
+
+        // Every atomic storage operation that has accumulated from the moment
+        // the writes have been paused is executed as a single atomic batch.
+        let batch = mem::take(&mut *self.atomic_batch.lock());
+        if !DISCARD_BATCH {
+            self.write(batch)?;
+        }
+
+        // Unset the flag indicating that the pause is in effect.
+        self.atomic_writes_paused.store(false, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Checks whether the atomic writes are currently paused.
+    fn are_atomic_writes_paused(&self) -> bool {
+        self.atomic_writes_paused.load(Ordering::SeqCst)
+    }
+
+    /// Opens the test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        use console::prelude::{Rng, TestRng};
+
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        // Construct the directory for the test database.
+        let primary = match dev {
+            Some(dev) => temp_dir.join(dev.to_string()),
+            None => temp_dir,
+        };
+
+        // Prepare the storage mode.
+        let storage_mode = StorageMode::from(primary.clone());
+
+        // Open the test database.
+        let database = Self::open_file(primary, u16::MAX, storage_mode.clone())?;
+
+        // Ensure the database storage mode match.
+        match database.storage_mode == storage_mode {
+            true => Ok(database),
+            false => bail!("Mismatching storage mode in the test database"),
+        }
+    }
+
+    /// Opens the test map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<DataMap<K, V>> {
+        // Open the redb test database.
+        let database = Self::open_testing(temp_dir, dev)?;
+        // Return the DataMap.
+        Ok(DataMap::new(database, map_id.into()))
+    }
+
+    /// Opens the test nested map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_nested_map_testing<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>> {
+        // Open the redb test database.
+        let database = Self::open_testing(temp_dir, dev)?;
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id.into()))
+    }
+
+    /// Returns the prefix of the map with the given `map_id`.
+    fn map_context(&self, map_id: u16) -> Vec<u8> {
+        let mut context = self.network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&map_id.to_le_bytes());
+        context
+    }
+}
+
+/// An iterator over the raw key-value pairs whose keys start with a given prefix.
+pub struct PrefixIter {
+    /// The range of the table, starting at the prefix.
+    range: ::redb::Range<'static, &'static [u8], &'static [u8]>,
+    /// The prefix of the keys.
+    prefix: Vec<u8>,
+}
+
+impl Iterator for PrefixIter {
+    type Item = Result<(Vec<u8>, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.range.next()? {
+            Ok((key, value)) => {
+                // Stop at the first key that does not start with the prefix.
+                if !key.value().starts_with(&self.prefix) {
+                    return None;
+                }
+                Some(Ok((key.value().to_vec(), value.value().to_vec())))
+            }
+            Err(error) => Some(Err(error.into())),
+        }
+    }
+}
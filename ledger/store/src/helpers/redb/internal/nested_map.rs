@@ -0,0 +1,726 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use super::*;
+use crate::helpers::{KeyRange, NestedMap, NestedMapRead};
+use console::prelude::{anyhow, cfg_into_iter, FromBytes};
+
+use core::{fmt, fmt::Debug, hash::Hash, ops::Bound};
+use std::borrow::Cow;
+use tracing::error;
+
+#[cfg(not(feature = "serial"))]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[derive(Clone)]
+pub struct NestedDataMap<
+    M: Serialize + DeserializeOwned,
+    K: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+> {
+    /// The redb instance.
+    pub(super) database: Redb,
+    /// The context for the map.
+    pub(super) context: Vec<u8>,
+    /// The tracker for whether a database transaction is in progress.
+    pub(super) batch_in_progress: Arc<AtomicBool>,
+    /// The database transaction.
+    pub(super) atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
+    /// The checkpoint stack for the batched operations within the map.
+    pub(super) checkpoints: Arc<Mutex<Vec<usize>>>,
+}
+
+impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Debug
+    for NestedDataMap<M, K, V>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NestedDataMap").field("context", &self.context).finish()
+    }
+}
+
+impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
+    NestedDataMap<M, K, V>
+{
+    /// Initializes a new nested map with the given `map_id` in the given database.
+    pub(super) fn new(database: Redb, map_id: u16) -> Self {
+        // Combine contexts to create a new scope.
+        let context = database.map_context(map_id);
+
+        Self {
+            database,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>> {
+        let mut raw_map = self.context.clone();
+
+        let map_size: u32 = bincode::serialized_size(&map)?.try_into()?;
+        raw_map.extend_from_slice(&map_size.to_le_bytes());
+
+        bincode::serialize_into(&mut raw_map, map)?;
+        Ok(raw_map)
+    }
+
+    #[inline]
+    fn create_prefixed_map_key(&self, map: &M, key: &K) -> Result<Vec<u8>> {
+        let mut raw_map_key = self.create_prefixed_map(map)?;
+        bincode::serialize_into(&mut raw_map_key, key)?;
+        Ok(raw_map_key)
+    }
+
+    #[inline]
+    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<Vec<u8>>> {
+        let raw_map_key = self.create_prefixed_map_key(map, key)?;
+        self.database.get(&raw_map_key)
+    }
+}
+
+#[inline]
+fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
+    // Retrieve the map length.
+    let map_len = u32::from_bytes_le(
+        map_key.get(PREFIX_LEN..PREFIX_LEN + 4).ok_or_else(|| anyhow!("NestedMap map_len index out of range"))?,
+    )? as usize;
+
+    // Retrieve the map bytes.
+    let map = map_key
+        .get(PREFIX_LEN + 4..PREFIX_LEN + 4 + map_len)
+        .ok_or_else(|| anyhow!("NestedMap map index out of range"))?;
+
+    // Retrieve the key bytes.
+    let key = map_key.get(PREFIX_LEN + 4 + map_len..).ok_or_else(|| anyhow!("NestedMap key index out of range"))?;
+
+    // Return the map and key bytes.
+    Ok((map, key))
+}
+
+impl<
+        'a,
+        M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+        K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+    > NestedMap<'a, M, K, V> for NestedDataMap<M, K, V>
+{
+    ///
+    /// Inserts the given map-key-value pair.
+    ///
+    fn insert(&self, map: M, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.is_atomic_in_progress() {
+            // If a batch is in progress, add the map-key-value pair to the batch.
+            true => self.atomic_batch.lock().push((map, Some(key), Some(value))),
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                // Prepare the prefixed map-key and serialized value.
+                let raw_key = self.create_prefixed_map_key(&map, &key)?;
+                let raw_value = bincode::serialize(&value)?;
+                self.database.write(vec![Operation::Put(raw_key, raw_value)])?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the given map.
+    ///
+    fn remove_map(&self, map: &M) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.is_atomic_in_progress() {
+            // If a batch is in progress, add the map-None pair to the batch.
+            true => self.atomic_batch.lock().push((*map, None, None)),
+            // Otherwise, remove the map directly from the map.
+            false => {
+                // The map prefix includes the serialized map length, so it only matches the keys of this map.
+                let prefix = self.create_prefixed_map(map)?;
+                self.database.write(vec![Operation::DeletePrefix(prefix)])?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given map and key.
+    ///
+    fn remove_key(&self, map: &M, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        match self.is_atomic_in_progress() {
+            // If a batch is in progress, add the key to the batch.
+            true => self.atomic_batch.lock().push((*map, Some(key.clone()), None)),
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                // Prepare the prefixed map-key.
+                let map_key = self.create_prefixed_map_key(map, key)?;
+                self.database.write(vec![Operation::Delete(map_key)])?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Increment the atomic depth index.
+        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+        // Ensure that the database atomic batch is empty; skip this check if the atomic
+        // writes are paused, as there may be pending operations.
+        if !self.database.are_atomic_writes_paused() {
+            assert!(self.database.atomic_batch.lock().is_empty());
+        }
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// we roll back all future operations, and return to the start of this checkpoint.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Push the current length of the atomic batch to the checkpoint stack.
+        self.checkpoints.lock().push(self.atomic_batch.lock().len());
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        // Removes the latest checkpoint.
+        let _ = self.checkpoints.lock().pop();
+    }
+
+    ///
+    /// Removes all pending operations to the last `atomic_checkpoint`
+    /// (or to `start_atomic` if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self) {
+        // Acquire the write lock on the atomic batch.
+        let mut atomic_batch = self.atomic_batch.lock();
+
+        // Retrieve the last checkpoint.
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+
+        // Remove all operations after the checkpoint.
+        atomic_batch.truncate(checkpoint);
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        self.atomic_batch.lock().clear();
+        // Clear the checkpoint stack.
+        self.checkpoints.lock().clear();
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+        // Clear the database-wide atomic batch.
+        self.database.atomic_batch.lock().clear();
+        // Reset the atomic batch depth.
+        self.database.atomic_depth.store(0, Ordering::SeqCst);
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch belonging to the map.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+
+        if !operations.is_empty() {
+            // Prepare all the operations before enqueuing any of them, so that a serialization
+            // failure cannot leave a partial batch in the database-wide batch.
+            let prepared_operations = operations
+                .into_iter()
+                .map(|(map, key, value)| match (key, value) {
+                    (Some(key), Some(value)) => {
+                        Ok(Operation::Put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?))
+                    }
+                    (Some(key), None) => Ok(Operation::Delete(self.create_prefixed_map_key(&map, &key)?)),
+                    (None, None) => Ok(Operation::DeletePrefix(self.create_prefixed_map(&map)?)),
+                    (None, Some(_)) => unreachable!("Cannot insert a value without a key"),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Enqueue all the operations from the map in the database-wide batch.
+            self.database.atomic_batch.lock().extend(prepared_operations);
+        }
+
+        // Clear the checkpoint stack.
+        self.checkpoints.lock().clear();
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+
+        // Subtract the atomic depth index.
+        let previous_atomic_depth = self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+
+        // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
+        // calls to `start_atomic` have corresponding calls to `finish_atomic`.
+        assert!(previous_atomic_depth != 0);
+
+        // If we're at depth 0, it is the final call to `finish_atomic` and the
+        // atomic write batch can be physically executed. This is skipped if the
+        // atomic writes are paused.
+        if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
+            // Empty the collection of pending operations.
+            let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            // Execute all the operations atomically.
+            self.database.write(batch)?;
+            // Ensure that the database atomic batch is empty.
+            assert!(self.database.atomic_batch.lock().is_empty());
+        }
+
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
+        K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
+    > NestedMapRead<'a, M, K, V> for NestedDataMap<M, K, V>
+{
+    type Iterator = NestedIter<'a, M, K, V>;
+    type Keys = NestedKeys<'a, M, K>;
+    type PendingIterator = core::iter::Map<
+        std::vec::IntoIter<(M, Option<K>, Option<V>)>,
+        fn((M, Option<K>, Option<V>)) -> (Cow<'a, M>, Option<Cow<'a, K>>, Option<Cow<'a, V>>),
+    >;
+    type Values = NestedValues<'a, V>;
+
+    ///
+    /// Returns the number of confirmed entries in the map.
+    ///
+    fn len_map_confirmed(&self, map: &M) -> Result<usize> {
+        // The map prefix includes the serialized map length, so it only matches the keys of this map.
+        let prefix = self.create_prefixed_map(map)?;
+        Ok(self.database.prefix_iterator(&prefix)?.count())
+    }
+
+    ///
+    /// Returns `true` if the given map and key exists.
+    ///
+    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool> {
+        self.get_map_key_raw(map, key).map(|v| v.is_some())
+    }
+
+    ///
+    /// Returns `true` if the given map and key exists.
+    /// This method first checks the atomic batch, and if it does not exist, then checks the map.
+    ///
+    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool> {
+        // If a batch is in progress, check the atomic batch first.
+        if self.is_atomic_in_progress() {
+            // We iterate from the back of the `atomic_batch` to find the latest value.
+            for (m, k, v) in self.atomic_batch.lock().iter().rev() {
+                // If the map does not match the given map, then continue.
+                if m != map {
+                    continue;
+                }
+                // If the key is 'None', then the map is scheduled to be removed.
+                if k.is_none() {
+                    return Ok(false);
+                }
+                // If the key matches the given key, then return whether the value is 'Some(V)'.
+                if k.as_ref().unwrap() == key {
+                    // If the value is 'Some(V)', then the key exists.
+                    // If the value is 'None', then the key is scheduled to be removed.
+                    return Ok(v.is_some());
+                }
+            }
+        }
+        // Otherwise, check the map for the key.
+        self.contains_key_confirmed(map, key)
+    }
+
+    ///
+    /// Returns the key-value pairs for the given map, if it exists.
+    ///
+    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+        // Collect the raw entries belonging to the map.
+        let entries = self
+            .database
+            .prefix_iterator(&self.create_prefixed_map(map)?)?
+            .map(|entry| {
+                let (map_key, value) = entry?;
+                // Extract the bytes belonging to the key.
+                let (_, entry_key) = get_map_and_key(&map_key)?;
+                Ok((entry_key.to_owned(), value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Possibly deserialize the entries in parallel.
+        Ok(cfg_into_iter!(entries)
+            .map(|(k, v)| {
+                let k = bincode::deserialize::<K>(&k);
+                let v = bincode::deserialize::<V>(&v);
+
+                k.and_then(|k| v.map(|v| (k, v)))
+            })
+            .collect::<Result<_, bincode::Error>>()?)
+    }
+
+    ///
+    /// Returns the speculative key-value pairs for the given map, if it exists.
+    ///
+    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+        // Retrieve the confirmed key-value pairs for the given map.
+        let mut key_values = self.get_map_confirmed(map)?;
+
+        // Retrieve the atomic batch.
+        let operations = self.atomic_batch.lock().clone();
+
+        if !operations.is_empty() {
+            // Traverse the queued operations.
+            for (m, k, v) in operations {
+                // If the map does not match the given map, then continue.
+                if &m != map {
+                    continue;
+                }
+
+                // Update the confirmed pairs based on the pending operations.
+                match (k, v) {
+                    // Insert or update the key-value pair for the key.
+                    (Some(k), Some(v)) => {
+                        // If the key exists, then update the value.
+                        // Otherwise, insert the key-value pair.
+                        match key_values.iter_mut().find(|(key, _)| key == &k) {
+                            Some((_, value)) => *value = v,
+                            None => key_values.push((k, v)),
+                        }
+                    }
+                    // Clear the key-value pairs for the map.
+                    (None, None) => key_values.clear(),
+                    // Remove the key-value pair for the key.
+                    (Some(k), None) => key_values.retain(|(key, _)| key != &k),
+                    (None, Some(_)) => unreachable!("Cannot remove a key-value pair from a map without a key."),
+                }
+            }
+        }
+
+        // Return the key-value pairs for the map.
+        Ok(key_values)
+    }
+
+    ///
+    /// Returns the value for the given map and key, if it exists.
+    ///
+    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
+        match self.get_map_key_raw(map, key) {
+            Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+            Ok(None) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_value_pending(&self, map: &M, key: &K) -> Option<Option<V>> {
+        // Return early if there is no atomic batch in progress.
+        if self.is_atomic_in_progress() {
+            // We iterate from the back of the `atomic_batch` to find the latest value.
+            for (m, k, v) in self.atomic_batch.lock().iter().rev() {
+                // If the map does not match the given map, then continue.
+                if m != map {
+                    continue;
+                }
+                // If the key is 'None', then the map is scheduled to be removed.
+                if k.is_none() {
+                    return Some(None);
+                }
+                // If the key matches the given key, then return whether the value is 'Some(V)'.
+                if k.as_ref().unwrap() == key {
+                    // If the value is 'Some(V)', then the key exists.
+                    // If the value is 'Some(None)', then the key is scheduled to be removed.
+                    return Some(v.clone());
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the atomic batch.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        self.atomic_batch.lock().clone().into_iter().map(|(m, k, v)| {
+            // Return the map-key-value triple.
+            (Cow::Owned(m), k.map(Cow::Owned), v.map(Cow::Owned))
+        })
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter_confirmed(&'a self) -> Self::Iterator {
+        NestedIter::new(self.database.prefix_iterator(&self.context))
+    }
+
+    ///
+    /// Returns an iterator over each key.
+    ///
+    fn keys_confirmed(&'a self) -> Self::Keys {
+        NestedKeys::new(self.database.prefix_iterator(&self.context))
+    }
+
+    ///
+    /// Returns an iterator over each value.
+    ///
+    fn values_confirmed(&'a self) -> Self::Values {
+        NestedValues::new(self.database.prefix_iterator(&self.context))
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose keys are within the given bounds,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the map and the bounds.
+        let map_prefix = self.create_prefixed_map(map)?;
+        let range = KeyRange::new(start, end)?;
+        // Retrieve the raw entries of the map within the range.
+        let entries = self.database.prefix_range_entries(&map_prefix, &range, limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[map_prefix.len()..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose serialized keys start with
+    /// the serialized `prefix`, up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_prefix_confirmed<P: Serialize + ?Sized>(
+        &'a self,
+        map: &M,
+        prefix: &P,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the map and the prefix.
+        let map_prefix = self.create_prefixed_map(map)?;
+        let mut raw_prefix = map_prefix.clone();
+        bincode::serialize_into(&mut raw_prefix, prefix)?;
+        // Retrieve the raw entries of the map with the prefix.
+        let entries = self.database.prefix_range_entries(&raw_prefix, &KeyRange::unbounded(), limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[map_prefix.len()..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
+}
+
+/// An iterator over all map-key-value pairs in a data map.
+pub struct NestedIter<
+    'a,
+    M: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+    K: 'a + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+    V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+> {
+    db_iter: Option<PrefixIter>,
+    _phantom: PhantomData<(&'a (), M, K, V)>,
+}
+
+impl<
+        'a,
+        M: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+        K: 'a + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+        V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+    > NestedIter<'a, M, K, V>
+{
+    pub(super) fn new(db_iter: Result<PrefixIter>) -> Self {
+        Self { db_iter: log_iterator_error(db_iter, "NestedIter"), _phantom: PhantomData }
+    }
+}
+
+impl<
+        'a,
+        M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+        K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+        V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned,
+    > Iterator for NestedIter<'a, M, K, V>
+{
+    type Item = (Cow<'a, M>, Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (map_key, value) = self
+            .db_iter
+            .as_mut()?
+            .next()?
+            .map_err(|e| {
+                error!("redb NestedIter iterator error: {e}");
+            })
+            .ok()?;
+
+        // Extract the bytes belonging to the map and the key.
+        let (entry_map, entry_key) = get_map_and_key(&map_key)
+            .map_err(|e| {
+                error!("redb NestedIter get_map_and_key error: {e}");
+            })
+            .ok()?;
+
+        // Deserialize the map, key, and value.
+        let map = bincode::deserialize(entry_map)
+            .map_err(|e| {
+                error!("redb NestedIter deserialize(map) error: {e}");
+            })
+            .ok()?;
+        let key = bincode::deserialize(entry_key)
+            .map_err(|e| {
+                error!("redb NestedIter deserialize(key) error: {e}");
+            })
+            .ok()?;
+        // Deserialize the value.
+        let value = bincode::deserialize(&value)
+            .map_err(|e| {
+                error!("redb NestedIter deserialize(value) error: {e}");
+            })
+            .ok()?;
+
+        Some((Cow::Owned(map), Cow::Owned(key), Cow::Owned(value)))
+    }
+}
+
+/// An iterator over the keys of a prefix.
+pub struct NestedKeys<
+    'a,
+    M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+    K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+> {
+    db_iter: Option<PrefixIter>,
+    _phantom: PhantomData<(&'a (), M, K)>,
+}
+
+impl<
+        'a,
+        M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+        K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+    > NestedKeys<'a, M, K>
+{
+    pub(crate) fn new(db_iter: Result<PrefixIter>) -> Self {
+        Self { db_iter: log_iterator_error(db_iter, "NestedKeys"), _phantom: PhantomData }
+    }
+}
+
+impl<
+        'a,
+        M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
+        K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+    > Iterator for NestedKeys<'a, M, K>
+{
+    type Item = (Cow<'a, M>, Cow<'a, K>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (map_key, _) = self
+            .db_iter
+            .as_mut()?
+            .next()?
+            .map_err(|e| {
+                error!("redb NestedKeys iterator error: {e}");
+            })
+            .ok()?;
+
+        // Extract the bytes belonging to the map and the key.
+        let (entry_map, entry_key) = get_map_and_key(&map_key)
+            .map_err(|e| {
+                error!("redb NestedKeys get_map_and_key error: {e}");
+            })
+            .ok()?;
+
+        // Deserialize the map and key.
+        let map = bincode::deserialize(entry_map)
+            .map_err(|e| {
+                error!("redb NestedKeys deserialize(map) error: {e}");
+            })
+            .ok()?;
+        let key = bincode::deserialize(entry_key)
+            .map_err(|e| {
+                error!("redb NestedKeys deserialize(key) error: {e}");
+            })
+            .ok()?;
+
+        Some((Cow::Owned(map), Cow::Owned(key)))
+    }
+}
+
+/// An iterator over the values of a prefix.
+pub struct NestedValues<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
+    db_iter: Option<PrefixIter>,
+    _phantom: PhantomData<(&'a (), V)>,
+}
+
+impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> NestedValues<'a, V> {
+    pub(crate) fn new(db_iter: Result<PrefixIter>) -> Self {
+        Self { db_iter: log_iterator_error(db_iter, "NestedValues"), _phantom: PhantomData }
+    }
+}
+
+impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator for NestedValues<'a, V> {
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, value) = self
+            .db_iter
+            .as_mut()?
+            .next()?
+            .map_err(|e| {
+                error!("redb NestedValues iterator error: {e}");
+            })
+            .ok()?;
+
+        // Deserialize the value.
+        let value = bincode::deserialize(&value)
+            .map_err(|e| {
+                error!("redb NestedValues deserialize(value) error: {e}");
+            })
+            .ok()?;
+
+        Some(Cow::Owned(value))
+    }
+}
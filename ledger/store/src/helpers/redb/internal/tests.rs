@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Redb;
+use crate::helpers::{
+    redb::{MapID, TestMap as TestMapID},
+    test_helpers,
+    Map,
+    MapRead,
+    NestedMap,
+    NestedMapRead,
+};
+
+use aleo_std_storage::StorageMode;
+use serial_test::serial;
+
+pub(crate) fn temp_dir() -> std::path::PathBuf {
+    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+}
+
+#[test]
+#[serial]
+fn test_open() {
+    let _storage = Redb::open_testing(temp_dir(), None).expect("Failed to open storage");
+}
+
+#[test]
+#[serial]
+fn test_open_map() {
+    let _map = Redb::open_map_testing::<u32, String, _>(temp_dir(), None, MapID::Test(TestMapID::Test))
+        .expect("Failed to open data map");
+}
+
+#[test]
+#[serial]
+fn test_insert_and_get() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert_eq!(
+        Some("123456789".to_string()),
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string())
+    );
+
+    assert_eq!(None, map.get_confirmed(&000000000).expect("Failed to get"));
+}
+
+#[test]
+#[serial]
+fn test_insert_and_remove() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert!(map.contains_key_confirmed(&123456789).expect("Failed to call contains key"));
+
+    map.remove(&123456789).expect("Failed to remove");
+    assert!(map.get_confirmed(&123456789).expect("Failed to get").is_none());
+}
+
+#[test]
+#[serial]
+fn test_iterator_ordering() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    // Insert values into the map.
+    for (key, value) in [(5, "d"), (6, "c"), (7, "b"), (8, "a"), (1, "h"), (2, "g"), (3, "f"), (4, "e")] {
+        map.insert(key, value.to_string()).expect("Failed to insert");
+    }
+
+    // Check that the order of the iterator is lexicographical.
+    let entries = map.iter_confirmed().map(|(k, v)| (*k, v.to_string())).collect::<Vec<_>>();
+    let expected_order = ["h", "g", "f", "e", "d", "c", "b", "a"];
+    assert_eq!(entries.len(), expected_order.len());
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        assert_eq!(key, i + 1);
+        assert_eq!(value, expected_order[i]);
+    }
+}
+
+#[test]
+#[serial]
+fn test_maps_are_isolated() {
+    let database = Redb::open_testing(temp_dir(), None).expect("Failed to open storage");
+    let map_1 = super::DataMap::<usize, String>::new(database.clone(), MapID::Test(TestMapID::Test).into());
+    let map_2 = super::DataMap::<usize, String>::new(database, MapID::Test(TestMapID::Test2).into());
+
+    map_1.insert(1, "1".to_string()).unwrap();
+    map_2.insert(2, "2".to_string()).unwrap();
+
+    // Ensure each map only sees its own entries.
+    assert_eq!(map_1.keys_confirmed().map(|k| *k).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(map_2.keys_confirmed().map(|k| *k).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(map_1.len_confirmed(), 1);
+    assert_eq!(map_2.len_confirmed(), 1);
+}
+
+#[test]
+#[serial]
+fn test_remove_map_is_isolated() {
+    let map = Redb::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test))
+        .expect("Failed to open nested data map");
+
+    // Insert entries into neighbouring nested maps.
+    for m in 0..3usize {
+        for k in 0..3usize {
+            map.insert(m, k, format!("{m}-{k}")).unwrap();
+        }
+    }
+
+    // Remove the middle map, both directly and as part of an atomic batch.
+    map.remove_map(&1).unwrap();
+    assert_eq!(map.len_map_confirmed(&0).unwrap(), 3);
+    assert_eq!(map.len_map_confirmed(&1).unwrap(), 0);
+    assert_eq!(map.len_map_confirmed(&2).unwrap(), 3);
+
+    map.start_atomic();
+    map.remove_map(&2).unwrap();
+    map.insert(2, 7, "2-7".to_string()).unwrap();
+    map.finish_atomic().unwrap();
+    assert_eq!(map.get_map_confirmed(&0).unwrap().len(), 3);
+    assert_eq!(map.get_map_confirmed(&2).unwrap(), vec![(7, "2-7".to_string())]);
+}
+
+#[test]
+#[serial]
+fn test_paused_writes_are_discarded() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.pause_atomic_writes().unwrap();
+    map.start_atomic();
+    map.insert(1, "1".to_string()).unwrap();
+    map.finish_atomic().unwrap();
+    // The write is queued until the writes are unpaused.
+    assert!(!map.contains_key_confirmed(&1).unwrap());
+    map.unpause_atomic_writes::<true>().unwrap();
+    assert!(!map.contains_key_confirmed(&1).unwrap());
+
+    map.pause_atomic_writes().unwrap();
+    map.start_atomic();
+    map.insert(2, "2".to_string()).unwrap();
+    map.finish_atomic().unwrap();
+    map.unpause_atomic_writes::<false>().unwrap();
+    assert!(map.contains_key_confirmed(&2).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_entries_persist_across_reopen() {
+    let directory = temp_dir();
+    let storage_mode = StorageMode::from(directory.clone());
+
+    {
+        let database = Redb::open_file(directory.clone(), u16::MAX, storage_mode.clone()).unwrap();
+        let map = super::DataMap::<usize, String>::new(database, MapID::Test(TestMapID::Test).into());
+        map.insert(1, "1".to_string()).unwrap();
+    }
+
+    // Reopen the database file and check the entry is still there.
+    let database = Redb::open_file(directory, u16::MAX, storage_mode).unwrap();
+    let map = super::DataMap::<usize, String>::new(database, MapID::Test(TestMapID::Test).into());
+    assert_eq!(map.get_confirmed(&1).unwrap().map(|v| v.to_string()), Some("1".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_map_conformance() {
+    test_helpers::map::check_conformance(|| {
+        Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map")
+    });
+}
+
+#[test]
+#[serial]
+fn test_nested_map_conformance() {
+    test_helpers::nested_map::check_conformance(|| {
+        Redb::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test))
+            .expect("Failed to open nested data map")
+    });
+}
@@ -15,9 +15,9 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{KeyRange, Map, MapRead};
 
-use core::{fmt, fmt::Debug, hash::Hash, mem};
+use core::{fmt, fmt::Debug, hash::Hash, mem, ops::Bound};
 use indexmap::IndexMap;
 use smallvec::SmallVec;
 use std::{borrow::Cow, ops::Deref, sync::atomic::Ordering};
@@ -392,6 +392,39 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         Values::new(self.database.prefix_iterator(&self.context))
     }
+
+    ///
+    /// Returns the confirmed key-value pairs whose keys are within the given bounds, up to `limit` of them.
+    /// The keys are ordered by their serialized bytes, which is the iteration order of every backend.
+    ///
+    fn range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: Option<usize>) -> Result<Vec<(K, V)>> {
+        // Serialize the bounds.
+        let range = KeyRange::new(start, end)?;
+        // Retrieve the raw entries within the range.
+        let entries = self.database.prefix_range_entries(&self.context, &range, limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[PREFIX_LEN..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs whose serialized keys start with the serialized `prefix`,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn prefix_confirmed<P: Serialize + ?Sized>(&'a self, prefix: &P, limit: Option<usize>) -> Result<Vec<(K, V)>> {
+        // Prepare the prefixed prefix.
+        let mut raw_prefix = self.context.clone();
+        bincode::serialize_into(&mut raw_prefix, prefix)?;
+        // Retrieve the raw entries with the prefix.
+        let entries = self.database.prefix_range_entries(&raw_prefix, &KeyRange::unbounded(), limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[PREFIX_LEN..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
 }
 
 /// An iterator over all key-value pairs in a data map.
@@ -790,6 +823,17 @@ mod tests {
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_range_iteration() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+
+        crate::helpers::test_helpers::map::check_range_iteration(map);
+    }
+
     #[test]
     #[serial]
     #[traced_test]
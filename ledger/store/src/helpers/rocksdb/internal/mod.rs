@@ -12,8 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod id;
-pub use id::*;
+pub use crate::helpers::map_id::*;
+use crate::helpers::KeyRange;
 
 mod map;
 pub use map::*;
@@ -222,6 +222,47 @@ impl Database for RocksDB {
 }
 
 impl RocksDB {
+    /// Returns the raw entries whose keys start with the given `prefix`, and whose remaining key bytes
+    /// are within the given `range`, up to `limit` of them; the keys are returned in full.
+    pub(super) fn prefix_range_entries(
+        &self,
+        prefix: &[u8],
+        range: &KeyRange,
+        limit: Option<usize>,
+    ) -> Result<Vec<(Box<[u8]>, Box<[u8]>)>> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut entries = Vec::new();
+        if range.is_empty() || limit == 0 {
+            return Ok(entries);
+        }
+
+        // Seek to the lower bound of the range, right after the prefix.
+        let mut seek_key = prefix.to_vec();
+        seek_key.extend_from_slice(range.seek_key());
+
+        for entry in self.prefix_iterator(&seek_key) {
+            let (key, value) = entry?;
+            // Stop once the keys no longer start with the prefix.
+            let Some(suffix) = key.strip_prefix(prefix) else {
+                break;
+            };
+            // Skip the key matching an excluded lower bound, and stop past the upper bound.
+            if range.is_before_start(suffix) {
+                continue;
+            }
+            if range.is_past_end(suffix) {
+                break;
+            }
+
+            entries.push((key, value));
+            if entries.len() == limit {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
     /// Pause the execution of atomic writes for the entire database.
     fn pause_atomic_writes(&self) -> Result<()> {
         // This operation is only intended to be performed before or after
@@ -389,60 +430,3 @@ impl RocksDB {
         })
     }
 }
-
-// impl RocksDB {
-//     /// Imports a file with the given path to reconstruct storage.
-//     fn import<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//         let file = File::open(path)?;
-//         let mut reader = BufReader::new(file);
-//
-//         let len = reader.seek(SeekFrom::End(0))?;
-//         reader.rewind()?;
-//
-//         let mut buf = vec![0u8; 16 * 1024];
-//
-//         while reader.stream_position()? < len {
-//             reader.read_exact(&mut buf[..4])?;
-//             let key_len = u32::from_le_bytes(buf[..4].try_into().unwrap()) as usize;
-//
-//             if key_len + 4 > buf.len() {
-//                 buf.resize(key_len + 4, 0);
-//             }
-//
-//             reader.read_exact(&mut buf[..key_len + 4])?;
-//             let value_len = u32::from_le_bytes(buf[key_len..][..4].try_into().unwrap()) as usize;
-//
-//             if key_len + value_len > buf.len() {
-//                 buf.resize(key_len + value_len, 0);
-//             }
-//
-//             reader.read_exact(&mut buf[key_len..][..value_len])?;
-//
-//             self.rocksdb.put(&buf[..key_len], &buf[key_len..][..value_len])?;
-//         }
-//
-//         Ok(())
-//     }
-//
-//     /// Exports the current state of storage to a single file at the specified location.
-//     fn export<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//         let file = File::create(path)?;
-//         let mut writer = BufWriter::new(file);
-//
-//         let mut iterator = self.rocksdb.raw_iterator();
-//         iterator.seek_to_first();
-//
-//         while iterator.valid() {
-//             if let (Some(key), Some(value)) = (iterator.key(), iterator.value()) {
-//                 writer.write_all(&(key.len() as u32).to_le_bytes())?;
-//                 writer.write_all(key)?;
-//
-//                 writer.write_all(&(value.len() as u32).to_le_bytes())?;
-//                 writer.write_all(value)?;
-//             }
-//             iterator.next();
-//         }
-//
-//         Ok(())
-//     }
-// }
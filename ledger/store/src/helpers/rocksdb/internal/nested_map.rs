@@ -15,10 +15,10 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{KeyRange, NestedMap, NestedMapRead};
 use console::prelude::{anyhow, cfg_into_iter, FromBytes};
 
-use core::{fmt, fmt::Debug, hash::Hash, mem};
+use core::{fmt, fmt::Debug, hash::Hash, mem, ops::Bound};
 use std::{borrow::Cow, sync::atomic::Ordering};
 use tracing::error;
 
@@ -585,6 +585,52 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         NestedValues::new(self.database.prefix_iterator(&self.context))
     }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose keys are within the given bounds,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the map and the bounds.
+        let map_prefix = self.create_prefixed_map(map)?;
+        let range = KeyRange::new(start, end)?;
+        // Retrieve the raw entries of the map within the range.
+        let entries = self.database.prefix_range_entries(&map_prefix, &range, limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[map_prefix.len()..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose serialized keys start with
+    /// the serialized `prefix`, up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_prefix_confirmed<P: Serialize + ?Sized>(
+        &'a self,
+        map: &M,
+        prefix: &P,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the map and the prefix.
+        let map_prefix = self.create_prefixed_map(map)?;
+        let mut raw_prefix = map_prefix.clone();
+        bincode::serialize_into(&mut raw_prefix, prefix)?;
+        // Retrieve the raw entries of the map with the prefix.
+        let entries = self.database.prefix_range_entries(&raw_prefix, &KeyRange::unbounded(), limit)?;
+        // Deserialize the entries.
+        entries
+            .into_iter()
+            .map(|(k, v)| Ok((bincode::deserialize(&k[map_prefix.len()..])?, bincode::deserialize(&v)?)))
+            .collect()
+    }
 }
 
 /// An iterator over all map-key-value pairs in a data map.
@@ -1051,6 +1097,18 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_iterators_match(map);
     }
 
+    #[test]
+    #[serial]
+    #[traced_test]
+    fn test_range_iteration() {
+        // Initialize a map.
+        let map: NestedDataMap<usize, usize, String> =
+            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+                .expect("Failed to open data map");
+
+        crate::helpers::test_helpers::nested_map::check_range_iteration(map);
+    }
+
     #[test]
     #[serial]
     #[traced_test]
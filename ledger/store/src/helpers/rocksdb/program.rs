@@ -38,6 +38,14 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The history map.
+    history_map: NestedDataMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>,
+    /// The history metadata map.
+    history_metadata_map: DataMap<u8, u32>,
+    /// The mapping length map.
+    mapping_length_map: DataMap<(ProgramID<N>, Identifier<N>), u64>,
+    /// The history index map.
+    history_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Vec<u32>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +55,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type HistoryMap = NestedDataMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    type HistoryMetadataMap = DataMap<u8, u32>;
+    type MappingLengthMap = DataMap<(ProgramID<N>, Identifier<N>), u64>;
+    type HistoryIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Vec<u32>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +69,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            history_metadata_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryMetadata))?,
+            mapping_length_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::MappingLength))?,
+            history_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryIndex))?,
             storage_mode: storage.into(),
         })
     }
@@ -70,7 +86,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            history_metadata_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryMetadata))?,
+            mapping_length_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::MappingLength))?,
+            history_index_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::HistoryIndex))?,
             storage_mode: dev.into(),
         })
     }
@@ -90,6 +110,26 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history metadata map.
+    fn history_metadata_map(&self) -> &Self::HistoryMetadataMap {
+        &self.history_metadata_map
+    }
+
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the history index map.
+    fn history_index_map(&self) -> &Self::HistoryIndexMap {
+        &self.history_index_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
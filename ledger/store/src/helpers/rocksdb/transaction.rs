@@ -21,6 +21,7 @@ use crate::{
         ExecutionMap,
         FeeMap,
         MapID,
+        NestedDataMap,
         TransactionMap,
         TransitionDB,
     },
@@ -52,6 +53,8 @@ pub struct TransactionDB<N: Network> {
     execution_store: ExecutionStore<N, ExecutionDB<N>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
+    /// The mapping of `program ID` to `transaction ID`.
+    program_index_map: NestedDataMap<ProgramID<N>, N::TransactionID, ()>,
 }
 
 #[rustfmt::skip]
@@ -61,6 +64,7 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     type ExecutionStorage = ExecutionDB<N>;
     type FeeStorage = FeeDB<N>;
     type TransitionStorage = TransitionDB<N>;
+    type ProgramIndexMap = NestedDataMap<ProgramID<N>, N::TransactionID, ()>;
 
     /// Initializes the transaction storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
@@ -70,8 +74,10 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         let deployment_store = DeploymentStore::<N, DeploymentDB<N>>::open(fee_store.clone())?;
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(fee_store.clone())?;
+        // Initialize the program index map.
+        let program_index_map = rocksdb::RocksDB::open_nested_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::ProgramIndex))?;
         // Return the transaction storage.
-        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
+        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store, program_index_map })
     }
 
     /// Returns the ID map.
@@ -93,6 +99,11 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
     }
+
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
 }
 
 /// A database deployment storage.
@@ -195,6 +206,8 @@ pub struct ExecutionDB<N: Network> {
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The bundle map.
+    bundle_map: DataMap<N::TransactionID, Vec<(u16, N::StateRoot, Option<Proof<N>>)>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -204,6 +217,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type BundleMap = DataMap<N::TransactionID, Vec<(u16, N::StateRoot, Option<Proof<N>>)>>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the execution storage.
@@ -214,6 +228,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
             id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ID))?,
             reverse_id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ReverseID))?,
             inclusion_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Inclusion))?,
+            bundle_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Bundle))?,
             fee_store,
         })
     }
@@ -233,6 +248,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
         &self.inclusion_map
     }
 
+    /// Returns the bundle map.
+    fn bundle_map(&self) -> &Self::BundleMap {
+        &self.bundle_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
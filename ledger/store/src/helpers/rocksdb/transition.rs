@@ -13,7 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::rocksdb::{
+        self,
+        DataMap,
+        Database,
+        MapID,
+        NestedDataMap,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
@@ -23,7 +32,7 @@ use crate::{
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -47,6 +56,12 @@ pub struct TransitionDB<N: Network> {
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: DataMap<N::TransitionID, Field<N>>,
+    /// The function index map.
+    function_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), N::TransitionID, ()>,
+    /// The address index map.
+    address_index_map: NestedDataMap<Address<N>, N::TransitionID, ()>,
+    /// The index metadata map.
+    index_metadata_map: DataMap<u8, bool>,
 }
 
 #[rustfmt::skip]
@@ -59,6 +74,9 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
     type SCMMap = DataMap<N::TransitionID, Field<N>>;
+    type FunctionIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), N::TransitionID, ()>;
+    type AddressIndexMap = NestedDataMap<Address<N>, N::TransitionID, ()>;
+    type IndexMetadataMap = DataMap<u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -71,6 +89,9 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(),  MapID::Transition(TransitionMap::ReverseTCM))?,
             scm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::SCM))?,
+            function_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::FunctionIndex))?,
+            address_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::AddressIndex))?,
+            index_metadata_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::IndexMetadata))?,
         })
     }
 
@@ -113,6 +134,21 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the index metadata map.
+    fn index_metadata_map(&self) -> &Self::IndexMetadataMap {
+        &self.index_metadata_map
+    }
 }
 
 /// An database transition input storage.
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::Map;
+
+use core::ops::Bound;
+
+const NUM_ITEMS: usize = 20;
+
+/// Returns the expected entries for the given keys.
+fn entries(keys: impl IntoIterator<Item = usize>) -> Vec<(usize, String)> {
+    keys.into_iter().map(|i| (i, i.to_string())).collect()
+}
+
+pub fn check_range_iteration(map: impl for<'a> Map<'a, usize, String>) {
+    ensure_map_is_empty(&map);
+
+    // Note: The keys below 256 are ordered numerically, as their serialized bytes are little-endian.
+    for i in 0..NUM_ITEMS {
+        map.insert(i, i.to_string()).unwrap();
+    }
+
+    // Check the bounded ranges.
+    assert_eq!(map.range_confirmed(Bound::Unbounded, Bound::Unbounded, None).unwrap(), entries(0..NUM_ITEMS));
+    assert_eq!(map.range_confirmed(Bound::Included(&5), Bound::Excluded(&8), None).unwrap(), entries(5..8));
+    assert_eq!(map.range_confirmed(Bound::Excluded(&5), Bound::Included(&8), None).unwrap(), entries(6..=8));
+    assert_eq!(map.range_confirmed(Bound::Included(&15), Bound::Unbounded, Some(3)).unwrap(), entries(15..18));
+    assert_eq!(map.range_confirmed(Bound::Unbounded, Bound::Included(&2), Some(10)).unwrap(), entries(0..=2));
+
+    // Check the empty ranges.
+    assert!(map.range_confirmed(Bound::Included(&8), Bound::Excluded(&8), None).unwrap().is_empty());
+    assert!(map.range_confirmed(Bound::Included(&9), Bound::Included(&8), None).unwrap().is_empty());
+    assert!(map.range_confirmed(Bound::Included(&NUM_ITEMS), Bound::Unbounded, None).unwrap().is_empty());
+    assert!(map.range_confirmed(Bound::Unbounded, Bound::Unbounded, Some(0)).unwrap().is_empty());
+
+    // Check that the pages cover all of the entries, in order.
+    let mut pages = Vec::new();
+    let mut after = None;
+    loop {
+        let page = map.page_confirmed(after.as_ref(), 6).unwrap();
+        if page.is_empty() {
+            break;
+        }
+        after = page.last().map(|(key, _)| *key);
+        pages.push(page);
+    }
+    assert_eq!(pages.iter().map(|page| page.len()).collect::<Vec<_>>(), vec![6, 6, 6, 2]);
+    assert_eq!(pages.concat(), entries(0..NUM_ITEMS));
+
+    // Check the prefixes; the serialized `u8` prefix matches the keys whose least significant byte is equal to it.
+    map.insert(259, 259.to_string()).unwrap();
+    map.insert(515, 515.to_string()).unwrap();
+    assert_eq!(map.prefix_confirmed(&3u8, None).unwrap(), entries([3, 259, 515]));
+    assert_eq!(map.prefix_confirmed(&3u8, Some(2)).unwrap(), entries([3, 259]));
+    assert_eq!(map.prefix_confirmed(&4usize, None).unwrap(), entries([4]));
+    assert!(map.prefix_confirmed(&(NUM_ITEMS as u8), None).unwrap().is_empty());
+
+    // Ensure the pending writes are not visible.
+    map.start_atomic();
+    map.insert(NUM_ITEMS, NUM_ITEMS.to_string()).unwrap();
+    map.remove(&0).unwrap();
+    assert_eq!(map.range_confirmed(Bound::Unbounded, Bound::Excluded(&2), None).unwrap(), entries(0..2));
+    assert!(map.range_confirmed(Bound::Included(&NUM_ITEMS), Bound::Excluded(&256), None).unwrap().is_empty());
+    map.abort_atomic();
+
+    for i in (0..NUM_ITEMS).chain([259, 515]) {
+        map.remove(&i).unwrap();
+    }
+
+    ensure_map_is_empty(&map);
+}
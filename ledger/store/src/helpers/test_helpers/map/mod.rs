@@ -27,9 +27,23 @@ pub use check_insert_and_get_speculative::*;
 mod check_iterators_match;
 pub use check_iterators_match::*;
 
+mod check_range_iteration;
+pub use check_range_iteration::*;
+
 mod check_remove_and_get_speculative;
 pub use check_remove_and_get_speculative::*;
 
+/// Runs the entire map conformance suite, using `new_map` to open a fresh, empty map for each check.
+pub fn check_conformance<M: for<'a> crate::helpers::Map<'a, usize, String>>(new_map: impl Fn() -> M) {
+    check_insert_and_get_speculative(new_map());
+    check_remove_and_get_speculative(new_map());
+    check_contains_key(new_map());
+    check_iterators_match(new_map());
+    check_range_iteration(new_map());
+    check_atomic_writes_are_batched(new_map());
+    check_atomic_writes_can_be_aborted(new_map());
+}
+
 fn ensure_map_is_empty(map: &impl for<'a> crate::helpers::Map<'a, usize, String>) {
     // Sanity check.
     assert!(map.iter_pending().next().is_none());
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The conformance suite for storage backends.
+//!
+//! Every implementation of [`Map`](crate::helpers::Map) and [`NestedMap`](crate::helpers::NestedMap)
+//! is expected to pass these checks; a new backend can run all of them at once with
+//! [`map::check_conformance`] and [`nested_map::check_conformance`].
+
 pub mod map;
 
 pub mod nested_map;
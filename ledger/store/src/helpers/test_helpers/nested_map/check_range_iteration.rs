@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::NestedMap;
+
+use core::ops::Bound;
+
+const NUM_MAPS: usize = 3;
+const NUM_ITEMS: usize = 20;
+
+/// Returns the expected entries for the given keys of the given map.
+fn entries(map: usize, keys: impl IntoIterator<Item = usize>) -> Vec<(usize, String)> {
+    keys.into_iter().map(|i| (i, format!("{map}-{i}"))).collect()
+}
+
+pub fn check_range_iteration(map: impl for<'a> NestedMap<'a, usize, usize, String>) {
+    ensure_map_is_empty(&map);
+
+    // Note: The keys below 256 are ordered numerically, as their serialized bytes are little-endian.
+    for m in 0..NUM_MAPS {
+        for i in 0..NUM_ITEMS {
+            map.insert(m, i, format!("{m}-{i}")).unwrap();
+        }
+    }
+
+    // Check the bounded ranges, which must not include the entries of the neighbouring maps.
+    for m in 0..NUM_MAPS {
+        let range = |start: Bound<&usize>, end: Bound<&usize>, limit: Option<usize>| {
+            map.get_map_range_confirmed(&m, start, end, limit).unwrap()
+        };
+        assert_eq!(range(Bound::Unbounded, Bound::Unbounded, None), entries(m, 0..NUM_ITEMS));
+        assert_eq!(range(Bound::Included(&5), Bound::Excluded(&8), None), entries(m, 5..8));
+        assert_eq!(range(Bound::Excluded(&5), Bound::Included(&8), None), entries(m, 6..=8));
+        assert_eq!(range(Bound::Included(&15), Bound::Unbounded, Some(3)), entries(m, 15..18));
+        assert_eq!(range(Bound::Included(&15), Bound::Unbounded, None), entries(m, 15..NUM_ITEMS));
+        assert!(range(Bound::Included(&8), Bound::Excluded(&8), None).is_empty());
+        assert!(range(Bound::Included(&9), Bound::Included(&8), None).is_empty());
+        assert!(range(Bound::Unbounded, Bound::Unbounded, Some(0)).is_empty());
+    }
+    assert!(map.get_map_range_confirmed(&NUM_MAPS, Bound::Unbounded, Bound::Unbounded, None).unwrap().is_empty());
+
+    // Check that the pages cover all of the entries of the map, in order.
+    let mut pages = Vec::new();
+    let mut after = None;
+    loop {
+        let page = map.get_map_page_confirmed(&1, after.as_ref(), 6).unwrap();
+        if page.is_empty() {
+            break;
+        }
+        after = page.last().map(|(key, _)| *key);
+        pages.push(page);
+    }
+    assert_eq!(pages.iter().map(|page| page.len()).collect::<Vec<_>>(), vec![6, 6, 6, 2]);
+    assert_eq!(pages.concat(), entries(1, 0..NUM_ITEMS));
+
+    // Check the prefixes; the serialized `u8` prefix matches the keys whose least significant byte is equal to it.
+    map.insert(1, 259, "1-259".to_string()).unwrap();
+    map.insert(2, 515, "2-515".to_string()).unwrap();
+    assert_eq!(map.get_map_prefix_confirmed(&1, &3u8, None).unwrap(), entries(1, [3, 259]));
+    assert_eq!(map.get_map_prefix_confirmed(&1, &3u8, Some(1)).unwrap(), entries(1, [3]));
+    assert_eq!(map.get_map_prefix_confirmed(&2, &3u8, None).unwrap(), entries(2, [3, 515]));
+    assert_eq!(map.get_map_prefix_confirmed(&0, &4usize, None).unwrap(), entries(0, [4]));
+    assert!(map.get_map_prefix_confirmed(&0, &(NUM_ITEMS as u8), None).unwrap().is_empty());
+
+    // Ensure the pending writes are not visible.
+    map.start_atomic();
+    map.remove_map(&0).unwrap();
+    map.insert(1, NUM_ITEMS, format!("1-{NUM_ITEMS}")).unwrap();
+    assert_eq!(map.get_map_range_confirmed(&0, Bound::Unbounded, Bound::Excluded(&2), None).unwrap(), entries(0, 0..2));
+    let pending = map.get_map_range_confirmed(&1, Bound::Included(&NUM_ITEMS), Bound::Excluded(&256), None).unwrap();
+    assert!(pending.is_empty());
+    map.abort_atomic();
+
+    for m in 0..NUM_MAPS {
+        map.remove_map(&m).unwrap();
+    }
+
+    ensure_map_is_empty(&map);
+}
@@ -14,7 +14,7 @@
 
 use console::network::prelude::{Deserialize, Result, Serialize};
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::Bound};
 use std::borrow::Cow;
 
 /// A trait representing map-like storage operations with read-write capabilities.
@@ -186,4 +186,30 @@ pub trait MapRead<
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values;
+
+    ///
+    /// Returns the confirmed key-value pairs whose keys are within the given bounds, up to `limit` of them.
+    /// The keys are ordered by their serialized bytes, which is the iteration order of every backend.
+    ///
+    fn range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: Option<usize>) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns the confirmed key-value pairs whose serialized keys start with the serialized `prefix`,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    /// Note: For a tuple key, the serialized prefix of its first element(s) selects the matching keys.
+    ///
+    fn prefix_confirmed<P: Serialize + ?Sized>(&'a self, prefix: &P, limit: Option<usize>) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns a page of at most `limit` confirmed key-value pairs, starting right after the key `after`
+    /// (or from the first key, if `after` is `None`). The keys are ordered by their serialized bytes.
+    ///
+    fn page_confirmed(&'a self, after: Option<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        let start = match after {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Unbounded,
+        };
+        self.range_confirmed(start, Bound::Unbounded, Some(limit))
+    }
 }
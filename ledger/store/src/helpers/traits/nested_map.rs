@@ -14,7 +14,7 @@
 
 use console::network::prelude::{Deserialize, Result, Serialize};
 
-use core::hash::Hash;
+use core::{hash::Hash, ops::Bound};
 use std::borrow::Cow;
 
 /// A trait representing 'nested map'-like storage operations with read-write capabilities.
@@ -174,4 +174,39 @@ pub trait NestedMapRead<
     /// Returns an iterator over each confirmed value.
     ///
     fn values_confirmed(&'a self) -> Self::Values;
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose keys are within the given bounds,
+    /// up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns the confirmed key-value pairs of the given map whose serialized keys start with
+    /// the serialized `prefix`, up to `limit` of them. The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_prefix_confirmed<P: Serialize + ?Sized>(
+        &'a self,
+        map: &M,
+        prefix: &P,
+        limit: Option<usize>,
+    ) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns a page of at most `limit` confirmed key-value pairs of the given map, starting right after
+    /// the key `after` (or from the first key, if `after` is `None`). The keys are ordered by their serialized bytes.
+    ///
+    fn get_map_page_confirmed(&'a self, map: &M, after: Option<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        let start = match after {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Unbounded,
+        };
+        self.get_map_range_confirmed(map, start, Bound::Unbounded, Some(limit))
+    }
 }
@@ -235,6 +235,73 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Removes the committees after the given `height`, in the process
+    /// removing all round to height entries after the starting round of the committee for the given `height`.
+    fn rollback_to(&self, height: u32) -> Result<()> {
+        // Retrieve the current round.
+        let current_round = self.current_round()?;
+        // Retrieve the current height.
+        let current_height = self.current_height()?;
+        // Ensure the height does not exceed the current height.
+        ensure!(
+            height <= current_height,
+            "Cannot roll back to height {height}, as the current height is {current_height}"
+        );
+        // Retrieve the committee for the given height.
+        let Some(committee) = self.get_committee(height)? else {
+            bail!("Committee not found for height {height} in committee storage");
+        };
+        // The starting round of the committee becomes the current round.
+        let next_current_round = committee.starting_round();
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Update the current round.
+            self.current_round_map().insert(ROUND_KEY, next_current_round)?;
+            // Remove the round to height mappings.
+            for round in next_current_round.saturating_add(1)..=current_round {
+                self.round_to_height_map().remove(&round)?;
+            }
+            // Remove the committees.
+            for height in height.saturating_add(1)..=current_height {
+                self.committee_map().remove(&height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Restores empty storage with the given `(round, height)` and `(height, committee)` entries,
+    /// setting the current round to the given `current round`.
+    fn restore(&self, current_round: u64, rounds: &[(u64, u32)], committees: &[(u32, Committee<N>)]) -> Result<()> {
+        // Ensure the storage is empty.
+        ensure!(self.current_round().is_err(), "Cannot restore into a non-empty committee storage");
+        // Ensure the current round is indexed.
+        let Some((_, current_height)) = rounds.iter().find(|(round, _)| *round == current_round) else {
+            bail!("Missing the height for the current round {current_round} in the committee entries")
+        };
+        // Ensure the current committee is included.
+        ensure!(
+            committees.iter().any(|(height, _)| height == current_height),
+            "Missing the committee for the current height {current_height} in the committee entries"
+        );
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Store the current round.
+            self.current_round_map().insert(ROUND_KEY, current_round)?;
+            // Store the round to height mappings.
+            for (round, height) in rounds {
+                self.round_to_height_map().insert(*round, *height)?;
+            }
+            // Store the committees.
+            for (height, committee) in committees {
+                self.committee_map().insert(*height, committee.clone())?;
+            }
+            Ok(())
+        })
+    }
+
     /// Returns the current round.
     fn current_round(&self) -> Result<u64> {
         match self.current_round_map().get_confirmed(&ROUND_KEY)? {
@@ -374,6 +441,18 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn remove(&self, height: u32) -> Result<()> {
         self.storage.remove(height)
     }
+
+    /// Removes the committees after the given `height`, in the process
+    /// removing all round to height entries after the starting round of the committee for the given `height`.
+    pub fn rollback_to(&self, height: u32) -> Result<()> {
+        self.storage.rollback_to(height)
+    }
+
+    /// Restores empty storage with the given `(round, height)` and `(height, committee)` entries,
+    /// setting the current round to the given `current round`.
+    pub fn restore(&self, current_round: u64, rounds: &[(u64, u32)], committees: &[(u32, Committee<N>)]) -> Result<()> {
+        self.storage.restore(current_round, rounds, committees)
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
@@ -536,6 +615,87 @@ mod tests {
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
 
+    #[test]
+    fn test_restore() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(8, rng);
+        let rounds = [(5, 1), (6, 1), (7, 1), (8, 2)];
+        let committees = [(1, committee_1.clone()), (2, committee_2.clone())];
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+
+        // Ensure the current round and committee must be included.
+        assert!(store.restore(9, &rounds, &committees).is_err());
+        assert!(store.restore(8, &rounds, &committees[..1]).is_err());
+
+        // Restore the committee store.
+        store.restore(8, &rounds, &committees).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+        assert_eq!(store.current_height().unwrap(), 2);
+        assert_eq!(store.current_committee().unwrap(), committee_2);
+
+        assert_eq!(store.get_height_for_round(4).unwrap(), None);
+        assert_eq!(store.get_committee_for_round(6).unwrap().unwrap(), committee_1);
+        assert_eq!(store.get_committee_for_round(8).unwrap().unwrap(), committee_2);
+
+        // Ensure a non-empty store cannot be restored.
+        assert!(store.restore(8, &rounds, &committees).is_err());
+
+        // Ensure the next committee can be inserted.
+        let committee_3 = ledger_committee::test_helpers::sample_committee_for_round(10, rng);
+        store.insert(3, committee_3.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 10);
+        assert_eq!(store.get_committee_for_round(9).unwrap().unwrap(), committee_2);
+        assert_eq!(store.get_committee_for_round(10).unwrap().unwrap(), committee_3);
+    }
+
+    #[test]
+    fn test_rollback_to() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(8, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1.clone()).unwrap();
+        store.insert(2, committee_2).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+
+        // Ensure the store cannot be rolled forward.
+        assert!(store.rollback_to(3).is_err());
+
+        // Roll back to height 1.
+        store.rollback_to(1).unwrap();
+        assert_eq!(store.current_round().unwrap(), 5);
+        assert_eq!(store.current_height().unwrap(), 1);
+        assert_eq!(store.current_committee().unwrap(), committee_1);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+        assert_eq!(store.get_height_for_round(4).unwrap().unwrap(), 0);
+        assert_eq!(store.get_height_for_round(6).unwrap(), None);
+        assert_eq!(store.get_height_for_round(8).unwrap(), None);
+
+        // Ensure the next committee can be inserted.
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(7, rng);
+        store.insert(2, committee_2.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 7);
+        assert_eq!(store.get_committee_for_round(6).unwrap().unwrap(), committee_1);
+        assert_eq!(store.get_committee_for_round(7).unwrap().unwrap(), committee_2);
+
+        // Roll back to genesis.
+        store.rollback_to(0).unwrap();
+        assert_eq!(store.current_round().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+        assert_eq!(store.get_height_for_round(1).unwrap(), None);
+    }
+
     #[test]
     fn test_remove_hole() {
         let rng = &mut TestRng::default();
@@ -29,7 +29,16 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::Mutex;
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
+
+/// The history metadata key for the earliest block height that may be queried.
+const HISTORY_START_KEY: u8 = 0;
+/// The history metadata key for the latest block height recorded.
+const HISTORY_HEIGHT_KEY: u8 = 1;
+/// The number of confirmed keys read at a time, when looking up a key by its index.
+const KEY_AT_PAGE_SIZE: usize = 256;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -77,6 +86,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `block height` to `[(program ID, mapping name, key) => value before the block]`.
+    type HistoryMap: for<'a> NestedMap<'a, u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    /// The mapping of `history metadata key` to `block height`.
+    type HistoryMetadataMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `(program ID, mapping name)` to `number of entries`.
+    type MappingLengthMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u64>;
+    /// The mapping of `(program ID, mapping name)` to `[(key, [block height])]`, for each key in the history map.
+    type HistoryIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Vec<u32>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -91,6 +108,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the history metadata map.
+    fn history_metadata_map(&self) -> &Self::HistoryMetadataMap;
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap;
+    /// Returns the history index map.
+    fn history_index_map(&self) -> &Self::HistoryIndexMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -100,6 +125,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.history_map().start_atomic();
+        self.history_metadata_map().start_atomic();
+        self.mapping_length_map().start_atomic();
+        self.history_index_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -107,6 +136,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.history_metadata_map().is_atomic_in_progress()
+            || self.mapping_length_map().is_atomic_in_progress()
+            || self.history_index_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +147,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.history_metadata_map().atomic_checkpoint();
+        self.mapping_length_map().atomic_checkpoint();
+        self.history_index_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -121,6 +158,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.history_metadata_map().clear_latest_checkpoint();
+        self.mapping_length_map().clear_latest_checkpoint();
+        self.history_index_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +169,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.history_metadata_map().atomic_rewind();
+        self.mapping_length_map().atomic_rewind();
+        self.history_index_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -135,13 +180,21 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.history_metadata_map().abort_atomic();
+        self.mapping_length_map().abort_atomic();
+        self.history_index_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.history_metadata_map().finish_atomic()?;
+        self.mapping_length_map().finish_atomic()?;
+        self.history_index_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -170,6 +223,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the program ID map with the new mapping name.
             self.program_id_map().insert(program_id, mapping_names)?;
+            // Initialize the number of entries in the mapping.
+            self.mapping_length_map().insert((program_id, mapping_name), 0)?;
 
             Ok(())
         })?;
@@ -199,14 +254,21 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             );
         }
 
+        // Retrieve the number of entries in the mapping.
+        let length = self.get_mapping_length_speculative(program_id, mapping_name)?;
+
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         atomic_batch_scope!(self, {
+            // Record the previous value in the history.
+            self.record_history(program_id, mapping_name, &key)?;
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // Update the number of entries in the mapping.
+            self.set_mapping_length(program_id, mapping_name, length.saturating_add(1))?;
 
             Ok(())
         })?;
@@ -230,6 +292,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot update key-value.")
         }
+        // Retrieve the number of entries in the mapping, once the key-value is stored.
+        let length = match self.contains_key_speculative(program_id, mapping_name, &key)? {
+            true => self.get_mapping_length_speculative(program_id, mapping_name)?,
+            false => self.get_mapping_length_speculative(program_id, mapping_name)?.saturating_add(1),
+        };
 
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
@@ -237,8 +304,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         atomic_batch_scope!(self, {
+            // Record the previous value in the history.
+            self.record_history(program_id, mapping_name, &key)?;
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            // Update the number of entries in the mapping.
+            self.set_mapping_length(program_id, mapping_name, length)?;
 
             Ok(())
         })?;
@@ -263,13 +334,19 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_key_speculative(program_id, mapping_name, key)? {
             return Ok(None);
         }
+        // Retrieve the number of entries in the mapping.
+        let length = self.get_mapping_length_speculative(program_id, mapping_name)?;
 
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, key)?;
 
         atomic_batch_scope!(self, {
+            // Record the previous value in the history.
+            self.record_history(program_id, mapping_name, key)?;
             // Update the key-value map with the new key.
             self.key_value_map().remove_key(&(program_id, mapping_name), key)?;
+            // Update the number of entries in the mapping.
+            self.set_mapping_length(program_id, mapping_name, length.saturating_sub(1))?;
 
             Ok(())
         })?;
@@ -290,8 +367,18 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot replace mapping.")
         }
+        // Count the distinct keys, as a repeated key overwrites the earlier entry.
+        let length = entries.iter().map(|(key, _)| key.to_bytes_le()).collect::<Result<IndexSet<_>>>()?.len();
 
         atomic_batch_scope!(self, {
+            // Record the previous values of the existing and new keys in the history.
+            for (key, _) in self.key_value_map().get_map_speculative(&(program_id, mapping_name))? {
+                self.record_history(program_id, mapping_name, &key)?;
+            }
+            for (key, _) in &entries {
+                self.record_history(program_id, mapping_name, key)?;
+            }
+
             // Remove the existing key-value entries.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
 
@@ -300,6 +387,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 // Insert the key-value entry.
                 self.key_value_map().insert((program_id, mapping_name), key, value)?;
             }
+            // Update the number of entries in the mapping.
+            self.set_mapping_length(program_id, mapping_name, length)?;
 
             Ok(())
         })?;
@@ -322,10 +411,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
 
         atomic_batch_scope!(self, {
+            // Record the previous values of the existing keys in the history.
+            for (key, _) in self.key_value_map().get_map_speculative(&(program_id, mapping_name))? {
+                self.record_history(program_id, mapping_name, &key)?;
+            }
             // Update the mapping names.
             self.program_id_map().insert(program_id, mapping_names)?;
             // Remove the mapping.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the number of entries in the mapping.
+            self.mapping_length_map().remove(&(program_id, mapping_name))?;
 
             Ok(())
         })?;
@@ -348,8 +443,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Remove each mapping.
             for mapping_name in mapping_names.iter() {
+                // Record the previous values of the existing keys in the history.
+                for (key, _) in self.key_value_map().get_map_speculative(&(*program_id, *mapping_name))? {
+                    self.record_history(*program_id, *mapping_name, &key)?;
+                }
                 // Remove the mapping.
                 self.key_value_map().remove_map(&(*program_id, *mapping_name))?;
+                // Remove the number of entries in the mapping.
+                self.mapping_length_map().remove(&(*program_id, *mapping_name))?;
             }
             Ok(())
         })
@@ -360,6 +461,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.program_id_map().contains_key_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` exist.
+    fn contains_program_speculative(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        self.program_id_map().contains_key_speculative(program_id)
+    }
+
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping_confirmed(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
         Ok(self.program_id_map().get_confirmed(program_id)?.map_or(false, |m| m.contains(mapping_name)))
@@ -428,6 +534,141 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns a page of at most `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// starting right after the key `after` (or from the first key, if `after` is `None`).
+    /// The keys are ordered by their serialized bytes, so the pages are the same in every storage backend.
+    fn get_mapping_page_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        after: Option<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping page.")
+        }
+        // Retrieve the page of key-values for the mapping.
+        self.key_value_map().get_map_page_confirmed(&(program_id, mapping_name), after, limit)
+    }
+
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping length.")
+        }
+        // Retrieve the number of entries in the mapping.
+        match self.mapping_length_map().get_speculative(&(program_id, mapping_name))? {
+            Some(length) => Ok(usize::try_from(cow_to_copied!(length))?),
+            None => bail!("Missing the length of '{program_id}/{mapping_name}' in storage"),
+        }
+    }
+
+    /// Stores the number of entries for the given `program ID` and `mapping name`.
+    fn set_mapping_length(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>, length: usize) -> Result<()> {
+        self.mapping_length_map().insert((program_id, mapping_name), u64::try_from(length)?)
+    }
+
+    /// Stores the number of entries for every mapping that is missing its length,
+    /// such as the mappings initialized before the lengths were stored.
+    fn backfill_mapping_lengths(&self) -> Result<()> {
+        // Collect the mappings that are missing their length.
+        let mut missing = Vec::new();
+        for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                let mapping = (cow_to_copied!(program_id), *mapping_name);
+                if !self.mapping_length_map().contains_key_confirmed(&mapping)? {
+                    missing.push(mapping);
+                }
+            }
+        }
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            // Count the entries of each mapping.
+            for (program_id, mapping_name) in missing {
+                let length = self.key_value_map().len_map_confirmed(&(program_id, mapping_name))?;
+                self.set_mapping_length(program_id, mapping_name, length)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the speculative key at the given `index` for the given `program ID` and `mapping name`,
+    /// where the keys are ordered by their serialized bytes, which is the order every storage backend iterates in.
+    /// If the `index` is out of bounds, the method returns `None`.
+    ///
+    /// The confirmed keys are read in order, one page at a time, and merged with the pending writes
+    /// of the atomic batch, so the cost grows with the `index` rather than with the size of the mapping.
+    fn get_key_at_index_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        index: usize,
+    ) -> Result<Option<Plaintext<N>>> {
+        // Ensure the index is within bounds. Note: This also ensures the mapping name exists.
+        if index >= self.get_mapping_length_speculative(program_id, mapping_name)? {
+            return Ok(None);
+        }
+
+        // Collect the pending writes to the mapping, ordered by their serialized keys.
+        // A pending key maps to `Some(key)` if it is inserted, and to `None` if it is removed.
+        let mapping = (program_id, mapping_name);
+        let mut is_cleared = false;
+        let mut pending = BTreeMap::new();
+        for (map, key, value) in self.key_value_map().iter_pending() {
+            if *map != mapping {
+                continue;
+            }
+            match key {
+                Some(key) => {
+                    let key = cow_to_cloned!(key);
+                    pending.insert(bincode::serialize(&key)?, value.map(|_| key));
+                }
+                // If the mapping is removed in the batch, its confirmed keys no longer apply.
+                None => {
+                    is_cleared = true;
+                    pending.clear();
+                }
+            }
+        }
+        let mut inserted = pending.iter().filter_map(|(bytes, key)| Some((bytes, key.as_ref()?))).peekable();
+
+        // Walk the confirmed keys in order, merging in the pending inserted keys.
+        let mut remaining = index;
+        let mut after = None;
+        while !is_cleared {
+            let page = self.key_value_map().get_map_page_confirmed(&mapping, after.as_ref(), KEY_AT_PAGE_SIZE)?;
+            let is_last_page = page.len() < KEY_AT_PAGE_SIZE;
+            for (key, _) in page {
+                let bytes = bincode::serialize(&key)?;
+                // Count the pending inserted keys that precede the confirmed key.
+                while let Some((_, pending_key)) = inserted.next_if(|(pending_bytes, _)| **pending_bytes < bytes) {
+                    match remaining {
+                        0 => return Ok(Some(pending_key.clone())),
+                        _ => remaining -= 1,
+                    }
+                }
+                // Count the confirmed key, unless it is overwritten or removed in the batch.
+                if !pending.contains_key(&bytes) {
+                    match remaining {
+                        0 => return Ok(Some(key)),
+                        _ => remaining -= 1,
+                    }
+                }
+                after = Some(key);
+            }
+            if is_last_page {
+                break;
+            }
+        }
+        // Otherwise, the key is among the remaining pending inserted keys.
+        Ok(inserted.nth(remaining).map(|(_, key)| key.clone()))
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -454,6 +695,285 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Enables the mapping history, starting from the given `block height`.
+    /// Once enabled, the previous value of every mapping entry modified by a subsequent block is recorded.
+    fn enable_history(&self, block_height: u32) -> Result<()> {
+        // Ensure the history is not already enabled.
+        if self.history_metadata_map().contains_key_speculative(&HISTORY_HEIGHT_KEY)? {
+            bail!("The mapping history is already enabled")
+        }
+
+        atomic_batch_scope!(self, {
+            // Set the earliest and latest block heights of the history.
+            self.history_metadata_map().insert(HISTORY_START_KEY, block_height)?;
+            self.history_metadata_map().insert(HISTORY_HEIGHT_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Disables the mapping history, removing all recorded history from storage.
+    fn disable_history(&self) -> Result<()> {
+        // Retrieve the history range.
+        let Some((start, end)) = self.get_history_range()? else {
+            return Ok(());
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the recorded history, and its index.
+            for height in start.saturating_add(1)..=end {
+                for ((program_id, mapping_name, key), _) in self.history_map().get_map_speculative(&height)? {
+                    self.history_index_map().remove_key(&(program_id, mapping_name), &key)?;
+                }
+                self.history_map().remove_map(&height)?;
+            }
+            // Remove the history metadata.
+            self.history_metadata_map().remove(&HISTORY_START_KEY)?;
+            self.history_metadata_map().remove(&HISTORY_HEIGHT_KEY)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the mapping history up to the given `block height`,
+    /// such that the earliest block height that may be queried becomes `block height`.
+    fn prune_history(&self, block_height: u32) -> Result<()> {
+        // Retrieve the history range.
+        let Some((start, end)) = self.get_history_range()? else {
+            bail!("Cannot prune the mapping history, as it is not enabled")
+        };
+        // Ensure the block height is within the history range.
+        if block_height > end {
+            bail!("Cannot prune the mapping history beyond block {end} (requested {block_height})")
+        }
+        // If the history is already pruned up to the block height, return early.
+        if block_height <= start {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            // Remove the recorded history up to the block height, and its index.
+            for height in start.saturating_add(1)..=block_height {
+                for ((program_id, mapping_name, key), _) in self.history_map().get_map_speculative(&height)? {
+                    self.retain_history_heights(program_id, mapping_name, &key, |height| height > block_height)?;
+                }
+                self.history_map().remove_map(&height)?;
+            }
+            // Update the earliest block height of the history.
+            self.history_metadata_map().insert(HISTORY_START_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the `(earliest, latest)` block heights that may be queried in the mapping history,
+    /// or `None` if the history is not enabled.
+    fn get_history_range(&self) -> Result<Option<(u32, u32)>> {
+        let start = self.history_metadata_map().get_confirmed(&HISTORY_START_KEY)?;
+        let end = self.history_metadata_map().get_confirmed(&HISTORY_HEIGHT_KEY)?;
+        match (start, end) {
+            (Some(start), Some(end)) => Ok(Some((cow_to_copied!(start), cow_to_copied!(end)))),
+            (None, None) => Ok(None),
+            _ => bail!("The mapping history metadata is corrupted"),
+        }
+    }
+
+    /// Begins recording the mapping history for the given `block height`.
+    /// If the history is not enabled, this method is a no-op.
+    fn begin_history_block(&self, block_height: u32) -> Result<()> {
+        // Retrieve the latest block height of the history.
+        let Some(latest_height) = self.history_metadata_map().get_speculative(&HISTORY_HEIGHT_KEY)? else {
+            return Ok(());
+        };
+        // Ensure the block height is the next block height.
+        let latest_height = cow_to_copied!(latest_height);
+        if latest_height.checked_add(1) != Some(block_height) {
+            bail!("Expected the mapping history to record block {}, found block {block_height}", latest_height + 1)
+        }
+
+        atomic_batch_scope!(self, {
+            // Update the latest block height of the history.
+            self.history_metadata_map().insert(HISTORY_HEIGHT_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the current value of the given `program ID`, `mapping name`, and `key` in the mapping history,
+    /// if the history is enabled and the entry has not been recorded for the current block yet.
+    fn record_history(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>, key: &Plaintext<N>) -> Result<()> {
+        // Retrieve the block height being recorded.
+        let Some(block_height) = self.history_metadata_map().get_speculative(&HISTORY_HEIGHT_KEY)? else {
+            return Ok(());
+        };
+        let block_height = cow_to_copied!(block_height);
+        // Ensure the entry has not already been recorded for the block, as only the earliest value is kept.
+        let entry = (program_id, mapping_name, key.clone());
+        if self.history_map().contains_key_speculative(&block_height, &entry)? {
+            return Ok(());
+        }
+        // Retrieve the current value.
+        let value = self.get_value_speculative(program_id, mapping_name, key)?;
+        // Retrieve the block heights at which the entry was recorded.
+        let mut heights = self.get_history_heights_speculative(program_id, mapping_name, key)?;
+        heights.push(block_height);
+
+        atomic_batch_scope!(self, {
+            // Record the current value.
+            self.history_map().insert(block_height, entry, value)?;
+            // Index the block height for the entry.
+            self.history_index_map().insert((program_id, mapping_name), key.clone(), heights)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the speculative block heights at which the given `program ID`, `mapping name`, and `key`
+    /// were recorded in the mapping history, in ascending order.
+    fn get_history_heights_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Vec<u32>> {
+        match self.history_index_map().get_value_speculative(&(program_id, mapping_name), key)? {
+            Some(heights) => Ok(cow_to_cloned!(heights)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Retains the block heights in the mapping history index of the given `program ID`, `mapping name`, and `key`
+    /// that satisfy the given predicate, removing the entry from the index if none remain.
+    fn retain_history_heights(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        predicate: impl Fn(u32) -> bool,
+    ) -> Result<()> {
+        // Retrieve the block heights at which the entry was recorded.
+        let mut heights = self.get_history_heights_speculative(program_id, mapping_name, key)?;
+        heights.retain(|height| predicate(*height));
+
+        atomic_batch_scope!(self, {
+            match heights.is_empty() {
+                true => self.history_index_map().remove_key(&(program_id, mapping_name), key)?,
+                false => self.history_index_map().insert((program_id, mapping_name), key.clone(), heights)?,
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`, after the given `block height`.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the block height is within the history range.
+        self.ensure_history_contains(block_height)?;
+        // Retrieve the block heights at which the entry was recorded.
+        let heights = match self.history_index_map().get_value_confirmed(&(program_id, mapping_name), key)? {
+            Some(heights) => cow_to_cloned!(heights),
+            None => Vec::new(),
+        };
+        // Find the earliest recorded value after the block height, which is the value at the block height.
+        let entry = (program_id, mapping_name, key.clone());
+        match heights.get(heights.partition_point(|height| *height <= block_height)) {
+            Some(height) => match self.history_map().get_value_confirmed(height, &entry)? {
+                Some(value) => Ok(cow_to_cloned!(value)),
+                None => bail!("The mapping history index is missing block {height} for '{program_id}/{mapping_name}'"),
+            },
+            // Otherwise, the value has not changed since the block height.
+            None => self.get_value_confirmed(program_id, mapping_name, key),
+        }
+    }
+
+    /// Returns the confirmed mapping entries for the given `program ID` and `mapping name`, after the given `block height`.
+    fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        block_height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the block height is within the history range.
+        self.ensure_history_contains(block_height)?;
+        // Retrieve the current mapping entries, indexed by the byte representation of the key.
+        let mut entries = self
+            .key_value_map()
+            .get_map_confirmed(&(program_id, mapping_name))?
+            .into_iter()
+            .map(|(key, value)| Ok((key.to_bytes_le()?, (key, value))))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        // Revert each key that changed after the block height to its earliest recorded value after the block height.
+        for (key, heights) in self.history_index_map().get_map_confirmed(&(program_id, mapping_name))? {
+            let Some(height) = heights.get(heights.partition_point(|height| *height <= block_height)) else {
+                continue;
+            };
+            match self.history_map().get_value_confirmed(height, &(program_id, mapping_name, key.clone()))? {
+                Some(value) => match cow_to_cloned!(value) {
+                    Some(value) => entries.insert(key.to_bytes_le()?, (key, value)),
+                    None => entries.shift_remove(&key.to_bytes_le()?),
+                },
+                None => bail!("The mapping history index is missing block {height} for '{program_id}/{mapping_name}'"),
+            };
+        }
+        Ok(entries.into_values().collect())
+    }
+
+    /// Reverts the mapping entries to their values after the given `block height`,
+    /// and removes the mapping history of the subsequent blocks.
+    /// Note: The mappings initialized after the given `block height` are emptied, but not removed.
+    fn rollback_history(&self, block_height: u32) -> Result<()> {
+        // Ensure the block height is within the history range.
+        let end = self.ensure_history_contains(block_height)?;
+
+        atomic_batch_scope!(self, {
+            // Undo the changes of each block after the block height, starting from the latest block.
+            for height in (block_height.saturating_add(1)..=end).rev() {
+                for ((program_id, mapping_name, key), value) in self.history_map().get_map_speculative(&height)? {
+                    // Determine the number of entries in the mapping, once the entry is reverted.
+                    let exists = self.contains_key_speculative(program_id, mapping_name, &key)?;
+                    let length = match self.mapping_length_map().get_speculative(&(program_id, mapping_name))? {
+                        Some(length) => cow_to_copied!(length),
+                        None => 0,
+                    };
+                    let length = match (exists, value.is_some()) {
+                        (false, true) => length.saturating_add(1),
+                        (true, false) => length.saturating_sub(1),
+                        _ => length,
+                    };
+                    match value {
+                        Some(value) => self.key_value_map().insert((program_id, mapping_name), key, value)?,
+                        None => self.key_value_map().remove_key(&(program_id, mapping_name), &key)?,
+                    }
+                    // Update the number of entries in the mapping.
+                    self.mapping_length_map().insert((program_id, mapping_name), length)?;
+                    // Remove the block height from the index of the entry.
+                    self.retain_history_heights(program_id, mapping_name, &key, |height| height <= block_height)?;
+                }
+                // Remove the recorded history of the block.
+                self.history_map().remove_map(&height)?;
+            }
+            // Update the latest block height of the history.
+            self.history_metadata_map().insert(HISTORY_HEIGHT_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Ensures the given `block height` is within the mapping history range, and returns the latest block height.
+    fn ensure_history_contains(&self, block_height: u32) -> Result<u32> {
+        match self.get_history_range()? {
+            Some((start, end)) if (start..=end).contains(&block_height) => Ok(end),
+            Some((start, end)) => bail!("Block {block_height} is outside the mapping history range ({start}..={end})"),
+            None => bail!("The mapping history is not enabled"),
+        }
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -523,11 +1043,32 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// A key-level update to a mapping, as recorded by the finalize store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingUpdate<N: Network> {
+    /// The `key` was set to the `value`, in the given `program ID` and `mapping name`.
+    Set(ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>),
+    /// The `key` was removed, from the given `program ID` and `mapping name`.
+    Remove(ProgramID<N>, Identifier<N>, Plaintext<N>),
+}
+
+/// The mapping updates recorded by the finalize store, within an atomic batch.
+struct Recording<N: Network> {
+    /// The recorded mapping updates.
+    updates: Vec<MappingUpdate<N>>,
+    /// The number of recorded mapping updates at each atomic checkpoint.
+    checkpoints: Vec<usize>,
+    /// Whether the atomic batch is finished, after which no further updates are recorded.
+    is_finished: bool,
+}
+
 /// The finalize store.
 #[derive(Clone)]
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The mapping updates recorded since `start_recording`, or `None` if the updates are not being recorded.
+    recording: Arc<Mutex<Option<Recording<N>>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -546,8 +1087,39 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Store the lengths of the mappings that were initialized before the lengths were stored.
+        storage.backfill_mapping_lengths()?;
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self { storage, recording: Default::default(), _phantom: PhantomData })
+    }
+
+    /// Starts recording the mapping updates in the current atomic batch, discarding any previously-recorded updates.
+    ///
+    /// The recorded updates follow the atomic batch: they are rewound with `atomic_rewind`,
+    /// discarded with `abort_atomic`, and kept once the batch is committed with `finish_atomic`.
+    pub fn start_recording(&self) {
+        *self.recording.lock() = Some(Recording { updates: Vec::new(), checkpoints: Vec::new(), is_finished: false });
+    }
+
+    /// Stops recording the mapping updates, and returns the updates committed by the atomic batch.
+    /// If the atomic batch was aborted or is still in progress, an empty list is returned.
+    pub fn stop_recording(&self) -> Vec<MappingUpdate<N>> {
+        match self.recording.lock().take() {
+            Some(recording) if recording.is_finished => recording.updates,
+            _ => Vec::new(),
+        }
+    }
+
+    /// Records the given mapping update, if the updates are being recorded.
+    fn record(&self, update: impl FnOnce() -> MappingUpdate<N>) {
+        if let Some(recording) = self.recording.lock().as_mut().filter(|recording| !recording.is_finished) {
+            recording.updates.push(update());
+        }
+    }
+
+    /// Returns `true` if the mapping updates are being recorded.
+    fn is_recording(&self) -> bool {
+        matches!(self.recording.lock().as_ref(), Some(recording) if !recording.is_finished)
     }
 
     /// Starts an atomic batch write operation.
@@ -563,26 +1135,46 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Checkpoints the atomic batch.
     pub fn atomic_checkpoint(&self) {
         self.storage.atomic_checkpoint();
+        if let Some(recording) = self.recording.lock().as_mut() {
+            recording.checkpoints.push(recording.updates.len());
+        }
     }
 
     /// Clears the latest atomic batch checkpoint.
     pub fn clear_latest_checkpoint(&self) {
         self.storage.clear_latest_checkpoint();
+        if let Some(recording) = self.recording.lock().as_mut() {
+            recording.checkpoints.pop();
+        }
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
     pub fn atomic_rewind(&self) {
         self.storage.atomic_rewind();
+        if let Some(recording) = self.recording.lock().as_mut() {
+            let checkpoint = recording.checkpoints.pop().unwrap_or(0);
+            recording.updates.truncate(checkpoint);
+        }
     }
 
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        // Discard the recorded mapping updates.
+        self.recording.lock().take();
     }
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        let result = self.storage.finish_atomic();
+        let mut recording = self.recording.lock();
+        match result.is_ok() {
+            // Keep the recorded mapping updates, as they are committed.
+            true => recording.iter_mut().for_each(|recording| recording.is_finished = true),
+            // Discard the recorded mapping updates.
+            false => *recording = None,
+        }
+        result
     }
 
     /// Returns the storage mode.
@@ -624,6 +1216,23 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        self.storage.get_mapping_length_speculative(program_id, mapping_name)
+    }
+
+    /// Returns the speculative key at the given `index` for the given `program ID` and `mapping name`,
+    /// where the keys are ordered by their little-endian byte representation.
+    /// If the `index` is out of bounds, the method returns `None`.
+    fn get_key_at_index_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        index: usize,
+    ) -> Result<Option<Plaintext<N>>> {
+        self.storage.get_key_at_index_speculative(program_id, mapping_name, index)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -634,7 +1243,9 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.insert_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+        self.record(|| MappingUpdate::Set(program_id, mapping_name, key, value));
+        Ok(operation)
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -648,7 +1259,9 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.update_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+        self.record(|| MappingUpdate::Set(program_id, mapping_name, key, value));
+        Ok(operation)
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -658,7 +1271,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        let operation = self.storage.remove_key_value(program_id, mapping_name, key)?;
+        if operation.is_some() {
+            self.record(|| MappingUpdate::Remove(program_id, mapping_name, key.clone()));
+        }
+        Ok(operation)
     }
 }
 
@@ -681,7 +1298,23 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.replace_mapping(program_id, mapping_name, entries)
+        // If the updates are being recorded, retrieve the existing keys and clone the new entries.
+        let recording = match self.is_recording() {
+            true => Some((self.storage.get_mapping_speculative(program_id, mapping_name)?, entries.clone())),
+            false => None,
+        };
+        let operation = self.storage.replace_mapping(program_id, mapping_name, entries)?;
+        // Record the removal of the existing keys that were not replaced, and the new entries.
+        if let Some((existing, entries)) = recording {
+            let keys = entries.iter().map(|(key, _)| key).collect::<Vec<_>>();
+            for (key, _) in existing.into_iter().filter(|(key, _)| !keys.contains(&key)) {
+                self.record(|| MappingUpdate::Remove(program_id, mapping_name, key));
+            }
+            for (key, value) in entries {
+                self.record(|| MappingUpdate::Set(program_id, mapping_name, key, value));
+            }
+        }
+        Ok(operation)
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -691,7 +1324,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        // If the updates are being recorded, retrieve the existing keys.
+        let existing = match self.is_recording() {
+            true => self.storage.get_mapping_speculative(program_id, mapping_name)?,
+            false => Vec::new(),
+        };
+        let operation = self.storage.remove_mapping(program_id, mapping_name)?;
+        // Record the removal of the existing keys.
+        for (key, _) in existing {
+            self.record(|| MappingUpdate::Remove(program_id, mapping_name, key));
+        }
+        Ok(operation)
     }
 
     /// Removes the program for the given `program ID` from storage,
@@ -701,12 +1344,70 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Enables the mapping history, starting from the given `block height`.
+    pub fn enable_history(&self, block_height: u32) -> Result<()> {
+        self.storage.enable_history(block_height)
+    }
+
+    /// Disables the mapping history, removing all recorded history from storage.
+    pub fn disable_history(&self) -> Result<()> {
+        self.storage.disable_history()
+    }
+
+    /// Prunes the mapping history up to the given `block height`.
+    pub fn prune_history(&self, block_height: u32) -> Result<()> {
+        self.storage.prune_history(block_height)
+    }
+
+    /// Begins recording the mapping history for the given `block height`.
+    pub fn begin_history_block(&self, block_height: u32) -> Result<()> {
+        self.storage.begin_history_block(block_height)
+    }
+
+    /// Returns the `(earliest, latest)` block heights that may be queried in the mapping history.
+    pub fn get_history_range(&self) -> Result<Option<(u32, u32)>> {
+        self.storage.get_history_range()
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`, after the given `block height`.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns the confirmed mapping entries for the given `program ID` and `mapping name`, after the given `block height`.
+    pub fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        block_height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_at_height(program_id, mapping_name, block_height)
+    }
+
+    /// Reverts the mapping entries to their values after the given `block height`.
+    pub fn rollback_history(&self, block_height: u32) -> Result<()> {
+        self.storage.rollback_history(block_height)
+    }
+}
+
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Returns `true` if the given `program ID` exist.
     pub fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.contains_program_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` exist.
+    pub fn contains_program_speculative(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        self.storage.contains_program_speculative(program_id)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     pub fn contains_key_confirmed(
         &self,
@@ -724,6 +1425,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_names_confirmed(program_id)
     }
 
+    /// Returns the speculative mapping names for the given `program ID`.
+    pub fn get_mapping_names_speculative(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
+        self.storage.get_mapping_names_speculative(program_id)
+    }
+
     /// Returns the confirmed mapping entries for the given `program ID` and `mapping name`.
     pub fn get_mapping_confirmed(
         &self,
@@ -742,6 +1448,19 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_speculative(program_id, mapping_name)
     }
 
+    /// Returns a page of at most `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// starting right after the key `after` (or from the first key, if `after` is `None`).
+    /// The last key of a page is the `after` key of the next one.
+    pub fn get_mapping_page_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        after: Option<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_page_confirmed(program_id, mapping_name, after, limit)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value_confirmed(
         &self,
@@ -768,6 +1487,13 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an iterator over the program IDs, for all programs in `self`.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys_confirmed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1080,6 +1806,200 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mapping_length_and_key_at_index() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the length of an un-initialized mapping fails.
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        // Ensure the key at an index of an un-initialized mapping fails.
+        assert!(finalize_store.get_key_at_index_speculative(program_id, mapping_name, 0).is_err());
+
+        // Now, initialize the mapping.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        // Ensure the mapping is empty.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+        assert!(finalize_store.get_key_at_index_speculative(program_id, mapping_name, 0).unwrap().is_none());
+
+        // Insert the list of keys and values, in reverse order.
+        let keys = (0..100).rev().map(|item| Plaintext::from_str(&format!("{item}field")).unwrap()).collect::<Vec<_>>();
+        for (item, key) in keys.iter().enumerate() {
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+        }
+        // Ensure the length is correct.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), keys.len());
+
+        // Order the expected keys by their serialized bytes.
+        let mut expected = keys.clone();
+        expected.sort_by_key(|key| bincode::serialize(key).unwrap());
+        // Ensure the keys are returned in order.
+        for (index, expected_key) in expected.iter().enumerate() {
+            let candidate = finalize_store.get_key_at_index_speculative(program_id, mapping_name, index).unwrap();
+            assert_eq!(candidate.as_ref(), Some(expected_key));
+        }
+        // Ensure an out-of-bounds index returns `None`.
+        assert!(finalize_store.get_key_at_index_speculative(program_id, mapping_name, keys.len()).unwrap().is_none());
+
+        // Remove a key, and ensure the length and ordering are updated.
+        finalize_store.remove_key_value(program_id, mapping_name, &expected[0]).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), keys.len() - 1);
+        let candidate = finalize_store.get_key_at_index_speculative(program_id, mapping_name, 0).unwrap();
+        assert_eq!(candidate.as_ref(), Some(&expected[1]));
+
+        // Update an existing key and a new key, and ensure the length only counts the new key.
+        let value = Value::from_str("0u64").unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, expected[1].clone(), value.clone()).unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, expected[0].clone(), value.clone()).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), keys.len());
+
+        // Replace the mapping with a repeated key, and ensure the length counts the distinct keys.
+        let entries = vec![(expected[2].clone(), value.clone()), (expected[2].clone(), value.clone())];
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 1);
+
+        // Remove the mapping, and ensure the length is no longer available.
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+    }
+
+    #[test]
+    fn test_backfill_mapping_lengths() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a mapping with a few entries.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory.clone()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        // Remove the stored length, as in a store created before the lengths were stored.
+        program_memory.mapping_length_map().remove(&(program_id, mapping_name)).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+
+        // Reopen the store, and ensure the length is restored.
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+
+        // Ensure the mapping can be written to.
+        let (key, value) = (Plaintext::from_str("10field").unwrap(), Value::from_str("10u64").unwrap());
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 11);
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_key_at_index_with_pending_writes() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Insert enough confirmed keys to span several pages.
+        let key = |item: usize| Plaintext::<CurrentNetwork>::from_str(&format!("{item}field")).unwrap();
+        let value = Value::from_str("0u64").unwrap();
+        for item in (0..2 * KEY_AT_PAGE_SIZE + 10).step_by(2) {
+            finalize_store.insert_key_value(program_id, mapping_name, key(item), value.clone()).unwrap();
+        }
+
+        // Checks the speculative length and keys against the speculative entries, ordered by their serialized bytes.
+        let check = || {
+            let mut expected = finalize_store
+                .get_mapping_speculative(program_id, mapping_name)
+                .unwrap()
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+            expected.sort_by_key(|key| bincode::serialize(key).unwrap());
+
+            let length = finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap();
+            assert_eq!(length, expected.len());
+            for (index, expected_key) in expected.iter().enumerate() {
+                let candidate = finalize_store.get_key_at_index_speculative(program_id, mapping_name, index).unwrap();
+                assert_eq!(candidate.as_ref(), Some(expected_key));
+            }
+            assert!(finalize_store.get_key_at_index_speculative(program_id, mapping_name, length).unwrap().is_none());
+        };
+        check();
+
+        // Start an atomic batch, and interleave pending inserts, updates, and removals with the confirmed keys.
+        finalize_store.start_atomic();
+        for item in (1..2 * KEY_AT_PAGE_SIZE + 20).step_by(7) {
+            finalize_store.update_key_value(program_id, mapping_name, key(item), value.clone()).unwrap();
+        }
+        for item in (0..2 * KEY_AT_PAGE_SIZE).step_by(6) {
+            finalize_store.remove_key_value(program_id, mapping_name, &key(item)).unwrap();
+        }
+        check();
+
+        // Replace the mapping within the batch, so that none of the confirmed keys apply.
+        let entries = (0..5).map(|item| (key(item * 3), value.clone())).collect();
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        check();
+
+        // Finish the batch, and ensure the confirmed keys match.
+        finalize_store.finish_atomic().unwrap();
+        check();
+    }
+
+    #[test]
+    fn test_mapping_pagination() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure a page of an un-initialized mapping fails.
+        assert!(finalize_store.get_mapping_page_confirmed(program_id, mapping_name, None, 10).is_err());
+
+        // Now, initialize the mapping.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        // Ensure the first page is empty.
+        assert!(finalize_store.get_mapping_page_confirmed(program_id, mapping_name, None, 10).unwrap().is_empty());
+
+        // Insert the list of keys and values.
+        for item in 0..25 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        // Collect the pages.
+        let mut pages = Vec::new();
+        let mut after = None;
+        loop {
+            let page = finalize_store.get_mapping_page_confirmed(program_id, mapping_name, after.as_ref(), 10).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            after = page.last().map(|(key, _)| key.clone());
+            pages.push(page);
+        }
+
+        // Ensure the pages cover the entire mapping, in the storage order.
+        assert_eq!(pages.iter().map(|page| page.len()).collect::<Vec<_>>(), vec![10, 10, 5]);
+        assert_eq!(pages.concat(), finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap());
+    }
+
     #[test]
     fn test_remove_mapping() {
         // Initialize a program ID and mapping name.
@@ -1208,6 +2128,181 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mapping_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+        let value = |item: u64| Value::from_str(&format!("{item}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(0)).unwrap();
+
+        // Ensure the history is disabled by default.
+        assert!(finalize_store.get_history_range().unwrap().is_none());
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).is_err());
+        // Ensure beginning a block is a no-op when the history is disabled.
+        finalize_store.begin_history_block(5).unwrap();
+
+        // Enable the history at block 1.
+        finalize_store.enable_history(1).unwrap();
+        assert!(finalize_store.enable_history(1).is_err());
+        assert_eq!(finalize_store.get_history_range().unwrap(), Some((1, 1)));
+
+        // Applies the given finalize operations as the block at the given height.
+        let apply_block = |height: u32, operations: &dyn Fn()| {
+            finalize_store.start_atomic();
+            finalize_store.begin_history_block(height).unwrap();
+            operations();
+            finalize_store.finish_atomic().unwrap();
+        };
+
+        // Block 2: update the key twice, and insert the other key.
+        apply_block(2, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(1)).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(2)).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, other_key.clone(), value(10)).unwrap();
+        });
+        // Block 3: no changes.
+        apply_block(3, &|| {});
+        // Block 4: remove the key.
+        apply_block(4, &|| {
+            finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        });
+        // Block 5: replace the mapping.
+        apply_block(5, &|| {
+            finalize_store.replace_mapping(program_id, mapping_name, vec![(key.clone(), value(3))]).unwrap();
+        });
+        assert_eq!(finalize_store.get_history_range().unwrap(), Some((1, 5)));
+
+        // Returns the block heights at which the given key was recorded in the history.
+        let get_heights = |key: &Plaintext<_>| {
+            finalize_store.storage.get_history_heights_speculative(program_id, mapping_name, key).unwrap()
+        };
+        // Ensure the history is indexed by key.
+        assert_eq!(get_heights(&key), vec![2, 4, 5]);
+        assert_eq!(get_heights(&other_key), vec![2, 5]);
+
+        // Ensure a block height cannot be skipped.
+        finalize_store.start_atomic();
+        assert!(finalize_store.begin_history_block(7).is_err());
+        finalize_store.abort_atomic();
+
+        // Ensure the values at each height are correct.
+        let expected = [
+            (1, Some(value(0)), None),
+            (2, Some(value(2)), Some(value(10))),
+            (3, Some(value(2)), Some(value(10))),
+            (4, None, Some(value(10))),
+            (5, Some(value(3)), None),
+        ];
+        for (height, expected_value, expected_other_value) in expected {
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, expected_value);
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &other_key, height).unwrap();
+            assert_eq!(candidate, expected_other_value);
+
+            // Ensure the mapping at each height is correct.
+            let mut mapping = finalize_store.get_mapping_at_height(program_id, mapping_name, height).unwrap();
+            mapping.sort_by_key(|(key, _)| key.to_string());
+            let expected_mapping = [(key.clone(), expected_value), (other_key.clone(), expected_other_value)]
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+                .collect::<Vec<_>>();
+            assert_eq!(mapping, expected_mapping);
+        }
+        // Ensure heights outside the history range are rejected.
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).is_err());
+        assert!(finalize_store.get_mapping_at_height(program_id, mapping_name, 6).is_err());
+
+        // Prune the history up to block 3.
+        assert!(finalize_store.prune_history(6).is_err());
+        finalize_store.prune_history(3).unwrap();
+        assert_eq!(finalize_store.get_history_range().unwrap(), Some((3, 5)));
+        assert_eq!(get_heights(&key), vec![4, 5]);
+        assert_eq!(get_heights(&other_key), vec![5]);
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 2).is_err());
+        let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, 3).unwrap();
+        assert_eq!(candidate, Some(value(2)));
+
+        // Roll back the mapping entries to block 3.
+        assert!(finalize_store.rollback_history(2).is_err());
+        finalize_store.rollback_history(3).unwrap();
+        assert_eq!(finalize_store.get_history_range().unwrap(), Some((3, 3)));
+        assert!(get_heights(&key).is_empty());
+        assert!(get_heights(&other_key).is_empty());
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), Some(value(2)));
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &other_key).unwrap(), Some(value(10)));
+        // Ensure the history resumes from the rolled back block.
+        apply_block(4, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(4)).unwrap();
+        });
+        let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, 3).unwrap();
+        assert_eq!(candidate, Some(value(2)));
+        assert_eq!(get_heights(&key), vec![4]);
+
+        // Disable the history.
+        finalize_store.disable_history().unwrap();
+        assert!(finalize_store.get_history_range().unwrap().is_none());
+        assert!(get_heights(&key).is_empty());
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 4).is_err());
+    }
+
+    #[test]
+    fn test_recording() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+        let value = |item: u64| Value::from_str(&format!("{item}u64")).unwrap();
+        let set =
+            |key: &Plaintext<_>, item: u64| MappingUpdate::Set(program_id, mapping_name, key.clone(), value(item));
+        let remove = |key: &Plaintext<_>| MappingUpdate::Remove(program_id, mapping_name, key.clone());
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Ensure the updates are not recorded by default.
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(0)).unwrap();
+        assert!(finalize_store.stop_recording().is_empty());
+
+        // Record the updates in an atomic batch, rewinding the updates after a checkpoint.
+        finalize_store.start_atomic();
+        finalize_store.start_recording();
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(1)).unwrap();
+        finalize_store.atomic_checkpoint();
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(2)).unwrap();
+        finalize_store.atomic_rewind();
+        finalize_store.insert_key_value(program_id, mapping_name, other_key.clone(), value(3)).unwrap();
+        finalize_store.replace_mapping(program_id, mapping_name, vec![(other_key.clone(), value(4))]).unwrap();
+        // Finish the atomic batch, committing the recorded updates.
+        finalize_store.finish_atomic().unwrap();
+        // Ensure the updates after the atomic batch is finished are not recorded.
+        finalize_store.remove_key_value(program_id, mapping_name, &other_key).unwrap();
+        assert_eq!(finalize_store.stop_recording(), vec![
+            set(&key, 1),
+            set(&other_key, 3),
+            remove(&key),
+            set(&other_key, 4)
+        ]);
+        assert!(finalize_store.stop_recording().is_empty());
+
+        // Ensure the updates in an aborted atomic batch are discarded.
+        finalize_store.start_atomic();
+        finalize_store.start_recording();
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(5)).unwrap();
+        finalize_store.abort_atomic();
+        assert!(finalize_store.stop_recording().is_empty());
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -22,7 +22,7 @@ use crate::{
     TransitionStore,
 };
 use console::network::prelude::*;
-use ledger_block::{Execution, Transaction, Transition};
+use ledger_block::{Execution, Fee, Transaction, Transition};
 use synthesizer_snark::Proof;
 
 use aleo_std_storage::StorageMode;
@@ -38,6 +38,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `[(number of transitions, global state root, (optional) proof)]`, for bundles.
+    type BundleMap: for<'a> Map<'a, N::TransactionID, Vec<(u16, N::StateRoot, Option<Proof<N>>)>>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -50,6 +52,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
     fn inclusion_map(&self) -> &Self::InclusionMap;
+    /// Returns the bundle map.
+    fn bundle_map(&self) -> &Self::BundleMap;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
     /// Returns the transition store.
@@ -67,6 +71,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
+        self.bundle_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -75,6 +80,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
+            || self.bundle_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -83,6 +89,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
+        self.bundle_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -91,6 +98,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
+        self.bundle_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -99,6 +107,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
+        self.bundle_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -107,6 +116,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
+        self.bundle_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -115,6 +125,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
+        self.bundle_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -125,6 +136,9 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             Transaction::Deploy(..) => bail!("Attempted to insert a deploy transaction into execution storage."),
             Transaction::Execute(transaction_id, execution, fee) => (transaction_id, execution, fee),
             Transaction::Fee(..) => bail!("Attempted to insert a fee transaction into execution storage."),
+            Transaction::Bundle(transaction_id, executions, fee) => {
+                return self.insert_bundle(transaction_id, executions, fee);
+            }
         };
 
         // Retrieve the transitions.
@@ -161,6 +175,47 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `bundle transaction` into storage.
+    fn insert_bundle(
+        &self,
+        transaction_id: &N::TransactionID,
+        executions: &[Execution<N>],
+        fee: &Fee<N>,
+    ) -> Result<()> {
+        // Retrieve the transitions.
+        let transitions = executions.iter().flat_map(Execution::transitions);
+        // Retrieve the transition IDs.
+        let transition_ids = executions.iter().flat_map(Execution::transitions).map(Transition::id).copied().collect();
+        // Retrieve the number of transitions, global state root, and proof of each execution.
+        let inclusions = executions
+            .iter()
+            .map(|execution| {
+                Ok((u16::try_from(execution.len())?, execution.global_state_root(), execution.proof().cloned()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_batch_scope!(self, {
+            // Store the transition IDs.
+            self.id_map().insert(*transaction_id, (transition_ids, true))?;
+
+            // Store the executions.
+            for transition in transitions {
+                // Store the transition ID.
+                self.reverse_id_map().insert(*transition.id(), *transaction_id)?;
+                // Store the transition.
+                self.transition_store().insert(transition)?;
+            }
+
+            // Store the global state roots and proofs.
+            self.bundle_map().insert(*transaction_id, inclusions)?;
+
+            // Store the fee.
+            self.fee_store().insert(*transaction_id, fee)?;
+
+            Ok(())
+        })
+    }
+
     /// Removes the execution transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         // Retrieve the transition IDs and fee boolean.
@@ -181,8 +236,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
                 self.transition_store().remove(&transition_id)?;
             }
 
-            // Remove the global state root and proof.
-            self.inclusion_map().remove(transaction_id)?;
+            // Remove the global state root(s) and proof(s).
+            match self.bundle_map().contains_key_confirmed(transaction_id)? {
+                true => self.bundle_map().remove(transaction_id)?,
+                false => self.inclusion_map().remove(transaction_id)?,
+            }
 
             // Remove the fee.
             if has_fee {
@@ -194,6 +252,46 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the execution transaction for the given `transaction ID`,
+    /// removing the proofs and transition values while retaining the transaction structure.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee boolean.
+        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transitions.
+            for transition_id in transition_ids {
+                self.transition_store().prune(&transition_id)?;
+            }
+
+            // Prune the proof(s).
+            match self.bundle_map().get_confirmed(transaction_id)? {
+                Some(inclusions) => {
+                    let inclusions = cow_to_cloned!(inclusions)
+                        .into_iter()
+                        .map(|(num_transitions, global_state_root, _)| (num_transitions, global_state_root, None))
+                        .collect();
+                    self.bundle_map().insert(*transaction_id, inclusions)?;
+                }
+                None => {
+                    if let Some(inclusion) = self.inclusion_map().get_confirmed(transaction_id)? {
+                        self.inclusion_map().insert(*transaction_id, (inclusion.0, None))?;
+                    }
+                }
+            }
+
+            // Prune the fee.
+            if has_fee {
+                self.fee_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -239,8 +337,63 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
     }
 
+    /// Returns the bundled executions for the given `transaction ID`.
+    fn get_bundle(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<Execution<N>>>> {
+        // Retrieve the number of transitions, global state root, and proof of each execution.
+        let inclusions = match self.bundle_map().get_confirmed(transaction_id)? {
+            Some(inclusions) => cow_to_cloned!(inclusions),
+            None => return Ok(None),
+        };
+        // Retrieve the transition IDs.
+        let (transition_ids, _) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the bundle '{transaction_id}'"),
+        };
+        // Ensure the number of transitions matches.
+        ensure!(
+            inclusions.iter().map(|(num_transitions, ..)| *num_transitions as usize).sum::<usize>()
+                == transition_ids.len(),
+            "Mismatching number of transitions for the bundle '{transaction_id}'"
+        );
+
+        // Initialize an iterator over the transition IDs.
+        let mut transition_ids = transition_ids.iter();
+        // Reconstruct the executions.
+        let mut executions = Vec::with_capacity(inclusions.len());
+        for (num_transitions, global_state_root, proof) in inclusions {
+            // Retrieve the transitions.
+            let transitions = transition_ids
+                .by_ref()
+                .take(num_transitions as usize)
+                .map(|transition_id| match self.transition_store().get_transition(transition_id)? {
+                    Some(transition) => Ok(transition),
+                    None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            // Construct the execution.
+            executions.push(Execution::from(transitions.into_iter(), global_state_root, proof)?);
+        }
+        Ok(Some(executions))
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        // Check if the transaction is a bundle.
+        if let Some(executions) = self.get_bundle(transaction_id)? {
+            // Retrieve the fee.
+            let fee = match self.fee_store().get_fee(transaction_id)? {
+                Some(fee) => fee,
+                None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
+            };
+            // Construct the transaction.
+            let transaction = Transaction::from_bundle(executions, fee)?;
+            // Ensure the transaction ID matches.
+            return match *transaction_id == transaction.id() {
+                true => Ok(Some(transaction)),
+                false => bail!("Mismatching transaction ID for transaction '{transaction_id}'"),
+            };
+        }
+
         // Retrieve the transition IDs and fee boolean.
         let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
             Some(ids) => cow_to_cloned!(ids),
@@ -319,6 +472,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the execution transaction for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -370,6 +528,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         self.storage.get_execution(transaction_id)
     }
+
+    /// Returns the bundled executions for the given `transaction ID`.
+    pub fn get_bundle(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<Execution<N>>>> {
+        self.storage.get_bundle(transaction_id)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
@@ -480,6 +643,13 @@ mod tests {
         // Sample the execution transaction.
         let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
         insert_get_remove(transaction).unwrap();
+        // Sample the bundle transaction.
+        let transaction = ledger_test_helpers::sample_bundle_transaction(true, rng);
+        insert_get_remove(transaction).unwrap();
+
+        // Sample the bundle transaction.
+        let transaction = ledger_test_helpers::sample_bundle_transaction(false, rng);
+        insert_get_remove(transaction).unwrap();
     }
 
     #[test]
@@ -493,5 +663,8 @@ mod tests {
         // Sample the execution transaction.
         let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
         find_transaction_id(transaction).unwrap();
+        // Sample the bundle transaction.
+        let transaction = ledger_test_helpers::sample_bundle_transaction(false, rng);
+        find_transaction_id(transaction).unwrap();
     }
 }
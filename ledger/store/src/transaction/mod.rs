@@ -24,7 +24,7 @@ pub use fee::*;
 use crate::{
     atomic_batch_scope,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
     TransitionStorage,
     TransitionStore,
 };
@@ -38,6 +38,7 @@ use synthesizer_snark::{Certificate, VerifyingKey};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
@@ -49,6 +50,8 @@ pub enum TransactionType {
     Execute,
     /// A transaction that is a fee.
     Fee,
+    /// A transaction that is a bundle.
+    Bundle,
 }
 
 /// A trait for transaction storage.
@@ -63,6 +66,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     type FeeStorage: FeeStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
     type TransitionStorage: TransitionStorage<N>;
+    /// The mapping of `program ID` to `transaction ID`, if the secondary indexes are enabled.
+    type ProgramIndexMap: for<'a> NestedMap<'a, ProgramID<N>, N::TransactionID, ()>;
 
     /// Initializes the transaction storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
@@ -75,6 +80,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     fn execution_store(&self) -> &ExecutionStore<N, Self::ExecutionStorage>;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap;
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
         debug_assert!(self.deployment_store().storage_mode() == self.execution_store().storage_mode());
@@ -93,6 +100,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
         self.fee_store().start_atomic();
+        self.program_index_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -101,6 +109,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
+            || self.program_index_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -109,6 +118,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().atomic_checkpoint();
         self.execution_store().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
+        self.program_index_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -117,6 +127,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().clear_latest_checkpoint();
         self.execution_store().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
+        self.program_index_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -125,6 +136,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().atomic_rewind();
         self.execution_store().atomic_rewind();
         self.fee_store().atomic_rewind();
+        self.program_index_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -133,6 +145,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
         self.fee_store().abort_atomic();
+        self.program_index_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -140,7 +153,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()?;
-        self.fee_store().finish_atomic()
+        self.fee_store().finish_atomic()?;
+        self.program_index_map().finish_atomic()
     }
 
     /// Stores the given `transaction` into storage.
@@ -165,6 +179,18 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                     // Store the fee transaction.
                     self.fee_store().insert(transaction.id(), fee)?;
                 }
+                Transaction::Bundle(..) => {
+                    // Store the transaction type.
+                    self.id_map().insert(transaction.id(), TransactionType::Bundle)?;
+                    // Store the bundle transaction.
+                    self.execution_store().insert(transaction)?;
+                }
+            }
+            // Store the secondary indexes, if they are enabled.
+            if self.transition_store().indexes_enabled()? {
+                for program_id in to_program_ids(transaction) {
+                    self.program_index_map().insert(program_id, transaction.id(), ())?;
+                }
             }
             Ok(())
         })
@@ -177,8 +203,20 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             Some(transaction_type) => cow_to_copied!(transaction_type),
             None => bail!("Failed to get the type for transaction '{transaction_id}'"),
         };
+        // Retrieve the indexed program IDs, if the secondary indexes are enabled.
+        let program_ids = match self.transition_store().indexes_enabled()? {
+            true => match self.get_transaction(transaction_id)? {
+                Some(transaction) => to_program_ids(&transaction),
+                None => bail!("Failed to get transaction '{transaction_id}'"),
+            },
+            false => IndexSet::new(),
+        };
 
         atomic_batch_scope!(self, {
+            // Remove the secondary indexes.
+            for program_id in &program_ids {
+                self.program_index_map().remove_key(program_id, transaction_id)?;
+            }
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
             // Remove the transaction.
@@ -189,11 +227,79 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                 TransactionType::Execute => self.execution_store().remove(transaction_id)?,
                 // Remove the fee transaction.
                 TransactionType::Fee => self.fee_store().remove(transaction_id)?,
+                // Remove the bundle transaction.
+                TransactionType::Bundle => self.execution_store().remove(transaction_id)?,
+            }
+            Ok(())
+        })
+    }
+
+    /// Prunes the transaction for the given `transaction ID`, removing the proofs and transition values.
+    /// Note: The programs and verifying keys of deployments are retained, as they are needed for execution.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            match transaction_type {
+                // Prune the fee of the deployment transaction.
+                TransactionType::Deploy => self.fee_store().prune(transaction_id)?,
+                // Prune the execution transaction.
+                TransactionType::Execute => self.execution_store().prune(transaction_id)?,
+                // Prune the fee transaction.
+                TransactionType::Fee => self.fee_store().prune(transaction_id)?,
+                // Prune the bundle transaction.
+                TransactionType::Bundle => self.execution_store().prune(transaction_id)?,
             }
             Ok(())
         })
     }
 
+    /// Enables the secondary indexes, indexing all of the transactions and transitions in storage.
+    fn enable_indexes(&self) -> Result<()> {
+        // If the secondary indexes are already enabled, return early.
+        if self.transition_store().indexes_enabled()? {
+            return Ok(());
+        }
+        // Retrieve the transaction IDs.
+        let transaction_ids = self.id_map().keys_confirmed().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+
+        atomic_batch_scope!(self, {
+            // Enable the secondary indexes of the transitions.
+            self.transition_store().enable_indexes()?;
+            // Index the transactions.
+            for transaction_id in &transaction_ids {
+                let Some(transaction) = self.get_transaction(transaction_id)? else {
+                    bail!("Failed to index the missing transaction '{transaction_id}'")
+                };
+                for program_id in to_program_ids(&transaction) {
+                    self.program_index_map().insert(program_id, *transaction_id, ())?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Disables the secondary indexes, removing them from storage.
+    fn disable_indexes(&self) -> Result<()> {
+        // Retrieve the indexed program IDs.
+        let program_ids =
+            self.program_index_map().keys_confirmed().map(|(map, _)| cow_to_copied!(map)).collect::<IndexSet<_>>();
+
+        atomic_batch_scope!(self, {
+            // Remove the secondary indexes of the transactions.
+            for program_id in &program_ids {
+                self.program_index_map().remove_map(program_id)?;
+            }
+            // Disable the secondary indexes of the transitions.
+            self.transition_store().disable_indexes()
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -225,10 +331,27 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                 Some(fee) => Ok(Some(Transaction::Fee(*transaction_id, fee))),
                 None => bail!("Failed to get fee for transaction '{transaction_id}'"),
             },
+            // Return the bundle transaction.
+            TransactionType::Bundle => self.execution_store().get_transaction(transaction_id),
         }
     }
 }
 
+/// Returns the program IDs that the given transaction deploys or executes, excluding the program of its fee.
+fn to_program_ids<N: Network>(transaction: &Transaction<N>) -> IndexSet<ProgramID<N>> {
+    match transaction {
+        Transaction::Deploy(_, _, deployment, _) => IndexSet::from([*deployment.program_id()]),
+        Transaction::Execute(_, execution, _) => {
+            execution.transitions().map(|transition| *transition.program_id()).collect()
+        }
+        Transaction::Bundle(_, executions, _) => executions
+            .iter()
+            .flat_map(|execution| execution.transitions().map(|transition| *transition.program_id()))
+            .collect(),
+        Transaction::Fee(..) => IndexSet::new(),
+    }
+}
+
 /// The transaction store.
 #[derive(Clone)]
 pub struct TransactionStore<N: Network, T: TransactionStorage<N>> {
@@ -262,6 +385,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the transaction for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -313,6 +441,33 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     }
 }
 
+impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
+    /// Returns `true` if the secondary indexes are enabled.
+    pub fn indexes_enabled(&self) -> Result<bool> {
+        self.storage.transition_store().indexes_enabled()
+    }
+
+    /// Enables the secondary indexes, indexing all of the transactions and transitions in storage.
+    pub fn enable_indexes(&self) -> Result<()> {
+        self.storage.enable_indexes()
+    }
+
+    /// Disables the secondary indexes, removing them from storage.
+    pub fn disable_indexes(&self) -> Result<()> {
+        self.storage.disable_indexes()
+    }
+
+    /// Returns the IDs of the transactions that deploy or execute the given `program ID`.
+    pub fn find_transaction_ids_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransactionID>> {
+        ensure!(self.indexes_enabled()?, "The secondary indexes are not enabled");
+        let entries = self.storage.program_index_map().get_map_confirmed(program_id)?;
+        Ok(entries.into_iter().map(|(transaction_id, _)| transaction_id))
+    }
+}
+
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
@@ -334,6 +489,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => bail!("Tried to get a deployment for execution transaction '{transaction_id}'"),
             // Throw an error.
             TransactionType::Fee => bail!("Tried to get a deployment for fee transaction '{transaction_id}'"),
+            // Throw an error.
+            TransactionType::Bundle => bail!("Tried to get a deployment for bundle transaction '{transaction_id}'"),
         }
     }
 
@@ -352,6 +509,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => self.storage.execution_store().get_execution(transaction_id),
             // Throw an error.
             TransactionType::Fee => bail!("Tried to get an execution for fee transaction '{transaction_id}'"),
+            // Throw an error.
+            TransactionType::Bundle => bail!("Tried to get an execution for bundle transaction '{transaction_id}'"),
         }
     }
 
@@ -365,11 +524,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Retrieve the program ID and edition.
+                match self.storage.deployment_store().get_program_id_and_edition(transaction_id)? {
+                    Some((_, edition)) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -377,6 +534,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => Ok(None),
             // Return 'None'.
             TransactionType::Fee => Ok(None),
+            // Return 'None'.
+            TransactionType::Bundle => Ok(None),
         }
     }
 
@@ -568,4 +727,69 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_secondary_indexes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        for transaction in [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let transaction_id = transaction.id();
+            let program_ids = to_program_ids(&transaction);
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            // Initialize a new transaction store.
+            let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+            // Ensure the secondary indexes are disabled, and may not be queried.
+            assert!(!transaction_store.indexes_enabled().unwrap());
+            assert!(transaction_store
+                .find_transaction_ids_for_program(&ProgramID::from_str("credits.aleo").unwrap())
+                .is_err());
+
+            // Insert the transaction, and then enable the secondary indexes.
+            transaction_store.insert(&transaction).unwrap();
+            transaction_store.enable_indexes().unwrap();
+            assert!(transaction_store.indexes_enabled().unwrap());
+
+            // Ensure the transaction and its transitions are indexed.
+            let check_indexed = |is_indexed: bool| {
+                for program_id in &program_ids {
+                    let candidates = transaction_store.find_transaction_ids_for_program(program_id).unwrap();
+                    assert_eq!(is_indexed, candidates.collect::<Vec<_>>().contains(&transaction_id));
+                }
+                for transition in transaction.transitions() {
+                    let candidates = transaction_store
+                        .transition_store()
+                        .find_transition_ids_for_function(transition.program_id(), transition.function_name())
+                        .unwrap();
+                    assert_eq!(is_indexed, candidates.collect::<Vec<_>>().contains(transition.id()));
+                }
+            };
+            check_indexed(true);
+
+            // Remove the transaction, and ensure it is no longer indexed.
+            transaction_store.remove(&transaction_id).unwrap();
+            check_indexed(false);
+
+            // Insert the transaction again, and ensure it is indexed.
+            transaction_store.insert(&transaction).unwrap();
+            check_indexed(true);
+
+            // Disable the secondary indexes, and ensure they may not be queried.
+            transaction_store.disable_indexes().unwrap();
+            assert!(!transaction_store.indexes_enabled().unwrap());
+            assert!(transaction_store
+                .transition_store()
+                .find_transition_ids_for_function(
+                    transaction.transitions().next().unwrap().program_id(),
+                    transaction.transitions().next().unwrap().function_name()
+                )
+                .is_err());
+        }
+    }
 }
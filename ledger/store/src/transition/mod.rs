@@ -22,19 +22,23 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
 };
 use console::{
     network::prelude::*,
-    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    program::{Argument, Ciphertext, Future, Identifier, Literal, Plaintext, ProgramID, Record},
+    types::{Address, Field, Group},
 };
 use ledger_block::{Input, Output, Transition};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use indexmap::IndexSet;
 use std::borrow::Cow;
 
+/// The key of the index metadata map, for the flag indicating the secondary indexes are enabled.
+const INDEXES_ENABLED_KEY: u8 = 0;
+
 /// A trait for transition storage.
 pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// The transition program IDs and function names.
@@ -53,6 +57,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
     /// The signer commitments.
     type SCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
+    /// The mapping of `(program ID, function name)` to `transition ID`, if the secondary indexes are enabled.
+    type FunctionIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), N::TransitionID, ()>;
+    /// The mapping of public `address` to `transition ID`, if the secondary indexes are enabled.
+    type AddressIndexMap: for<'a> NestedMap<'a, Address<N>, N::TransitionID, ()>;
+    /// The secondary index metadata.
+    type IndexMetadataMap: for<'a> Map<'a, u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -73,6 +83,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
     /// Returns the signer commitments map.
     fn scm_map(&self) -> &Self::SCMMap;
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap;
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap;
+    /// Returns the index metadata map.
+    fn index_metadata_map(&self) -> &Self::IndexMetadataMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -90,6 +106,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
         self.scm_map().start_atomic();
+        self.function_index_map().start_atomic();
+        self.address_index_map().start_atomic();
+        self.index_metadata_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -102,6 +121,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
             || self.scm_map().is_atomic_in_progress()
+            || self.function_index_map().is_atomic_in_progress()
+            || self.address_index_map().is_atomic_in_progress()
+            || self.index_metadata_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +136,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
         self.scm_map().atomic_checkpoint();
+        self.function_index_map().atomic_checkpoint();
+        self.address_index_map().atomic_checkpoint();
+        self.index_metadata_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -126,6 +151,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
         self.scm_map().clear_latest_checkpoint();
+        self.function_index_map().clear_latest_checkpoint();
+        self.address_index_map().clear_latest_checkpoint();
+        self.index_metadata_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -138,6 +166,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
         self.scm_map().atomic_rewind();
+        self.function_index_map().atomic_rewind();
+        self.address_index_map().atomic_rewind();
+        self.index_metadata_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -150,6 +181,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
         self.scm_map().abort_atomic();
+        self.function_index_map().abort_atomic();
+        self.address_index_map().abort_atomic();
+        self.index_metadata_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -161,7 +195,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
         self.reverse_tcm_map().finish_atomic()?;
-        self.scm_map().finish_atomic()
+        self.scm_map().finish_atomic()?;
+        self.function_index_map().finish_atomic()?;
+        self.address_index_map().finish_atomic()?;
+        self.index_metadata_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -185,6 +222,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
             // Store `scm`.
             self.scm_map().insert(transition_id, *transition.scm())?;
+            // Store the secondary indexes, if they are enabled.
+            if self.indexes_enabled()? {
+                self.insert_indexes(transition)?;
+            }
 
             Ok(())
         })
@@ -202,8 +243,17 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             Some(tcm) => cow_to_copied!(tcm),
             None => return Ok(()),
         };
+        // Retrieve the transition, if the secondary indexes are enabled.
+        let transition = match self.indexes_enabled()? {
+            true => self.get(transition_id)?,
+            false => None,
+        };
 
         atomic_batch_scope!(self, {
+            // Remove the secondary indexes.
+            if let Some(transition) = &transition {
+                self.remove_indexes(transition)?;
+            }
             // Remove the program ID and function name.
             self.locator_map().remove(transition_id)?;
             // Remove the inputs.
@@ -225,6 +275,101 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Returns `true` if the secondary indexes are enabled.
+    fn indexes_enabled(&self) -> Result<bool> {
+        self.index_metadata_map().contains_key_speculative(&INDEXES_ENABLED_KEY)
+    }
+
+    /// Enables the secondary indexes, indexing all of the transitions in storage.
+    /// Note: The public addresses of pruned transitions are not indexed.
+    fn enable_indexes(&self) -> Result<()> {
+        // If the secondary indexes are already enabled, return early.
+        if self.indexes_enabled()? {
+            return Ok(());
+        }
+        // Retrieve the transition IDs.
+        let transition_ids = self.locator_map().keys_confirmed().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+
+        atomic_batch_scope!(self, {
+            // Enable the secondary indexes.
+            self.index_metadata_map().insert(INDEXES_ENABLED_KEY, true)?;
+            // Index the transitions.
+            for transition_id in &transition_ids {
+                match self.get(transition_id)? {
+                    Some(transition) => self.insert_indexes(&transition)?,
+                    None => bail!("Failed to index the missing transition '{transition_id}'"),
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Disables the secondary indexes, removing them from storage.
+    fn disable_indexes(&self) -> Result<()> {
+        // Retrieve the indexed functions and addresses.
+        let functions =
+            self.function_index_map().keys_confirmed().map(|(map, _)| cow_to_copied!(map)).collect::<IndexSet<_>>();
+        let addresses =
+            self.address_index_map().keys_confirmed().map(|(map, _)| cow_to_copied!(map)).collect::<IndexSet<_>>();
+
+        atomic_batch_scope!(self, {
+            // Remove the secondary indexes.
+            for function in &functions {
+                self.function_index_map().remove_map(function)?;
+            }
+            for address in &addresses {
+                self.address_index_map().remove_map(address)?;
+            }
+            // Disable the secondary indexes.
+            self.index_metadata_map().remove(&INDEXES_ENABLED_KEY)?;
+
+            Ok(())
+        })
+    }
+
+    /// Stores the secondary indexes for the given `transition`.
+    fn insert_indexes(&self, transition: &Transition<N>) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Index the program ID and function name.
+            let locator = (*transition.program_id(), *transition.function_name());
+            self.function_index_map().insert(locator, *transition.id(), ())?;
+            // Index the public addresses.
+            for address in to_public_addresses(transition) {
+                self.address_index_map().insert(address, *transition.id(), ())?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Removes the secondary indexes for the given `transition`.
+    fn remove_indexes(&self, transition: &Transition<N>) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Remove the program ID and function name.
+            let locator = (*transition.program_id(), *transition.function_name());
+            self.function_index_map().remove_key(&locator, transition.id())?;
+            // Remove the public addresses.
+            for address in to_public_addresses(transition) {
+                self.address_index_map().remove_key(&address, transition.id())?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the input and output values for the given `transition ID`.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -266,6 +411,47 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     }
 }
 
+/// Returns the public addresses in the inputs and outputs of the given transition,
+/// including the addresses in the arguments of its future.
+fn to_public_addresses<N: Network>(transition: &Transition<N>) -> IndexSet<Address<N>> {
+    /// Collects the addresses in the given plaintext.
+    fn collect_plaintext<N: Network>(plaintext: &Plaintext<N>, addresses: &mut IndexSet<Address<N>>) {
+        match plaintext {
+            Plaintext::Literal(Literal::Address(address), _) => {
+                addresses.insert(*address);
+            }
+            Plaintext::Literal(..) => (),
+            Plaintext::Struct(members, _) => members.values().for_each(|member| collect_plaintext(member, addresses)),
+            Plaintext::Array(elements, _) => elements.iter().for_each(|element| collect_plaintext(element, addresses)),
+        }
+    }
+
+    /// Collects the addresses in the arguments of the given future.
+    fn collect_future<N: Network>(future: &Future<N>, addresses: &mut IndexSet<Address<N>>) {
+        for argument in future.arguments() {
+            match argument {
+                Argument::Plaintext(plaintext) => collect_plaintext(plaintext, addresses),
+                Argument::Future(future) => collect_future(future, addresses),
+            }
+        }
+    }
+
+    let mut addresses = IndexSet::new();
+    for input in transition.inputs() {
+        if let Input::Public(_, Some(plaintext)) = input {
+            collect_plaintext(plaintext, &mut addresses);
+        }
+    }
+    for output in transition.outputs() {
+        match output {
+            Output::Public(_, Some(plaintext)) => collect_plaintext(plaintext, &mut addresses),
+            Output::Future(_, Some(future)) => collect_future(future, &mut addresses),
+            _ => (),
+        }
+    }
+    addresses
+}
+
 /// The transition store.
 #[derive(Clone)]
 pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
@@ -333,6 +519,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input and output values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -390,6 +581,52 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     }
 }
 
+impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
+    /// Returns `true` if the secondary indexes are enabled.
+    pub fn indexes_enabled(&self) -> Result<bool> {
+        self.storage.indexes_enabled()
+    }
+
+    /// Enables the secondary indexes, indexing all of the transitions in storage.
+    pub fn enable_indexes(&self) -> Result<()> {
+        self.storage.enable_indexes()
+    }
+
+    /// Disables the secondary indexes, removing them from storage.
+    pub fn disable_indexes(&self) -> Result<()> {
+        self.storage.disable_indexes()
+    }
+
+    /// Returns the IDs of the transitions that call the given `program ID` and `function name`.
+    pub fn find_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+        self.ensure_indexes_enabled()?;
+        let entries = self.storage.function_index_map().get_map_confirmed(&(*program_id, *function_name))?;
+        Ok(entries.into_iter().map(|(transition_id, _)| transition_id))
+    }
+
+    /// Returns the IDs of the transitions with the given `address` in their public inputs or outputs.
+    pub fn find_transition_ids_for_address(
+        &self,
+        address: &Address<N>,
+    ) -> Result<impl '_ + Iterator<Item = N::TransitionID>> {
+        self.ensure_indexes_enabled()?;
+        let entries = self.storage.address_index_map().get_map_confirmed(address)?;
+        Ok(entries.into_iter().map(|(transition_id, _)| transition_id))
+    }
+
+    /// Ensures the secondary indexes are enabled.
+    fn ensure_indexes_enabled(&self) -> Result<()> {
+        match self.indexes_enabled()? {
+            true => Ok(()),
+            false => bail!("The secondary indexes are not enabled"),
+        }
+    }
+}
+
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /// Returns the transition for the given `transition ID`.
     pub fn get_transition(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
@@ -733,4 +970,35 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_address_index() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction, whose fee transition has the payer address in its public output.
+        let transaction = ledger_test_helpers::sample_fee_public_transaction(rng);
+        let transition = transaction.transitions().next().unwrap();
+        let transition_id = *transition.id();
+
+        // Ensure the transition has at least one public address.
+        let addresses = to_public_addresses(transition);
+        assert!(!addresses.is_empty());
+
+        // Initialize a new transition store, with the secondary indexes enabled.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        transition_store.enable_indexes().unwrap();
+
+        // Insert the transition, and ensure it is indexed by each address.
+        transition_store.insert(transition).unwrap();
+        for address in &addresses {
+            let candidates = transition_store.find_transition_ids_for_address(address).unwrap().collect::<Vec<_>>();
+            assert_eq!(candidates, vec![transition_id]);
+        }
+
+        // Remove the transition, and ensure it is no longer indexed.
+        transition_store.remove(&transition_id).unwrap();
+        for address in &addresses {
+            assert_eq!(transition_store.find_transition_ids_for_address(address).unwrap().count(), 0);
+        }
+    }
 }
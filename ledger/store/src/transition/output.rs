@@ -234,6 +234,40 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the output values for the given `transition ID`,
+    /// retaining the output IDs, commitments, checksums, and record nonces.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the output values.
+            for output_id in output_ids {
+                if self.constant_map().contains_key_confirmed(&output_id)? {
+                    self.constant_map().insert(output_id, None)?;
+                }
+                if self.public_map().contains_key_confirmed(&output_id)? {
+                    self.public_map().insert(output_id, None)?;
+                }
+                if self.private_map().contains_key_confirmed(&output_id)? {
+                    self.private_map().insert(output_id, None)?;
+                }
+                if let Some(record) = self.record_map().get_confirmed(&output_id)? {
+                    self.record_map().insert(output_id, (record.0, None))?;
+                }
+                if self.future_map().contains_key_confirmed(&output_id)? {
+                    self.future_map().insert(output_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -367,6 +401,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -185,7 +185,11 @@ pub fn sample_execution(rng: &mut TestRng) -> Execution<CurrentNetwork> {
     // Retrieve a transaction.
     let transaction = block.transactions().iter().next().unwrap().deref().clone();
     // Retrieve the execution.
-    if let Transaction::Execute(_, execution, _) = transaction { execution } else { unreachable!() }
+    if let Transaction::Execute(_, execution, _) = transaction {
+        execution
+    } else {
+        unreachable!()
+    }
 }
 
 /// Samples a rejected execution.
@@ -443,6 +447,25 @@ pub fn sample_large_execution_transaction(rng: &mut TestRng) -> Transaction<Curr
     Transaction::from_execution(execution, Some(fee)).unwrap()
 }
 
+/// Samples a random bundle transaction with a private or public fee.
+pub fn sample_bundle_transaction(is_fee_private: bool, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+    // Sample the genesis block.
+    let block = crate::sample_genesis_block(rng);
+    // Retrieve the executions.
+    let executions: Vec<_> = block.transactions().iter().filter_map(|tx| tx.execution().cloned()).take(2).collect();
+    // Compute the bundle ID.
+    let bundle_id = *Transaction::bundle_tree(&executions, None).unwrap().root();
+
+    // Sample the fee.
+    let fee = match is_fee_private {
+        true => crate::sample_fee_private(bundle_id, rng),
+        false => crate::sample_fee_public(bundle_id, rng),
+    };
+
+    // Construct a bundle transaction.
+    Transaction::from_bundle(executions, fee).unwrap()
+}
+
 /// Samples a random private fee transaction.
 pub fn sample_fee_private_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
     // Sample a private fee.
@@ -12,17 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Process, Stack, StackProgramTypes};
+use crate::{Authorization, Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, Literal, LiteralType, PlaintextType},
 };
-use ledger_block::{Deployment, Execution};
-use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
+use ledger_block::{Deployment, Execution, Input};
+use synthesizer_program::{CastType, Command, Finalize, Instruction, KeyAt, Operand, StackProgram};
+use synthesizer_snark::Proof;
 
-/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, namespace cost)).
-pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64, u64))> {
+use indexmap::IndexMap;
+
+/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, constructor cost, namespace cost)).
+/// The given stack is the stack computed for the deployment, as in `Process::verify_deployment`.
+pub fn deployment_cost<N: Network>(
+    stack: &Stack<N>,
+    deployment: &Deployment<N>,
+) -> Result<(u64, (u64, u64, u64, u64))> {
     // Determine the number of bytes in the deployment.
     let size_in_bytes = deployment.size_in_bytes()?;
     // Retrieve the program ID.
@@ -42,6 +49,13 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     // Compute the synthesis cost in microcredits.
     let synthesis_cost = num_combined_variables.saturating_add(num_combined_constraints) * N::SYNTHESIS_FEE_MULTIPLIER;
 
+    // Compute the constructor cost in microcredits.
+    // Note: The constructor only runs on the initial deployment of a program, so an upgrade does not pay for it.
+    let constructor_cost = match stack.program().constructor() {
+        Some(constructor) if stack.edition() == N::EDITION => stack.get_finalize_cost(constructor.name())?,
+        _ => 0,
+    };
+
     // Compute the namespace cost in credits: 10^(10 - num_characters).
     let namespace_cost = 10u64
         .checked_pow(10u32.saturating_sub(num_characters))
@@ -51,10 +65,11 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
         .checked_add(synthesis_cost)
+        .and_then(|x| x.checked_add(constructor_cost))
         .and_then(|x| x.checked_add(namespace_cost))
         .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
 
-    Ok((total_cost, (storage_cost, synthesis_cost, namespace_cost)))
+    Ok((total_cost, (storage_cost, synthesis_cost, constructor_cost, namespace_cost)))
 }
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, finalize cost)).
@@ -76,6 +91,84 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
+/// Returns the *minimum* cost in microcredits to publish the execution of the given authorization,
+/// without proving it (total cost, (storage cost, finalize cost)).
+///
+/// The given stack must be the stack of the root transition. The transitions in the authorization
+/// are the ones in the execution, and the size of the proof is determined by the number of instances
+/// of each circuit in it, so the cost matches `execution_cost` for the proven execution.
+pub fn authorization_cost<N: Network>(
+    stack: &Stack<N>,
+    authorization: &Authorization<N>,
+) -> Result<(u64, (u64, u64))> {
+    // Construct the execution from the transitions in the authorization, without a proof.
+    let execution = Execution::from(authorization.transitions().into_values(), N::StateRoot::default(), None)?;
+
+    // Get the root transition.
+    let transition = execution.peek()?;
+    // Ensure the stack is for the root transition.
+    ensure!(
+        stack.program_id() == transition.program_id(),
+        "The stack for '{}' does not match the root transition for '{}'",
+        stack.program_id(),
+        transition.program_id()
+    );
+
+    // Count the instances of each circuit in the proof, which has an instance for each transition
+    // of the function circuits, and an instance for each record input of the inclusion circuit.
+    let mut batch_sizes = IndexMap::<_, usize>::new();
+    for transition in execution.transitions() {
+        *batch_sizes.entry((transition.program_id(), transition.function_name())).or_default() += 1;
+    }
+    let mut batch_sizes = batch_sizes.into_values().collect::<Vec<_>>();
+    let num_record_inputs = execution
+        .transitions()
+        .flat_map(|transition| transition.inputs())
+        .filter(|input| matches!(input, Input::Record(..)))
+        .count();
+    if num_record_inputs > 0 {
+        batch_sizes.push(num_record_inputs);
+    }
+
+    // Compute the storage cost in microcredits.
+    // Note: The proof is serialized in place of the `None` flag, after the flag for `Some`.
+    let storage_cost = execution
+        .size_in_bytes()?
+        .checked_add(u64::try_from(Proof::<N>::size_in_bytes_for(&batch_sizes))?)
+        .ok_or(anyhow!("The storage cost computation overflowed for an authorization"))?;
+
+    // Get the finalize cost for the root transition.
+    let finalize_cost = stack.get_finalize_cost(transition.function_name())?;
+
+    // Compute the total cost in microcredits.
+    let total_cost = storage_cost
+        .checked_add(finalize_cost)
+        .ok_or(anyhow!("The total cost computation overflowed for an authorization"))?;
+
+    Ok((total_cost, (storage_cost, finalize_cost)))
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given bundle (total cost, (storage cost, finalize cost)).
+pub fn bundle_cost<N: Network>(process: &Process<N>, executions: &[Execution<N>]) -> Result<(u64, (u64, u64))> {
+    // Sum the storage and finalize costs of each execution.
+    let (storage_cost, finalize_cost) =
+        executions.iter().try_fold((0u64, 0u64), |(storage, finalize), execution| {
+            // Compute the cost of the execution.
+            let (_, (storage_cost, finalize_cost)) = execution_cost(process, execution)?;
+            // Accumulate the costs.
+            match (storage.checked_add(storage_cost), finalize.checked_add(finalize_cost)) {
+                (Some(storage), Some(finalize)) => Ok((storage, finalize)),
+                _ => bail!("The cost computation overflowed for a bundle"),
+            }
+        })?;
+
+    // Compute the total cost in microcredits.
+    let total_cost =
+        storage_cost.checked_add(finalize_cost).ok_or(anyhow!("The total cost computation overflowed for a bundle"))?;
+
+    Ok((total_cost, (storage_cost, finalize_cost)))
+}
+
 /// Finalize costs for compute heavy operations, derived as:
 /// `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
 
@@ -94,9 +187,15 @@ const HASH_PSD_PER_BYTE_COST: u64 = 75;
 const MAPPING_BASE_COST: u64 = 10_000;
 const MAPPING_PER_BYTE_COST: u64 = 10;
 
+const MAPPING_ITERATION_BASE_COST: u64 = 250_000;
+const MAPPING_ITERATION_PER_KEY_COST: u64 = 25;
+
 const SET_BASE_COST: u64 = 10_000;
 const SET_PER_BYTE_COST: u64 = 100;
 
+const SIGNATURE_VERIFY_BASE_COST: u64 = 500_000;
+const SIGNATURE_VERIFY_PER_BYTE_COST: u64 = 30;
+
 /// A helper function to determine the plaintext type in bytes.
 fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
     match plaintext_type {
@@ -116,6 +215,12 @@ fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &Plaint
             // Return the size of the struct.
             Ok(size_of_name.saturating_add(size_of_members))
         }
+        PlaintextType::ExternalStruct(locator) => {
+            // Retrieve the stack declaring the struct.
+            let (stack, _) = stack.resolve_struct(plaintext_type)?;
+            // Return the size of the struct, as declared in its program.
+            plaintext_size_in_bytes(stack, &PlaintextType::Struct(*locator.resource()))
+        }
         PlaintextType::Array(array_type) => {
             // Retrieve the number of elements in the array.
             let num_elements = **array_type.length() as u64;
@@ -169,6 +274,7 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+        Command::Instruction(Instruction::CallDynamic(_)) => bail!("'call.dynamic' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
@@ -217,12 +323,20 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => {
+                    bail!("'div' does not support structs")
+                }
                 FinalizeType::Future(_) => bail!("'div' does not support futures"),
             }
         }
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Double(_)) => Ok(500),
+        Command::Instruction(Instruction::ECDSAVerifySecp256k1(verify)) => {
+            cost_in_size(stack, finalize, verify.operands(), SIGNATURE_VERIFY_PER_BYTE_COST, SIGNATURE_VERIFY_BASE_COST)
+        }
+        Command::Instruction(Instruction::Ed25519Verify(verify)) => {
+            cost_in_size(stack, finalize, verify.operands(), SIGNATURE_VERIFY_PER_BYTE_COST, SIGNATURE_VERIFY_BASE_COST)
+        }
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(500),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(500),
         Command::Instruction(Instruction::HashBHP256(hash)) => {
@@ -296,7 +410,9 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) => Ok(10_000),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => {
+                    bail!("'mul' does not support structs")
+                }
                 FinalizeType::Future(_) => bail!("'mul' does not support futures"),
             }
         }
@@ -316,7 +432,9 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => {
+                    bail!("'pow' does not support structs")
+                }
                 FinalizeType::Future(_) => bail!("'pow' does not support futures"),
             }
         }
@@ -353,9 +471,54 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         }
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(500),
         Command::Position(_) => Ok(100),
+        Command::Loop(_) | Command::EndLoop(_) => Ok(500),
+        // Note: The number of entries of each mapping is kept in storage, so `length` is a single lookup.
+        Command::Length(_) => Ok(MAPPING_BASE_COST),
+        Command::KeyAt(command) => {
+            // Note: `key.at` walks the ordered keys of the mapping up to the index, so it is priced per key walked.
+            // If the index is a literal, it is priced up to the index, and otherwise, up to the maximum index.
+            let max_index = match command.index() {
+                Operand::Literal(Literal::U32(index)) => (**index).min(KeyAt::<N>::MAX_INDEX),
+                _ => KeyAt::<N>::MAX_INDEX,
+            };
+            let scan_cost = MAPPING_ITERATION_PER_KEY_COST.saturating_mul(u64::from(max_index).saturating_add(1));
+            let destination = Operand::Register(command.destination().clone());
+            Ok(cost_in_size(stack, finalize, [&destination], MAPPING_PER_BYTE_COST, MAPPING_ITERATION_BASE_COST)?
+                .saturating_add(scan_cost))
+        }
     }
 }
 
+/// Returns the cost of all commands in the given finalize scope,
+/// where the cost of each command in a loop is scaled by the bounds of its enclosing loops.
+fn cost_of_commands<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<u64> {
+    // Initialize the total cost.
+    let mut total_cost = 0u64;
+    // Initialize the stack of multipliers, for the enclosing loops.
+    let mut multipliers = vec![1u64];
+    for command in finalize.commands() {
+        // Retrieve the multiplier of the enclosing loops.
+        let multiplier = *multipliers.last().ok_or(anyhow!("Unmatched 'end.loop' in the finalize cost"))?;
+        // Compute the cost of the command, scaled by the multiplier.
+        let cost = cost_per_command(stack, finalize, command)?
+            .checked_mul(multiplier)
+            .ok_or(anyhow!("Finalize cost overflowed"))?;
+        total_cost = total_cost.checked_add(cost).ok_or(anyhow!("Finalize cost overflowed"))?;
+
+        match command {
+            // The commands up to (and including) the matching `end.loop` are evaluated up to `bound` times.
+            Command::Loop(loop_) => multipliers
+                .push(multiplier.checked_mul(u64::from(loop_.bound())).ok_or(anyhow!("Finalize cost overflowed"))?),
+            // The commands after the `end.loop` are evaluated with the multiplier of the enclosing loops.
+            Command::EndLoop(_) => {
+                multipliers.pop();
+            }
+            _ => (),
+        }
+    }
+    Ok(total_cost)
+}
+
 /// Returns the minimum number of microcredits required to run the finalize.
 pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
     // Retrieve the finalize logic.
@@ -376,11 +539,16 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         }
     }
     // Aggregate the cost of all commands in the program.
-    finalize
-        .commands()
-        .iter()
-        .map(|command| cost_per_command(stack, finalize, command))
-        .try_fold(future_cost, |acc, res| {
-            res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed")))
-        })
+    future_cost.checked_add(cost_of_commands(stack, finalize)?).ok_or(anyhow!("Finalize cost overflowed"))
+}
+
+/// Returns the minimum number of microcredits required to run the constructor.
+pub fn constructor_cost_in_microcredits<N: Network>(stack: &Stack<N>) -> Result<u64> {
+    // Retrieve the constructor.
+    let Some(constructor) = stack.program().constructor() else {
+        // Return a constructor cost of 0, if the program does not have a constructor.
+        return Ok(0);
+    };
+    // Aggregate the cost of all commands in the constructor.
+    cost_of_commands(stack, constructor.finalize_logic())
 }
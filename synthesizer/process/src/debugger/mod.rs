@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+
+use crate::RegistersDebug;
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+use synthesizer_program::{Opcode, Operand, RegistersLoad, StackMatches, StackProgram};
+
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+
+/// The scope of an instruction or command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DebugScope {
+    /// An instruction in a closure.
+    Closure,
+    /// An instruction in a function.
+    Function,
+    /// A command in a finalize scope.
+    Finalize,
+}
+
+impl Display for DebugScope {
+    /// Prints the scope as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+            Self::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// The location of an instruction in a closure or function, or of a command in a finalize scope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DebugLocation<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The name of the closure or function.
+    pub name: Identifier<N>,
+    /// The scope.
+    pub scope: DebugScope,
+    /// The index of the instruction or command in the scope.
+    pub index: usize,
+}
+
+impl<N: Network> DebugLocation<N> {
+    /// Initializes a new location.
+    pub const fn new(program_id: ProgramID<N>, name: Identifier<N>, scope: DebugScope, index: usize) -> Self {
+        Self { program_id, name, scope, index }
+    }
+}
+
+impl<N: Network> Display for DebugLocation<N> {
+    /// Prints the location as a string, e.g. `token.aleo/transfer (finalize) #3`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{} ({}) #{}", self.program_id, self.name, self.scope, self.index)
+    }
+}
+
+/// The action to take after the evaluation pauses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DebugAction {
+    /// Continues until the next breakpoint.
+    Continue,
+    /// Pauses again before the next instruction or command.
+    Step,
+    /// Aborts the evaluation with an error.
+    Abort,
+}
+
+/// A hook that is called when the evaluation pauses, before an instruction or command is evaluated.
+pub trait DebugHook<N: Network>: Send + Sync {
+    /// Returns the action to take, given the location and the values of the assigned registers.
+    fn pause(&mut self, location: &DebugLocation<N>, registers: &IndexMap<u64, Value<N>>) -> DebugAction;
+}
+
+impl<N: Network, F> DebugHook<N> for F
+where
+    F: FnMut(&DebugLocation<N>, &IndexMap<u64, Value<N>>) -> DebugAction + Send + Sync,
+{
+    /// Returns the action to take, given the location and the values of the assigned registers.
+    fn pause(&mut self, location: &DebugLocation<N>, registers: &IndexMap<u64, Value<N>>) -> DebugAction {
+        self(location, registers)
+    }
+}
+
+/// A step in the trace, for an evaluated instruction or command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugStep<N: Network> {
+    /// The location of the instruction or command.
+    pub location: DebugLocation<N>,
+    /// The opcode.
+    pub opcode: String,
+    /// The instruction or command.
+    pub statement: String,
+    /// The values of the operands, or `None` if an operand could not be loaded.
+    pub operands: Vec<Option<Value<N>>>,
+    /// The values assigned to the destination registers.
+    pub destinations: Vec<(Register<N>, Value<N>)>,
+    /// The index of the next command, if the command is a branch or loop command.
+    pub branch: Option<usize>,
+    /// The error, if the instruction or command failed.
+    pub error: Option<String>,
+}
+
+/// A debugger, which traces the evaluation of the instructions and commands,
+/// and pauses the evaluation at breakpoints to call its hook.
+pub struct Debugger<N: Network> {
+    /// The steps of the trace, in the order in which they were evaluated.
+    steps: Vec<DebugStep<N>>,
+    /// The indices of the steps that have been entered, but not exited.
+    pending: Vec<usize>,
+    /// The set of breakpoints.
+    breakpoints: IndexSet<DebugLocation<N>>,
+    /// A flag indicating whether to pause before the next instruction or command.
+    is_stepping: bool,
+    /// The hook that is called when the evaluation pauses.
+    hook: Option<Box<dyn DebugHook<N>>>,
+}
+
+impl<N: Network> Default for Debugger<N> {
+    /// Initializes a new debugger, which only traces the evaluation.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Debugger<N> {
+    /// Initializes a new debugger, which only traces the evaluation.
+    pub fn new() -> Self {
+        Self { steps: Vec::new(), pending: Vec::new(), breakpoints: IndexSet::new(), is_stepping: false, hook: None }
+    }
+
+    /// Initializes a new debugger, which calls the given hook when the evaluation pauses.
+    pub fn with_hook(hook: impl DebugHook<N> + 'static) -> Self {
+        Self { hook: Some(Box::new(hook)), ..Self::new() }
+    }
+
+    /// Returns the steps of the trace.
+    pub fn steps(&self) -> &[DebugStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the step that failed, if any.
+    pub fn failure(&self) -> Option<&DebugStep<N>> {
+        self.steps.iter().find(|step| step.error.is_some())
+    }
+
+    /// Returns the breakpoints.
+    pub fn breakpoints(&self) -> &IndexSet<DebugLocation<N>> {
+        &self.breakpoints
+    }
+
+    /// Adds a breakpoint at the given location. Returns `false` if the breakpoint already exists.
+    pub fn add_breakpoint(&mut self, location: DebugLocation<N>) -> bool {
+        self.breakpoints.insert(location)
+    }
+
+    /// Removes the breakpoint at the given location. Returns `false` if the breakpoint does not exist.
+    pub fn remove_breakpoint(&mut self, location: &DebugLocation<N>) -> bool {
+        self.breakpoints.shift_remove(location)
+    }
+
+    /// Pauses the evaluation before the next instruction or command.
+    pub fn step(&mut self) {
+        self.is_stepping = true;
+    }
+
+    /// Returns the trace as a JSON string.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.steps)?)
+    }
+}
+
+impl<N: Network> Debugger<N> {
+    /// Records the instruction or command at the given location, before it is evaluated.
+    /// If there is a breakpoint at the location, or the debugger is stepping, the hook is called first.
+    ///
+    /// Note: The hook is called without holding the lock on the debugger, so the hook may access the debugger,
+    /// e.g. to add breakpoints or to inspect the trace.
+    pub(crate) fn enter(
+        debugger: &RwLock<Self>,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &(impl RegistersLoad<N> + RegistersDebug<N>),
+        location: DebugLocation<N>,
+        opcode: Opcode,
+        statement: String,
+        operands: &[Operand<N>],
+    ) -> Result<()> {
+        // If the evaluation pauses, take the hook out of the debugger, and call it.
+        let hook = debugger.write().take_hook(&location);
+        if let Some(mut hook) = hook {
+            let action = hook.pause(&location, registers.values());
+            // Restore the hook, and apply the action.
+            let mut debugger = debugger.write();
+            debugger.hook = Some(hook);
+            match action {
+                DebugAction::Continue => debugger.is_stepping = false,
+                DebugAction::Step => debugger.is_stepping = true,
+                DebugAction::Abort => bail!("The evaluation was aborted by the debugger at '{location}'"),
+            }
+        }
+
+        // Load the values of the operands.
+        let operands = operands.iter().map(|operand| registers.load(stack, operand).ok()).collect();
+
+        // Record the step.
+        let mut debugger = debugger.write();
+        let index = debugger.steps.len();
+        debugger.pending.push(index);
+        debugger.steps.push(DebugStep {
+            location,
+            opcode: opcode.to_string(),
+            statement,
+            operands,
+            destinations: Vec::new(),
+            branch: None,
+            error: None,
+        });
+        Ok(())
+    }
+
+    /// Takes the hook out of the debugger, if the evaluation pauses at the given location.
+    /// Note: If the hook is called re-entrantly, e.g. from a nested evaluation within the hook, it is not taken.
+    fn take_hook(&mut self, location: &DebugLocation<N>) -> Option<Box<dyn DebugHook<N>>> {
+        match self.is_stepping || self.breakpoints.contains(location) {
+            true => self.hook.take(),
+            false => None,
+        }
+    }
+
+    /// Records the values of the given destination registers, and the branch taken, on the last entered step,
+    /// after it is evaluated.
+    pub(crate) fn exit(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+        destinations: Vec<Register<N>>,
+        branch: Option<usize>,
+    ) -> Result<()> {
+        // Retrieve the last entered step.
+        let step = match self.pending.pop() {
+            Some(index) => &mut self.steps[index],
+            None => bail!("The debugger has no step to exit"),
+        };
+        // Load the values of the destination registers.
+        // Note: A destination may be unassigned, e.g. the loop-carried registers of a loop that is skipped.
+        for register in destinations {
+            if let Ok(value) = registers.load(stack, &Operand::Register(register.clone())) {
+                step.destinations.push((register, value));
+            }
+        }
+        step.branch = branch;
+        Ok(())
+    }
+
+    /// Records the given error on the innermost step that was entered and not exited,
+    /// which is the step that failed. The remaining entered steps are the calls that led to it.
+    pub(crate) fn fail(&mut self, error: &Error) {
+        if let Some(index) = self.pending.pop() {
+            self.steps[index].error = Some(error.to_string());
+        }
+        self.pending.clear();
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Serialize for DebugScope {
+    /// Serializes the scope into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<N: Network> Serialize for DebugLocation<N> {
+    /// Serializes the location into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut location = serializer.serialize_struct("DebugLocation", 4)?;
+        location.serialize_field("program_id", &self.program_id)?;
+        location.serialize_field("name", &self.name)?;
+        location.serialize_field("scope", &self.scope)?;
+        location.serialize_field("index", &self.index)?;
+        location.end()
+    }
+}
+
+impl<N: Network> Serialize for DebugStep<N> {
+    /// Serializes the step into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut step = serializer.serialize_struct("DebugStep", 7)?;
+        step.serialize_field("location", &self.location)?;
+        step.serialize_field("opcode", &self.opcode)?;
+        step.serialize_field("statement", &self.statement)?;
+        step.serialize_field("operands", &self.operands)?;
+        step.serialize_field(
+            "destinations",
+            &self.destinations.iter().map(|(register, value)| (register, value)).collect::<IndexMap<_, _>>(),
+        )?;
+        if let Some(branch) = &self.branch {
+            step.serialize_field("branch", branch)?;
+        }
+        if let Some(error) = &self.error {
+            step.serialize_field("error", error)?;
+        }
+        step.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let location = DebugLocation::<CurrentNetwork>::new(
+            ProgramID::from_str("token.aleo")?,
+            Identifier::from_str("mint")?,
+            DebugScope::Finalize,
+            1,
+        );
+        let step = DebugStep {
+            location,
+            opcode: "add".to_string(),
+            statement: "add r0 r1 into r2;".to_string(),
+            operands: vec![Some(Value::from_str("1u64")?), None],
+            destinations: vec![(Register::from_str("r2")?, Value::from_str("3u64")?)],
+            branch: None,
+            error: Some("Failed".to_string()),
+        };
+
+        let expected = serde_json::json!({
+            "location": { "program_id": "token.aleo", "name": "mint", "scope": "finalize", "index": 1 },
+            "opcode": "add",
+            "statement": "add r0 r1 into r2;",
+            "operands": ["1u64", null],
+            "destinations": { "r2": "3u64" },
+            "error": "Failed",
+        });
+        assert_eq!(expected, serde_json::to_value(&step)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromBytes for ProvingConstraints {
+    /// Reads the proving constraints from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the maximum fee.
+        let max_fee_in_microcredits = u64::read_le(&mut reader)?;
+        // Read the maximum priority fee.
+        let max_priority_fee_in_microcredits = u64::read_le(&mut reader)?;
+        // Return the proving constraints.
+        Ok(Self::new(max_fee_in_microcredits, max_priority_fee_in_microcredits))
+    }
+}
+
+impl ToBytes for ProvingConstraints {
+    /// Writes the proving constraints to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the maximum fee.
+        self.max_fee_in_microcredits.write_le(&mut writer)?;
+        // Write the maximum priority fee.
+        self.max_priority_fee_in_microcredits.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for ProvingRequest<N> {
+    /// Reads the proving request from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error("Invalid proving request version"));
+        }
+
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee_authorization = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Authorization::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid fee authorization variant '{variant}'"))),
+        };
+        // Read the constraints.
+        let constraints = ProvingConstraints::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+
+        // Return the proving request.
+        Self::from_unchecked(authorization, fee_authorization, constraints, signature).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProvingRequest<N> {
+    /// Writes the proving request to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the fee authorization.
+        match &self.fee_authorization {
+            None => 0u8.write_le(&mut writer)?,
+            Some(fee_authorization) => {
+                1u8.write_le(&mut writer)?;
+                fee_authorization.write_le(&mut writer)?;
+            }
+        }
+        // Write the constraints.
+        self.constraints.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new proving request.
+        let constraints = ProvingConstraints::new(100_000, 10_000);
+        let (_, expected) = crate::delegation::test_helpers::sample_proving_request(constraints, rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+
+        // Ensure an unknown version is rejected.
+        let mut invalid_bytes = expected_bytes;
+        invalid_bytes[0] = ProvingRequest::<console::network::MainnetV0>::VERSION + 1;
+        assert!(ProvingRequest::<console::network::MainnetV0>::read_le(&invalid_bytes[..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Authorization;
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    program::{Literal, Plaintext, Value},
+    types::Field,
+};
+use ledger_block::Transaction;
+
+/// The constraints that a client binds to a proving request.
+///
+/// Note: The fee limits are binding, since the fee authorization is signed with its amounts.
+/// A proving request does not carry an expiration height, as the authorizations contain no block height
+/// for validators to check. A client that needs a deadline must invalidate the authorization itself,
+/// e.g. by spending its input records.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProvingConstraints {
+    /// The maximum fee (in microcredits), which is the sum of the base fee and the priority fee.
+    max_fee_in_microcredits: u64,
+    /// The maximum priority fee (in microcredits).
+    max_priority_fee_in_microcredits: u64,
+}
+
+impl ProvingConstraints {
+    /// Initializes new proving constraints.
+    pub const fn new(max_fee_in_microcredits: u64, max_priority_fee_in_microcredits: u64) -> Self {
+        Self { max_fee_in_microcredits, max_priority_fee_in_microcredits }
+    }
+
+    /// Returns the maximum fee (in microcredits).
+    pub const fn max_fee_in_microcredits(&self) -> u64 {
+        self.max_fee_in_microcredits
+    }
+
+    /// Returns the maximum priority fee (in microcredits).
+    pub const fn max_priority_fee_in_microcredits(&self) -> u64 {
+        self.max_priority_fee_in_microcredits
+    }
+}
+
+/// A request to a third-party prover, to prove an authorization and its fee authorization into a transaction.
+///
+/// The request is signed by the signer of the root request in the authorization, which binds the constraints
+/// to the transitions in the authorization and in the fee authorization.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingRequest<N: Network> {
+    /// The authorization.
+    authorization: Authorization<N>,
+    /// The fee authorization, if the execution requires a fee.
+    fee_authorization: Option<Authorization<N>>,
+    /// The constraints.
+    constraints: ProvingConstraints,
+    /// The signature of the signer on the proving request.
+    signature: Signature<N>,
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// The version of the proving request.
+    pub const VERSION: u8 = 1;
+
+    /// Initializes a new proving request, signed by the given private key.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        constraints: ProvingConstraints,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the private key is for the signer of the root request.
+        ensure!(
+            Address::try_from(private_key)? == Self::signer_of(&authorization)?,
+            "The proving request must be signed by the signer of the root request"
+        );
+        // Sign the proving request.
+        let message = Self::message(&authorization, fee_authorization.as_ref(), &constraints)?;
+        let signature = Signature::sign(private_key, &message, rng)?;
+        // Return the proving request.
+        Ok(Self { authorization, fee_authorization, constraints, signature })
+    }
+
+    /// Initializes a proving request from its parts.
+    ///
+    /// Note: This method is used primarily for serialization, and does not check the signature.
+    /// The prover must call `ProvingRequest::check` before it proves the request.
+    fn from_unchecked(
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        constraints: ProvingConstraints,
+        signature: Signature<N>,
+    ) -> Result<Self> {
+        // Ensure the authorization is not empty.
+        ensure!(!authorization.is_empty(), "The proving request must contain an authorization");
+        // Ensure the fee authorization is for a fee.
+        if let Some(fee_authorization) = &fee_authorization {
+            ensure!(
+                fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+                "The fee authorization in the proving request must be for a fee"
+            );
+        }
+        Ok(Self { authorization, fee_authorization, constraints, signature })
+    }
+
+    /// Returns the authorization.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the fee authorization, if the execution requires a fee.
+    pub const fn fee_authorization(&self) -> Option<&Authorization<N>> {
+        self.fee_authorization.as_ref()
+    }
+
+    /// Returns the constraints.
+    pub const fn constraints(&self) -> &ProvingConstraints {
+        &self.constraints
+    }
+
+    /// Returns the signature.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the signer of the proving request.
+    pub fn signer(&self) -> Result<Address<N>> {
+        Self::signer_of(&self.authorization)
+    }
+
+    /// Returns the `(base_fee, priority_fee)` (in microcredits) of the fee authorization, if it exists.
+    pub fn fee_amounts(&self) -> Result<Option<(u64, u64)>> {
+        match &self.fee_authorization {
+            Some(fee_authorization) => {
+                let (base_fee, priority_fee, _) = Self::fee_inputs(fee_authorization)?;
+                Ok(Some((base_fee, priority_fee)))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Checks that the proving request may be proven.
+    ///
+    /// This method ensures the signature is valid, and the fee authorization pays for the authorization,
+    /// within the constraints.
+    pub fn check(&self) -> Result<()> {
+        // Ensure the signature is valid.
+        let message = Self::message(&self.authorization, self.fee_authorization.as_ref(), &self.constraints)?;
+        ensure!(self.signature.verify(&self.signer()?, &message), "The proving request has an invalid signature");
+
+        // Ensure the fee authorization is within the constraints.
+        if let Some(fee_authorization) = &self.fee_authorization {
+            let (base_fee, priority_fee, execution_id) = Self::fee_inputs(fee_authorization)?;
+            // Ensure the fee is for the authorization.
+            ensure!(
+                execution_id == self.authorization.to_execution_id()?,
+                "The fee authorization in the proving request is not for its authorization"
+            );
+            // Ensure the priority fee does not exceed the maximum priority fee.
+            ensure!(
+                priority_fee <= self.constraints.max_priority_fee_in_microcredits,
+                "The priority fee ({priority_fee}) exceeds the maximum priority fee ({})",
+                self.constraints.max_priority_fee_in_microcredits
+            );
+            // Ensure the fee does not exceed the maximum fee.
+            let fee = base_fee.checked_add(priority_fee).ok_or_else(|| anyhow!("The fee overflowed"))?;
+            ensure!(
+                fee <= self.constraints.max_fee_in_microcredits,
+                "The fee ({fee}) exceeds the maximum fee ({})",
+                self.constraints.max_fee_in_microcredits
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the given transaction, which is returned by the prover, proves the signed requests.
+    ///
+    /// Note: This method does not verify the proofs. The transaction must still be verified by the VM.
+    pub fn verify_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        let (execution, fee) = match transaction {
+            Transaction::Execute(_, execution, fee) => (execution, fee),
+            _ => bail!("The transaction for the proving request must be an execute transaction"),
+        };
+
+        // Ensure the execution contains the transitions of the authorization, in order.
+        let transitions = self.authorization.transitions();
+        ensure!(
+            execution.len() == transitions.len(),
+            "The execution has {} transitions, but the authorization has {}",
+            execution.len(),
+            transitions.len()
+        );
+        for (transition, expected_id) in execution.transitions().zip_eq(transitions.keys()) {
+            ensure!(
+                transition.id() == expected_id,
+                "The execution contains transition '{}', but the authorization expects '{expected_id}'",
+                transition.id()
+            );
+        }
+
+        // Ensure the fee contains the transition of the fee authorization.
+        match (fee, &self.fee_authorization) {
+            (Some(fee), Some(fee_authorization)) => {
+                let transitions = fee_authorization.transitions();
+                ensure!(
+                    transitions.len() == 1 && transitions.contains_key(fee.transition_id()),
+                    "The fee contains transition '{}', which is not in the fee authorization",
+                    fee.transition_id()
+                );
+            }
+            (None, None) => (),
+            (Some(_), None) => bail!("The transaction contains a fee, but the proving request has none"),
+            (None, Some(_)) => bail!("The transaction is missing the fee of the proving request"),
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Returns the signer of the root request in the given authorization.
+    fn signer_of(authorization: &Authorization<N>) -> Result<Address<N>> {
+        Ok(*authorization.get(0)?.signer())
+    }
+
+    /// Returns the message that is signed, which binds the constraints to the transitions
+    /// in the authorization and in the fee authorization.
+    fn message(
+        authorization: &Authorization<N>,
+        fee_authorization: Option<&Authorization<N>>,
+        constraints: &ProvingConstraints,
+    ) -> Result<Vec<Field<N>>> {
+        // Retrieve the ID of the fee transition, or zero if there is no fee.
+        let fee_transition_id = match fee_authorization {
+            Some(fee_authorization) => match fee_authorization.transitions().keys().next() {
+                Some(transition_id) => **transition_id,
+                None => bail!("The fee authorization does not contain a transition"),
+            },
+            None => Field::zero(),
+        };
+        Ok(vec![
+            Field::from_u8(Self::VERSION),
+            authorization.to_execution_id()?,
+            fee_transition_id,
+            Field::from_u64(constraints.max_fee_in_microcredits),
+            Field::from_u64(constraints.max_priority_fee_in_microcredits),
+        ])
+    }
+
+    /// Returns the `(base_fee, priority_fee, deployment_or_execution_id)` inputs of the given fee authorization.
+    fn fee_inputs(fee_authorization: &Authorization<N>) -> Result<(u64, u64, Field<N>)> {
+        // Determine the index of the base fee.
+        let index = match (fee_authorization.is_fee_private(), fee_authorization.is_fee_public()) {
+            (true, false) => 1,
+            (false, true) => 0,
+            _ => bail!("The fee authorization must be for a call to 'credits.aleo/fee_private' or 'fee_public'"),
+        };
+        // Retrieve the inputs.
+        let request = fee_authorization.get(0)?;
+        let inputs = request.inputs();
+        match (inputs.get(index), inputs.get(index + 1), inputs.get(index + 2)) {
+            (
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(base_fee), _))),
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(priority_fee), _))),
+                Some(Value::Plaintext(Plaintext::Literal(Literal::Field(execution_id), _))),
+            ) => Ok((**base_fee, **priority_fee, *execution_id)),
+            _ => bail!("Failed to retrieve the fee inputs from the fee authorization"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Process;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a sample proving request for a call to `credits.aleo/transfer_public`,
+    /// and the private key of its signer.
+    pub(crate) fn sample_proving_request(
+        constraints: ProvingConstraints,
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, ProvingRequest<CurrentNetwork>) {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        // Sample a recipient.
+        let recipient = Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Authorize the transfer.
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        // Authorize the fee.
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 50_000, 1_000, execution_id, rng).unwrap();
+
+        // Construct the proving request.
+        let proving_request =
+            ProvingRequest::new(&private_key, authorization, Some(fee_authorization), constraints, rng).unwrap();
+        (private_key, proving_request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_check() {
+        let rng = &mut TestRng::default();
+
+        // Sample a proving request.
+        let constraints = ProvingConstraints::new(100_000, 10_000);
+        let (private_key, proving_request) = test_helpers::sample_proving_request(constraints, rng);
+        assert_eq!(proving_request.signer().unwrap(), Address::try_from(&private_key).unwrap());
+        assert_eq!(proving_request.fee_amounts().unwrap(), Some((50_000, 1_000)));
+
+        // Check the proving request.
+        proving_request.check().unwrap();
+
+        // Ensure the constraints are bound to the signature.
+        let mut tampered = proving_request.clone();
+        tampered.constraints = ProvingConstraints::new(200_000, 10_000);
+        assert!(tampered.check().is_err());
+
+        // Ensure the proving request can only be signed by the signer.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = proving_request.authorization().clone();
+        let fee_authorization = proving_request.fee_authorization().cloned();
+        assert!(ProvingRequest::new(&other_private_key, authorization, fee_authorization, constraints, rng).is_err());
+    }
+
+    #[test]
+    fn test_check_fee_constraints() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the priority fee cannot exceed the maximum priority fee.
+        let (_, proving_request) = test_helpers::sample_proving_request(ProvingConstraints::new(100_000, 999), rng);
+        assert!(proving_request.check().is_err());
+
+        // Ensure the fee cannot exceed the maximum fee.
+        let (_, proving_request) = test_helpers::sample_proving_request(ProvingConstraints::new(50_999, 1_000), rng);
+        assert!(proving_request.check().is_err());
+
+        // Ensure the fee may be exactly the maximum fee.
+        let (_, proving_request) = test_helpers::sample_proving_request(ProvingConstraints::new(51_000, 1_000), rng);
+        proving_request.check().unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Serialize for ProvingConstraints {
+    /// Serializes the proving constraints into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut constraints = serializer.serialize_struct("ProvingConstraints", 2)?;
+                constraints.serialize_field("max_fee_in_microcredits", &self.max_fee_in_microcredits)?;
+                constraints
+                    .serialize_field("max_priority_fee_in_microcredits", &self.max_priority_fee_in_microcredits)?;
+                constraints.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProvingConstraints {
+    /// Deserializes the proving constraints from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving constraints from a string into a value.
+                let mut constraints = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving constraints.
+                Ok(Self::new(
+                    // Retrieve the maximum fee.
+                    DeserializeExt::take_from_value::<D>(&mut constraints, "max_fee_in_microcredits")?,
+                    // Retrieve the maximum priority fee.
+                    DeserializeExt::take_from_value::<D>(&mut constraints, "max_priority_fee_in_microcredits")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving constraints"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for ProvingRequest<N> {
+    /// Serializes the proving request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let num_fields = 4 + self.fee_authorization.is_some() as usize;
+                let mut proving_request = serializer.serialize_struct("ProvingRequest", num_fields)?;
+                proving_request.serialize_field("version", &Self::VERSION)?;
+                proving_request.serialize_field("authorization", &self.authorization)?;
+                if let Some(fee_authorization) = &self.fee_authorization {
+                    proving_request.serialize_field("fee_authorization", fee_authorization)?;
+                }
+                proving_request.serialize_field("constraints", &self.constraints)?;
+                proving_request.serialize_field("signature", &self.signature)?;
+                proving_request.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingRequest<N> {
+    /// Deserializes the proving request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving request from a string into a value.
+                let mut proving_request = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the version.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut proving_request, "version")?;
+                // Ensure the version is valid.
+                if version != Self::VERSION {
+                    return Err(de::Error::custom(format!("Invalid proving request version '{version}'")));
+                }
+                // Retrieve the authorization.
+                let authorization = DeserializeExt::take_from_value::<D>(&mut proving_request, "authorization")?;
+                // Retrieve the fee authorization, if it exists.
+                let fee_authorization = serde_json::from_value(
+                    proving_request.get_mut("fee_authorization").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Retrieve the constraints.
+                let constraints = DeserializeExt::take_from_value::<D>(&mut proving_request, "constraints")?;
+                // Retrieve the signature.
+                let signature = DeserializeExt::take_from_value::<D>(&mut proving_request, "signature")?;
+                // Recover the proving request.
+                Self::from_unchecked(authorization, fee_authorization, constraints, signature)
+                    .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving request"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let constraints = ProvingConstraints::new(100_000, 10_000);
+        let (_, expected) = crate::delegation::test_helpers::sample_proving_request(constraints, rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Ensure an unknown version is rejected.
+        let mut value = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+        value["version"] = serde_json::json!(ProvingRequest::<console::network::MainnetV0>::VERSION + 1);
+        assert!(serde_json::from_value::<ProvingRequest<console::network::MainnetV0>>(value).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let constraints = ProvingConstraints::new(100_000, 10_000);
+        let (_, expected) = crate::delegation::test_helpers::sample_proving_request(constraints, rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
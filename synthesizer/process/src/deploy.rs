@@ -15,7 +15,8 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program ID.
+    /// If the program already exists, the deployment is an upgrade to the next edition of the program.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -25,7 +26,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::deploy");
 
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = self.compute_deployment_stack(program)?;
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -44,7 +45,9 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let mut stack = self.compute_deployment_stack(deployment.program())?;
+        // Set the edition, as the deployment may be an upgrade loaded from storage.
+        stack.set_edition(deployment.edition());
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,10 +57,48 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.add_stack(stack);
+        self.add_stack(stack)?;
 
         finish!(timer);
 
         Ok(())
     }
+
+    /// Reverts the program of the given deployment to the edition of the given deployment.
+    /// This method should **only** be used to roll back an upgrade of the program.
+    #[inline]
+    pub fn revert_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the deployment is a previous edition of the program.
+        let current_edition = self.get_stack(program_id)?.edition();
+        ensure!(
+            deployment.edition() < current_edition,
+            "Cannot revert program '{program_id}' from edition {current_edition} to edition {}",
+            deployment.edition()
+        );
+
+        // Compute the program stack.
+        let mut stack = Stack::initialize(self, deployment.program())?;
+        // Set the edition of the deployment.
+        stack.set_edition(deployment.edition());
+
+        // Insert the verifying keys.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+        }
+
+        // Replace the stack in the process, reloading the stacks that depend on it.
+        self.add_stack(stack)
+    }
+
+    /// Returns the stack for the given program.
+    /// If the program already exists, the stack is the next edition of the program.
+    #[inline]
+    pub(crate) fn compute_deployment_stack(&self, program: &Program<N>) -> Result<Stack<N>> {
+        match self.contains_program(program.id()) {
+            true => Stack::new_upgrade(self, program),
+            false => Stack::new(self, program),
+        }
+    }
 }
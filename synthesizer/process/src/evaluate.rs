@@ -36,4 +36,33 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given request, with the given debugger attached.
+    /// The debugger traces the evaluated instructions, and pauses the evaluation at its breakpoints.
+    /// If the evaluation fails, the error is recorded on the failed instruction in the trace.
+    #[inline]
+    pub fn debug_evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        debugger: Arc<RwLock<Debugger<N>>>,
+    ) -> Result<Response<N>> {
+        let timer = timer!("Process::debug_evaluate");
+
+        // Retrieve the top-level request (without popping it).
+        let request = authorization.peek_next()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+        // Evaluate the function.
+        let response = stack.evaluate_function::<A>(CallStack::debug(authorization, debugger.clone())?, None);
+        lap!(timer, "Evaluate the function");
+
+        // If the evaluation failed, record the error on the failed instruction.
+        if let Err(error) = &response {
+            debugger.write().fail(error);
+        }
+
+        finish!(timer);
+
+        response
+    }
 }
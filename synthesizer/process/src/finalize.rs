@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use console::program::{Argument, FinalizeType, Future, Register};
+use synthesizer_program::{Await, CommandTrait, EndLoop, FinalizeRegistersState, Loop, Operand, UpgradePolicy};
 use utilities::try_vm_runtime;
 
 use std::collections::HashSet;
@@ -33,8 +33,23 @@ impl<N: Network> Process<N> {
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the previous edition of the program, if the deployment is an upgrade.
+        let previous_program = match self.contains_program(program_id) {
+            true => Some(self.get_program(program_id)?),
+            false => None,
+        };
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment.program())?;
+        // Ensure the edition matches.
+        ensure!(
+            stack.edition() == deployment.edition(),
+            "Expected edition '{}' for program '{program_id}', found edition '{}'",
+            stack.edition(),
+            deployment.edition()
+        );
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -58,14 +73,38 @@ impl<N: Network> Process<N> {
 
             /* Finalize the deployment. */
 
-            // Retrieve the program ID.
-            let program_id = deployment.program_id();
+            // If the upgrade is governed by an admin function, finalize the admin function of the previous edition.
+            if let Some(UpgradePolicy::Admin(function_name)) = previous_program.map(|program| program.upgrade_policy())
+            {
+                // Retrieve the stack of the previous edition.
+                let previous_stack = self.get_stack(program_id)?;
+                // Finalize the admin function.
+                finalize_operations.extend(finalize_upgrade_admin(
+                    state,
+                    store,
+                    previous_stack,
+                    function_name,
+                    deployment,
+                )?);
+                lap!(timer, "Finalize the admin function '{program_id}/{function_name}'");
+            }
+
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
-                // Initialize the mapping.
-                finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+                // Initialize the mapping, if it is new.
+                // Note: The mappings of the previous edition are preserved across an upgrade.
+                if !previous_program.map_or(false, |program| program.contains_mapping(mapping.name())) {
+                    finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+                }
             }
-            finish!(timer, "Initialize the program mappings");
+            lap!(timer, "Initialize the program mappings");
+
+            // Finalize the constructor, if it exists and this is the initial deployment of the program.
+            // Note: A failure in the constructor rejects the deployment.
+            if previous_program.is_none() {
+                finalize_operations.extend(finalize_constructor(state, store, &stack, deployment)?);
+            }
+            finish!(timer, "Finalize the constructor");
 
             // Return the stack and finalize operations.
             Ok((stack, finalize_operations))
@@ -94,12 +133,7 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(transition.program_id())?;
         // Ensure the number of calls matches the number of transitions.
-        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-        ensure!(
-            number_of_calls == execution.len(),
-            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-            execution.len()
-        );
+        Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
         lap!(timer, "Verify the number of transitions");
 
         // Construct the call graph.
@@ -127,6 +161,157 @@ impl<N: Network> Process<N> {
         })
     }
 
+    /// Finalizes the bundle and fee.
+    /// This method assumes the given bundle **is valid**.
+    /// This method should **only** be called by `VM::finalize()`.
+    ///
+    /// The executions are finalized in order within a single atomic batch scope,
+    /// so that if any execution fails, the finalize operations of all executions are reverted.
+    #[inline]
+    pub fn finalize_bundle<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        executions: &[Execution<N>],
+        fee: &Fee<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("Program::finalize_bundle");
+
+        // Ensure the bundle contains executions.
+        ensure!(!executions.is_empty(), "There are no executions in the bundle");
+
+        // Prepare the root transition, stack, and call graph of each execution.
+        let mut calls = Vec::with_capacity(executions.len());
+        for execution in executions {
+            // Ensure the execution contains transitions.
+            ensure!(!execution.is_empty(), "There are no transitions in the execution");
+            // Retrieve the root transition (without popping it).
+            let transition = execution.peek()?;
+            // Retrieve the stack.
+            let stack = self.get_stack(transition.program_id())?;
+            // Ensure the number of calls matches the number of transitions.
+            Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
+            // Construct the call graph.
+            let call_graph = self.construct_call_graph(execution)?;
+            // Store the call.
+            calls.push((stack, transition, call_graph));
+        }
+        lap!(timer, "Construct the call graphs");
+
+        atomic_batch_scope!(store, {
+            // Initialize a list for the finalize operations.
+            let mut finalize_operations = Vec::new();
+
+            // Finalize the root transition of each execution, in order.
+            for (stack, transition, call_graph) in calls {
+                finalize_operations.extend(finalize_transition(state, store, stack, transition, call_graph)?);
+                lap!(timer, "Finalize transition for '{}/{}'", transition.program_id(), transition.function_name());
+            }
+
+            /* Finalize the fee. */
+
+            // Retrieve the fee stack.
+            let fee_stack = self.get_stack(fee.program_id())?;
+            // Finalize the fee transition.
+            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+
+            finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
+            // Return the finalize operations.
+            Ok(finalize_operations)
+        })
+    }
+
+    /// Finalizes the transitions in the given authorization, without a proof.
+    /// This method assumes the given authorization **is valid**.
+    /// This method should **only** be called by `VM::simulate()`, inside an atomic batch that is aborted.
+    ///
+    /// Returns the finalize operations, or the failure at the command that could not be finalized.
+    #[inline]
+    pub fn finalize_authorization<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        authorization: &Authorization<N>,
+    ) -> Result<Result<Vec<FinalizeOperation<N>>, FinalizeFailure<N>>> {
+        self.finalize_authorization_with_debugger(state, store, authorization, None)
+    }
+
+    /// Finalizes the transitions in the given authorization, without a proof, with the given debugger attached.
+    /// The debugger traces the evaluated commands, and pauses the evaluation at its breakpoints.
+    /// If a command fails, the error is recorded on the failed command in the trace.
+    /// This method assumes the given authorization **is valid**.
+    /// This method should **only** be called inside an atomic batch that is aborted.
+    ///
+    /// Returns the finalize operations, or the failure at the command that could not be finalized.
+    #[inline]
+    pub fn debug_finalize_authorization<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        authorization: &Authorization<N>,
+        debugger: Arc<RwLock<Debugger<N>>>,
+    ) -> Result<Result<Vec<FinalizeOperation<N>>, FinalizeFailure<N>>> {
+        self.finalize_authorization_with_debugger(state, store, authorization, Some(&debugger))
+    }
+
+    /// Finalizes the transitions in the given authorization, without a proof, with an optional debugger attached.
+    fn finalize_authorization_with_debugger<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        authorization: &Authorization<N>,
+        debugger: Option<&RwLock<Debugger<N>>>,
+    ) -> Result<Result<Vec<FinalizeOperation<N>>, FinalizeFailure<N>>> {
+        let timer = timer!("Program::finalize_authorization");
+
+        // Construct an execution from the transitions in the authorization, without a proof.
+        let execution = Execution::from(authorization.transitions().into_values(), N::StateRoot::default(), None)?;
+        // Retrieve the root transition (without popping it).
+        let transition = execution.peek()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Ensure the number of calls matches the number of transitions.
+        Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
+        // Construct the call graph.
+        let call_graph = self.construct_call_graph(&execution)?;
+        lap!(timer, "Construct the call graph");
+
+        // If the last output of the transition is a future, retrieve it. Otherwise, there is nothing to finalize.
+        let Some(future) = transition.outputs().last().and_then(|output| output.future()) else {
+            finish!(timer);
+            return Ok(Ok(Vec::new()));
+        };
+        // Check that the program ID and function name of the transition match those in the future.
+        ensure!(
+            future.program_id() == transition.program_id() && future.function_name() == transition.function_name(),
+            "The program ID and function name of the future do not match the transition"
+        );
+        // Initialize the top-level finalize state.
+        let finalize_state = initialize_finalize_state(state, future, stack, *transition.id())?;
+
+        // Initialize the cursor, which tracks the command being evaluated.
+        let mut cursor = None;
+        // Evaluate the finalize state.
+        let result = evaluate_finalize_with_cursor(state, store, finalize_state, call_graph, &mut cursor, debugger);
+        // If the debugger is attached and a command failed, record the error on the failed command.
+        if let (Some(debugger), Err(error)) = (debugger, &result) {
+            debugger.write().fail(error);
+        }
+        let result = match result {
+            Ok(finalize_operations) => Ok(finalize_operations),
+            // If a command fails, return the failure.
+            Err(error) => match cursor {
+                Some((program_id, function_name, command_index)) => {
+                    Err(FinalizeFailure { program_id, function_name, command_index, error: error.to_string() })
+                }
+                None => return Err(error),
+            },
+        };
+        finish!(timer, "Finalize transition for '{}/{}'", transition.program_id(), transition.function_name());
+
+        Ok(result)
+    }
+
     /// Finalizes the fee.
     /// This method assumes the given fee **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
@@ -172,6 +357,72 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     }
 }
 
+/// Finalizes the admin function of the previous edition of a program, to authorize the upgrade in the given deployment.
+/// The admin function is given the new edition and the checksum of the new program as inputs.
+fn finalize_upgrade_admin<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    function_name: &Identifier<N>,
+    deployment: &Deployment<N>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    // Construct the future for the admin function.
+    let future = Future::new(*stack.program_id(), *function_name, vec![
+        Argument::Plaintext(Plaintext::from(Literal::U16(U16::new(deployment.edition())))),
+        Argument::Plaintext(Plaintext::from(Literal::Field(deployment.program().to_checksum()?))),
+    ]);
+    // Note: The admin function is not invoked by a transition, so the deployment ID stands in for the transition ID.
+    let transition_id = N::TransitionID::from(deployment.to_deployment_id()?);
+
+    // Finalize the future.
+    match finalize_future(state, store, stack, &future, transition_id, HashMap::new()) {
+        // If the evaluation succeeds, return the finalize operations.
+        Ok(finalize_operations) => Ok(finalize_operations),
+        // If the evaluation fails, bail and return the error.
+        Err(error) => bail!("The upgrade was not approved by '{}/{function_name}' - {error}", stack.program_id()),
+    }
+}
+
+/// Finalizes the constructor of the program in the given deployment, if it exists.
+fn finalize_constructor<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    deployment: &Deployment<N>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    // Retrieve the constructor.
+    let Some(constructor) = stack.program().constructor() else {
+        return Ok(Vec::new());
+    };
+    // Note: The constructor is not invoked by a transition, so the deployment ID stands in for the transition ID.
+    let transition_id = N::TransitionID::from(deployment.to_deployment_id()?);
+
+    // Initialize the registers.
+    let registers = FinalizeRegisters::new(
+        state,
+        transition_id,
+        *constructor.name(),
+        stack.get_finalize_types(constructor.name())?.clone(),
+    );
+    // Initialize the finalize state.
+    let finalize_state = FinalizeState {
+        counter: 0,
+        finalize: constructor.finalize_logic(),
+        registers,
+        stack,
+        call_counter: 0,
+        awaited: Default::default(),
+    };
+
+    // Finalize the constructor.
+    match evaluate_finalize(state, store, finalize_state, HashMap::new()) {
+        // If the evaluation succeeds, return the finalize operations.
+        Ok(finalize_operations) => Ok(finalize_operations),
+        // If the evaluation fails, bail and return the error.
+        Err(error) => bail!("The constructor of '{}' failed - {error}", stack.program_id()),
+    }
+}
+
 /// Finalizes the given transition.
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
@@ -201,14 +452,52 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         "The program ID and function name of the future do not match the transition"
     );
 
+    // Finalize the future.
+    finalize_future(state, store, stack, future, *transition.id(), call_graph)
+}
+
+/// Finalizes the given future, and the futures it awaits.
+fn finalize_future<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    future: &Future<N>,
+    transition_id: N::TransitionID,
+    call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    // Initialize the top-level finalize state.
+    let finalize_state = initialize_finalize_state(state, future, stack, transition_id)?;
+    // Evaluate the finalize state.
+    evaluate_finalize(state, store, finalize_state, call_graph)
+}
+
+/// Evaluates the given finalize state, and the futures it awaits.
+fn evaluate_finalize<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    finalize_state: FinalizeState<N>,
+    call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    evaluate_finalize_with_cursor(state, store, finalize_state, call_graph, &mut None, None)
+}
+
+/// Evaluates the given finalize state, and the futures it awaits.
+/// The cursor is set to the program ID, function name, and index of the command being evaluated,
+/// so that it points to the failed command if the evaluation fails.
+/// If a debugger is given, each command is recorded in its trace.
+fn evaluate_finalize_with_cursor<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    finalize_state: FinalizeState<N>,
+    call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    cursor: &mut Option<(ProgramID<N>, Identifier<N>, usize)>,
+    debugger: Option<&RwLock<Debugger<N>>>,
+) -> Result<Vec<FinalizeOperation<N>>> {
     // Initialize a list for finalize operations.
     let mut finalize_operations = Vec::new();
 
     // Initialize a stack of active finalize states.
-    let mut states = Vec::new();
-
-    // Initialize the top-level finalize state.
-    states.push(initialize_finalize_state(state, future, stack, *transition.id())?);
+    let mut states = vec![finalize_state];
 
     // While there are active finalize states, finalize them.
     'outer: while let Some(FinalizeState {
@@ -222,8 +511,16 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     {
         // Evaluate the commands.
         while counter < finalize.commands().len() {
+            // Update the cursor.
+            *cursor = Some((*stack.program_id(), *finalize.name(), counter));
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // If the debugger is attached, record the command.
+            if let Some(debugger) = debugger {
+                let location = DebugLocation::new(*stack.program_id(), *finalize.name(), DebugScope::Finalize, counter);
+                let (opcode, operands) = (command.opcode(), command.operands());
+                Debugger::enter(debugger, stack, &registers, location, opcode, command.to_string(), &operands)?;
+            }
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -250,6 +547,30 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
                 }
+                Command::Loop(loop_) => {
+                    let result = try_vm_runtime!(|| enter_loop(counter, loop_, finalize, stack, &mut registers));
+                    match result {
+                        Ok(Ok(new_counter)) => {
+                            counter = new_counter;
+                        }
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                }
+                Command::EndLoop(end_loop) => {
+                    let result = try_vm_runtime!(|| end_loop_to(counter, end_loop, finalize, stack, &mut registers));
+                    match result {
+                        Ok(Ok(new_counter)) => {
+                            counter = new_counter;
+                        }
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                }
                 Command::Await(await_) => {
                     // Check that the `await` register's is a locator.
                     if let Register::Access(_, _) = await_.register() {
@@ -283,6 +604,11 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                         };
 
+                    // If the debugger is attached, record the command.
+                    if let Some(debugger) = debugger {
+                        debugger.write().exit(stack, &registers, Vec::new(), None)?;
+                    }
+
                     // Increment the call counter.
                     call_counter += 1;
                     // Increment the counter.
@@ -315,7 +641,18 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
                 }
             };
+            // If the debugger is attached, record the destination registers, and the branch taken.
+            if let Some(debugger) = debugger {
+                let branch = matches!(
+                    command,
+                    Command::BranchEq(_) | Command::BranchNeq(_) | Command::Loop(_) | Command::EndLoop(_)
+                )
+                .then_some(counter);
+                debugger.write().exit(stack, &registers, command.destinations(), branch)?;
+            }
         }
+        // Update the cursor, to point past the last command.
+        *cursor = Some((*stack.program_id(), *finalize.name(), counter));
         // Check that all future registers have been awaited.
         let mut unawaited = Vec::new();
         for input in finalize.inputs() {
@@ -334,6 +671,20 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     Ok(finalize_operations)
 }
 
+/// The failure of a `finalize` scope, at the command that could not be evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeFailure<N: Network> {
+    /// The program ID of the failed scope.
+    pub program_id: ProgramID<N>,
+    /// The function name of the failed scope.
+    pub function_name: Identifier<N>,
+    /// The index of the failed command in the scope.
+    /// If the scope failed after its last command (e.g. on an unawaited future), this is the number of commands.
+    pub command_index: usize,
+    /// The error message.
+    pub error: String,
+}
+
 // A helper struct to track the execution of a finalize block.
 struct FinalizeState<'a, N: Network> {
     // A counter for the index of the commands.
@@ -443,6 +794,94 @@ fn branch_to<N: Network, const VARIANT: u8>(
     }
 }
 
+// A helper function that enters the given loop, and returns the index of the next command to evaluate.
+#[inline]
+fn enter_loop<N: Network>(
+    counter: usize,
+    loop_: &Loop<N>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<usize> {
+    // Retrieve the index of the matching `end.loop`.
+    let end_index = match finalize.loops().get(&counter) {
+        Some(Some(end_index)) => *end_index,
+        _ => bail!("The loop at index '{counter}' is not closed."),
+    };
+    // Retrieve the start and end of the counter.
+    let start = registers.load_literal(stack, loop_.start())?;
+    let end = registers.load_literal(stack, loop_.end())?;
+    // Retrieve the initial values of the loop-carried registers.
+    let values = loop_.operands().iter().map(|operand| registers.load(stack, operand)).collect::<Result<Vec<_>>>()?;
+
+    // Determine the number of iterations, ensuring it does not exceed the bound.
+    match loop_.num_iterations(&start, &end)? {
+        // If there are no iterations, store the initial values into the destinations of the `end.loop`, and skip the loop.
+        0 => {
+            let Some(Command::EndLoop(end_loop)) = finalize.commands().get(end_index) else {
+                bail!("The loop at index '{counter}' does not match an 'end.loop'.")
+            };
+            for (destination, value) in end_loop.destinations().iter().zip_eq(values) {
+                registers.store(stack, destination, value)?;
+            }
+            Ok(end_index + 1)
+        }
+        // Otherwise, store the counter and the initial values, and enter the loop.
+        _ => {
+            registers.store_literal(stack, loop_.counter(), start)?;
+            for (destination, value) in loop_.destinations().iter().zip_eq(values) {
+                registers.store(stack, destination, value)?;
+            }
+            Ok(counter + 1)
+        }
+    }
+}
+
+// A helper function that ends an iteration of a loop, and returns the index of the next command to evaluate.
+#[inline]
+fn end_loop_to<N: Network>(
+    counter: usize,
+    end_loop: &EndLoop<N>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<usize> {
+    // Retrieve the index of the matching `loop`.
+    let Some((start_index, _)) = finalize.loops().iter().find(|(_, end_index)| **end_index == Some(counter)) else {
+        bail!("The 'end.loop' at index '{counter}' does not close a loop.")
+    };
+    let Some(Command::Loop(loop_)) = finalize.commands().get(*start_index) else {
+        bail!("The 'end.loop' at index '{counter}' does not match a 'loop'.")
+    };
+    // Retrieve the next values of the loop-carried registers.
+    let values =
+        end_loop.operands().iter().map(|operand| registers.load(stack, operand)).collect::<Result<Vec<_>>>()?;
+    // Retrieve the current value and the end of the counter.
+    let current = registers.load_literal(stack, &Operand::Register(loop_.counter().clone()))?;
+    let end = registers.load_literal(stack, loop_.end())?;
+
+    // Determine the next value of the counter.
+    // Note that the number of iterations is bounded, as `loop_.num_iterations` is checked upon entering the loop.
+    match loop_.next_counter(&current, &end)? {
+        // If the counter has not reached the end, clear the registers of the iteration, and start the next iteration.
+        Some(next) => {
+            registers.rewind(loop_.counter())?;
+            registers.store_literal(stack, loop_.counter(), next)?;
+            for (destination, value) in loop_.destinations().iter().zip_eq(values) {
+                registers.store(stack, destination, value)?;
+            }
+            Ok(start_index + 1)
+        }
+        // Otherwise, store the final values into the destinations, and exit the loop.
+        None => {
+            for (destination, value) in end_loop.destinations().iter().zip_eq(values) {
+                registers.store(stack, destination, value)?;
+            }
+            Ok(counter + 1)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,7 +952,7 @@ function compute:
         let (stack, _) =
             process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
         // Add the stack *manually* to the process.
-        process.add_stack(stack);
+        process.add_stack(stack).unwrap();
 
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
@@ -21,9 +21,18 @@
 mod cost;
 pub use cost::*;
 
+mod debugger;
+pub use debugger::*;
+
+mod delegation;
+pub use delegation::*;
+
 mod stack;
 pub use stack::*;
 
+mod finalize;
+pub use finalize::*;
+
 mod trace;
 pub use trace::*;
 
@@ -34,7 +43,6 @@ mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
-mod finalize;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -52,6 +60,7 @@ use ledger_block::{Deployment, Execution, Fee, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
+    CallDynamic,
     Closure,
     Command,
     Finalize,
@@ -66,19 +75,38 @@ use synthesizer_program::{
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
-#[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The shared mapping of program IDs to stacks, used by the stacks to resolve dynamic calls.
+    dynamic_stacks: Arc<RwLock<IndexMap<ProgramID<N>, Arc<Stack<N>>>>>,
+}
+
+impl<N: Network> Clone for Process<N> {
+    /// Clones the process.
+    /// Note: The stacks are copied with a new shared mapping for dynamic calls, so that the dynamic calls
+    /// in the clone resolve against the stacks of the clone, and changes to the clone do not affect this process.
+    fn clone(&self) -> Self {
+        // Copy the stacks, along with their external stacks, against a new shared mapping.
+        let dynamic_stacks = Arc::new(RwLock::new(IndexMap::new()));
+        let mut rebound = IndexMap::with_capacity(self.stacks.len());
+        let stacks = self
+            .stacks
+            .values()
+            .map(|stack| (*stack.program_id(), stack.rebind(&dynamic_stacks, &mut rebound)))
+            .collect::<IndexMap<_, _>>();
+        *dynamic_stacks.write() = stacks.clone();
+        Self { universal_srs: self.universal_srs.clone(), stacks, dynamic_stacks }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -88,7 +116,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -107,7 +139,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Synthesize credits program keys");
 
         // Add the 'credits.aleo' stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer);
         // Return the process.
@@ -122,17 +154,88 @@ impl<N: Network> Process<N> {
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
-            self.add_stack(Stack::new(self, program)?);
+            self.add_stack(Stack::new(self, program)?)?;
         }
         Ok(())
     }
 
     /// Adds a new stack to the process.
+    /// If the stack is an upgrade of an existing program, the stacks that depend on it are reloaded.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
-    pub fn add_stack(&mut self, stack: Stack<N>) {
+    pub fn add_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        let stack = Arc::new(stack);
+        self.dynamic_stacks.write().insert(program_id, stack.clone());
+        if let Some(previous) = self.stacks.insert(program_id, stack) {
+            // Reload the stacks that depend on the upgraded program.
+            // Note: This is guaranteed to succeed for an upgrade constructed by `Stack::new_upgrade`.
+            if let Err(error) = self.reload_dependents(&program_id) {
+                // Restore the previous stack, and reload its dependents against it.
+                self.dynamic_stacks.write().insert(program_id, previous.clone());
+                self.stacks.insert(program_id, previous);
+                self.reload_dependents(&program_id)?;
+                bail!("Failed to reload the programs that depend on '{program_id}' - {error}")
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the given program from the process.
+    /// This method should **only** be used to roll back the initial deployment of a program.
+    #[inline]
+    pub fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::<N>::from_str("credits.aleo")?, "Cannot remove 'credits.aleo'");
+        // Ensure the program exists.
+        ensure!(self.contains_program(program_id), "Program '{program_id}' does not exist");
+        // Ensure no other program depends on the program.
+        if let Some(stack) = self.stacks.values().find(|stack| stack.depends_on(program_id)) {
+            bail!("Cannot remove program '{program_id}', because '{}' depends on it", stack.program_id())
+        }
+        // Remove the stack from the process.
+        self.dynamic_stacks.write().shift_remove(program_id);
+        self.stacks.shift_remove(program_id);
+        Ok(())
+    }
+
+    /// Reloads every stack that imports the given program ID, directly or transitively,
+    /// so that they reference the current stack of the given program.
+    fn reload_dependents(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Collect the program IDs of the dependents.
+        let dependents = self
+            .stacks
+            .values()
+            .filter(|stack| stack.depends_on(program_id))
+            .map(|stack| *stack.program_id())
+            .collect::<IndexSet<_>>();
+
+        // Reload the dependents, ensuring each stack is reloaded after all of its dependent imports.
+        let mut reloaded = IndexSet::with_capacity(dependents.len());
+        while reloaded.len() < dependents.len() {
+            // Find a dependent whose dependent imports have all been reloaded.
+            let next = dependents.iter().find(|id| {
+                !reloaded.contains(*id)
+                    && self.stacks.get(*id).map_or(false, |stack| {
+                        stack
+                            .program()
+                            .imports()
+                            .keys()
+                            .all(|import| !dependents.contains(import) || reloaded.contains(import))
+                    })
+            });
+            let Some(next) = next.copied() else {
+                bail!("Failed to reload the programs that depend on '{program_id}' due to a cyclic import")
+            };
+            // Reload the stack.
+            let stack = Arc::new(self.get_stack(next)?.reinitialize(self)?);
+            self.dynamic_stacks.write().insert(next, stack.clone());
+            self.stacks.insert(next, stack);
+            reloaded.insert(next);
+        }
+        Ok(())
     }
 }
 
@@ -143,7 +246,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -169,7 +276,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Load circuit keys");
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer, "Process::load");
         // Return the process.
@@ -181,7 +288,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            dynamic_stacks: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -190,7 +301,7 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         // Return the process.
         Ok(process)
@@ -286,6 +397,19 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes the circuit for the given program ID and function name, with sampled inputs,
+    /// and returns the constraint profile of each of its instructions.
+    #[inline]
+    pub fn profile_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<ConstraintProfile<N>> {
+        // Profile the circuit.
+        self.get_stack(program_id)?.profile_function::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
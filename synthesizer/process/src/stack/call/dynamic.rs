@@ -0,0 +1,366 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    program::{compute_function_id, Identifier, Literal, Locator},
+    types::U16,
+};
+use synthesizer_program::CallDynamic;
+
+/// Ensures the target of the dynamic call is a function whose inputs and outputs match the declared types.
+fn check_target<N: Network>(
+    call: &CallDynamic<N>,
+    substack: &impl StackProgram<N>,
+    locator: &Locator<N>,
+) -> Result<()> {
+    // Ensure the target is not 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.
+    let is_credits_program = &locator.program_id().to_string() == "credits.aleo";
+    let is_fee = matches!(locator.resource().to_string().as_str(), "fee_private" | "fee_public");
+    if is_credits_program && is_fee {
+        bail!("Cannot perform a dynamic call to 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.")
+    }
+    // Retrieve the function.
+    let function = substack.get_function_ref(locator.resource())?;
+    // Ensure the input types match the declared input types.
+    ensure!(
+        function.input_types() == call.input_types(),
+        "The inputs of '{locator}' do not match the declared input types of the dynamic call"
+    );
+    // Ensure the output types match the declared output types.
+    ensure!(
+        function.output_types() == call.declared_output_types(),
+        "The outputs of '{locator}' do not match the declared output types of the dynamic call"
+    );
+    Ok(())
+}
+
+/// Ensures the given request is for the target of the dynamic call.
+fn check_request<N: Network>(request: &Request<N>, locator: &Locator<N>) -> Result<()> {
+    ensure!(
+        request.program_id() == locator.program_id() && request.function_name() == locator.resource(),
+        "Expected a request for '{locator}' in a 'call.dynamic' instruction, found '{}/{}'",
+        request.program_id(),
+        request.function_name()
+    );
+    Ok(())
+}
+
+impl<N: Network> CallTrait<N> for CallDynamic<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::evaluate");
+
+        // Load the target.
+        let program_name = registers.load(stack, self.program_name())?;
+        let function_name = registers.load(stack, self.function_name())?;
+        let locator = CallDynamic::to_locator(&program_name, &function_name)?;
+
+        // Retrieve the substack, and ensure the target is valid.
+        let substack = stack.get_dynamic_stack(locator.program_id())?;
+        check_target(self, substack.as_ref(), &locator)?;
+        // Ensure the next request is for the target.
+        check_request(&registers.call_stack().peek()?, &locator)?;
+        lap!(timer, "Retrieved the substack and function");
+
+        // Evaluate the function.
+        let response = substack.evaluate_function::<A>(registers.call_stack(), Some(*stack.program_id()))?;
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in response.outputs().iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output.clone())?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSigner<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::execute");
+
+        /// Returns the field element stored in the given circuit value.
+        fn to_field<A: circuit::Aleo>(value: circuit::Value<A>) -> Result<circuit::Field<A>> {
+            match value {
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(field), _)) => Ok(field),
+                _ => bail!("Expected a field element for the target of a dynamic call"),
+            }
+        }
+
+        use circuit::{Eject, Inject};
+
+        // Load the target.
+        let program_name = to_field(registers.load_circuit(stack, self.program_name())?)?;
+        let function_name = to_field(registers.load_circuit(stack, self.function_name())?)?;
+        // Load the inputs.
+        let inputs: Vec<_> =
+            self.inputs().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // If we are not handling the root request, retrieve the root request's tvk.
+        let root_tvk = registers.root_tvk().ok();
+        // Retrieve the number of public variables in the circuit.
+        let num_public = A::num_public();
+        // Indicate that dynamic calls are never a root request.
+        let is_root = false;
+
+        // Eject the existing circuit.
+        let r1cs = A::eject_r1cs_and_reset();
+        let (request, outputs, (target_program_name, target_function_name)) = {
+            // Eject the circuit inputs.
+            let inputs = inputs.eject_value();
+            // Eject the target.
+            let to_value = |field: &circuit::Field<A>| Value::from(Literal::Field(field.eject_value()));
+            let target = CallDynamic::to_locator(&to_value(&program_name), &to_value(&function_name));
+
+            // Set the (console) caller.
+            let console_caller = Some(*stack.program_id());
+
+            match registers.call_stack() {
+                // If the circuit is in authorize mode, then add the dynamic call to the stack.
+                CallStack::Authorize(_, private_key, authorization) => {
+                    // Ensure the number of transitions remains within bounds.
+                    // Note that one transition is reserved for the fee.
+                    ensure!(
+                        authorization.len() < ledger_block::Transaction::<N>::MAX_TRANSITIONS - 1,
+                        "The dynamic call exceeds the maximum allowed number of transitions"
+                    );
+                    // Retrieve the substack, and ensure the target is valid.
+                    let locator = target?;
+                    let substack = stack.get_dynamic_stack(locator.program_id())?;
+                    check_target(self, substack.as_ref(), &locator)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *locator.program_id(),
+                        *locator.resource(),
+                        inputs.iter(),
+                        self.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+                    // Add the request to the authorization.
+                    authorization.push(request.clone());
+
+                    // Execute the request.
+                    let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request, outputs, and target.
+                    (request, response.outputs().to_vec(), CallDynamic::to_fields(&locator)?)
+                }
+                CallStack::PackageRun(requests, private_key, ..) => {
+                    // Ensure the depth of the call stack remains within bounds.
+                    ensure!(
+                        requests.len() < ledger_block::Transaction::<N>::MAX_TRANSITIONS - 1,
+                        "The dynamic call exceeds the maximum allowed number of transitions"
+                    );
+                    // Retrieve the substack, and ensure the target is valid.
+                    let locator = target?;
+                    let substack = stack.get_dynamic_stack(locator.program_id())?;
+                    check_target(self, substack.as_ref(), &locator)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *locator.program_id(),
+                        *locator.resource(),
+                        inputs.iter(),
+                        self.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Execute the request.
+                    let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request, outputs, and target.
+                    (request, response.outputs().to_vec(), CallDynamic::to_fields(&locator)?)
+                }
+                // If the circuit is in synthesize, profile, or check deployment mode, then the target is not known.
+                // Instead, compute a request and outputs of the declared types, to synthesize the circuit.
+                CallStack::Synthesize(_, private_key, ..)
+                | CallStack::Profile(_, private_key, ..)
+                | CallStack::CheckDeployment(_, private_key, ..) => {
+                    // Compute the request for a placeholder target in the current program.
+                    let request = Request::sign(
+                        &private_key,
+                        *stack.program_id(),
+                        Identifier::from_str("dynamic")?,
+                        inputs.iter(),
+                        self.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Compute the address.
+                    let address = Address::try_from(&private_key)?;
+                    // Sample dummy outputs.
+                    let outputs = self
+                        .declared_output_types()
+                        .iter()
+                        .map(|output_type| stack.sample_value(&address, output_type, rng))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    // Return the request, outputs, and target (as given in the registers).
+                    (request, outputs, (program_name.eject_value(), function_name.eject_value()))
+                }
+                // If the circuit is in evaluate mode, then throw an error.
+                CallStack::Evaluate(..) | CallStack::Debug(..) => {
+                    bail!("Cannot 'execute' a function in 'evaluate' mode.")
+                }
+                // If the circuit is in execute mode, then evaluate and execute the instructions.
+                CallStack::Execute(authorization, ..) => {
+                    // Retrieve the substack, and ensure the target is valid.
+                    let locator = target?;
+                    let substack = stack.get_dynamic_stack(locator.program_id())?;
+                    check_target(self, substack.as_ref(), &locator)?;
+
+                    // Retrieve the next request (without popping it).
+                    let request = authorization.peek_next()?;
+                    // Ensure the request is for the target.
+                    check_request(&request, &locator)?;
+                    // Ensure the inputs match the original inputs.
+                    request.inputs().iter().zip_eq(&inputs).try_for_each(|(request_input, input)| {
+                        ensure!(request_input == input, "Inputs do not match in a 'call.dynamic' instruction.");
+                        Ok(())
+                    })?;
+
+                    // Evaluate the function, and load the outputs.
+                    let console_response =
+                        substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+                    // Execute the request.
+                    let response =
+                        substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
+                    // Ensure the values are equal.
+                    if console_response.outputs() != response.outputs() {
+                        #[cfg(debug_assertions)]
+                        eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
+                        bail!("Function '{locator}' outputs do not match in a 'call.dynamic' instruction.")
+                    }
+                    // Return the request, outputs, and target.
+                    (request, response.outputs().to_vec(), CallDynamic::to_fields(&locator)?)
+                }
+            }
+        };
+        lap!(timer, "Computed the request and response");
+
+        // Inject the existing circuit.
+        A::inject_r1cs(r1cs);
+
+        // Ensure the number of public variables remains the same.
+        ensure!(A::num_public() == num_public, "Forbidden: 'call.dynamic' injected excess public variables");
+
+        // Compute the function ID of the request.
+        let function_id = compute_function_id(&U16::new(N::ID), request.program_id(), request.function_name())?;
+        // Inject the program name and function name of the target as `Mode::Public`.
+        let target_program_name = circuit::Field::<A>::new(circuit::Mode::Public, target_program_name);
+        let target_function_name = circuit::Field::<A>::new(circuit::Mode::Public, target_function_name);
+        // Ensure the target matches the registers.
+        A::assert_eq(target_program_name, &program_name);
+        A::assert_eq(target_function_name, &function_name);
+        // Inject the function ID as `Mode::Public`.
+        let function_id = circuit::Field::new(circuit::Mode::Public, function_id);
+
+        // Inject the `signer` (from the request) as `Mode::Private`.
+        let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
+        // Inject the `sk_tag` (from the request) as `Mode::Private`.
+        let sk_tag = circuit::Field::new(circuit::Mode::Private, *request.sk_tag());
+        // Inject the `tvk` (from the request) as `Mode::Private`.
+        let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
+        // Inject the `tcm` (from the request) as `Mode::Public`.
+        let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
+        // Compute the transition commitment as `Hash(tvk)`.
+        let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
+        // Ensure the transition commitment matches the computed transition commitment.
+        A::assert_eq(&tcm, candidate_tcm);
+        // Inject the input IDs (from the request) as `Mode::Public`.
+        let input_ids = request
+            .input_ids()
+            .iter()
+            .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the candidate input IDs match their computed inputs.
+        // Note: The declared types of a dynamic call never contain records, so the program ID is not required.
+        let (check_input_ids, _) = circuit::Request::check_input_ids_with_function_id::<false>(
+            &function_id,
+            None,
+            &input_ids,
+            &inputs,
+            self.input_types(),
+            &signer,
+            &sk_tag,
+            &tvk,
+            &tcm,
+            None,
+        );
+        A::assert(check_input_ids);
+        lap!(timer, "Checked the input ids");
+
+        // Inject the outputs as `Mode::Private` (with the 'tcm' and output IDs as `Mode::Public`).
+        let outputs = circuit::Response::process_outputs_from_callback_with_function_id(
+            &function_id,
+            None,
+            self.inputs().len(),
+            &tvk,
+            &tcm,
+            outputs,
+            self.declared_output_types(),
+        );
+        lap!(timer, "Checked the outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        lap!(timer, "Assigned the outputs to registers");
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dynamic;
+
 use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
@@ -226,9 +228,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
                 let console_caller = Some(*stack.program_id());
 
                 match registers.call_stack() {
-                    // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
+                    // If the circuit is in authorize, synthesize, or profile mode, then add any external calls
+                    // to the stack.
                     CallStack::Authorize(_, private_key, authorization)
-                    | CallStack::Synthesize(_, private_key, authorization) => {
+                    | CallStack::Synthesize(_, private_key, authorization)
+                    | CallStack::Profile(_, private_key, authorization, _) => {
                         // Compute the request.
                         let request = Request::sign(
                             &private_key,
@@ -346,7 +350,7 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         (request, response)
                     }
                     // If the circuit is in evaluate mode, then throw an error.
-                    CallStack::Evaluate(..) => {
+                    CallStack::Evaluate(..) | CallStack::Debug(..) => {
                         bail!("Cannot 'execute' a function in 'evaluate' mode.")
                     }
                     // If the circuit is in execute mode, then evaluate and execute the instructions.
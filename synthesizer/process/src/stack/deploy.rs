@@ -50,7 +50,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+        Deployment::new(self.edition, self.program.clone(), verifying_keys)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -68,6 +68,13 @@ impl<N: Network> Stack<N> {
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        // Ensure the edition in the stack and deployment matches.
+        ensure!(
+            self.edition == deployment.edition(),
+            "The deployment edition '{}' does not match the expected edition '{}'",
+            deployment.edition(),
+            self.edition
+        );
 
         // Check Verifying Keys //
 
@@ -36,6 +36,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Retrieve the debugger, if the call stack is in debug mode.
+        let debugger = call_stack.debugger().cloned();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition signer.
@@ -54,11 +57,21 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If the debugger is attached, record the instruction.
+            if let Some(debugger) = &debugger {
+                let location = DebugLocation::new(*self.program_id(), *closure.name(), DebugScope::Closure, index);
+                let (opcode, operands) = (instruction.opcode(), instruction.operands());
+                Debugger::enter(debugger, self, &registers, location, opcode, instruction.to_string(), operands)?;
+            }
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // If the debugger is attached, record the destination registers.
+            if let Some(debugger) = &debugger {
+                debugger.write().exit(self, &registers, instruction.destinations(), None)?;
+            }
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -84,6 +97,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
+                    // If the operand is a block state, throw an error.
+                    Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                        bail!("Cannot retrieve '{}' from a closure scope.", output.operand())
+                    }
                 }
             })
             .collect();
@@ -107,7 +124,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization) | CallStack::Debug(authorization, _) => {
+                (authorization.next()?, call_stack)
+            }
             // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
             CallStack::Execute(authorization, _) => {
@@ -118,7 +137,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 let call_stack = CallStack::Evaluate(authorization);
                 (request, call_stack)
             }
-            _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
+            _ => bail!("Illegal operation: call stack must be `Evaluate`, `Execute` or `Debug` in `evaluate_function`"),
         };
         lap!(timer, "Retrieve the next request");
 
@@ -154,6 +173,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         }
         lap!(timer, "Perform input checks");
 
+        // Retrieve the debugger, if the call stack is in debug mode.
+        let debugger = call_stack.debugger().cloned();
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
         // Set the transition signer.
@@ -168,6 +190,17 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         ensure!(request.verify(&function.input_types(), is_root), "Request is invalid");
         lap!(timer, "Verify the request");
 
+        // Ensure the program records belong to the parent.
+        //  - If this evaluation is the top-level caller, then the parent is the program ID.
+        //  - If this evaluation is a child caller, then the parent is the caller.
+        let parent = if is_root { self.program_id().to_address()? } else { caller };
+        for (input, input_type) in inputs.iter().zip_eq(&function.input_types()) {
+            if let (Value::Record(record), ValueType::ProgramRecord(..)) = (input, input_type) {
+                ensure!(**record.owner() == parent, "Input program record does not belong to the parent '{parent}'");
+            }
+        }
+        lap!(timer, "Verify the program records");
+
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
             // Assign the input value to the register.
@@ -177,11 +210,18 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If the debugger is attached, record the instruction.
+            if let Some(debugger) = &debugger {
+                let location = DebugLocation::new(*self.program_id(), *function.name(), DebugScope::Function, index);
+                let (opcode, operands) = (instruction.opcode(), instruction.operands());
+                Debugger::enter(debugger, self, &registers, location, opcode, instruction.to_string(), operands)?;
+            }
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                Instruction::CallDynamic(call) => CallTrait::evaluate(call, self, &mut registers),
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
@@ -189,6 +229,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // If the debugger is attached, record the destination registers.
+            if let Some(debugger) = &debugger {
+                debugger.write().exit(self, &registers, instruction.destinations(), None)?;
+            }
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -217,6 +261,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
                     // If the operand is the network id, throw an error.
                     Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
+                    // If the operand is a block state, throw an error.
+                    Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                        bail!("Cannot retrieve '{operand}' from a function scope.")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
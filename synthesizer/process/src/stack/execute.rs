@@ -31,8 +31,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
     ) -> Result<Vec<circuit::Value<A>>> {
         let timer = timer!("Stack::execute_closure");
 
-        // Ensure the call stack is not `Evaluate`.
-        ensure!(!matches!(call_stack, CallStack::Evaluate(..)), "Illegal operation: cannot evaluate in execute mode");
+        // Ensure the call stack is not `Evaluate` or `Debug`.
+        ensure!(
+            !matches!(call_stack, CallStack::Evaluate(..) | CallStack::Debug(..)),
+            "Illegal operation: cannot evaluate in execute mode"
+        );
 
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
@@ -66,8 +69,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the constraint profile, if the call stack is in profile mode.
+        let profile = registers.call_stack().profile().cloned();
+
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -75,8 +81,17 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
+            // If the circuit is in profile mode, then start the profile of the instruction.
+            if let Some(profile) = &profile {
+                let counts = CircuitCounts::current::<A>();
+                profile.write().enter(*self.program_id(), *closure.name(), index, instruction.to_string(), counts)?;
+            }
             // Execute the instruction.
             instruction.execute(self, &mut registers)?;
+            // If the circuit is in profile mode, then finish the profile of the instruction.
+            if let Some(profile) = &profile {
+                profile.write().exit(CircuitCounts::current::<A>())?;
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -119,6 +134,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a closure scope")
                     }
+                    // If the operand is a block state, throw an error.
+                    Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                        bail!("Illegal operation: cannot retrieve '{}' in a closure scope", output.operand())
+                    }
                 }
             })
             .collect();
@@ -207,6 +226,16 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
         lap!(timer, "Verify the console request");
 
+        // Ensure the program records belong to the parent.
+        for (input, input_type) in console_request.inputs().iter().zip_eq(&input_types) {
+            if let (Value::Record(record), ValueType::ProgramRecord(..)) = (input, input_type) {
+                ensure!(
+                    **record.owner() == console_parent,
+                    "Input program record does not belong to the parent '{console_parent}'"
+                );
+            }
+        }
+
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
 
@@ -240,6 +269,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         A::assert(request.verify(&input_types, &tpk, root_tvk, is_root));
         lap!(timer, "Verify the circuit request");
 
+        // Ensure the program records belong to the parent, as only the parent program may authorize their spend.
+        for (input, input_type) in request.inputs().iter().zip_eq(&input_types) {
+            if let (circuit::Value::Record(record), ValueType::ProgramRecord(..)) = (input, input_type) {
+                A::assert((**record.owner()).is_equal(&parent));
+            }
+        }
+
         // Set the transition signer.
         registers.set_signer(*console_request.signer());
         // Set the transition signer, as a circuit.
@@ -266,6 +302,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the number of public variables in the circuit.
         let num_public = A::num_public();
 
+        // Retrieve the constraint profile, if the call stack is in profile mode.
+        let profile = registers.call_stack().profile().cloned();
+        // If the circuit is in profile mode, then start the profile of the function.
+        if let Some(profile) = &profile {
+            profile.write().start_function(*self.program_id(), *function.name(), CircuitCounts::current::<A>());
+        }
+
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(request.inputs()).try_for_each(|(register, input)| {
             // If the circuit is in execute mode, then store the console input.
@@ -282,13 +325,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    Instruction::CallDynamic(call) => CallTrait::evaluate(call, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -298,10 +342,17 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
+            // If the circuit is in profile mode, then start the profile of the instruction.
+            if let Some(profile) = &profile {
+                let counts = CircuitCounts::current::<A>();
+                profile.write().enter(*self.program_id(), *function.name(), index, instruction.to_string(), counts)?;
+            }
+
             // Execute the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                Instruction::CallDynamic(call) => CallTrait::execute(call, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
@@ -309,13 +360,18 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
+            // If the circuit is in profile mode, then finish the profile of the instruction.
+            if let Some(profile) = &profile {
+                profile.write().exit(CircuitCounts::current::<A>())?;
+            }
 
             // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
+            match instruction {
                 // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
-                }
+                Instruction::Call(call) if call.is_function_call(self)? => contains_function_call = true,
+                // A dynamic call is always a function call.
+                Instruction::CallDynamic(..) => contains_function_call = true,
+                _ => {}
             }
         }
         lap!(timer, "Execute the instructions");
@@ -354,6 +410,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::NetworkID => {
                         bail!("Illegal operation: cannot retrieve the network id in a function scope")
                     }
+                    // If the operand is a block state, throw an error.
+                    Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                        bail!("Illegal operation: cannot retrieve '{operand}' in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -404,6 +464,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Complete");
 
+        // If the circuit is in profile mode, then finish the profile of the function.
+        if let Some(profile) = &profile {
+            profile.write().finish_function(CircuitCounts::current::<A>())?;
+        }
+
         // Eject the response.
         let response = response.eject_value();
 
@@ -45,6 +45,19 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::NetworkID => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID)))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the previous block hash, load the previous block hash.
+            Operand::BlockPreviousHash => {
+                let previous_block_hash = Field::<N>::from_bytes_le(self.state.previous_block_hash())?;
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(previous_block_hash))));
+            }
+            // If the operand is the block epoch, load the puzzle epoch number of the block.
+            Operand::BlockEpoch => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_epoch::<N>())))));
+            }
         };
 
         // Retrieve the value.
@@ -12,14 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod debug;
 mod load;
 mod store;
 
-use crate::FinalizeTypes;
+use crate::{FinalizeTypes, RegistersDebug};
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
-    types::{U16, U32},
+    types::{Field, I64, U16, U32},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -60,6 +61,23 @@ impl<N: Network> FinalizeRegisters<N> {
     ) -> Self {
         Self { state, transition_id, finalize_types, function_name, registers: IndexMap::new(), last_register: None }
     }
+
+    /// Clears the given register and all registers assigned after it, so that they may be assigned again.
+    /// This is used to start a new iteration of a loop, where `register` is the counter of the loop.
+    #[inline]
+    pub fn rewind(&mut self, register: &Register<N>) -> Result<()> {
+        match register {
+            Register::Locator(locator) => {
+                // Clear the register and all registers after it.
+                self.registers.retain(|assigned, _| assigned < locator);
+                // Update the last register locator.
+                self.last_register = locator.checked_sub(1);
+                Ok(())
+            }
+            // Ensure the register is not a register access.
+            Register::Access(..) => bail!("Cannot rewind to a register access: '{register}'"),
+        }
+    }
 }
 
 impl<N: Network> FinalizeRegistersState<N> for FinalizeRegisters<N> {
@@ -49,9 +49,9 @@ impl<N: Network> FinalizeTypes<N> {
         let mut consumed_futures = HashSet::new();
 
         // Step 2. Check the commands are well-formed. Make sure all the input futures are awaited.
-        for command in finalize.commands() {
+        for (index, command) in finalize.commands().iter().enumerate() {
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize, command)?;
+            finalize_types.check_command(stack, finalize, index, command)?;
 
             // If the command is an `await`, add the future to the set of consumed futures.
             if let Command::Await(await_) = command {
@@ -73,6 +73,32 @@ impl<N: Network> FinalizeTypes<N> {
             )
         }
 
+        // Step 3. Check the loops are well-formed.
+        for (start, end) in finalize.loops() {
+            // Ensure the loop is closed.
+            ensure!(end.is_some(), "Command '{}' in '{}' is not closed.", finalize.commands()[*start], finalize.name());
+        }
+        // A helper to retrieve the index of the innermost loop enclosing the given command, if any.
+        let get_enclosing_loop = |index: usize| {
+            finalize
+                .loops()
+                .iter()
+                .filter(|(start, end)| **start < index && end.map_or(false, |end| index <= end))
+                .map(|(start, _)| *start)
+                .last()
+        };
+        // Ensure each branch jumps to a position within the same loop, so that it cannot enter or exit a loop.
+        for (index, command) in finalize.commands().iter().enumerate() {
+            if let Some(position) = command.branch_to() {
+                // Note: `check_branch` ensures that the position is defined.
+                let target = finalize.positions().get(position).copied().unwrap_or_default();
+                ensure!(
+                    get_enclosing_loop(index) == get_enclosing_loop(target),
+                    "Command '{command}' cannot branch into or out of a loop."
+                );
+            }
+        }
+
         Ok(finalize_types)
     }
 }
@@ -142,6 +168,9 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
+            FinalizeType::Plaintext(PlaintextType::ExternalStruct(locator)) => {
+                RegisterTypes::check_external_struct(stack, locator)?
+            }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
             FinalizeType::Future(..) => (),
         };
@@ -163,6 +192,7 @@ impl<N: Network> FinalizeTypes<N> {
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
         finalize: &Finalize<N>,
+        index: usize,
         command: &Command<N>,
     ) -> Result<()> {
         match command {
@@ -178,6 +208,10 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::Loop(loop_) => self.check_loop(stack, loop_)?,
+            Command::EndLoop(end_loop) => self.check_end_loop(stack, finalize, index, end_loop)?,
+            Command::Length(length) => self.check_length(stack, length)?,
+            Command::KeyAt(key_at) => self.check_key_at(stack, key_at)?,
         }
         Ok(())
     }
@@ -241,6 +275,126 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `loop` command is well-formed.
+    /// Note that the bound is checked when constructing `Finalize`.
+    #[inline]
+    fn check_loop(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), loop_: &Loop<N>) -> Result<()> {
+        // A helper to retrieve the integer type of the given operand.
+        let get_integer_type = |operand: &Operand<N>| match self.get_type_from_operand(stack, operand)? {
+            FinalizeType::Plaintext(PlaintextType::Literal(
+                literal_type @ (LiteralType::I8
+                | LiteralType::I16
+                | LiteralType::I32
+                | LiteralType::I64
+                | LiteralType::I128
+                | LiteralType::U8
+                | LiteralType::U16
+                | LiteralType::U32
+                | LiteralType::U64
+                | LiteralType::U128),
+            )) => Ok(literal_type),
+            finalize_type => {
+                bail!("Command '{}' expects an integer operand, found '{finalize_type}'", Loop::<N>::opcode())
+            }
+        };
+        // Retrieve the types of the start and end of the counter.
+        let start_type = get_integer_type(loop_.start())?;
+        let end_type = get_integer_type(loop_.end())?;
+        // Ensure the start and end of the counter have the same type.
+        ensure!(
+            start_type == end_type,
+            "Command '{}' expects operands of the same type. Found operands of type '{start_type}' and '{end_type}'",
+            Loop::<N>::opcode()
+        );
+
+        // Ensure the number of initial values matches the number of loop-carried registers.
+        ensure!(
+            loop_.operands().len() == loop_.destinations().len(),
+            "Command '{}' expects as many initial values as loop-carried registers",
+            Loop::<N>::opcode()
+        );
+        // Retrieve the types of the initial values.
+        let carried_types = loop_
+            .operands()
+            .iter()
+            .map(|operand| match self.get_type_from_operand(stack, operand)? {
+                // If the operand is a plaintext type, return it.
+                FinalizeType::Plaintext(plaintext_type) => Ok(FinalizeType::Plaintext(plaintext_type)),
+                // If the operand is a future, throw an error.
+                FinalizeType::Future(..) => bail!("A future cannot be carried by a `loop` command"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Get the counter register.
+        let counter = loop_.counter().clone();
+        // Ensure the counter register is a locator (and does not reference an access).
+        ensure!(matches!(counter, Register::Locator(..)), "Destination '{counter}' must be a locator.");
+        // Insert the counter register.
+        self.add_destination(counter, FinalizeType::Plaintext(PlaintextType::Literal(start_type)))?;
+
+        // Insert the loop-carried registers.
+        for (destination, carried_type) in loop_.destinations().iter().zip_eq(carried_types) {
+            // Ensure the destination register is a locator (and does not reference an access).
+            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            // Insert the destination register.
+            self.add_destination(destination.clone(), carried_type)?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `end.loop` command is well-formed.
+    #[inline]
+    fn check_end_loop(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize: &Finalize<N>,
+        index: usize,
+        end_loop: &EndLoop<N>,
+    ) -> Result<()> {
+        // Retrieve the matching `loop` command.
+        let loop_ = match finalize.loops().iter().find(|(_, end)| **end == Some(index)) {
+            Some((start, _)) => match finalize.commands().get(*start) {
+                Some(Command::Loop(loop_)) => loop_,
+                _ => bail!("Command '{}' does not match a `loop` command", EndLoop::<N>::opcode()),
+            },
+            None => bail!("Command '{}' does not close a loop", EndLoop::<N>::opcode()),
+        };
+
+        // Ensure the number of next values matches the number of loop-carried registers.
+        ensure!(
+            end_loop.operands().len() == loop_.destinations().len()
+                && end_loop.destinations().len() == loop_.destinations().len(),
+            "Command '{}' expects {} operands and destinations",
+            EndLoop::<N>::opcode(),
+            loop_.destinations().len()
+        );
+        // Ensure the next values match the types of the loop-carried registers.
+        let carried_types = loop_
+            .destinations()
+            .iter()
+            .zip_eq(end_loop.operands())
+            .map(|(register, operand)| {
+                let carried_type = self.get_type(stack, register)?;
+                let operand_type = self.get_type_from_operand(stack, operand)?;
+                ensure!(
+                    carried_type == operand_type,
+                    "Command '{}' expects '{operand}' to be of type '{carried_type}', found '{operand_type}'",
+                    EndLoop::<N>::opcode()
+                );
+                Ok(carried_type)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Insert the destination registers.
+        for (destination, carried_type) in end_loop.destinations().iter().zip_eq(carried_types) {
+            // Ensure the destination register is a locator (and does not reference an access).
+            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            // Insert the destination register.
+            self.add_destination(destination.clone(), carried_type)?;
+        }
+        Ok(())
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -284,7 +438,7 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
+        let mapping_key_type = Self::qualify_mapping_type(contains.mapping(), mapping.key().plaintext_type());
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, contains.key())? {
             // If the register is a plaintext type, return it.
@@ -293,7 +447,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `contains` command"),
         };
         // Check that the key type in the mapping matches the key type in the instruction.
-        if *mapping_key_type != key_type {
+        if stack.matches_plaintext_type(&key_type, &mapping_key_type).is_err() {
             bail!(
                 "Key type in `contains` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
             )
@@ -307,6 +461,106 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `length` command is well-formed.
+    #[inline]
+    fn check_length(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), length: &Length<N>) -> Result<()> {
+        // Ensure the mapping is defined.
+        match length.mapping() {
+            CallOperator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+            }
+            CallOperator::Resource(mapping_name) => {
+                // Ensure the declared mapping in `length` is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+            }
+        }
+        // Get the destination register.
+        let destination = length.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
+    /// Ensures the given `key.at` command is well-formed.
+    #[inline]
+    fn check_key_at(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), key_at: &KeyAt<N>) -> Result<()> {
+        // Retrieve the mapping.
+        let mapping = match key_at.mapping() {
+            CallOperator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+                // Retrieve the mapping from the program.
+                external.get_mapping(mapping_name)?
+            }
+            CallOperator::Resource(mapping_name) => {
+                // Ensure the declared mapping in `key.at` is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)?
+            }
+        };
+
+        // Retrieve the register type of the index.
+        match self.get_type_from_operand(stack, key_at.index())? {
+            // Ensure the index is a `u32`.
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => (),
+            FinalizeType::Plaintext(plaintext_type) => {
+                bail!("Index type in `key.at` '{plaintext_type}' must be 'u32'.")
+            }
+            // If the register is a future, throw an error.
+            FinalizeType::Future(..) => bail!("A future cannot be used as an index in a `key.at` command"),
+        }
+        // Get the destination register.
+        let destination = key_at.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register, with the mapping key type.
+        let mapping_key_type = Self::qualify_mapping_type(key_at.mapping(), mapping.key().plaintext_type());
+        self.add_destination(destination, FinalizeType::Plaintext(mapping_key_type))?;
+        Ok(())
+    }
+
     /// Ensures the given `get` command is well-formed.
     #[inline]
     fn check_get(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), get: &Get<N>) -> Result<()> {
@@ -346,9 +600,9 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
+        let mapping_key_type = Self::qualify_mapping_type(get.mapping(), mapping.key().plaintext_type());
         // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
+        let mapping_value_type = Self::qualify_mapping_type(get.mapping(), mapping.value().plaintext_type());
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get.key())? {
             // If the register is a plaintext type, return it.
@@ -357,7 +611,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get` command"),
         };
         // Check that the key type in the mapping matches the key type in the instruction.
-        if *mapping_key_type != key_type {
+        if stack.matches_plaintext_type(&key_type, &mapping_key_type).is_err() {
             bail!("Key type in `get` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
         }
         // Get the destination register.
@@ -365,7 +619,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference an access).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, FinalizeType::Plaintext(mapping_value_type.clone()))?;
+        self.add_destination(destination, FinalizeType::Plaintext(mapping_value_type))?;
         Ok(())
     }
 
@@ -412,9 +666,9 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
+        let mapping_key_type = Self::qualify_mapping_type(get_or_use.mapping(), mapping.key().plaintext_type());
         // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
+        let mapping_value_type = Self::qualify_mapping_type(get_or_use.mapping(), mapping.value().plaintext_type());
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get_or_use.key())? {
             // If the register is a plaintext type, return it.
@@ -423,7 +677,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get.or_use` command"),
         };
         // Check that the key type in the mapping matches the key type.
-        if *mapping_key_type != key_type {
+        if stack.matches_plaintext_type(&key_type, &mapping_key_type).is_err() {
             bail!(
                 "Key type in `get.or_use` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
             )
@@ -436,7 +690,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A default value cannot be a future"),
         };
         // Check that the value type in the mapping matches the default value type.
-        if mapping_value_type != &default_value_type {
+        if stack.matches_plaintext_type(&default_value_type, &mapping_value_type).is_err() {
             bail!(
                 "Default value type in `get.or_use` '{default_value_type}' does not match the value type in the mapping '{mapping_value_type}'."
             )
@@ -508,7 +762,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `set` command"),
         };
         // Check that the key type in the mapping matches the key type.
-        if *mapping_key_type != key_type {
+        if stack.matches_plaintext_type(&key_type, mapping_key_type).is_err() {
             bail!("Key type in `set` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
         }
         // Retrieve the type of the value.
@@ -519,7 +773,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a value in a `set` command"),
         };
         // Check that the value type in the mapping matches the type of the value.
-        if mapping_value_type != &value_type {
+        if stack.matches_plaintext_type(&value_type, mapping_value_type).is_err() {
             bail!(
                 "Value type in `set` '{value_type}' does not match the value type in the mapping '{mapping_value_type}'."
             )
@@ -552,7 +806,7 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `remove` command"),
         };
         // Check that the key type in the mapping matches the key type.
-        if *mapping_key_type != key_type {
+        if stack.matches_plaintext_type(&key_type, mapping_key_type).is_err() {
             bail!("Key type in `remove` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
         }
         Ok(())
@@ -636,6 +890,9 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
+            Opcode::CallDynamic => {
+                bail!("Instruction 'call.dynamic' is not allowed in 'finalize'");
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -667,6 +924,11 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure the operand types match the struct.
                             self.matches_struct(stack, instruction.operands(), struct_)?;
                         }
+                        CastType::Plaintext(PlaintextType::ExternalStruct(locator)) => {
+                            // Ensure the external struct is defined in the imported program.
+                            // Note: The operand types are checked against the struct members in `output_types`.
+                            RegisterTypes::check_external_struct(stack, locator)?;
+                        }
                         CastType::Plaintext(PlaintextType::Array(array_type)) => {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
@@ -727,10 +989,30 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Verify(opcode) => match opcode {
+                "ecdsa.verify.secp256k1" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifySecp256k1(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "ed25519.verify" => ensure!(
+                    matches!(instruction, Instruction::Ed25519Verify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
 
+    /// Returns the given key or value type of the mapping, in the context of the current program.
+    /// If the mapping is declared in an imported program, its struct types are qualified by the program ID.
+    fn qualify_mapping_type(mapping: &CallOperator<N>, plaintext_type: &PlaintextType<N>) -> PlaintextType<N> {
+        match mapping {
+            CallOperator::Locator(locator) => plaintext_type.qualify(locator.program_id()),
+            CallOperator::Resource(..) => plaintext_type.clone(),
+        }
+    }
+
     // TODO (howardwu & d0cd): Reimplement this for cast and cast.lossy.
     // /// Checks the cast operation is well-formed.
     // fn check_cast_operation<const VARIANT: u8>(
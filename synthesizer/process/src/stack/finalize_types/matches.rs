@@ -64,7 +64,7 @@ impl<N: Network> FinalizeTypes<N> {
                     };
                     // Ensure the register type matches the member type.
                     ensure!(
-                        &plaintext_type == member_type,
+                        stack.matches_plaintext_type(&plaintext_type, member_type).is_ok(),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{plaintext_type}' in the operand '{operand}'.",
                     )
                 }
@@ -106,6 +106,36 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the previous block hash type (field) matches the member type.
+                Operand::BlockPreviousHash => {
+                    // Retrieve the previous block hash type.
+                    let block_previous_hash_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the previous block hash type matches the member type.
+                    ensure!(
+                        &block_previous_hash_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_previous_hash_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the block epoch type (u32) matches the member type.
+                Operand::BlockEpoch => {
+                    // Retrieve the block epoch type.
+                    let block_epoch_type = PlaintextType::Literal(LiteralType::U32);
+                    // Ensure the block epoch type matches the member type.
+                    ensure!(
+                        &block_epoch_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_epoch_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -156,7 +186,7 @@ impl<N: Network> FinalizeTypes<N> {
                     };
                     // Ensure the register type matches the member type.
                     ensure!(
-                        &plaintext_type == array_type.next_element_type(),
+                        stack.matches_plaintext_type(&plaintext_type, array_type.next_element_type()).is_ok(),
                         "Array element expects {}, but found '{plaintext_type}' in the operand '{operand}'.",
                         array_type.next_element_type()
                     )
@@ -198,6 +228,39 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the previous block hash type (field) matches the member type.
+                Operand::BlockPreviousHash => {
+                    // Retrieve the previous block hash type.
+                    let block_previous_hash_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the previous block hash type matches the member type.
+                    ensure!(
+                        &block_previous_hash_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_previous_hash_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the block epoch type (u32) matches the member type.
+                Operand::BlockEpoch => {
+                    // Retrieve the block epoch type.
+                    let block_epoch_type = PlaintextType::Literal(LiteralType::U32);
+                    // Ensure the block epoch type matches the member type.
+                    ensure!(
+                        &block_epoch_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_epoch_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -38,12 +38,17 @@ use synthesizer_program::{
     CallOperator,
     CastType,
     Command,
+    CommandTrait,
     Contains,
+    EndLoop,
     Finalize,
     Get,
     GetOrUse,
     Instruction,
     InstructionTrait,
+    KeyAt,
+    Length,
+    Loop,
     Opcode,
     Operand,
     Program,
@@ -104,6 +109,9 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
             Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::BlockPreviousHash => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            Operand::BlockEpoch => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
         })
     }
 
@@ -146,6 +154,9 @@ impl<N: Network> FinalizeTypes<N> {
             }
         };
 
+        // Track the stack of the program declaring the current type, as accesses may traverse into imported programs.
+        let mut context = stack;
+
         // Traverse the path to find the register type.
         for access in path.iter() {
             match (&finalize_type, access) {
@@ -156,13 +167,27 @@ impl<N: Network> FinalizeTypes<N> {
                 // Access the member on the path to output the register type.
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct and check that it exists.
-                    match stack.program().get_struct(struct_name)?.members().get(identifier) {
+                    match context.program().get_struct(struct_name)?.members().get(identifier) {
                         // Retrieve the member and update `finalize_type` for the next iteration.
                         Some(member_type) => finalize_type = FinalizeRefType::Plaintext(member_type),
                         // Halts if the member does not exist.
                         None => bail!("'{identifier}' does not exist in struct '{struct_name}'"),
                     }
                 }
+                // Access the member on the path to output the register type.
+                (FinalizeRefType::Plaintext(PlaintextType::ExternalStruct(locator)), Access::Member(identifier)) => {
+                    // Switch to the context of the program declaring the external struct.
+                    if locator.program_id() != context.program_id() {
+                        context = &**context.get_external_stack(locator.program_id())?;
+                    }
+                    // Retrieve the member type from the external struct and check that it exists.
+                    match context.program().get_struct(locator.resource())?.members().get(identifier) {
+                        // Retrieve the member and update `finalize_type` for the next iteration.
+                        Some(member_type) => finalize_type = FinalizeRefType::Plaintext(member_type),
+                        // Halts if the member does not exist.
+                        None => bail!("'{identifier}' does not exist in struct '{locator}'"),
+                    }
+                }
                 // Access the member on the path to output the register type and check that it is in bounds.
                 (FinalizeRefType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
                     match index < array_type.length() {
@@ -175,10 +200,12 @@ impl<N: Network> FinalizeTypes<N> {
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (FinalizeRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
-                    let function = match locator.program_id() == stack.program_id() {
-                        true => stack.get_function_ref(locator.resource())?,
+                    let function = match locator.program_id() == context.program_id() {
+                        true => context.get_function_ref(locator.resource())?,
                         false => {
-                            stack.get_external_program(locator.program_id())?.get_function_ref(locator.resource())?
+                            // Switch to the context of the program declaring the function.
+                            context = &**context.get_external_stack(locator.program_id())?;
+                            context.get_function_ref(locator.resource())?
                         }
                     };
                     // Retrieve the finalize inputs.
@@ -200,6 +227,7 @@ impl<N: Network> FinalizeTypes<N> {
                     }
                 }
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                | (FinalizeRefType::Plaintext(PlaintextType::ExternalStruct(..)), Access::Index(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
                 | (FinalizeRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
@@ -209,7 +237,11 @@ impl<N: Network> FinalizeTypes<N> {
 
         // Return the output type.
         Ok(match finalize_type {
-            FinalizeRefType::Plaintext(plaintext_type) => FinalizeType::Plaintext(plaintext_type.clone()),
+            // If the type is declared in an imported program, qualify any struct types by its program ID.
+            FinalizeRefType::Plaintext(plaintext_type) => match context.program_id() == stack.program_id() {
+                true => FinalizeType::Plaintext(plaintext_type.clone()),
+                false => FinalizeType::Plaintext(plaintext_type.qualify(context.program_id())),
+            },
             FinalizeRefType::Future(locator) => FinalizeType::Future(*locator),
         })
     }
@@ -22,14 +22,17 @@ impl<N: Network> Stack<N> {
         let mut stack = Self {
             program: program.clone(),
             external_stacks: Default::default(),
+            dynamic_stacks: Arc::downgrade(&process.dynamic_stacks),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
             number_of_calls: Default::default(),
+            dynamic_functions: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
+            edition: N::EDITION,
         };
 
         // Add all the imports into the stack.
@@ -60,19 +63,36 @@ impl<N: Network> Stack<N> {
             // Add the function to the stack.
             stack.insert_function(function)?;
             // Determine the number of calls for the function.
+            // Note: For a function that invokes a dynamic call, this is the minimum number of calls,
+            // as the calls made by the target of a dynamic call are only known at runtime.
             let mut num_calls = 1;
+            let mut is_dynamic = false;
             for instruction in function.instructions() {
-                if let Instruction::Call(call) = instruction {
+                match instruction {
                     // Determine if this is a function call.
-                    if call.is_function_call(&stack)? {
+                    Instruction::Call(call) if call.is_function_call(&stack)? => {
                         // Increment by the number of calls.
-                        num_calls += match call.operator() {
-                            CallOperator::Locator(locator) => stack
-                                .get_external_stack(locator.program_id())?
-                                .get_number_of_calls(locator.resource())?,
-                            CallOperator::Resource(resource) => stack.get_number_of_calls(resource)?,
+                        let (callee_calls, callee_is_dynamic) = match call.operator() {
+                            CallOperator::Locator(locator) => {
+                                let external_stack = stack.get_external_stack(locator.program_id())?;
+                                (
+                                    external_stack.get_number_of_calls(locator.resource())?,
+                                    external_stack.contains_dynamic_calls(locator.resource()),
+                                )
+                            }
+                            CallOperator::Resource(resource) => {
+                                (stack.get_number_of_calls(resource)?, stack.contains_dynamic_calls(resource))
+                            }
                         };
+                        num_calls += callee_calls;
+                        is_dynamic |= callee_is_dynamic;
                     }
+                    // A dynamic call invokes at least one function.
+                    Instruction::CallDynamic(..) => {
+                        num_calls += 1;
+                        is_dynamic = true;
+                    }
+                    _ => {}
                 }
             }
             // Check that the number of calls does not exceed the maximum.
@@ -83,6 +103,10 @@ impl<N: Network> Stack<N> {
             );
             // Add the number of calls to the stack.
             stack.number_of_calls.insert(*function.name(), num_calls);
+            // Track the function, if it invokes a dynamic call.
+            if is_dynamic {
+                stack.dynamic_functions.insert(*function.name());
+            }
 
             // Get the finalize cost.
             let finalize_cost = cost_in_microcredits(&stack, function.name())?;
@@ -96,6 +120,22 @@ impl<N: Network> Stack<N> {
             stack.finalize_costs.insert(*function.name(), finalize_cost);
         }
 
+        // Add the program constructor to the stack, if it exists.
+        if let Some(constructor) = program.constructor() {
+            // Add the constructor to the stack.
+            stack.insert_constructor(constructor)?;
+
+            // Get the constructor cost.
+            let constructor_cost = constructor_cost_in_microcredits(&stack)?;
+            // Check that the constructor cost does not exceed the maximum.
+            ensure!(
+                constructor_cost <= N::TRANSACTION_SPEND_LIMIT,
+                "The constructor has a cost '{constructor_cost}' which exceeds the transaction spend limit '{}'",
+                N::TRANSACTION_SPEND_LIMIT
+            );
+            stack.finalize_costs.insert(*constructor.name(), constructor_cost);
+        }
+
         // Return the stack.
         Ok(stack)
     }
@@ -158,4 +198,20 @@ impl<N: Network> Stack<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the finalize types of the given constructor to the stack.
+    #[inline]
+    fn insert_constructor(&mut self, constructor: &Constructor<N>) -> Result<()> {
+        // Retrieve the constructor name.
+        let name = constructor.name();
+        // Ensure the constructor name is not already added.
+        ensure!(!self.finalize_types.contains_key(name), "Constructor '{name}' already exists");
+
+        // Compute the finalize types.
+        let finalize_types = FinalizeTypes::from_finalize(self, constructor.finalize_logic())?;
+        // Add the constructor name and finalize types to the stack.
+        self.finalize_types.insert(*name, finalize_types);
+        // Return success.
+        Ok(())
+    }
 }
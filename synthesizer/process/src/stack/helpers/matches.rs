@@ -24,7 +24,10 @@ impl<N: Network> StackMatches<N> for Stack<N> {
             | (Value::Plaintext(plaintext), ValueType::Private(plaintext_type)) => {
                 self.matches_plaintext(plaintext, plaintext_type)
             }
-            (Value::Record(record), ValueType::Record(record_name)) => self.matches_record(record, record_name),
+            (Value::Record(record), ValueType::Record(record_name))
+            | (Value::Record(record), ValueType::ProgramRecord(record_name)) => {
+                self.matches_record(record, record_name)
+            }
             (Value::Record(record), ValueType::ExternalRecord(locator)) => {
                 self.matches_external_record(record, locator)
             }
@@ -92,6 +95,11 @@ impl<N: Network> StackMatches<N> for Stack<N> {
         self.matches_plaintext_internal(plaintext, plaintext_type, 0)
     }
 
+    /// Checks that the candidate plaintext type is structurally equivalent to the expected plaintext type.
+    fn matches_plaintext_type(&self, candidate: &PlaintextType<N>, expected: &PlaintextType<N>) -> Result<()> {
+        self.matches_plaintext_type_internal(candidate, self, expected, 0)
+    }
+
     /// Checks that the given future matches the layout of the future type.
     fn matches_future(&self, future: &Future<N>, locator: &Locator<N>) -> Result<()> {
         self.matches_future_internal(future, locator, 0)
@@ -260,6 +268,12 @@ impl<N: Network> Stack<N> {
 
                 Ok(())
             }
+            PlaintextType::ExternalStruct(locator) => {
+                // Retrieve the stack declaring the struct.
+                let (stack, _) = self.resolve_struct(plaintext_type)?;
+                // Ensure the plaintext matches the struct in its declaring program.
+                stack.matches_plaintext_internal(plaintext, &PlaintextType::Struct(*locator.resource()), depth)
+            }
             PlaintextType::Array(array_type) => match plaintext {
                 // If `plaintext` is a literal, this is a mismatch.
                 Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected array, found literal"),
@@ -284,6 +298,95 @@ impl<N: Network> Stack<N> {
         }
     }
 
+    /// Checks that the candidate plaintext type, declared in this stack, is structurally equivalent
+    /// to the expected plaintext type, declared in the given stack.
+    fn matches_plaintext_type_internal(
+        &self,
+        candidate: &PlaintextType<N>,
+        expected_stack: &Stack<N>,
+        expected: &PlaintextType<N>,
+        depth: usize,
+    ) -> Result<()> {
+        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext type exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        match (candidate, expected) {
+            (PlaintextType::Literal(candidate_type), PlaintextType::Literal(expected_type)) => {
+                // Ensure the literal types match.
+                ensure!(candidate_type == expected_type, "Expected '{expected_type}', found '{candidate_type}'");
+                Ok(())
+            }
+            (PlaintextType::Array(candidate_array), PlaintextType::Array(expected_array)) => {
+                // Ensure the array lengths match.
+                ensure!(
+                    candidate_array.length() == expected_array.length(),
+                    "Expected '{expected}', found '{candidate}'"
+                );
+                // Ensure the element types match (recursive call).
+                self.matches_plaintext_type_internal(
+                    candidate_array.next_element_type(),
+                    expected_stack,
+                    expected_array.next_element_type(),
+                    depth + 1,
+                )
+            }
+            (
+                PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..),
+                PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..),
+            ) => {
+                // Retrieve the declaring stacks and struct definitions.
+                let (candidate_stack, candidate_struct) = self.resolve_struct(candidate)?;
+                let (expected_stack, expected_struct) = expected_stack.resolve_struct(expected)?;
+
+                // Ensure the struct names match.
+                ensure!(
+                    candidate_struct.name() == expected_struct.name(),
+                    "Expected struct '{expected}', found struct '{candidate}'"
+                );
+                // Ensure the number of struct members match.
+                ensure!(
+                    candidate_struct.members().len() == expected_struct.members().len(),
+                    "Struct '{candidate}' does not have the same number of members as '{expected}'"
+                );
+                // Ensure the struct members match, in the same order.
+                for ((candidate_name, candidate_type), (expected_name, expected_type)) in
+                    candidate_struct.members().iter().zip_eq(expected_struct.members())
+                {
+                    // Ensure the member name matches.
+                    ensure!(
+                        candidate_name == expected_name,
+                        "Member '{candidate_name}' in '{candidate}' does not match member '{expected_name}' in '{expected}'"
+                    );
+                    // Ensure the member type matches (recursive call).
+                    candidate_stack.matches_plaintext_type_internal(
+                        candidate_type,
+                        expected_stack,
+                        expected_type,
+                        depth + 1,
+                    )?;
+                }
+                Ok(())
+            }
+            _ => bail!("Expected '{expected}', found '{candidate}'"),
+        }
+    }
+
+    /// Returns the stack declaring the given struct type, along with the struct definition.
+    pub(crate) fn resolve_struct(&self, struct_type: &PlaintextType<N>) -> Result<(&Stack<N>, &StructType<N>)> {
+        match struct_type {
+            PlaintextType::Struct(struct_name) => Ok((self, self.program().get_struct(struct_name)?)),
+            PlaintextType::ExternalStruct(locator) if locator.program_id() == self.program_id() => {
+                Ok((self, self.program().get_struct(locator.resource())?))
+            }
+            PlaintextType::ExternalStruct(locator) => {
+                // Retrieve the external stack.
+                let external_stack = self.get_external_stack(locator.program_id())?;
+                Ok((external_stack, external_stack.program().get_struct(locator.resource())?))
+            }
+            PlaintextType::Literal(..) | PlaintextType::Array(..) => bail!("'{struct_type}' is not a struct type"),
+        }
+    }
+
     /// Checks that the given future matches the layout of the future type.
     fn matches_future_internal(&self, future: &Future<N>, locator: &Locator<N>, depth: usize) -> Result<()> {
         // If the depth exceeds the maximum depth, then the future type is invalid.
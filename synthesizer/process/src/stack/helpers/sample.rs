@@ -136,6 +136,13 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Struct(members, Default::default())
             }
+            // Sample an external struct.
+            PlaintextType::ExternalStruct(locator) => {
+                // Retrieve the stack declaring the struct.
+                let (stack, _) = self.resolve_struct(plaintext_type)?;
+                // Sample the struct from its declaring program.
+                stack.sample_plaintext_internal(&PlaintextType::Struct(*locator.resource()), depth, rng)?
+            }
             // Sample an array.
             PlaintextType::Array(array_type) => {
                 // Sample each element of the array.
@@ -27,6 +27,76 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        // Sample a burner private key and a request for the function.
+        let (burner_private_key, request) = self.sample_request(function_name, rng)?;
+        // The `root_tvk` is `None` when deploying an individual circuit.
+        let root_tvk = None;
+        // The caller is `None` when deploying an individual circuit.
+        let caller = None;
+
+        // Initialize the authorization.
+        let authorization = Authorization::new(request.clone());
+        // Initialize the call stack.
+        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
+
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Synthesizes the circuit for the given function name, with sampled inputs,
+    /// and returns the constraint profile of each of its instructions.
+    #[inline]
+    pub fn profile_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<ConstraintProfile<N>> {
+        // Sample a burner private key and a request for the function.
+        let (burner_private_key, request) = self.sample_request(function_name, rng)?;
+        // Initialize the authorization.
+        let authorization = Authorization::new(request.clone());
+        // Initialize the constraint profile.
+        let profile = Arc::new(RwLock::new(ConstraintProfile::new()));
+        // Initialize the call stack.
+        let call_stack = CallStack::Profile(vec![request], burner_private_key, authorization, profile.clone());
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, None, None, rng)?;
+        // Return the constraint profile.
+        let profile = profile.read().clone();
+        Ok(profile)
+    }
+
+    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
+    #[inline]
+    pub fn synthesize_from_assignment(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // If the proving and verifying key already exist, skip the synthesis for this function.
+        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
+            return Ok(());
+        }
+
+        // Synthesize the proving and verifying key.
+        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // Insert the proving key.
+        self.insert_proving_key(function_name, proving_key)?;
+        // Insert the verifying key.
+        self.insert_verifying_key(function_name, verifying_key)
+    }
+
+    /// Samples a burner private key, and a request for the given function name that is signed by it.
+    fn sample_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(PrivateKey<N>, Request<N>)> {
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -55,9 +125,6 @@ impl<N: Network> Stack<N> {
         // The `root_tvk` is `None` when deploying an individual circuit.
         let root_tvk = None;
 
-        // The caller is `None` when deploying an individual circuit.
-        let caller = None;
-
         // Compute the request, with a burner private key.
         let request = Request::sign(
             &burner_private_key,
@@ -69,37 +136,6 @@ impl<N: Network> Stack<N> {
             is_root,
             rng,
         )?;
-        // Initialize the authorization.
-        let authorization = Authorization::new(request.clone());
-        // Initialize the call stack.
-        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
-        // Synthesize the circuit.
-        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
-        Ok(())
-    }
-
-    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
-    #[inline]
-    pub fn synthesize_from_assignment(
-        &self,
-        function_name: &Identifier<N>,
-        assignment: &circuit::Assignment<N::Field>,
-    ) -> Result<()> {
-        // If the proving and verifying key already exist, skip the synthesis for this function.
-        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
-            return Ok(());
-        }
-
-        // Synthesize the proving and verifying key.
-        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
-        // Insert the proving key.
-        self.insert_proving_key(function_name, proving_key)?;
-        // Insert the verifying key.
-        self.insert_verifying_key(function_name, verifying_key)
+        Ok((burner_private_key, request))
     }
 }
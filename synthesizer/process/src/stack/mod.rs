@@ -36,7 +36,19 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
+use crate::{
+    constructor_cost_in_microcredits,
+    cost_in_microcredits,
+    traits::*,
+    CallMetrics,
+    CircuitCounts,
+    ConstraintProfile,
+    DebugLocation,
+    DebugScope,
+    Debugger,
+    Process,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -58,19 +70,20 @@ use console::{
         RegisterType,
         Request,
         Response,
+        StructType,
         Value,
         ValueType,
     },
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
+use synthesizer_program::{traits::*, CallOperator, Closure, Constructor, Function, Instruction, Operand, Program};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -81,9 +94,11 @@ pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
+    Profile(Vec<Request<N>>, PrivateKey<N>, Authorization<N>, Arc<RwLock<ConstraintProfile<N>>>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>, Option<u64>),
     Evaluate(Authorization<N>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
+    Debug(Authorization<N>, Arc<RwLock<Debugger<N>>>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
 }
 
@@ -97,6 +112,27 @@ impl<N: Network> CallStack<N> {
     pub fn execute(authorization: Authorization<N>, trace: Arc<RwLock<Trace<N>>>) -> Result<Self> {
         Ok(CallStack::Execute(authorization, trace))
     }
+
+    /// Initializes a call stack as `Self::Debug`.
+    pub fn debug(authorization: Authorization<N>, debugger: Arc<RwLock<Debugger<N>>>) -> Result<Self> {
+        Ok(CallStack::Debug(authorization, debugger))
+    }
+
+    /// Returns the constraint profile, if the call stack is in `Self::Profile` mode.
+    pub fn profile(&self) -> Option<&Arc<RwLock<ConstraintProfile<N>>>> {
+        match self {
+            CallStack::Profile(.., profile) => Some(profile),
+            _ => None,
+        }
+    }
+
+    /// Returns the debugger, if the call stack is in `Self::Debug` mode.
+    pub fn debugger(&self) -> Option<&Arc<RwLock<Debugger<N>>>> {
+        match self {
+            CallStack::Debug(_, debugger) => Some(debugger),
+            _ => None,
+        }
+    }
 }
 
 impl<N: Network> CallStack<N> {
@@ -109,6 +145,12 @@ impl<N: Network> CallStack<N> {
             CallStack::Synthesize(requests, private_key, authorization) => {
                 CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate())
             }
+            CallStack::Profile(requests, private_key, authorization, profile) => CallStack::Profile(
+                requests.clone(),
+                *private_key,
+                authorization.replicate(),
+                Arc::new(RwLock::new(profile.read().clone())),
+            ),
             CallStack::CheckDeployment(requests, private_key, assignments, constraint_limit, variable_limit) => {
                 CallStack::CheckDeployment(
                     requests.clone(),
@@ -122,6 +164,8 @@ impl<N: Network> CallStack<N> {
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
+            // Note: The debugger is shared with the replica, so that it traces the replica as well.
+            CallStack::Debug(authorization, debugger) => CallStack::Debug(authorization.replicate(), debugger.clone()),
             CallStack::PackageRun(requests, private_key, assignments) => {
                 CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
             }
@@ -133,10 +177,12 @@ impl<N: Network> CallStack<N> {
         match self {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
+            | CallStack::Profile(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
             CallStack::Evaluate(authorization) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
+            CallStack::Debug(authorization, ..) => authorization.push(request),
         }
         Ok(())
     }
@@ -146,12 +192,14 @@ impl<N: Network> CallStack<N> {
         match self {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
+            | CallStack::Profile(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
+            CallStack::Debug(authorization, ..) => authorization.next(),
         }
     }
 
@@ -160,12 +208,14 @@ impl<N: Network> CallStack<N> {
         match self {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
+            | CallStack::Profile(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
+            CallStack::Debug(authorization, ..) => authorization.peek_next(),
         }
     }
 }
@@ -176,6 +226,8 @@ pub struct Stack<N: Network> {
     program: Program<N>,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The shared mapping of program IDs to stacks in the process, used to resolve dynamic calls.
+    dynamic_stacks: Weak<RwLock<IndexMap<ProgramID<N>, Arc<Stack<N>>>>>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
@@ -188,10 +240,14 @@ pub struct Stack<N: Network> {
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
+    /// The set of function names that invoke a dynamic call, directly or transitively.
+    dynamic_functions: IndexSet<Identifier<N>>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
     /// The program depth.
     program_depth: usize,
+    /// The program edition.
+    edition: u16,
 }
 
 impl<N: Network> Stack<N> {
@@ -218,6 +274,123 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Stack::initialize(process, program)
     }
+
+    /// Initializes a new stack for an upgrade of an existing program, given the process and the new edition of the program.
+    #[inline]
+    pub fn new_upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Retrieve the stack of the previous edition.
+        let previous = process.get_stack(program_id)?;
+        // Ensure the program is a valid upgrade of the previous edition.
+        program.check_upgrade_of(previous.program())?;
+        // Compute the edition.
+        let Some(edition) = previous.edition().checked_add(1) else {
+            bail!("Program '{program_id}' has exceeded the maximum number of upgrades")
+        };
+        // Ensure the program contains functions.
+        ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
+
+        // Ensure the upgrade does not introduce a cyclic import.
+        for import in program.imports().keys() {
+            ensure!(
+                !process.get_stack(import)?.depends_on(program_id),
+                "Program '{program_id}' cannot import '{import}', because '{import}' depends on '{program_id}'"
+            );
+        }
+
+        // Serialize the program into bytes.
+        let program_bytes = program.to_bytes_le()?;
+        // Ensure the program deserializes from bytes correctly.
+        ensure!(program == &Program::from_bytes_le(&program_bytes)?, "Program byte serialization failed");
+
+        // Serialize the program into string.
+        let program_string = program.to_string();
+        // Ensure the program deserializes from a string correctly.
+        ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
+
+        // Initialize the stack.
+        let mut stack = Stack::initialize(process, program)?;
+        stack.edition = edition;
+
+        // Ensure the programs that depend on this program remain well-formed after the upgrade.
+        let mut upgraded_process = process.clone();
+        // Copy the stack against the stacks of the upgraded process, so that its calls resolve within it.
+        let mut rebound = upgraded_process.stacks.clone();
+        rebound.shift_remove(program_id);
+        let upgraded_stack = stack.rebind(&upgraded_process.dynamic_stacks, &mut rebound);
+        upgraded_process.dynamic_stacks.write().insert(*program_id, upgraded_stack.clone());
+        upgraded_process.stacks.insert(*program_id, upgraded_stack);
+        upgraded_process.reload_dependents(program_id)?;
+
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Re-initializes the stack against the current stacks of its imports in the given process.
+    /// The proving keys, verifying keys, and edition of the stack are preserved.
+    #[inline]
+    pub(crate) fn reinitialize(&self, process: &Process<N>) -> Result<Self> {
+        // Initialize the stack.
+        let mut stack = Stack::initialize(process, &self.program)?;
+        // Preserve the circuit keys and edition.
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        stack.edition = self.edition;
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Returns a copy of the stack, and of its external stacks, that resolves dynamic calls against
+    /// the given shared mapping. The copied stacks are memoized in `rebound` by program ID.
+    /// The proving keys and verifying keys are shared with the original stack.
+    pub(crate) fn rebind(
+        &self,
+        dynamic_stacks: &Arc<RwLock<IndexMap<ProgramID<N>, Arc<Stack<N>>>>>,
+        rebound: &mut IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    ) -> Arc<Stack<N>> {
+        // If the stack has already been copied, return the copy.
+        if let Some(stack) = rebound.get(self.program_id()) {
+            return stack.clone();
+        }
+        // Copy the external stacks.
+        let external_stacks = self
+            .external_stacks
+            .values()
+            .map(|external_stack| (*external_stack.program_id(), external_stack.rebind(dynamic_stacks, rebound)))
+            .collect();
+        // Copy the stack.
+        let stack =
+            Arc::new(Self { external_stacks, dynamic_stacks: Arc::downgrade(dynamic_stacks), ..self.clone() });
+        rebound.insert(*self.program_id(), stack.clone());
+        stack
+    }
+
+    /// Sets the edition of the stack.
+    /// This method should **only** be used when loading a previously-upgraded program from storage.
+    #[inline]
+    pub(crate) fn set_edition(&mut self, edition: u16) {
+        self.edition = edition;
+    }
+
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns `true` if the function invokes a dynamic call, directly or transitively.
+    /// If so, the number of calls for the function is a lower bound.
+    #[inline]
+    pub fn contains_dynamic_calls(&self, function_name: &Identifier<N>) -> bool {
+        self.dynamic_functions.contains(function_name)
+    }
+
+    /// Returns `true` if the program imports the given program ID, directly or transitively.
+    #[inline]
+    pub fn depends_on(&self, program_id: &ProgramID<N>) -> bool {
+        self.external_stacks.iter().any(|(id, stack)| id == program_id || stack.depends_on(program_id))
+    }
 }
 
 impl<N: Network> StackProgram<N> for Stack<N> {
@@ -258,6 +431,20 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         self.external_stacks.get(program_id).ok_or_else(|| anyhow!("External program '{program_id}' does not exist."))
     }
 
+    /// Returns the stack for the given program ID, as resolved at runtime by a dynamic call.
+    #[inline]
+    fn get_dynamic_stack(&self, program_id: &ProgramID<N>) -> Result<Arc<Stack<N>>> {
+        // Ensure the dynamic call does not target the main program.
+        ensure!(self.program.id() != program_id, "A dynamic call cannot target the main program '{program_id}'");
+        // Retrieve the shared mapping of stacks.
+        let Some(stacks) = self.dynamic_stacks.upgrade() else {
+            bail!("Cannot resolve the dynamic call to '{program_id}', as the process no longer exists")
+        };
+        // Retrieve the stack.
+        let stack = stacks.read().get(program_id).cloned();
+        stack.ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))
+    }
+
     /// Returns the external program for the given program ID.
     #[inline]
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>> {
@@ -328,6 +515,11 @@ impl<N: Network> StackProgram<N> for Stack<N> {
                 Ok(Value::Record(stack.sample_record(burner_address, locator.resource(), Group::rand(rng), rng)?))
             }
             ValueType::Future(locator) => Ok(Value::Future(self.sample_future(locator, rng)?)),
+            ValueType::ProgramRecord(record_name) => {
+                // Note: A program record is sampled as owned by this program, as the parent of a top-level call.
+                let program_address = self.program_id().to_address()?;
+                Ok(Value::Record(self.sample_record(&program_address, record_name, Group::rand(rng), rng)?))
+            }
         }
     }
 
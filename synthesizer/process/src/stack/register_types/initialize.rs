@@ -37,6 +37,11 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the closure contains no dynamic call instructions.
+            ensure!(
+                instruction.opcode() != Opcode::CallDynamic,
+                "A 'call.dynamic' instruction is not allowed in closures"
+            );
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -107,6 +112,10 @@ impl<N: Network> RegisterTypes<N> {
                     // Ensure the `call` instruction precedes any `async` instruction.
                     ensure!(async_.is_none(), "The 'call' can only be invoked before an 'async' instruction")
                 }
+                Opcode::CallDynamic => {
+                    // Ensure the `call.dynamic` instruction precedes any `async` instruction.
+                    ensure!(async_.is_none(), "The 'call.dynamic' can only be invoked before an 'async' instruction")
+                }
                 _ => {}
             }
         }
@@ -124,6 +133,11 @@ impl<N: Network> RegisterTypes<N> {
 
         let mut num_futures = 0;
         for output in function.outputs() {
+            // Ensure the output is not a program record, as a program record can only be consumed.
+            ensure!(
+                !matches!(output.value_type(), ValueType::ProgramRecord(..)),
+                "Program record outputs are not supported"
+            );
             // Check the output operand type.
             register_types.check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))?;
             // Additional validation.
@@ -266,6 +280,9 @@ impl<N: Network> RegisterTypes<N> {
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
+            RegisterType::Plaintext(PlaintextType::ExternalStruct(locator)) => {
+                Self::check_external_struct(stack, locator)?
+            }
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
@@ -318,6 +335,9 @@ impl<N: Network> RegisterTypes<N> {
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
+            RegisterType::Plaintext(PlaintextType::ExternalStruct(locator)) => {
+                Self::check_external_struct(stack, locator)?
+            }
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
@@ -340,11 +360,20 @@ impl<N: Network> RegisterTypes<N> {
             }
         };
 
+        // Retrieve the operand type.
+        let operand_type = self.get_type_from_operand(stack, operand)?;
         // Ensure the operand type and the output type match.
-        if *register_type != self.get_type_from_operand(stack, operand)? {
+        let is_match = match (&operand_type, register_type) {
+            // Plaintext types match if they are structurally equivalent.
+            (RegisterType::Plaintext(candidate), RegisterType::Plaintext(expected)) => {
+                stack.matches_plaintext_type(candidate, expected).is_ok()
+            }
+            _ => operand_type == *register_type,
+        };
+        if !is_match {
             bail!(
                 "Output '{operand}' does not match the expected output operand type: expected '{}', found '{}'",
-                self.get_type_from_operand(stack, operand)?,
+                operand_type,
                 register_type
             )
         }
@@ -497,6 +526,15 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
+            Opcode::CallDynamic => {
+                // Ensure the instruction is a dynamic call operation.
+                // Note: The declared input and output types are checked in `CallDynamic::output_types`,
+                // and the target is resolved and checked against them at runtime.
+                ensure!(
+                    matches!(instruction, Instruction::CallDynamic(..)),
+                    "Instruction '{instruction}' is not a dynamic call operation."
+                );
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -528,6 +566,11 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the struct.
                             self.matches_struct(stack, instruction.operands(), struct_)?;
                         }
+                        CastType::Plaintext(PlaintextType::ExternalStruct(locator)) => {
+                            // Ensure the external struct is defined in the imported program.
+                            // Note: The operand types are checked against the struct members in `output_types`.
+                            RegisterTypes::check_external_struct(stack, locator)?;
+                        }
                         CastType::Plaintext(PlaintextType::Array(array_type)) => {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
@@ -595,6 +638,17 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Verify(opcode) => match opcode {
+                "ecdsa.verify.secp256k1" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifySecp256k1(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "ed25519.verify" => ensure!(
+                    matches!(instruction, Instruction::Ed25519Verify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
@@ -663,23 +717,42 @@ impl<N: Network> RegisterTypes<N> {
             match member {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
+                PlaintextType::ExternalStruct(locator) => Self::check_external_struct(stack, locator)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
             }
         }
         Ok(())
     }
 
+    /// Ensures the external struct exists in the imported program, and recursively-checks its members.
+    pub(crate) fn check_external_struct(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        locator: &Locator<N>,
+    ) -> Result<()> {
+        // Retrieve the stack of the imported program.
+        let Ok(external_stack) = stack.get_external_stack(locator.program_id()) else {
+            bail!("External program '{}' in '{}' is not imported.", locator.program_id(), stack.program_id())
+        };
+        // Ensure the struct is defined in the imported program.
+        Self::check_struct(&**external_stack, locator.resource())
+    }
+
     /// Ensure the base element type of the array is defined in the program.
     pub(crate) fn check_array(
         stack: &(impl StackMatches<N> + StackProgram<N>),
         array_type: &ArrayType<N>,
     ) -> Result<()> {
-        // If the base element type is a struct, check that it is defined in the program.
-        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
-            // Ensure the struct is defined in the program.
-            if !stack.program().contains_struct(struct_name) {
-                bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+        match array_type.base_element_type() {
+            // If the base element type is a struct, check that it is defined in the program.
+            PlaintextType::Struct(struct_name) => {
+                // Ensure the struct is defined in the program.
+                if !stack.program().contains_struct(struct_name) {
+                    bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                }
             }
+            // If the base element type is an external struct, check that it is defined in the imported program.
+            PlaintextType::ExternalStruct(locator) => Self::check_external_struct(stack, locator)?,
+            PlaintextType::Literal(..) | PlaintextType::Array(..) => (),
         }
         Ok(())
     }
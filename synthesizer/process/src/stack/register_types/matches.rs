@@ -68,7 +68,7 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the register type matches the member type.
                         RegisterType::Plaintext(type_) => {
                             ensure!(
-                                &type_ == member_type,
+                                stack.matches_plaintext_type(&type_, member_type).is_ok(),
                                 "Struct entry '{struct_name}.{member_name}' expects a '{member_type}', but found '{type_}' in the operand '{operand}'.",
                             )
                         }
@@ -92,6 +92,10 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::NetworkID => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a network ID in a non-finalize scope"
                 ),
+                // If the operand is a block state type, throw an error.
+                Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from '{operand}' in a non-finalize scope"
+                ),
             }
         }
         Ok(())
@@ -146,7 +150,7 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the register type matches the element type.
                         RegisterType::Plaintext(type_) => {
                             ensure!(
-                                &type_ == array_type.next_element_type(),
+                                stack.matches_plaintext_type(&type_, array_type.next_element_type()).is_ok(),
                                 "Array element expects a '{}', but found '{type_}' in the operand '{operand}'.",
                                 array_type.next_element_type()
                             )
@@ -168,6 +172,10 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
                 // If the operand is a network ID type, throw an error.
                 Operand::NetworkID => bail!("Array element cannot be from a network ID in a non-finalize scope"),
+                // If the operand is a block state type, throw an error.
+                Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                    bail!("Array element cannot be from '{operand}' in a non-finalize scope")
+                }
             }
         }
         Ok(())
@@ -233,6 +241,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::NetworkID => {
                 bail!("Forbidden operation: Cannot cast a network ID as a record owner")
             }
+            Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                bail!("Forbidden operation: Cannot cast '{}' as a record owner", operands[0])
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -294,6 +305,12 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a network ID in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is a block state.
+                        Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block state in the operand '{operand}'."
+                            )
+                        }
                     }
                 }
             }
@@ -99,6 +99,9 @@ impl<N: Network> RegisterTypes<N> {
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
             Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp | Operand::BlockPreviousHash | Operand::BlockEpoch => {
+                bail!("'{operand}' is not a valid operand in a non-finalize context.")
+            }
         })
     }
 
@@ -142,6 +145,9 @@ impl<N: Network> RegisterTypes<N> {
         // A literal address type.
         let literal_address_type = PlaintextType::Literal(LiteralType::Address);
 
+        // Track the stack of the program declaring the current type, as accesses may traverse into imported programs.
+        let mut context = stack;
+
         // Because the register is an access, the accessed type must be a plaintext type.
         // We perform a single access, if the register type is a record.
         // This is done to minimize the number of `clone` operations and simplify the code.
@@ -174,6 +180,8 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::ExternalRecord(locator) => {
                 // Ensure the external record type exists.
                 ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
+                // Switch to the context of the program declaring the external record.
+                context = &**stack.get_external_stack(locator.program_id())?;
                 // Retrieve the first access.
                 // Note: this unwrap is safe since the path is checked to be non-empty above.
                 let access = path_iter.next().unwrap();
@@ -209,13 +217,26 @@ impl<N: Network> RegisterTypes<N> {
                 // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct.
-                    match stack.program().get_struct(struct_name)?.members().get(identifier) {
+                    match context.program().get_struct(struct_name)?.members().get(identifier) {
                         // Update the member type.
                         Some(member_type) => register_type = RegisterRefType::Plaintext(member_type),
                         None => bail!("'{identifier}' does not exist in struct '{struct_name}'"),
                     }
                 }
                 // Traverse the path to output the register type.
+                (RegisterRefType::Plaintext(PlaintextType::ExternalStruct(locator)), Access::Member(identifier)) => {
+                    // Switch to the context of the program declaring the external struct.
+                    if locator.program_id() != context.program_id() {
+                        context = &**context.get_external_stack(locator.program_id())?;
+                    }
+                    // Retrieve the member type from the external struct.
+                    match context.program().get_struct(locator.resource())?.members().get(identifier) {
+                        // Update the member type.
+                        Some(member_type) => register_type = RegisterRefType::Plaintext(member_type),
+                        None => bail!("'{identifier}' does not exist in struct '{locator}'"),
+                    }
+                }
+                // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
                     match index < array_type.length() {
                         true => register_type = RegisterRefType::Plaintext(array_type.next_element_type()),
@@ -225,10 +246,12 @@ impl<N: Network> RegisterTypes<N> {
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (RegisterRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
-                    let function = match locator.program_id() == stack.program_id() {
-                        true => stack.get_function_ref(locator.resource())?,
+                    let function = match locator.program_id() == context.program_id() {
+                        true => context.get_function_ref(locator.resource())?,
                         false => {
-                            stack.get_external_program(locator.program_id())?.get_function_ref(locator.resource())?
+                            // Switch to the context of the program declaring the function.
+                            context = &**context.get_external_stack(locator.program_id())?;
+                            context.get_function_ref(locator.resource())?
                         }
                     };
                     // Retrieve the finalize inputs.
@@ -250,6 +273,7 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
                 (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                | (RegisterRefType::Plaintext(PlaintextType::ExternalStruct(..)), Access::Index(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
                 | (RegisterRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
@@ -259,7 +283,11 @@ impl<N: Network> RegisterTypes<N> {
 
         // Output the register type.
         Ok(match register_type {
-            RegisterRefType::Plaintext(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
+            // If the type is declared in an imported program, qualify any struct types by its program ID.
+            RegisterRefType::Plaintext(plaintext_type) => match context.program_id() == stack.program_id() {
+                true => RegisterType::Plaintext(plaintext_type.clone()),
+                false => RegisterType::Plaintext(plaintext_type.qualify(context.program_id())),
+            },
             RegisterRefType::Future(locator) => RegisterType::Future(*locator),
         })
     }
@@ -14,10 +14,11 @@
 
 mod call;
 mod caller;
+mod debug;
 mod load;
 mod store;
 
-use crate::{CallStack, RegisterTypes, RegistersCall};
+use crate::{CallStack, RegisterTypes, RegistersCall, RegistersDebug};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
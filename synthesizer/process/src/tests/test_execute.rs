@@ -15,6 +15,10 @@
 use crate::{
     traits::{StackEvaluate, StackExecute},
     CallStack,
+    DebugAction,
+    DebugLocation,
+    DebugScope,
+    Debugger,
     Process,
     Stack,
     Trace,
@@ -35,7 +39,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, KeyAt, Program, StackProgram};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -47,7 +51,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -1038,6 +1042,229 @@ function compute:
     // assert_eq!(159387, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_program_record() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program escrow.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function release:
+    input r0 as token.record.program;
+    input r1 as address.private;
+    cast r1 r0.amount into r2 as token.record;
+    output r2 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("release").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Prepare a record belonging to the program address.
+    let program_address = program.id().to_address().unwrap();
+    let record_string =
+        format!("{{ owner: {program_address}.private, amount: 100u64.private, _nonce: 0group.public }}");
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&record_string).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1.clone()].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+    let request = authorization.peek_next().unwrap();
+
+    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+    let randomizer = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(2)]).unwrap();
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+
+    // Declare the expected output value.
+    let r2 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+    ))
+    .unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [r2.clone()]);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [r2]);
+
+    // Prepare a record belonging to the caller, instead of the program.
+    let record_string = format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}");
+    let r0 = Value::<CurrentNetwork>::from_str(&record_string).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Ensure the program does not authorize spending a record it does not own.
+    assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+    assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+}
+
+#[test]
+fn test_process_execute_external_struct() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program geometry.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+mapping origins:
+    key as address.public;
+    value as point.public;
+
+function make:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    cast r0 r1 into r2 as point;
+    output r2 as point.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import geometry.aleo;
+
+program canvas.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+struct segment:
+    start as geometry.aleo/point;
+    end as geometry.aleo/point;
+
+mapping origins:
+    key as address.public;
+    value as point.public;
+
+function draw:
+    input r0 as geometry.aleo/point.public;
+    input r1 as u32.public;
+    call geometry.aleo/make r1 r1 into r2;
+    cast r0 r2 into r3 as segment;
+    cast r3.end.x r0.y into r4 as geometry.aleo/point;
+    cast r4.x r4.y into r5 as point;
+    output r3 as segment.public;
+    output r4 as geometry.aleo/point.public;
+    output r5 as point.public;
+    output r2 as point.public;
+
+function copy:
+    input r0 as address.public;
+    async copy r0 into r1;
+    output r1 as canvas.aleo/copy.future;
+
+finalize copy:
+    input r0 as address.public;
+    get geometry.aleo/origins[r0] into r1;
+    cast r1.y r1.x into r2 as geometry.aleo/point;
+    set r2 into origins[r0];",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("draw").unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("{ x: 1u32, y: 2u32 }").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u32").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Declare the expected output values.
+    let expected = [
+        Value::from_str("{ start: { x: 1u32, y: 2u32 }, end: { x: 3u32, y: 3u32 } }").unwrap(),
+        Value::from_str("{ x: 3u32, y: 2u32 }").unwrap(),
+        Value::from_str("{ x: 3u32, y: 2u32 }").unwrap(),
+        Value::from_str("{ x: 3u32, y: 3u32 }").unwrap(),
+    ];
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure a struct with the same name but different members is not equivalent to the external struct.
+    let program2 = Program::<CurrentNetwork>::from_str(
+        r"
+import geometry.aleo;
+
+program mismatch.aleo;
+
+struct point:
+    y as u32;
+    x as u32;
+
+function convert:
+    input r0 as geometry.aleo/point.public;
+    output r0 as point.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program2).is_err());
+
+    // Ensure an external struct must reference an imported program.
+    let result = Program::<CurrentNetwork>::from_str(
+        r"
+program unknown.aleo;
+
+struct line:
+    start as geometry.aleo/point;
+
+function noop:",
+    );
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_process_execute_call_external_function() {
     // Initialize a new program.
@@ -1170,53 +1397,596 @@ function transfer:
     // Check again to make sure we didn't modify the authorization after calling `evaluate`.
     assert_eq!(authorization.len(), 5);
 
-    // Execute the request.
-    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(2, candidate.len());
-    assert_eq!(output_a, candidate[0]);
-    assert_eq!(output_b, candidate[1]);
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(output_a, candidate[0]);
+    assert_eq!(output_b, candidate[1]);
+
+    // use circuit::Environment;
+    //
+    // assert_eq!(6427, CurrentAleo::num_constants());
+    // assert_eq!(8, CurrentAleo::num_public());
+    // assert_eq!(21264, CurrentAleo::num_private());
+    // assert_eq!(21279, CurrentAleo::num_constraints());
+    // assert_eq!(81872, CurrentAleo::num_gates());
+    //
+    // assert_eq!(18504, CurrentAleo::num_constants());
+    // assert_eq!(17, CurrentAleo::num_public());
+    // assert_eq!(58791, CurrentAleo::num_private());
+    // assert_eq!(58855, CurrentAleo::num_constraints());
+    // assert_eq!(215810, CurrentAleo::num_gates());
+}
+
+#[test]
+fn test_process_execute_call_dynamic() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+function double:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+
+function negate:
+    input r0 as i64.public;
+    neg r0 into r1;
+    output r1 as i64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program, which does not import the first program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+program router.aleo;
+
+function route:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as u64.public;
+    call.dynamic r0 r1 with r2 as u64.public into r3 as u64.public;
+    output r3 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Ensure the number of calls is a lower bound.
+    let function_name = Identifier::from_str("route").unwrap();
+    let stack = process.get_stack(program1.id()).unwrap();
+    assert_eq!(stack.get_number_of_calls(&function_name).unwrap(), 2);
+    assert!(stack.contains_dynamic_calls(&function_name));
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program1.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns the given identifier as a field element.
+    let to_field = |name: &str| {
+        Value::<CurrentNetwork>::from(Literal::Field(
+            Identifier::<CurrentNetwork>::from_str(name).unwrap().to_field().unwrap(),
+        ))
+    };
+
+    // Declare the input value.
+    let r2 = Value::<CurrentNetwork>::from_str("21u64").unwrap();
+
+    // Ensure a target whose types do not match the declared types fails.
+    let inputs = [to_field("token"), to_field("negate"), r2.clone()];
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .is_err());
+    // Ensure a target in the same program fails.
+    let inputs = [to_field("router"), to_field("route"), r2.clone()];
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .is_err());
+
+    // Authorize the function call.
+    let inputs = [to_field("token"), to_field("double"), r2];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Compute the output value.
+    let expected = Value::from_str("42u64").unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [expected.clone()]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), [expected]);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("router", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_clone_call_dynamic_upgrade() {
+    // Initialize a new upgradable program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+upgrade owner;
+
+function double:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program, which calls the first program dynamically.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+program router.aleo;
+
+function route:
+    input r0 as field.public;
+    input r1 as field.public;
+    input r2 as u64.public;
+    call.dynamic r0 r1 with r2 as u64.public into r3 as u64.public;
+    output r3 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Upgrade the first program in a clone of the process.
+    let (string, upgrade) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+upgrade owner;
+
+function double:
+    input r0 as u64.public;
+    mul r0 3u64 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+    let mut cloned_process = process.clone();
+    let stack = Stack::new_upgrade(&cloned_process, &upgrade).unwrap();
+    cloned_process.add_stack(stack).unwrap();
+    assert_eq!(cloned_process.get_stack(program0.id()).unwrap().edition(), 1);
+    assert_eq!(process.get_stack(program0.id()).unwrap().edition(), 0);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns the given identifier as a field element.
+    let to_field = |name: &str| {
+        Value::<CurrentNetwork>::from(Literal::Field(
+            Identifier::<CurrentNetwork>::from_str(name).unwrap().to_field().unwrap(),
+        ))
+    };
+    let inputs = [to_field("token"), to_field("double"), Value::from_str("21u64").unwrap()];
+    let function_name = Identifier::from_str("route").unwrap();
+
+    // Evaluates the dynamic call in the given process.
+    let mut evaluate = |process: &Process<CurrentNetwork>| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        process.evaluate::<CurrentAleo>(authorization).unwrap().outputs().to_vec()
+    };
+
+    // Ensure the dynamic call resolves to the upgrade in the clone, and to the first edition in the original.
+    assert_eq!(evaluate(&cloned_process), [Value::from_str("63u64").unwrap()]);
+    assert_eq!(evaluate(&process), [Value::from_str("42u64").unwrap()]);
+}
+
+#[test]
+fn test_process_execute_and_finalize_get_add_set() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the account balance is now 8.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_loop() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping totals:
+    key as address.public;
+    value as u64.public;
+
+mapping entries:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u8.public;
+    async compute r0 r1 into r2;
+    output r2 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u8.public;
+    loop r2 in 0u8 to r1 bound 8u32 with 0u64 into r3;
+        cast r2 into r4 as u64;
+        add r3 r4 into r5;
+        set r5 into entries[r2];
+    end.loop r5 into r6;
+    set r6 into totals[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Check that the finalize cost of the loop body is scaled by the bound.
+    let stack = process.get_stack(program_id).unwrap();
+    let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+    let costs = finalize
+        .commands()
+        .iter()
+        .map(|command| crate::cost_per_command(stack, finalize, command).unwrap())
+        .collect::<Vec<_>>();
+    let expected_cost = costs[0] + 8 * (costs[1] + costs[2] + costs[3] + costs[4]) + costs[5];
+    assert_eq!(stack.get_finalize_cost(&function_name).unwrap(), expected_cost);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Executes and finalizes the function with the given number of iterations.
+    let mut execute_and_finalize = |num_iterations: &str| {
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str(num_iterations).unwrap();
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        // Finalize the execution.
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None)
+    };
+
+    // A helper to retrieve the total of the caller.
+    let get_total = || {
+        finalize_store
+            .get_value_speculative(
+                *program_id,
+                Identifier::from_str("totals").unwrap(),
+                &Plaintext::from(Literal::Address(caller)),
+            )
+            .unwrap()
+    };
+
+    // Check that the loop carries the running sum across iterations.
+    execute_and_finalize("5u8").unwrap();
+    assert_eq!(get_total(), Some(Value::from_str("10u64").unwrap()));
+    let candidate = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("entries").unwrap(),
+            &Plaintext::from_str("3u8").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(candidate, Some(Value::from_str("6u64").unwrap()));
+
+    // Check that a loop without iterations stores the initial values.
+    execute_and_finalize("0u8").unwrap();
+    assert_eq!(get_total(), Some(Value::from_str("0u64").unwrap()));
+
+    // Check that a loop exceeding its bound fails.
+    assert!(execute_and_finalize("9u8").is_err());
+    assert_eq!(get_total(), Some(Value::from_str("0u64").unwrap()));
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_state() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as i64.public;
+
+mapping hashes:
+    key as u8.public;
+    value as field.public;
+
+mapping epochs:
+    key as u8.public;
+    value as u32.public;
+
+function record_state:
+    async record_state into r0;
+    output r0 as testing.aleo/record_state.future;
+
+finalize record_state:
+    set block.timestamp into timestamps[0u8];
+    set block.previous_hash into hashes[0u8];
+    set block.epoch into epochs[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("record_state").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, Vec::<Value<_>>::new().iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Construct the finalize state.
+    let block_height = 2 * CurrentNetwork::NUM_BLOCKS_PER_EPOCH + 5;
+    let previous_block_hash = Field::<CurrentNetwork>::from_u64(12345);
+    let mut previous_block_hash_bytes = [0u8; 32];
+    previous_block_hash_bytes.copy_from_slice(&previous_block_hash.to_bytes_le().unwrap());
+    let state = FinalizeGlobalState::from(
+        block_height as u64,
+        block_height,
+        1_700_000_000,
+        previous_block_hash_bytes,
+        [0u8; 32],
+    );
+    // Finalize the execution.
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // A helper to retrieve the value at `0u8` in the given mapping.
+    let get_value = |mapping_name: &str| {
+        finalize_store
+            .get_value_speculative(
+                *program_id,
+                Identifier::from_str(mapping_name).unwrap(),
+                &Plaintext::from_str("0u8").unwrap(),
+            )
+            .unwrap()
+    };
 
-    // use circuit::Environment;
-    //
-    // assert_eq!(6427, CurrentAleo::num_constants());
-    // assert_eq!(8, CurrentAleo::num_public());
-    // assert_eq!(21264, CurrentAleo::num_private());
-    // assert_eq!(21279, CurrentAleo::num_constraints());
-    // assert_eq!(81872, CurrentAleo::num_gates());
-    //
-    // assert_eq!(18504, CurrentAleo::num_constants());
-    // assert_eq!(17, CurrentAleo::num_public());
-    // assert_eq!(58791, CurrentAleo::num_private());
-    // assert_eq!(58855, CurrentAleo::num_constraints());
-    // assert_eq!(215810, CurrentAleo::num_gates());
+    // Check that the block state was stored.
+    assert_eq!(get_value("timestamps"), Some(Value::from_str("1700000000i64").unwrap()));
+    assert_eq!(get_value("hashes"), Some(Value::from_str(&previous_block_hash.to_string()).unwrap()));
+    assert_eq!(get_value("epochs"), Some(Value::from_str("2u32").unwrap()));
 }
 
 #[test]
-fn test_process_execute_and_finalize_get_add_set() {
+fn test_process_execute_and_finalize_length_and_key_at() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
 program testing.aleo;
 
-mapping account:
-    key as address.public;
-    value as u64.public;
+mapping members:
+    key as u8.public;
+    value as boolean.public;
 
-function compute:
-    input r0 as address.public;
-    input r1 as u64.public;
-    input r2 as u64.public;
-    add r1 r2 into r3;
-    async compute r0 r3 into r4;
-    output r4 as testing.aleo/compute.future;
+mapping stats:
+    key as u8.public;
+    value as u32.public;
 
-finalize compute:
-    input r0 as address.public;
-    input r1 as u64.public;
-    get.or_use account[r0] 0u64 into r2;
-    add r2 r1 into r3;
-    set r3 into account[r0];
+mapping selected:
+    key as u8.public;
+    value as u8.public;
+
+function register:
+    input r0 as u8.public;
+    input r1 as u32.public;
+    async register r0 r1 into r2;
+    output r2 as testing.aleo/register.future;
+
+finalize register:
+    input r0 as u8.public;
+    input r1 as u32.public;
+    set true into members[r0];
+    length members into r2;
+    set r2 into stats[0u8];
+    key.at members[r1] into r3;
+    set r3 into selected[0u8];
 ",
     )
     .unwrap();
@@ -1224,20 +1994,13 @@ finalize compute:
 
     // Declare the program ID.
     let program_id = program.id();
-    // Declare the mapping.
-    let mapping_name = Identifier::from_str("account").unwrap();
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("register").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
-
-    // Reset the process.
     let mut process = Process::load().unwrap();
 
     // Initialize a new block store.
@@ -1254,54 +2017,108 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-    let caller = Address::try_from(&caller_private_key).unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
-    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    // Executes and finalizes the function with the given member and index.
+    let mut execute_and_finalize = |member: &str, index: &str| {
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str(member).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str(index).unwrap();
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        // Finalize the execution.
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None)
+    };
 
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
-        .unwrap();
-    assert_eq!(authorization.len(), 1);
+    // A helper to retrieve the value at `0u8` in the given mapping.
+    let get_value = |mapping_name: &str| {
+        finalize_store
+            .get_value_speculative(
+                *program_id,
+                Identifier::from_str(mapping_name).unwrap(),
+                &Plaintext::from_str("0u8").unwrap(),
+            )
+            .unwrap()
+    };
 
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    // Register the members, and check the length and the ordering of the keys.
+    execute_and_finalize("7u8", "0u32").unwrap();
+    assert_eq!(get_value("stats"), Some(Value::from_str("1u32").unwrap()));
+    assert_eq!(get_value("selected"), Some(Value::from_str("7u8").unwrap()));
 
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+    execute_and_finalize("3u8", "0u32").unwrap();
+    assert_eq!(get_value("stats"), Some(Value::from_str("2u32").unwrap()));
+    assert_eq!(get_value("selected"), Some(Value::from_str("3u8").unwrap()));
 
-    // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    execute_and_finalize("5u8", "2u32").unwrap();
+    assert_eq!(get_value("stats"), Some(Value::from_str("3u32").unwrap()));
+    assert_eq!(get_value("selected"), Some(Value::from_str("7u8").unwrap()));
 
-    // Prepare the trace.
-    trace.prepare(Query::from(block_store)).unwrap();
-    // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Check that an out-of-bounds index fails, and the mappings are left unchanged.
+    assert!(execute_and_finalize("9u8", "4u32").is_err());
+    assert_eq!(get_value("stats"), Some(Value::from_str("3u32").unwrap()));
+    assert_eq!(get_value("selected"), Some(Value::from_str("7u8").unwrap()));
+}
 
-    // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+#[test]
+fn test_process_key_at_cost() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
 
-    // Now, finalize the execution.
-    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+mapping members:
+    key as u8.public;
+    value as boolean.public;
 
-    // Check that the account balance is now 8.
-    let candidate = finalize_store
-        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
-        .unwrap()
-        .unwrap();
-    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+function select:
+    input r0 as u32.public;
+    async select r0 into r1;
+    output r1 as testing.aleo/select.future;
+
+finalize select:
+    input r0 as u32.public;
+    key.at members[0u32] into r1;
+    key.at members[1000u32] into r2;
+    key.at members[4000000000u32] into r3;
+    key.at members[r0] into r4;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Compute the cost of each `key.at` command.
+    let function_name = Identifier::from_str("select").unwrap();
+    let stack = process.get_stack(program.id()).unwrap();
+    let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+    let costs = finalize
+        .commands()
+        .iter()
+        .map(|command| crate::cost_per_command(stack, finalize, command).unwrap())
+        .collect::<Vec<_>>();
+
+    // Ensure the cost grows with the index, by the cost of each key walked.
+    assert!(costs[0] < costs[1]);
+    assert_eq!(costs[1] - costs[0], 1000 * 25);
+    // Ensure an index beyond the maximum index, or in a register, is priced up to the maximum index.
+    assert_eq!(costs[2] - costs[0], u64::from(KeyAt::<CurrentNetwork>::MAX_INDEX) * 25);
+    assert_eq!(costs[2], costs[3]);
 }
 
 #[test]
@@ -1367,7 +2184,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -1494,7 +2311,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1623,7 +2440,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1663,7 +2480,7 @@ finalize init:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1781,7 +2598,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2210,7 +3027,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2363,8 +3180,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        dynamic_stacks: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2437,7 +3257,7 @@ function {function_name}:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2645,3 +3465,305 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_debug_evaluate_call() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program example_call.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    output r3 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call execute r0 r1 into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the closure and function names.
+    let closure_name = Identifier::from_str("execute").unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Initialize a debugger, which steps from the breakpoint at the first instruction in the closure.
+    let pauses = Arc::new(RwLock::new(Vec::new()));
+    let debugger = {
+        let pauses = pauses.clone();
+        Debugger::with_hook(move |location: &DebugLocation<_>, registers: &IndexMap<u64, Value<_>>| {
+            pauses.write().push((*location, registers.len()));
+            match location.index {
+                0 => DebugAction::Step,
+                _ => DebugAction::Continue,
+            }
+        })
+    };
+    let breakpoint = DebugLocation::new(*program.id(), closure_name, DebugScope::Closure, 0);
+    let debugger = Arc::new(RwLock::new(debugger));
+    assert!(debugger.write().add_breakpoint(breakpoint));
+
+    // Evaluate the function with the debugger attached.
+    let response = process.debug_evaluate::<CurrentAleo>(authorization, debugger.clone()).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("11field").unwrap()]);
+
+    // Check the pauses, at the breakpoint and at the next step.
+    let second = DebugLocation::new(*program.id(), closure_name, DebugScope::Closure, 1);
+    assert_eq!(*pauses.read(), vec![(breakpoint, 2), (second, 3)]);
+
+    // Check the trace.
+    let debugger = debugger.read();
+    let steps = debugger.steps();
+    assert_eq!(steps.len(), 3);
+    // The call is recorded before the instructions in the closure, and its destination once the closure returns.
+    assert_eq!(steps[0].location, DebugLocation::new(*program.id(), function_name, DebugScope::Function, 0));
+    assert_eq!(steps[0].opcode, "call");
+    assert_eq!(steps[0].destinations.len(), 1);
+    assert_eq!(steps[0].destinations[0].1, Value::from_str("11field").unwrap());
+    // Check the instructions in the closure.
+    assert_eq!(steps[1].location, breakpoint);
+    assert_eq!(steps[1].opcode, "add");
+    let operands = vec![Some(Value::from_str("3field").unwrap()), Some(Value::from_str("5field").unwrap())];
+    assert_eq!(steps[1].operands, operands);
+    assert_eq!(steps[1].destinations[0].1, Value::from_str("8field").unwrap());
+    assert_eq!(steps[2].location, second);
+    assert_eq!(steps[2].destinations[0].1, Value::from_str("11field").unwrap());
+    assert!(debugger.failure().is_none());
+}
+
+#[test]
+fn test_process_debug_hook_accesses_debugger() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program example_hook.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r1 r3 into r4;
+    output r4 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("3field").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Initialize a debugger, whose hook inspects the trace and moves the breakpoint to the last instruction.
+    let location = |index| DebugLocation::new(*program.id(), function_name, DebugScope::Function, index);
+    let shared = Arc::new(std::sync::OnceLock::<Arc<RwLock<Debugger<CurrentNetwork>>>>::new());
+    let pauses = Arc::new(RwLock::new(Vec::new()));
+    let debugger = {
+        let (shared, pauses) = (shared.clone(), pauses.clone());
+        Debugger::with_hook(move |location: &DebugLocation<_>, _: &IndexMap<u64, Value<_>>| {
+            // Note: The hook is called without holding the lock on the debugger, so this does not deadlock.
+            let mut debugger = shared.get().unwrap().write();
+            pauses.write().push((*location, debugger.steps().len()));
+            debugger.remove_breakpoint(location);
+            debugger.add_breakpoint(DebugLocation { index: 2, ..*location });
+            DebugAction::Continue
+        })
+    };
+    let debugger = Arc::new(RwLock::new(debugger));
+    assert!(debugger.write().add_breakpoint(location(0)));
+    assert!(shared.set(debugger.clone()).is_ok());
+
+    // Evaluate the function with the debugger attached.
+    let response = process.debug_evaluate::<CurrentAleo>(authorization, debugger.clone()).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("16field").unwrap()]);
+
+    // Check the pauses, at the initial breakpoint and at the breakpoint added by the hook.
+    assert_eq!(*pauses.read(), vec![(location(0), 0), (location(2), 2)]);
+
+    // Check the trace and the breakpoints.
+    let debugger = debugger.read();
+    assert_eq!(debugger.steps().len(), 3);
+    assert_eq!(debugger.breakpoints().iter().copied().collect::<Vec<_>>(), vec![location(2)]);
+    assert!(debugger.failure().is_none());
+}
+
+#[test]
+fn test_process_debug_failed_finalize() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function withdraw:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async withdraw r0 r1 into r2;
+    output r2 as testing.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the mapping and function names.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let function_name = Identifier::from_str("withdraw").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new finalize store, with the mapping.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+    finalize_store.initialize_mapping(*program.id(), mapping_name).unwrap();
+
+    // Authorize a withdrawal, which underflows the balance.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str("8u64").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Finalize the authorization with the debugger attached.
+    let debugger = Arc::new(RwLock::new(Debugger::new()));
+    let result = process
+        .debug_finalize_authorization(sample_finalize_state(1), &finalize_store, &authorization, debugger.clone())
+        .unwrap();
+    let failure = result.unwrap_err();
+    assert_eq!(failure.command_index, 1);
+
+    // Check the trace.
+    let debugger = debugger.read();
+    let steps = debugger.steps();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].opcode, "get.or_use");
+    assert_eq!(steps[0].destinations[0].1, Value::from_str("0u64").unwrap());
+    assert!(steps[0].error.is_none());
+
+    // Check the failed command, with the values of its operands.
+    let step = debugger.failure().unwrap();
+    assert_eq!(step.location, DebugLocation::new(*program.id(), function_name, DebugScope::Finalize, 1));
+    assert_eq!(step.opcode, "sub");
+    assert_eq!(step.operands, vec![Some(Value::from_str("0u64").unwrap()), Some(Value::from_str("8u64").unwrap())]);
+    assert!(step.destinations.is_empty());
+    assert_eq!(step.error.as_ref(), Some(&failure.error));
+
+    // Check that the trace can be exported.
+    let json = serde_json::from_str::<serde_json::Value>(&debugger.to_json().unwrap()).unwrap();
+    assert_eq!(json[1]["location"]["scope"], "finalize");
+    assert_eq!(json[1]["operands"][0], "0u64");
+}
+
+#[test]
+fn test_process_profile_closure_call() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program example_profile.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    mul r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call execute r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the closure and function names.
+    let closure_name = Identifier::from_str("execute").unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Profile the function.
+    let profile = process.profile_function::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    assert_eq!(profile.functions().len(), 1);
+
+    // Check the function profile.
+    let function = &profile.functions()[0];
+    assert_eq!(function.function_name, function_name);
+    assert_eq!(function.instructions.len(), 2);
+    assert!(function.request.num_constraints > 0);
+    assert!(function.total.num_constraints > function.request.num_constraints);
+
+    // Check that the closure instructions are nested under the call instruction.
+    let call = &function.instructions[0];
+    assert_eq!(call.instruction, "call execute r0 r1 into r2;");
+    assert_eq!(call.children.len(), 2);
+    for (index, child) in call.children.iter().enumerate() {
+        assert_eq!(child.name, closure_name);
+        assert_eq!(child.index, index);
+        assert!(child.counts.num_constraints > 0);
+        assert!(child.children.is_empty());
+    }
+    let num_closure_constraints = call.children.iter().map(|child| child.counts.num_constraints).sum::<u64>();
+    assert!(call.counts.num_constraints >= num_closure_constraints);
+    assert_eq!(call.self_counts().num_constraints, call.counts.num_constraints - num_closure_constraints);
+
+    // Check that the flat profile contains each instruction line, once.
+    let flat = profile.flat();
+    assert_eq!(flat.len(), 4);
+    assert!(flat.iter().all(|entry| entry.num_calls == 1));
+    assert!(flat.iter().any(|entry| entry.name == closure_name && entry.index == 1));
+    let num_flat_constraints = flat.iter().map(|entry| entry.counts.num_constraints).sum::<u64>();
+    assert!(function.request.num_constraints + num_flat_constraints <= function.total.num_constraints);
+
+    // Check that the report contains the closure instructions.
+    let report = profile.to_string();
+    assert!(report.contains("execute#0 mul r0 r1 into r2;"));
+}
@@ -18,6 +18,9 @@ pub use call_metrics::*;
 mod inclusion;
 pub use inclusion::*;
 
+mod profile;
+pub use profile::*;
+
 use circuit::Assignment;
 use console::{
     network::prelude::*,
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+use indexmap::IndexMap;
+
+/// The counts of a circuit, or of the part of a circuit that is synthesized by an instruction.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CircuitCounts {
+    /// The number of public variables.
+    pub num_public: u64,
+    /// The number of private variables.
+    pub num_private: u64,
+    /// The number of constraints.
+    pub num_constraints: u64,
+    /// The number of non-zero entries in the A, B, and C matrices.
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl CircuitCounts {
+    /// Returns the counts of the current circuit.
+    pub fn current<A: circuit::Environment>() -> Self {
+        let (_, num_public, num_private, num_constraints, num_nonzeros) = A::count();
+        Self { num_public, num_private, num_constraints, num_nonzeros }
+    }
+
+    /// Returns the counts that were added since the given counts.
+    pub fn since(&self, previous: &Self) -> Self {
+        Self {
+            num_public: self.num_public.saturating_sub(previous.num_public),
+            num_private: self.num_private.saturating_sub(previous.num_private),
+            num_constraints: self.num_constraints.saturating_sub(previous.num_constraints),
+            num_nonzeros: (
+                self.num_nonzeros.0.saturating_sub(previous.num_nonzeros.0),
+                self.num_nonzeros.1.saturating_sub(previous.num_nonzeros.1),
+                self.num_nonzeros.2.saturating_sub(previous.num_nonzeros.2),
+            ),
+        }
+    }
+
+    /// Returns the sum of the counts.
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self {
+            num_public: self.num_public.saturating_add(other.num_public),
+            num_private: self.num_private.saturating_add(other.num_private),
+            num_constraints: self.num_constraints.saturating_add(other.num_constraints),
+            num_nonzeros: (
+                self.num_nonzeros.0.saturating_add(other.num_nonzeros.0),
+                self.num_nonzeros.1.saturating_add(other.num_nonzeros.1),
+                self.num_nonzeros.2.saturating_add(other.num_nonzeros.2),
+            ),
+        }
+    }
+}
+
+/// The profile of an instruction in a closure or function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionProfile<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The name of the closure or function that contains the instruction.
+    pub name: Identifier<N>,
+    /// The index of the instruction in the closure or function.
+    pub index: usize,
+    /// The instruction.
+    pub instruction: String,
+    /// The counts synthesized by the instruction, including the instructions of the closure it calls.
+    pub counts: CircuitCounts,
+    /// The profiles of the instructions in the closure it calls, if any.
+    pub children: Vec<InstructionProfile<N>>,
+}
+
+impl<N: Network> InstructionProfile<N> {
+    /// Returns the counts synthesized by the instruction itself, excluding the instructions of the closure it calls.
+    pub fn self_counts(&self) -> CircuitCounts {
+        self.children.iter().fold(self.counts, |counts, child| counts.since(&child.counts))
+    }
+}
+
+/// The profile of a function call, which is synthesized into a circuit of its own.
+///
+/// Note: The instructions of a function that is called are profiled in the function profile of the callee.
+/// The `call` instruction in the caller only counts the constraints that link the caller to the callee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionProfile<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The counts synthesized to verify the request.
+    pub request: CircuitCounts,
+    /// The profiles of the instructions.
+    pub instructions: Vec<InstructionProfile<N>>,
+    /// The counts of the circuit, including the request and the response.
+    pub total: CircuitCounts,
+}
+
+/// The aggregated profile of an instruction, over all of the times it was synthesized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlatProfileEntry<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The name of the closure or function that contains the instruction.
+    pub name: Identifier<N>,
+    /// The index of the instruction in the closure or function.
+    pub index: usize,
+    /// The instruction.
+    pub instruction: String,
+    /// The number of times the instruction was synthesized.
+    pub num_calls: usize,
+    /// The counts synthesized by the instruction itself, summed over all of the times it was synthesized.
+    pub counts: CircuitCounts,
+}
+
+/// The constraint profile of a synthesis, which attributes the counts of each circuit to its instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintProfile<N: Network> {
+    /// The profiles of the synthesized functions, in the order in which they were completed.
+    functions: Vec<FunctionProfile<N>>,
+    /// The functions that are being synthesized, each with the instructions that are being synthesized
+    /// and the counts of the circuit before each of those instructions.
+    frames: Vec<(FunctionProfile<N>, Vec<(InstructionProfile<N>, CircuitCounts)>)>,
+}
+
+impl<N: Network> Default for ConstraintProfile<N> {
+    /// Initializes a new constraint profile.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ConstraintProfile<N> {
+    /// Initializes a new constraint profile.
+    pub fn new() -> Self {
+        Self { functions: Vec::new(), frames: Vec::new() }
+    }
+
+    /// Returns the profiles of the synthesized functions.
+    pub fn functions(&self) -> &[FunctionProfile<N>] {
+        &self.functions
+    }
+
+    /// Appends the profiles of the synthesized functions in the given profile.
+    pub fn extend(&mut self, other: Self) {
+        self.functions.extend(other.functions);
+    }
+
+    /// Returns the profile of each instruction line, aggregated over all of the times it was synthesized,
+    /// and sorted by the number of constraints in descending order.
+    pub fn flat(&self) -> Vec<FlatProfileEntry<N>> {
+        // Aggregates the given instructions, and the instructions of the closures they call.
+        fn aggregate<N: Network>(
+            entries: &mut IndexMap<(ProgramID<N>, Identifier<N>, usize), FlatProfileEntry<N>>,
+            instructions: &[InstructionProfile<N>],
+        ) {
+            for profile in instructions {
+                let entry = entries.entry((profile.program_id, profile.name, profile.index)).or_insert_with(|| {
+                    FlatProfileEntry {
+                        program_id: profile.program_id,
+                        name: profile.name,
+                        index: profile.index,
+                        instruction: profile.instruction.clone(),
+                        num_calls: 0,
+                        counts: CircuitCounts::default(),
+                    }
+                });
+                entry.num_calls += 1;
+                entry.counts = entry.counts.saturating_add(&profile.self_counts());
+                aggregate(entries, &profile.children);
+            }
+        }
+
+        let mut entries = IndexMap::new();
+        for function in &self.functions {
+            aggregate(&mut entries, &function.instructions);
+        }
+        let mut entries = entries.into_values().collect::<Vec<_>>();
+        entries.sort_by(|a, b| b.counts.num_constraints.cmp(&a.counts.num_constraints));
+        entries
+    }
+}
+
+impl<N: Network> ConstraintProfile<N> {
+    /// Starts the profile of a function call, given the counts after the request is verified.
+    pub(crate) fn start_function(
+        &mut self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        request: CircuitCounts,
+    ) {
+        let function =
+            FunctionProfile { program_id, function_name, request, instructions: Vec::new(), total: request };
+        self.frames.push((function, Vec::new()));
+    }
+
+    /// Starts the profile of an instruction, given the counts before it is synthesized.
+    pub(crate) fn enter(
+        &mut self,
+        program_id: ProgramID<N>,
+        name: Identifier<N>,
+        index: usize,
+        instruction: String,
+        counts: CircuitCounts,
+    ) -> Result<()> {
+        let Some((_, instructions)) = self.frames.last_mut() else {
+            bail!("The constraint profile has no function to enter '{program_id}/{name}' into")
+        };
+        let profile = InstructionProfile {
+            program_id,
+            name,
+            index,
+            instruction,
+            counts: CircuitCounts::default(),
+            children: Vec::new(),
+        };
+        instructions.push((profile, counts));
+        Ok(())
+    }
+
+    /// Finishes the profile of the last entered instruction, given the counts after it is synthesized.
+    pub(crate) fn exit(&mut self, counts: CircuitCounts) -> Result<()> {
+        let Some((function, instructions)) = self.frames.last_mut() else {
+            bail!("The constraint profile has no function to exit from")
+        };
+        let Some((mut profile, previous)) = instructions.pop() else {
+            bail!("The constraint profile has no instruction to exit from")
+        };
+        profile.counts = counts.since(&previous);
+        // Add the profile to the instruction that called its closure, or else to the function.
+        match instructions.last_mut() {
+            Some((parent, _)) => parent.children.push(profile),
+            None => function.instructions.push(profile),
+        }
+        Ok(())
+    }
+
+    /// Finishes the profile of the last started function call, given the counts of its circuit.
+    pub(crate) fn finish_function(&mut self, total: CircuitCounts) -> Result<()> {
+        match self.frames.pop() {
+            Some((mut function, instructions)) => {
+                ensure!(instructions.is_empty(), "The constraint profile has unfinished instructions");
+                function.total = total;
+                self.functions.push(function);
+                Ok(())
+            }
+            None => bail!("The constraint profile has no function to finish"),
+        }
+    }
+}
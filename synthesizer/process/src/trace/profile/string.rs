@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Display for CircuitCounts {
+    /// Prints the counts as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (a, b, c) = self.num_nonzeros;
+        write!(
+            f,
+            "constraints: {}, public: {}, private: {}, nonzeros: ({a}, {b}, {c})",
+            self.num_constraints, self.num_public, self.num_private
+        )
+    }
+}
+
+impl<N: Network> Display for ConstraintProfile<N> {
+    /// Prints the profile as a tree of the instructions in each function call and closure call,
+    /// followed by the flat profile of each instruction line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Prints the given instructions, and the instructions of the closures they call, at the given depth.
+        fn write_tree<N: Network>(
+            f: &mut Formatter,
+            instructions: &[InstructionProfile<N>],
+            depth: usize,
+        ) -> fmt::Result {
+            for profile in instructions {
+                let indent = "  ".repeat(depth);
+                writeln!(f, "{indent}{}#{} {} ({})", profile.name, profile.index, profile.instruction, profile.counts)?;
+                write_tree(f, &profile.children, depth + 1)?;
+            }
+            Ok(())
+        }
+
+        for function in &self.functions {
+            writeln!(f, "{}/{} ({})", function.program_id, function.function_name, function.total)?;
+            writeln!(f, "  request ({})", function.request)?;
+            write_tree(f, &function.instructions, 1)?;
+        }
+
+        writeln!(f)?;
+        for entry in self.flat() {
+            writeln!(
+                f,
+                "{}/{}#{} {} (calls: {}, {})",
+                entry.program_id, entry.name, entry.index, entry.instruction, entry.num_calls, entry.counts
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -21,6 +21,8 @@ use console::{
     types::Field,
 };
 
+use indexmap::IndexMap;
+
 pub trait StackEvaluate<N: Network>: Clone {
     /// Evaluates a program closure on the given inputs.
     ///
@@ -89,3 +91,8 @@ pub trait RegistersCall<N: Network> {
     /// Returns the current call stack.
     fn call_stack(&self) -> CallStack<N>;
 }
+
+pub trait RegistersDebug<N: Network> {
+    /// Returns the values of the assigned registers, keyed by their locators.
+    fn values(&self) -> &IndexMap<u64, Value<N>>;
+}
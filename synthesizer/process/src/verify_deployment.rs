@@ -15,30 +15,29 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Verifies the given deployment is ordered.
+    /// Verifies the given deployment is ordered, and returns its minimum deployment cost in microcredits.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let timer = timer!("Process::verify_deployment");
 
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
-
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: If the program already exists, this ensures the deployment is a valid upgrade of the program.
+        let stack = self.compute_deployment_stack(deployment.program())?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        stack.verify_deployment::<A, R>(deployment, rng)?;
         lap!(timer, "Verify the deployment");
 
-        finish!(timer);
-        verification
+        // Compute the minimum deployment cost from the stack.
+        let (cost, _) = deployment_cost(&stack, deployment)?;
+        finish!(timer, "Compute the deployment cost");
+
+        Ok(cost)
     }
 }
 
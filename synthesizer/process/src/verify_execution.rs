@@ -31,12 +31,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(transition.program_id())?;
             // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-            ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
-            );
+            Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
             // Output the locator of the main function.
             Locator::new(*transition.program_id(), *transition.function_name()).to_string()
         };
@@ -155,6 +150,63 @@ impl<N: Network> Process<N> {
 }
 
 impl<N: Network> Process<N> {
+    /// Ensures the number of transitions in an execution matches the number of calls of the given root function.
+    /// If the function invokes a dynamic call, the number of calls is a lower bound.
+    pub(crate) fn check_number_of_calls(
+        stack: &Stack<N>,
+        function_name: &Identifier<N>,
+        num_transitions: usize,
+    ) -> Result<()> {
+        // Retrieve the number of calls.
+        let number_of_calls = stack.get_number_of_calls(function_name)?;
+        match stack.contains_dynamic_calls(function_name) {
+            true => ensure!(
+                number_of_calls <= num_transitions,
+                "The number of transitions in the execution is incorrect. Expected at least {number_of_calls}, but found {num_transitions}"
+            ),
+            false => ensure!(
+                number_of_calls == num_transitions,
+                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {num_transitions}"
+            ),
+        }
+        Ok(())
+    }
+
+    /// Returns the calls to functions in the given function, in the order they are invoked,
+    /// where each call is the locator of the callee, or `None` for a dynamic call.
+    fn function_calls(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<Option<Locator<N>>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Retrieve the function from the stack.
+        let function = stack.get_function_ref(function_name)?;
+        // Collect the function calls.
+        let mut calls = Vec::new();
+        for instruction in function.instructions() {
+            match instruction {
+                Instruction::Call(call) => {
+                    let (pid, fname) = match call.operator() {
+                        synthesizer_program::CallOperator::Locator(locator) => {
+                            (locator.program_id(), locator.resource())
+                        }
+                        synthesizer_program::CallOperator::Resource(fname) => (program_id, fname),
+                    };
+                    // Add the call, only if it is a call to a transition.
+                    if self.get_stack(pid)?.get_function(fname).is_ok() {
+                        calls.push(Some(Locator::new(*pid, *fname)));
+                    }
+                }
+                // The callee of a dynamic call is only known at runtime.
+                Instruction::CallDynamic(..) => calls.push(None),
+                _ => {}
+            }
+        }
+        Ok(calls)
+    }
+
     /// Returns the public inputs to verify the proof for the given transition.
     fn to_transition_verifier_inputs(
         &self,
@@ -183,17 +235,37 @@ impl<N: Network> Process<N> {
         // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*is_root, *parent_x, *parent_y]);
 
+        // Retrieve the function calls of the transition.
+        let calls = self.function_calls(transition.program_id(), transition.function_name())?;
+        // Retrieve the child transition IDs.
+        let child_ids = call_graph.get(transition.id()).unwrap();
+        ensure!(calls.len() == child_ids.len(), "The number of function calls does not match the call graph");
+
         // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
+        for (transition_id, call) in child_ids.iter().zip_eq(calls) {
             // Note: This unwrap is safe, as we are processing transitions in post-order,
             // which implies that all child transition IDs have been added to `transition_map`.
-            let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            let child: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            // If this is a dynamic call, append the target of the call.
+            if call.is_none() {
+                // Ensure the dynamic call does not target the program of the caller.
+                ensure!(
+                    child.program_id() != transition.program_id(),
+                    "A dynamic call cannot target the program of the caller"
+                );
+                // Compute the function ID of the dynamic call.
+                let function_id = compute_function_id(&U16::new(N::ID), child.program_id(), child.function_name())?;
+                // [Inputs] Extend the verifier inputs with the program name, function name, and function ID of the dynamic call.
+                let (program_name, function_name) =
+                    CallDynamic::to_fields(&Locator::new(*child.program_id(), *child.function_name()))?;
+                inputs.extend([*program_name, *function_name, *function_id]);
+            }
             // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
-            inputs.extend([**transition.tcm()]);
+            inputs.extend([**child.tcm()]);
             // [Inputs] Extend the verifier inputs with the input IDs of the external call.
-            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+            inputs.extend(child.inputs().iter().flat_map(|input| input.verifier_inputs()));
             // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-            inputs.extend(transition.output_ids().map(|id| **id));
+            inputs.extend(child.output_ids().map(|id| **id));
         }
 
         // [Inputs] Extend the verifier inputs with the output IDs.
@@ -233,19 +305,19 @@ impl<N: Network> Process<N> {
         execution: &Execution<N>,
     ) -> Result<HashMap<N::TransitionID, Vec<N::TransitionID>>> {
         // Metadata for each transition the execution.
+        // Note: The locator is `None` for the target of a dynamic call, until it is resolved from the execution.
         struct TransitionMetadata<N: Network> {
             uid: usize,
-            pid: ProgramID<N>,
-            fname: Identifier<N>,
+            locator: Option<Locator<N>>,
             tid: Option<N::TransitionID>,
             children: Option<Vec<usize>>,
         }
 
         impl<N: Network> TransitionMetadata<N> {
-            fn new(counter: &mut usize, pid: ProgramID<N>, fname: Identifier<N>, tid: Option<N::TransitionID>) -> Self {
+            fn new(counter: &mut usize, locator: Option<Locator<N>>, tid: Option<N::TransitionID>) -> Self {
                 let uid = *counter;
                 *counter += 1;
-                Self { uid, pid, fname, tid, children: None }
+                Self { uid, locator, tid, children: None }
             }
 
             /// Returns 'true' if the subgraph starting from this transition has been fully-indexed.
@@ -299,16 +371,23 @@ impl<N: Network> Process<N> {
                 None => {
                     traversal_stack.push(TransitionMetadata::new(
                         &mut counter,
-                        *transition.program_id(),
-                        *transition.function_name(),
+                        Some(Locator::new(*transition.program_id(), *transition.function_name())),
                         Some(*transition.id()),
                     ));
                 }
                 // If the stack is not empty, then add the current transition ID to the entry.
-                Some(head) => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
-                    true => head.tid = Some(*transition.id()),
-                    false => bail!("Invalid traversal - unexpected transition in the execution"),
-                },
+                Some(head) => {
+                    let locator = Locator::new(*transition.program_id(), *transition.function_name());
+                    match head.locator {
+                        // Ensure the transition matches the expected function.
+                        Some(expected) => {
+                            ensure!(expected == locator, "Invalid traversal - unexpected transition in the execution")
+                        }
+                        // If the entry is the target of a dynamic call, then resolve it from the transition.
+                        None => head.locator = Some(locator),
+                    }
+                    head.tid = Some(*transition.id());
+                }
             }
 
             // Process the entry at the top of the stack. By the previous step, this entry has a transition ID.
@@ -319,26 +398,15 @@ impl<N: Network> Process<N> {
                 // Note this unwrap is safe, for the same reason as above.
                 update_call_graph(traversal_stack.pop().unwrap(), &mut call_graph, &mut uid_to_tid)?;
             } else {
-                // Retrieve the stack.
-                let stack = self.get_stack(top.pid)?;
-                // Retrieve the function from the stack.
-                let function = stack.get_function(&top.fname)?;
+                // Retrieve the locator of the current transition.
+                // Note: This unwrap is safe, since the locator is resolved when the transition ID is assigned.
+                let locator = top.locator.unwrap();
                 // Collect the children of the current transition.
-                let mut children = Vec::new();
-                for instruction in function.instructions() {
-                    if let Instruction::Call(call) = instruction {
-                        let (pid, fname) = match call.operator() {
-                            synthesizer_program::CallOperator::Locator(locator) => {
-                                (locator.program_id(), locator.resource())
-                            }
-                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
-                        };
-                        // Add the child to the traversal stack, only if it is a call to a transition.
-                        if self.get_stack(pid)?.get_function(fname).is_ok() {
-                            children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
-                        }
-                    }
-                }
+                let children = self
+                    .function_calls(locator.program_id(), locator.resource())?
+                    .into_iter()
+                    .map(|call| TransitionMetadata::new(&mut counter, call, None))
+                    .collect::<Vec<_>>();
 
                 // Add the children UIDs to the metadata.
                 // Note this unwrap is safe, for the same reason as above.
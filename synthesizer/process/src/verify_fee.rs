@@ -226,6 +226,8 @@ mod tests {
             ledger_test_helpers::sample_deployment_transaction(false, rng),
             ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
             ledger_test_helpers::sample_execution_transaction_with_fee(false, rng),
+            ledger_test_helpers::sample_bundle_transaction(true, rng),
+            ledger_test_helpers::sample_bundle_transaction(false, rng),
             ledger_test_helpers::sample_fee_private_transaction(rng),
             ledger_test_helpers::sample_fee_public_transaction(rng),
         ];
@@ -251,6 +253,12 @@ mod tests {
                     true => process.verify_fee_private(&&fee).unwrap(),
                     false => process.verify_fee_public(&&fee).unwrap(),
                 },
+                Transaction::Bundle(_, executions, fee) => {
+                    // Compute the bundle ID.
+                    let bundle_id = *Transaction::bundle_tree(&executions, None).unwrap().root();
+                    // Verify the fee.
+                    process.verify_fee(&fee, bundle_id).unwrap();
+                }
             }
         }
     }
@@ -21,15 +21,31 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if !(1..=3).contains(&version) {
             return Err(error("Invalid program version"));
         }
 
         // Read the program ID.
         let id = ProgramID::read_le(&mut reader)?;
 
+        // Read the upgrade policy.
+        let upgrade_policy = match version {
+            1 => UpgradePolicy::Immutable,
+            _ => UpgradePolicy::read_le(&mut reader)?,
+        };
+
+        // Read the constructor.
+        let constructor = match version {
+            3 => Some(ConstructorCore::read_le(&mut reader)?),
+            _ => None,
+        };
+
         // Initialize the program.
         let mut program = ProgramCore::new(id).map_err(|e| error(e.to_string()))?;
+        // Add the constructor, if it exists.
+        if let Some(constructor) = constructor {
+            program.add_constructor(constructor).map_err(|e| error(e.to_string()))?;
+        }
 
         // Read the number of program imports.
         let imports_len = u8::read_le(&mut reader)?;
@@ -60,20 +76,50 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
             }
         }
 
+        // Set the upgrade policy.
+        program.set_upgrade_policy(upgrade_policy).map_err(|e| error(e.to_string()))?;
+
+        // Ensure the version is the one the program is written in, so that its bytes are not malleable.
+        if version != program.version() {
+            return Err(error(format!("Invalid program version '{version}', expected '{}'", program.version())));
+        }
+
         Ok(program)
     }
 }
 
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the version the program is serialized in.
+    /// Note: Programs are written in the earliest format that supports them, to preserve their bytes.
+    fn version(&self) -> u8 {
+        match (&self.constructor, self.upgrade_policy.is_immutable()) {
+            (Some(_), _) => 3u8,
+            (None, false) => 2u8,
+            (None, true) => 1u8,
+        }
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToBytes
     for ProgramCore<N, Instruction, Command>
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        let version = self.version();
+        version.write_le(&mut writer)?;
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
 
+        // Write the upgrade policy, if the version supports it.
+        if version >= 2 {
+            self.upgrade_policy.write_le(&mut writer)?;
+        }
+        // Write the constructor, if it exists.
+        if let Some(constructor) = &self.constructor {
+            constructor.write_le(&mut writer)?;
+        }
+
         // Write the number of program imports.
         u8::try_from(self.imports.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
         // Write the program imports.
@@ -172,4 +218,96 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_upgrade_policy() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+upgrade owner;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the upgradable program is written with the new version.
+        assert_eq!(expected_bytes[0], 2);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(candidate.upgrade_policy(), &UpgradePolicy::Owner);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_constructor() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+constructor:
+    set 0u64 into supply[0u8];
+
+mapping supply:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the program with a constructor is written with the new version.
+        assert_eq!(expected_bytes[0], 3);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.constructor().is_some());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_non_canonical_version() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+        // Initialize a new program.
+        let (string, expected) = Program::<CurrentNetwork>::parse(program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the immutable program is written with the original version.
+        assert_eq!(expected_bytes[0], 1);
+
+        // Rewrite the program with the upgradable version, and an immutable upgrade policy.
+        let id_length = expected.id().to_bytes_le()?.len();
+        let mut candidate_bytes = vec![2u8];
+        candidate_bytes.extend_from_slice(&expected_bytes[1..1 + id_length]);
+        candidate_bytes.extend(UpgradePolicy::<CurrentNetwork>::Immutable.to_bytes_le()?);
+        candidate_bytes.extend_from_slice(&expected_bytes[1 + id_length..]);
+
+        // Ensure the non-canonical bytes are rejected.
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&candidate_bytes).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::{finalize::FinalizeCore, traits::CommandTrait};
+use console::{network::prelude::*, program::Identifier};
+
+use std::collections::HashMap;
+
+/// A constructor is a block of commands that is evaluated once, when the program is first deployed.
+/// Upgrades of the program do not re-run the constructor.
+/// It is evaluated as a `finalize` scope named `constructor` without any inputs,
+/// and is typically used to initialize the mappings of the program.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstructorCore<N: Network, Command: CommandTrait<N>> {
+    /// The constructor logic.
+    logic: FinalizeCore<N, Command>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> ConstructorCore<N, Command> {
+    /// Initializes a new constructor.
+    pub fn new() -> Result<Self> {
+        Ok(Self { logic: FinalizeCore::new(Identifier::from_str(Self::type_name())?) })
+    }
+
+    /// Returns the name of the constructor, which is `constructor`.
+    pub const fn name(&self) -> &Identifier<N> {
+        self.logic.name()
+    }
+
+    /// Returns the constructor commands.
+    pub fn commands(&self) -> &[Command] {
+        self.logic.commands()
+    }
+
+    /// Returns the number of write commands.
+    pub const fn num_writes(&self) -> u16 {
+        self.logic.num_writes()
+    }
+
+    /// Returns the mapping of `Position`s to their index in `commands`.
+    pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
+        self.logic.positions()
+    }
+
+    /// Returns the constructor as a `finalize` scope without any inputs.
+    pub const fn finalize_logic(&self) -> &FinalizeCore<N, Command> {
+        &self.logic
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ConstructorCore<N, Command> {
+    /// Adds the given command to the constructor.
+    ///
+    /// # Errors
+    /// This method will halt if the command is an `await` command.
+    /// This method will halt if the command is not a valid `finalize` command.
+    #[inline]
+    pub fn add_command(&mut self, command: Command) -> Result<()> {
+        // Ensure the command is not an await command.
+        ensure!(!command.is_await(), "Forbidden operation: A constructor cannot 'await'");
+        // Add the command to the constructor logic.
+        self.logic.add_command(command)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for ConstructorCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "constructor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Command, Constructor};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_add_command() -> Result<()> {
+        // Initialize a new constructor.
+        let mut constructor = Constructor::<CurrentNetwork>::new()?;
+        assert_eq!(constructor.name().to_string(), "constructor");
+
+        // Ensure that a command can be added.
+        let command = Command::<CurrentNetwork>::from_str("set 1u64 into counter[0u8];")?;
+        assert!(constructor.add_command(command).is_ok());
+        assert_eq!(constructor.num_writes(), 1);
+
+        // Ensure that an await command cannot be added.
+        let command = Command::<CurrentNetwork>::from_str("await r0;")?;
+        assert!(constructor.add_command(command).is_err());
+
+        // Ensure that a call instruction cannot be added.
+        let command = Command::<CurrentNetwork>::from_str("call foo.aleo/bar 1u64 into r0;")?;
+        assert!(constructor.add_command(command).is_err());
+        Ok(())
+    }
+}
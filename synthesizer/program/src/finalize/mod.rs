@@ -24,7 +24,7 @@ use console::{
     program::{FinalizeType, Identifier, Register},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use std::collections::HashMap;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -36,16 +36,25 @@ pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command>,
-    /// The number of write commands.
+    /// The number of writes, where each write command counts once per iteration of its enclosing loops.
     num_writes: u16,
     /// A mapping from `Position`s to their index in `commands`.
     positions: HashMap<Identifier<N>, usize>,
+    /// A mapping from the index of each `loop` in `commands` to the index of its matching `end.loop`, if added.
+    loops: IndexMap<usize, Option<usize>>,
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), num_writes: 0, positions: HashMap::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            num_writes: 0,
+            positions: HashMap::new(),
+            loops: IndexMap::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -68,7 +77,7 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
         &self.commands
     }
 
-    /// Returns the number of write commands.
+    /// Returns the number of writes, where each write command counts once per iteration of its enclosing loops.
     pub const fn num_writes(&self) -> u16 {
         self.num_writes
     }
@@ -77,6 +86,11 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
         &self.positions
     }
+
+    /// Returns the mapping of `loop`s to the index of their matching `end.loop` in `commands`.
+    pub const fn loops(&self) -> &IndexMap<usize, Option<usize>> {
+        &self.loops
+    }
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
@@ -108,12 +122,12 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the maximum number of writes, counted across loop iterations, has been exceeded.
+    /// This method will halt if an `await` or `key.at` command is added inside a loop.
     #[inline]
     pub fn add_command(&mut self, command: Command) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() < N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
-        // Ensure the number of write commands has not been exceeded.
-        ensure!(self.num_writes < N::MAX_WRITES, "Cannot add more than {} 'set' & 'remove' commands", N::MAX_WRITES);
 
         // Ensure the command is not a call instruction.
         ensure!(!command.is_call(), "Forbidden operation: Finalize cannot invoke a 'call'");
@@ -142,12 +156,62 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
             self.positions.insert(*position, self.commands.len());
         }
 
-        // Check if the command is a write command.
-        if command.is_write() {
-            // Increment the number of write commands.
-            self.num_writes += 1;
+        // Retrieve the indices of the loops that are not yet closed, from outermost to innermost.
+        let open_loops =
+            self.loops.iter().filter_map(|(index, end)| end.is_none().then_some(*index)).collect::<Vec<_>>();
+
+        // Ensure the command is not an await command inside a loop.
+        ensure!(!command.is_await() || open_loops.is_empty(), "Forbidden operation: Cannot 'await' inside a loop");
+        // Ensure the command is not a key-by-index command inside a loop, as its cost grows with the index.
+        ensure!(!command.is_key_at() || open_loops.is_empty(), "Forbidden operation: Cannot 'key.at' inside a loop");
+
+        // Compute the number of writes of the command, which is the number of iterations of its enclosing loops.
+        let num_writes = match command.is_write() {
+            true => open_loops.iter().fold(1u16, |acc, index| {
+                let bound = self.commands[*index].loop_bound().unwrap_or(1);
+                acc.saturating_mul(u16::try_from(bound).unwrap_or(u16::MAX))
+            }),
+            false => 0,
+        };
+        // Ensure the number of writes, across all iterations of the enclosing loops, does not exceed the maximum.
+        ensure!(
+            self.num_writes.saturating_add(num_writes) <= N::MAX_WRITES,
+            "Cannot exceed {} writes, counting each 'set' & 'remove' command once per loop iteration",
+            N::MAX_WRITES
+        );
+
+        // Check if the command is a loop command.
+        if let Some(bound) = command.loop_bound() {
+            // Ensure the bound is at least one iteration.
+            ensure!(bound > 0, "The loop bound must be greater than zero");
+            // Compute the number of iterations of the loop, including its enclosing loops.
+            let num_iterations = open_loops.iter().try_fold(u64::from(bound), |acc, index| {
+                let outer_bound = self.commands[*index].loop_bound().unwrap_or(1);
+                acc.checked_mul(u64::from(outer_bound)).ok_or_else(|| anyhow!("The loop bound overflowed"))
+            })?;
+            // Ensure the number of iterations does not exceed the maximum.
+            ensure!(
+                num_iterations <= u64::from(N::MAX_LOOP_ITERATIONS),
+                "Cannot exceed {} iterations across nested loops",
+                N::MAX_LOOP_ITERATIONS
+            );
+            // Ensure that there are less than `u8::MAX` loops.
+            ensure!(self.loops.len() < u8::MAX as usize, "Cannot add more than {} loops", u8::MAX);
+            // Insert the loop.
+            self.loops.insert(self.commands.len(), None);
+        }
+
+        // Check if the command is an end-of-loop command.
+        if command.is_end_loop() {
+            // Retrieve the innermost open loop.
+            let Some(index) = open_loops.last() else { bail!("Cannot close a loop that was not opened") };
+            // Close the loop.
+            self.loops.insert(*index, Some(self.commands.len()));
         }
 
+        // Increment the number of writes.
+        self.num_writes += num_writes;
+
         // Insert the command.
         self.commands.push(command);
         Ok(())
@@ -266,4 +330,72 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_add_command_loops() {
+        // Initialize a new finalize instance.
+        let name = Identifier::from_str("finalize_core_test").unwrap();
+        let mut finalize = Finalize::<CurrentNetwork>::new(name);
+
+        // Ensure that closing a loop that was not opened will fail.
+        let end_loop = Command::<CurrentNetwork>::from_str("end.loop;").unwrap();
+        assert!(finalize.add_command(end_loop.clone()).is_err());
+
+        // Ensure that nested loops are matched with their `end.loop`.
+        let command = Command::<CurrentNetwork>::from_str("loop r1 in 0u8 to r0 bound 4u32;").unwrap();
+        assert!(finalize.add_command(command).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("loop r2 in 0u8 to r0 bound 256u32;").unwrap();
+        assert!(finalize.add_command(command).is_ok());
+        assert!(finalize.add_command(end_loop.clone()).is_ok());
+        assert_eq!(finalize.loops().get(&0), Some(&None));
+        assert_eq!(finalize.loops().get(&1), Some(&Some(2)));
+
+        // Ensure that an `await` inside a loop will fail.
+        let command = Command::<CurrentNetwork>::from_str("await r0;").unwrap();
+        assert!(finalize.add_command(command).is_err());
+
+        // Ensure that a `key.at` inside a loop will fail.
+        let command = Command::<CurrentNetwork>::from_str("key.at accounts[r2] into r3;").unwrap();
+        assert!(finalize.add_command(command).is_err());
+
+        // Ensure that exceeding the maximum number of iterations across nested loops will fail.
+        let command = Command::<CurrentNetwork>::from_str("loop r3 in 0u8 to r0 bound 257u32;").unwrap();
+        assert!(finalize.add_command(command).is_err());
+
+        // Ensure that a loop without iterations will fail.
+        let command = Command::<CurrentNetwork>::from_str("loop r3 in 0u8 to r0 bound 0u32;").unwrap();
+        assert!(finalize.add_command(command).is_err());
+
+        assert!(finalize.add_command(end_loop).is_ok());
+        assert_eq!(finalize.loops().get(&0), Some(&Some(3)));
+    }
+
+    #[test]
+    fn test_add_command_writes_in_loops() {
+        // Initialize a new finalize instance.
+        let name = Identifier::from_str("finalize_core_test").unwrap();
+        let mut finalize = Finalize::<CurrentNetwork>::new(name);
+
+        // Ensure that a write inside a loop counts once per iteration.
+        let command = Command::<CurrentNetwork>::from_str("loop r1 in 0u8 to r0 bound 4u32;").unwrap();
+        assert!(finalize.add_command(command).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("loop r2 in 0u8 to r0 bound 2u32;").unwrap();
+        assert!(finalize.add_command(command).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("set r1 into object[r2];").unwrap();
+        assert!(finalize.add_command(command).is_ok());
+        assert_eq!(finalize.num_writes(), 8);
+        let end_loop = Command::<CurrentNetwork>::from_str("end.loop;").unwrap();
+        assert!(finalize.add_command(end_loop.clone()).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("remove object[r1];").unwrap();
+        assert!(finalize.add_command(command.clone()).is_ok());
+        assert_eq!(finalize.num_writes(), 12);
+
+        // Ensure that exceeding the maximum number of writes across the loop iterations will fail.
+        assert!(finalize.add_command(command).is_ok());
+        assert_eq!(finalize.num_writes(), 16);
+        let command = Command::<CurrentNetwork>::from_str("remove object[r2];").unwrap();
+        assert!(finalize.add_command(command).is_err());
+        assert!(finalize.add_command(end_loop).is_ok());
+        assert_eq!(finalize.num_writes(), 16);
+    }
 }
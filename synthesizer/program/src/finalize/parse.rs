@@ -137,6 +137,33 @@ finalize foo:
         assert_eq!(1, finalize.commands().len());
     }
 
+    #[test]
+    fn test_finalize_parse_writes_in_loop() {
+        // A write inside a loop counts once per iteration.
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    loop r1 in 0u8 to r0 bound 16u32;
+        set r1 into object[r1];
+    end.loop;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(16, finalize.num_writes());
+
+        // Ensure a write inside a loop with more iterations than the maximum number of writes fails to parse.
+        let result = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    loop r1 in 0u8 to r0 bound 17u32;
+        set r1 into object[r1];
+    end.loop;",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
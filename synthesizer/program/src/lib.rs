@@ -20,10 +20,14 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type Constructor<N> = crate::ConstructorCore<N, Command<N>>;
 
 mod closure;
 pub use closure::*;
 
+mod constructor;
+pub use constructor::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -39,6 +43,9 @@ pub use logic::*;
 mod mapping;
 pub use mapping::*;
 
+mod upgrade_policy;
+pub use upgrade_policy::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -59,6 +66,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -79,12 +87,14 @@ use console::{
         Sanitizer,
         Serialize,
         Serializer,
+        ToBits,
         ToBytes,
         ToBytesSerializer,
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType, ProgramID, RecordType, StructType},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -109,6 +119,10 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     id: ProgramID<N>,
     /// A map of the declared imports for the program.
     imports: IndexMap<ProgramID<N>, Import<N>>,
+    /// The upgrade policy of the program.
+    upgrade_policy: UpgradePolicy<N>,
+    /// The optional constructor for the program.
+    constructor: Option<ConstructorCore<N, Command>>,
     /// A map of identifiers to their program declaration.
     identifiers: IndexMap<Identifier<N>, ProgramDefinition>,
     /// A map of the declared mappings for the program.
@@ -133,6 +147,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(Self {
             id,
             imports: IndexMap::new(),
+            upgrade_policy: UpgradePolicy::default(),
+            constructor: None,
             identifiers: IndexMap::new(),
             mappings: IndexMap::new(),
             structs: IndexMap::new(),
@@ -158,6 +174,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.imports
     }
 
+    /// Returns the upgrade policy of the program.
+    pub const fn upgrade_policy(&self) -> &UpgradePolicy<N> {
+        &self.upgrade_policy
+    }
+
+    /// Returns the constructor for the program, if it exists.
+    pub const fn constructor(&self) -> Option<&ConstructorCore<N, Command>> {
+        self.constructor.as_ref()
+    }
+
     /// Returns the mappings in the program.
     pub const fn mappings(&self) -> &IndexMap<Identifier<N>, Mapping<N>> {
         &self.mappings
@@ -319,6 +345,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(())
     }
 
+    /// Adds a constructor to the program.
+    ///
+    /// # Errors
+    /// This method will halt if a constructor was previously added.
+    #[inline]
+    fn add_constructor(&mut self, constructor: ConstructorCore<N, Command>) -> Result<()> {
+        // Ensure the program does not already have a constructor.
+        ensure!(self.constructor.is_none(), "Program '{}' already has a constructor.", self.id);
+        // Add the constructor to the program.
+        self.constructor = Some(constructor);
+        Ok(())
+    }
+
     /// Adds a new mapping to the program.
     ///
     /// # Errors
@@ -389,14 +428,30 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
-                PlaintextType::Array(array_type) => {
-                    if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                PlaintextType::ExternalStruct(locator) => {
+                    // Ensure the external struct is declared in an imported program.
+                    if !self.imports.contains_key(locator.program_id()) {
+                        bail!("External program '{}' in '{locator}' is not imported.", locator.program_id())
+                    }
+                }
+                PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                    PlaintextType::Struct(struct_name) => {
                         // Ensure the member struct name exists in the program.
                         if !self.structs.contains_key(struct_name) {
                             bail!("'{struct_name}' in array '{array_type}' is not defined.")
                         }
                     }
-                }
+                    PlaintextType::ExternalStruct(locator) => {
+                        // Ensure the external struct is declared in an imported program.
+                        if !self.imports.contains_key(locator.program_id()) {
+                            bail!(
+                                "External program '{}' in array '{array_type}' is not imported.",
+                                locator.program_id()
+                            )
+                        }
+                    }
+                    PlaintextType::Literal(..) | PlaintextType::Array(..) => {}
+                },
             }
         }
 
@@ -446,14 +501,30 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                     }
                 }
-                PlaintextType::Array(array_type) => {
-                    if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                PlaintextType::ExternalStruct(locator) => {
+                    // Ensure the external struct is declared in an imported program.
+                    if !self.imports.contains_key(locator.program_id()) {
+                        bail!("External program '{}' in '{locator}' is not imported.", locator.program_id())
+                    }
+                }
+                PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                    PlaintextType::Struct(struct_name) => {
                         // Ensure the member struct name exists in the program.
                         if !self.structs.contains_key(struct_name) {
                             bail!("'{struct_name}' in array '{array_type}' is not defined.")
                         }
                     }
-                }
+                    PlaintextType::ExternalStruct(locator) => {
+                        // Ensure the external struct is declared in an imported program.
+                        if !self.imports.contains_key(locator.program_id()) {
+                            bail!(
+                                "External program '{}' in array '{array_type}' is not imported.",
+                                locator.program_id()
+                            )
+                        }
+                    }
+                    PlaintextType::Literal(..) | PlaintextType::Array(..) => {}
+                },
             }
         }
 
@@ -561,6 +632,114 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Sets the upgrade policy of the program.
+    ///
+    /// # Errors
+    /// This method will halt if the admin function is not already defined in the program.
+    /// This method will halt if the admin function does not have a finalize scope.
+    /// This method will halt if the admin finalize scope does not take the edition and checksum as inputs.
+    #[inline]
+    fn set_upgrade_policy(&mut self, upgrade_policy: UpgradePolicy<N>) -> Result<()> {
+        // If the policy is governed by an admin function, ensure the admin function is well-formed.
+        if let Some(function_name) = upgrade_policy.admin_function() {
+            // Ensure the admin function exists in the program.
+            let Some(function) = self.functions.get(function_name) else {
+                bail!("Admin function '{function_name}' is not defined.")
+            };
+            // Ensure the admin function has a finalize scope.
+            let Some(finalize) = function.finalize_logic() else {
+                bail!("Admin function '{function_name}' is missing a finalize scope.")
+            };
+            // Ensure the finalize inputs are the new edition and the checksum of the new program.
+            let expected_input_types = [
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            ];
+            ensure!(
+                finalize.input_types() == expected_input_types,
+                "Admin function '{function_name}' must finalize with inputs 'u16.public' and 'field.public'"
+            );
+        }
+        // Set the upgrade policy.
+        self.upgrade_policy = upgrade_policy;
+        Ok(())
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the checksum of the program, which is the BHP hash of the program bytes.
+    pub fn to_checksum(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Checks that this program is a valid upgrade of the given previous edition of the program.
+    ///
+    /// An upgrade must preserve every import, mapping, struct, and record of the previous edition,
+    /// and every function of the previous edition with the same input, output, and finalize types.
+    /// New imports, mappings, structs, records, closures, and functions may be added,
+    /// and the body of any existing closure or function may change.
+    pub fn check_upgrade_of(&self, previous: &Self) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.id();
+        // Ensure the program IDs match.
+        ensure!(program_id == previous.id(), "Cannot upgrade '{}' into '{program_id}'", previous.id());
+        // Ensure the previous edition is upgradable.
+        ensure!(!previous.upgrade_policy().is_immutable(), "Program '{program_id}' is immutable");
+
+        // Ensure the imports are preserved.
+        for import in previous.imports().keys() {
+            ensure!(self.contains_import(import), "Upgrade of '{program_id}' is missing the import '{import}'");
+        }
+        // Ensure the mappings are preserved.
+        for (name, mapping) in previous.mappings() {
+            match self.mappings.get(name) {
+                Some(candidate) => ensure!(
+                    candidate == mapping,
+                    "Upgrade of '{program_id}' changes the key or value type of mapping '{name}'"
+                ),
+                None => bail!("Upgrade of '{program_id}' is missing the mapping '{name}'"),
+            }
+        }
+        // Ensure the structs are preserved.
+        for (name, struct_) in previous.structs() {
+            match self.structs.get(name) {
+                Some(candidate) => {
+                    ensure!(candidate == struct_, "Upgrade of '{program_id}' changes the members of struct '{name}'")
+                }
+                None => bail!("Upgrade of '{program_id}' is missing the struct '{name}'"),
+            }
+        }
+        // Ensure the records are preserved.
+        for (name, record) in previous.records() {
+            match self.records.get(name) {
+                Some(candidate) => {
+                    ensure!(candidate == record, "Upgrade of '{program_id}' changes the entries of record '{name}'")
+                }
+                None => bail!("Upgrade of '{program_id}' is missing the record '{name}'"),
+            }
+        }
+        // Ensure the function signatures are preserved.
+        for (name, function) in previous.functions() {
+            let Some(candidate) = self.functions.get(name) else {
+                bail!("Upgrade of '{program_id}' is missing the function '{name}'")
+            };
+            ensure!(
+                candidate.input_types() == function.input_types(),
+                "Upgrade of '{program_id}' changes the input types of function '{name}'"
+            );
+            ensure!(
+                candidate.output_types() == function.output_types(),
+                "Upgrade of '{program_id}' changes the output types of function '{name}'"
+            );
+            ensure!(
+                candidate.finalize_logic().map(|finalize| finalize.input_types())
+                    == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "Upgrade of '{program_id}' changes the finalize types of function '{name}'"
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -615,6 +794,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "value",
         "async",
         "finalize",
+        "constructor",
         // Reserved (catch all)
         "global",
         "block",
@@ -826,4 +1006,69 @@ function swap:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_check_upgrade_of() -> Result<()> {
+        // Initialize the previous edition.
+        let previous = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )?;
+
+        // An upgrade may change function bodies and add new definitions.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+upgrade owner;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+mapping supply:
+    key as boolean.public;
+    value as u64.public;
+
+function mint:
+    input r0 as u64.public;
+    mul r0 r0 into r1;
+    output r1 as u64.public;
+
+function burn:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )?;
+        assert!(upgrade.check_upgrade_of(&previous).is_ok());
+        // The reverse direction drops a mapping and a function.
+        assert!(previous.check_upgrade_of(&upgrade).is_err());
+
+        // An upgrade may not change the type of a mapping.
+        let changed_mapping =
+            Program::<CurrentNetwork>::from_str(&previous.to_string().replace("value as u64", "value as u128"))?;
+        assert!(changed_mapping.check_upgrade_of(&previous).is_err());
+
+        // An upgrade may not change the signature of a function.
+        let changed_function = Program::<CurrentNetwork>::from_str(
+            &previous.to_string().replace("output r1 as u64.public", "output r1 as u64.private"),
+        )?;
+        assert!(changed_function.check_upgrade_of(&previous).is_err());
+
+        // An immutable program may not be upgraded.
+        let immutable = Program::<CurrentNetwork>::from_str(&previous.to_string().replace("upgrade owner;", ""))?;
+        assert!(previous.check_upgrade_of(&immutable).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{Opcode, Operand};
+use console::{network::prelude::*, program::Register};
+
+/// An end-of-loop command, e.g. `end.loop;` or `end.loop r5 into r6;`.
+///
+/// Closes the innermost open `loop`. The operands are the next values of the loop-carried registers.
+/// If the counter has not reached the end, these values are carried into the next iteration.
+/// Otherwise, they are stored into the destinations, which hold the final values of the loop.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EndLoop<N: Network> {
+    /// The next values of the loop-carried registers.
+    operands: Vec<Operand<N>>,
+    /// The destination registers for the final values.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> EndLoop<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("end.loop")
+    }
+
+    /// Returns the next values of the loop-carried registers.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers for the final values.
+    #[inline]
+    pub fn destinations(&self) -> &[Register<N>] {
+        &self.destinations
+    }
+}
+
+impl<N: Network> Parser for EndLoop<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many0(complete(parse_operand))(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destinations from the string, if there are operands.
+        let (string, destinations) = match operands.is_empty() {
+            true => (string, vec![]),
+            false => {
+                // Parse the "into" from the string.
+                let (string, _) = tag("into")(string)?;
+                // Parse the destinations from the string.
+                many1(complete(parse_destination))(string)?
+            }
+        };
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Ensure the number of operands matches the number of destinations.
+        if operands.len() != destinations.len() {
+            return map_res(fail, |_: ParserResult<Self>| {
+                Err(error("Failed to parse 'end.loop': mismatched number of operands and destinations"))
+            })(string);
+        }
+        // Ensure the number of operands is within the bounds.
+        match operands.len() <= N::MAX_OPERANDS {
+            true => Ok((string, Self { operands, destinations })),
+            false => {
+                map_res(fail, |_: ParserResult<Self>| Err(error("Failed to parse 'end.loop': too many operands")))(
+                    string,
+                )
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for EndLoop<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for EndLoop<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for EndLoop<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{}", Self::opcode())?;
+        // Print the next values of the loop-carried registers, if any.
+        if !self.operands.is_empty() {
+            self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for EndLoop<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure that the number of operands does not exceed the upper bound.
+        if num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the destinations.
+        let destinations = (0..num_operands).map(|_| Register::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Return the command.
+        Ok(Self { operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for EndLoop<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands matches the number of destinations.
+        if self.operands.len() != self.destinations.len() {
+            return Err(error("The number of operands must match the number of destinations"));
+        }
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destinations.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, end_loop) = EndLoop::<CurrentNetwork>::parse("end.loop;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(end_loop.operands.is_empty(), "The operands are incorrect");
+        assert!(end_loop.destinations.is_empty(), "The destinations are incorrect");
+
+        let (string, end_loop) = EndLoop::<CurrentNetwork>::parse("end.loop r5 r6 into r7 r8;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(end_loop.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(end_loop.operands[0], Operand::Register(Register::Locator(5)), "The first operand is incorrect");
+        assert_eq!(end_loop.destinations, vec![Register::Locator(7), Register::Locator(8)]);
+
+        // Ensure the number of operands must match the number of destinations.
+        assert!(EndLoop::<CurrentNetwork>::parse("end.loop r5 into r7 r8;").is_err());
+        assert!(EndLoop::<CurrentNetwork>::parse("end.loop r5;").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        for expected in ["end.loop;", "end.loop r5 r6 into r7 r8;"] {
+            let end_loop = EndLoop::<CurrentNetwork>::from_str(expected).unwrap();
+            assert_eq!(expected, end_loop.to_string());
+            let bytes = end_loop.to_bytes_le().unwrap();
+            assert_eq!(end_loop, EndLoop::from_bytes_le(&bytes).unwrap());
+        }
+    }
+}
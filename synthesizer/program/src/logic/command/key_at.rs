@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    CallOperator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Register, Value},
+};
+
+/// A key-by-index command, e.g. `key.at accounts[r0] into r1;`.
+/// Stores the key at the `index` operand (a `u32`) in `mapping` into `destination`.
+/// The keys of a mapping are ordered by their serialized bytes, which is the order the storage keeps them in.
+/// If the index is out of bounds, or greater than `KeyAt::MAX_INDEX`, the command halts.
+/// As the cost of the lookup grows with the index, the command is priced per key up to the index,
+/// and it cannot be used inside a loop.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct KeyAt<N: Network> {
+    /// The mapping name.
+    mapping: CallOperator<N>,
+    /// The index of the key in the mapping.
+    index: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> KeyAt<N> {
+    /// The maximum index of a key, which bounds the number of keys walked by a lookup.
+    pub const MAX_INDEX: u32 = (1 << 16) - 1;
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("key.at")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.index.clone()]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the index.
+    #[inline]
+    pub const fn index(&self) -> &Operand<N> {
+        &self.index
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> KeyAt<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            CallOperator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Load the operand as a literal.
+        let index = match registers.load_literal(stack, &self.index)? {
+            Literal::U32(index) => *index,
+            literal => bail!("Expected the index to be a 'u32', found '{literal}'"),
+        };
+        // Ensure the index does not exceed the maximum index.
+        if index > Self::MAX_INDEX {
+            bail!("Index '{index}' exceeds the maximum index '{}' in 'key.at'", Self::MAX_INDEX);
+        }
+
+        // Retrieve the key at the index.
+        let key = match store.get_key_at_index_speculative(program_id, mapping_name, index as usize)? {
+            Some(key) => key,
+            None => bail!("Index '{index}' is out of bounds in mapping '{program_id}/{mapping_name}'"),
+        };
+
+        // Assign the key to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(key))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for KeyAt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the index operand from the string.
+        let (string, index) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, index, destination }))
+    }
+}
+
+impl<N: Network> FromStr for KeyAt<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for KeyAt<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for KeyAt<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and index operand.
+        write!(f, "{}[{}] into ", self.mapping, self.index)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for KeyAt<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the index operand.
+        let index = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, index, destination })
+    }
+}
+
+impl<N: Network> ToBytes for KeyAt<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the index operand.
+        self.index.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, key_at) = KeyAt::<CurrentNetwork>::parse("key.at account[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(key_at.mapping, CallOperator::from_str("account").unwrap());
+        assert_eq!(key_at.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(key_at.index, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(key_at.destination, Register::Locator(1), "The second operand is incorrect");
+
+        let (string, key_at) = KeyAt::<CurrentNetwork>::parse("key.at credits.aleo/account[0u32] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(key_at.mapping, CallOperator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(key_at.index, Operand::Literal(Literal::from_str("0u32").unwrap()), "The index is incorrect");
+        assert_eq!(key_at.destination, Register::Locator(1), "The second operand is incorrect");
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let (string, key_at) = KeyAt::<CurrentNetwork>::parse("key.at account[r0] into r1;").unwrap();
+        assert!(string.is_empty());
+        let bytes_le = key_at.to_bytes_le().unwrap();
+        let result = KeyAt::<CurrentNetwork>::from_bytes_le(&bytes_le[..]);
+        assert_eq!(result.unwrap(), key_at);
+    }
+}
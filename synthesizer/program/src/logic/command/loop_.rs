@@ -0,0 +1,399 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{Opcode, Operand};
+use console::{
+    network::prelude::*,
+    program::{Literal, Register},
+    types::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+};
+
+/// A loop command, e.g. `loop r2 in 0u32 to r1 bound 16u32 with 0u64 into r3;`.
+///
+/// Repeats the commands up to the matching `end.loop`, once for each value of the `counter`,
+/// from `start` (inclusive) to `end` (exclusive). The `bound` is the maximum number of iterations,
+/// and is used to price the loop at deployment. The optional `with` operands are the initial values
+/// of the loop-carried registers, which are updated by the matching `end.loop` after each iteration.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Loop<N: Network> {
+    /// The counter register.
+    counter: Register<N>,
+    /// The start of the counter (inclusive).
+    start: Operand<N>,
+    /// The end of the counter (exclusive).
+    end: Operand<N>,
+    /// The maximum number of iterations.
+    bound: U32<N>,
+    /// The initial values of the loop-carried registers.
+    operands: Vec<Operand<N>>,
+    /// The loop-carried registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Loop<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("loop")
+    }
+
+    /// Returns the counter register.
+    #[inline]
+    pub const fn counter(&self) -> &Register<N> {
+        &self.counter
+    }
+
+    /// Returns the start of the counter (inclusive).
+    #[inline]
+    pub const fn start(&self) -> &Operand<N> {
+        &self.start
+    }
+
+    /// Returns the end of the counter (exclusive).
+    #[inline]
+    pub const fn end(&self) -> &Operand<N> {
+        &self.end
+    }
+
+    /// Returns the maximum number of iterations.
+    #[inline]
+    pub fn bound(&self) -> u32 {
+        *self.bound
+    }
+
+    /// Returns the initial values of the loop-carried registers.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the loop-carried registers.
+    #[inline]
+    pub fn destinations(&self) -> &[Register<N>] {
+        &self.destinations
+    }
+}
+
+impl<N: Network> Loop<N> {
+    /// Returns the number of iterations for the given `start` and `end` of the counter.
+    ///
+    /// # Errors
+    /// This method will halt if the operands are not integers of the same type.
+    /// This method will halt if the number of iterations exceeds the bound.
+    pub fn num_iterations(&self, start: &Literal<N>, end: &Literal<N>) -> Result<u32> {
+        macro_rules! num_iterations {
+            ($( $variant:ident ),+) => {
+                match (start, end) {
+                    $( (Literal::$variant(start), Literal::$variant(end)) => match **end > **start {
+                        true => u128::from((**end).abs_diff(**start)),
+                        false => 0,
+                    }, )+
+                    _ => bail!("Expected the loop range to be integers of the same type, found '{start}' and '{end}'"),
+                }
+            };
+        }
+
+        // Compute the number of iterations.
+        let num_iterations = num_iterations!(I8, I16, I32, I64, I128, U8, U16, U32, U64, U128);
+        // Ensure the number of iterations does not exceed the bound.
+        match u32::try_from(num_iterations) {
+            Ok(num_iterations) if num_iterations <= self.bound() => Ok(num_iterations),
+            _ => bail!("The loop range '{start}..{end}' exceeds the bound of {} iterations", self.bound()),
+        }
+    }
+
+    /// Returns the next value of the given `counter`, if it is less than `end`.
+    /// Otherwise, returns `None`.
+    ///
+    /// # Errors
+    /// This method will halt if the operands are not integers of the same type.
+    pub fn next_counter(&self, counter: &Literal<N>, end: &Literal<N>) -> Result<Option<Literal<N>>> {
+        macro_rules! next_counter {
+            ($( $variant:ident ),+) => {
+                match (counter, end) {
+                    $( (Literal::$variant(counter), Literal::$variant(end)) => {
+                        match (**counter).checked_add(1) {
+                            Some(next) if next < **end => Some(Literal::$variant($variant::new(next))),
+                            _ => None,
+                        }
+                    } )+
+                    _ => bail!("Expected the loop counter to be integers of the same type, found '{counter}' and '{end}'"),
+                }
+            };
+        }
+
+        // Compute the next value of the counter.
+        Ok(next_counter!(I8, I16, I32, I64, I128, U8, U16, U32, U64, U128))
+    }
+}
+
+impl<N: Network> Parser for Loop<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the counter register from the string.
+        let (string, counter) = parse_destination(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "in" from the string.
+        let (string, _) = tag("in")(string)?;
+        // Parse the start of the counter from the string.
+        let (string, start) = parse_operand(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "to" from the string.
+        let (string, _) = tag("to")(string)?;
+        // Parse the end of the counter from the string.
+        let (string, end) = parse_operand(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "bound" from the string.
+        let (string, _) = tag("bound")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the bound from the string.
+        let (string, bound) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Optionally parse the "with" from the string.
+        let (string, (operands, destinations)) = match opt(tag("with"))(string)? {
+            // If the "with" was not parsed, return the string and empty vectors.
+            (string, None) => (string, (vec![], vec![])),
+            // If the "with" was parsed, parse the initial values and the loop-carried registers from the string.
+            (string, Some(_)) => {
+                // Parse the initial values from the string.
+                let (string, operands) = many1(complete(parse_operand))(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the "into" from the string.
+                let (string, _) = tag("into")(string)?;
+                // Parse the loop-carried registers from the string.
+                let (string, destinations) = many1(complete(parse_destination))(string)?;
+                (string, (operands, destinations))
+            }
+        };
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Ensure the number of operands matches the number of destinations.
+        if operands.len() != destinations.len() {
+            return map_res(fail, |_: ParserResult<Self>| {
+                Err(error("Failed to parse 'loop': mismatched number of operands and destinations"))
+            })(string);
+        }
+        // Ensure the number of operands is within the bounds.
+        match operands.len() <= N::MAX_OPERANDS {
+            true => Ok((string, Self { counter, start, end, bound, operands, destinations })),
+            false => {
+                map_res(fail, |_: ParserResult<Self>| Err(error("Failed to parse 'loop': too many operands")))(string)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for Loop<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Loop<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Loop<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} {} in {} to {} bound {}", Self::opcode(), self.counter, self.start, self.end, self.bound)?;
+        // Print the loop-carried registers, if any.
+        if !self.operands.is_empty() {
+            write!(f, " with")?;
+            self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for Loop<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the counter register.
+        let counter = Register::read_le(&mut reader)?;
+        // Read the start of the counter.
+        let start = Operand::read_le(&mut reader)?;
+        // Read the end of the counter.
+        let end = Operand::read_le(&mut reader)?;
+        // Read the bound.
+        let bound = U32::read_le(&mut reader)?;
+
+        // Read the number of loop-carried registers.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure that the number of operands does not exceed the upper bound.
+        if num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+        // Read the initial values.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the loop-carried registers.
+        let destinations = (0..num_operands).map(|_| Register::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Return the command.
+        Ok(Self { counter, start, end, bound, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Loop<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands matches the number of destinations.
+        if self.operands.len() != self.destinations.len() {
+            return Err(error("The number of operands must match the number of destinations"));
+        }
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the counter register.
+        self.counter.write_le(&mut writer)?;
+        // Write the start of the counter.
+        self.start.write_le(&mut writer)?;
+        // Write the end of the counter.
+        self.end.write_le(&mut writer)?;
+        // Write the bound.
+        self.bound.write_le(&mut writer)?;
+        // Write the number of loop-carried registers.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the initial values.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the loop-carried registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, loop_) = Loop::<CurrentNetwork>::parse("loop r2 in 0u32 to r1 bound 16u32;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(loop_.counter, Register::Locator(2), "The counter is incorrect");
+        assert_eq!(loop_.start, Operand::Literal(Literal::from_str("0u32").unwrap()), "The start is incorrect");
+        assert_eq!(loop_.end, Operand::Register(Register::Locator(1)), "The end is incorrect");
+        assert_eq!(loop_.bound(), 16, "The bound is incorrect");
+        assert!(loop_.operands.is_empty(), "The operands are incorrect");
+        assert!(loop_.destinations.is_empty(), "The destinations are incorrect");
+
+        let (string, loop_) =
+            Loop::<CurrentNetwork>::parse("loop r2 in r0 to 8u8 bound 8u32 with 0u64 r1 into r3 r4;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(loop_.counter, Register::Locator(2), "The counter is incorrect");
+        assert_eq!(loop_.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(
+            loop_.destinations,
+            vec![Register::Locator(3), Register::Locator(4)],
+            "The destinations are incorrect"
+        );
+
+        // Ensure the number of operands must match the number of destinations.
+        assert!(Loop::<CurrentNetwork>::parse("loop r2 in 0u32 to r1 bound 16u32 with r0 into r3 r4;").is_err());
+        // Ensure the bound is required.
+        assert!(Loop::<CurrentNetwork>::parse("loop r2 in 0u32 to r1;").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        for expected in
+            ["loop r2 in 0u32 to r1 bound 16u32;", "loop r2 in r0 to 8u8 bound 8u32 with 0u64 r1 into r3 r4;"]
+        {
+            let loop_ = Loop::<CurrentNetwork>::from_str(expected).unwrap();
+            assert_eq!(expected, loop_.to_string());
+            let bytes = loop_.to_bytes_le().unwrap();
+            assert_eq!(loop_, Loop::from_bytes_le(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_num_iterations() {
+        let loop_ = Loop::<CurrentNetwork>::from_str("loop r2 in 0u32 to r1 bound 16u32;").unwrap();
+        let literal = |string: &str| Literal::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(loop_.num_iterations(&literal("0u32"), &literal("16u32")).unwrap(), 16);
+        assert_eq!(loop_.num_iterations(&literal("5u32"), &literal("3u32")).unwrap(), 0);
+        assert_eq!(loop_.num_iterations(&literal("-8i8"), &literal("8i8")).unwrap(), 16);
+        assert_eq!(loop_.num_iterations(&literal("-128i8"), &literal("-127i8")).unwrap(), 1);
+        // Ensure the bound is enforced.
+        assert!(loop_.num_iterations(&literal("0u32"), &literal("17u32")).is_err());
+        assert!(loop_
+            .num_iterations(&literal("0u128"), &literal("340282366920938463463374607431768211455u128"))
+            .is_err());
+        // Ensure the operands must be integers of the same type.
+        assert!(loop_.num_iterations(&literal("0u32"), &literal("16u64")).is_err());
+        assert!(loop_.num_iterations(&literal("0field"), &literal("16field")).is_err());
+
+        assert_eq!(loop_.next_counter(&literal("0u32"), &literal("2u32")).unwrap(), Some(literal("1u32")));
+        assert_eq!(loop_.next_counter(&literal("1u32"), &literal("2u32")).unwrap(), None);
+        assert_eq!(loop_.next_counter(&literal("127i8"), &literal("127i8")).unwrap(), None);
+        assert!(loop_.next_counter(&literal("0u32"), &literal("2u8")).is_err());
+    }
+}
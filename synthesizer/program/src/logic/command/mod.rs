@@ -21,12 +21,24 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod end_loop;
+pub use end_loop::*;
+
 mod get;
 pub use get::*;
 
 mod get_or_use;
 pub use get_or_use::*;
 
+mod key_at;
+pub use key_at::*;
+
+mod length;
+pub use length::*;
+
+mod loop_;
+pub use loop_::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -53,6 +65,8 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -84,6 +98,14 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Repeats the commands up to the matching `end.loop`, for each value of the counter from `start` to `end`.
+    Loop(Loop<N>),
+    /// Closes the innermost loop, and carries the `operands` into the next iteration or into `destinations`.
+    EndLoop(EndLoop<N>),
+    /// Stores the number of entries in `mapping` into `destination`.
+    Length(Length<N>),
+    /// Stores the key at the `index` operand in `mapping` into `destination`.
+    KeyAt(KeyAt<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -96,6 +118,10 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
+            Command::Loop(loop_) => [vec![loop_.counter().clone()], loop_.destinations().to_vec()].concat(),
+            Command::EndLoop(end_loop) => end_loop.destinations().to_vec(),
+            Command::Length(length) => vec![length.destination().clone()],
+            Command::KeyAt(key_at) => vec![key_at.destination().clone()],
             Command::Await(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
@@ -126,10 +152,38 @@ impl<N: Network> CommandTrait<N> for Command<N> {
         }
     }
 
+    /// Returns the iteration bound, if the command is a loop command.
+    /// Otherwise, returns `None`.
+    #[inline]
+    fn loop_bound(&self) -> Option<u32> {
+        match self {
+            Command::Loop(loop_) => Some(loop_.bound()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the command is an end-of-loop command.
+    #[inline]
+    fn is_end_loop(&self) -> bool {
+        matches!(self, Command::EndLoop(_))
+    }
+
+    /// Returns `true` if the command is an await command.
+    #[inline]
+    fn is_await(&self) -> bool {
+        matches!(self, Command::Await(_))
+    }
+
+    /// Returns `true` if the command is a key-by-index command.
+    #[inline]
+    fn is_key_at(&self) -> bool {
+        matches!(self, Command::KeyAt(_))
+    }
+
     /// Returns `true` if the command is a call instruction.
     #[inline]
     fn is_call(&self) -> bool {
-        matches!(self, Command::Instruction(Instruction::Call(_)))
+        matches!(self, Command::Instruction(Instruction::Call(_) | Instruction::CallDynamic(_)))
     }
 
     /// Returns `true` if the command is a cast to record instruction.
@@ -145,6 +199,51 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+            Command::Loop(_) => Loop::<N>::opcode(),
+            Command::EndLoop(_) => EndLoop::<N>::opcode(),
+            Command::Length(_) => Length::<N>::opcode(),
+            Command::KeyAt(_) => KeyAt::<N>::opcode(),
+        }
+    }
+
+    /// Returns the operands of the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+            Command::Contains(contains) => contains.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => vec![branch_eq.first().clone(), branch_eq.second().clone()],
+            Command::BranchNeq(branch_neq) => vec![branch_neq.first().clone(), branch_neq.second().clone()],
+            Command::Loop(loop_) => {
+                [vec![loop_.start().clone(), loop_.end().clone()], loop_.operands().to_vec()].concat()
+            }
+            Command::EndLoop(end_loop) => end_loop.operands().to_vec(),
+            Command::KeyAt(key_at) => key_at.operands(),
+            Command::Position(_) | Command::Length(_) => vec![],
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -176,6 +275,14 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // 'loop' and 'end.loop' commands are processed by the caller of this method.
+            Command::Loop(_) | Command::EndLoop(_) => {
+                bail!("`loop` commands cannot be finalized directly.")
+            }
+            // Finalize the 'length' command, and return no finalize operation.
+            Command::Length(length) => length.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'key.at' command, and return no finalize operation.
+            Command::KeyAt(key_at) => key_at.finalize(stack, store, registers).map(|_| None),
         }
     }
 }
@@ -208,8 +315,16 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `loop` command.
+            11 => Ok(Self::Loop(Loop::read_le(&mut reader)?)),
+            // Read the `end.loop` command.
+            12 => Ok(Self::EndLoop(EndLoop::read_le(&mut reader)?)),
+            // Read the `length` command.
+            13 => Ok(Self::Length(Length::read_le(&mut reader)?)),
+            // Read the `key.at` command.
+            14 => Ok(Self::KeyAt(KeyAt::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            15.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +399,30 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Loop(loop_) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `loop` command.
+                loop_.write_le(&mut writer)
+            }
+            Self::EndLoop(end_loop) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `end.loop` command.
+                end_loop.write_le(&mut writer)
+            }
+            Self::Length(length) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `length` command.
+                length.write_le(&mut writer)
+            }
+            Self::KeyAt(key_at) => {
+                // Write the variant.
+                14u8.write_le(&mut writer)?;
+                // Write the `key.at` command.
+                key_at.write_le(&mut writer)
+            }
         }
     }
 }
@@ -305,6 +444,10 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(Loop::parse, |loop_| Self::Loop(loop_)),
+            map(EndLoop::parse, |end_loop| Self::EndLoop(end_loop)),
+            map(Length::parse, |length| Self::Length(length)),
+            map(KeyAt::parse, |key_at| Self::KeyAt(key_at)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -350,6 +493,10 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::Loop(loop_) => Display::fmt(loop_, f),
+            Self::EndLoop(end_loop) => Display::fmt(end_loop, f),
+            Self::Length(length) => Display::fmt(length, f),
+            Self::KeyAt(key_at) => Display::fmt(key_at, f),
         }
     }
 }
@@ -442,6 +589,30 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Loop
+        let expected = "loop r2 in 0u32 to r1 bound 16u32 with 0u64 into r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // EndLoop
+        let expected = "end.loop r5 into r6;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Length
+        let expected = "length object into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // KeyAt
+        let expected = "key.at object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -519,5 +690,29 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Loop
+        let expected = "loop r2 in 0u32 to r1 bound 16u32 with 0u64 into r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Loop(Loop::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // EndLoop
+        let expected = "end.loop;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::EndLoop(EndLoop::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Length
+        let expected = "length object into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Length(Length::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // KeyAt
+        let expected = "key.at object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::KeyAt(KeyAt::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -83,6 +83,8 @@ pub enum Instruction<N: Network> {
     Async(Async<N>),
     /// Calls a closure or function on the operands.
     Call(Call<N>),
+    /// Calls a function, whose program name and function name are given at runtime, on the operands.
+    CallDynamic(CallDynamic<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
     /// Casts the operands into the declared type, with lossy truncation if applicable.
@@ -105,6 +107,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether `signature` is a valid ECDSA secp256k1 signature over `message` by `address`, storing the outcome in `destination`.
+    ECDSAVerifySecp256k1(ECDSAVerifySecp256k1<N>),
+    /// Computes whether `signature` is a valid Ed25519 signature over `message` by `public_key`, storing the outcome in `destination`.
+    Ed25519Verify(Ed25519Verify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -300,6 +306,9 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            ECDSAVerifySecp256k1,
+            Ed25519Verify,
+            CallDynamic,
         }}
     };
     // A variant **without** curly braces:
@@ -437,7 +446,7 @@ impl<N: Network> Instruction<N> {
     #[inline]
     pub fn output_types(
         &self,
-        stack: &impl StackProgram<N>,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
         input_types: &[RegisterType<N>],
     ) -> Result<Vec<RegisterType<N>>> {
         instruction!(self, |instruction| instruction.output_types(stack, input_types))
@@ -469,7 +478,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            71,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
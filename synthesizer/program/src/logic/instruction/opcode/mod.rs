@@ -23,6 +23,8 @@ pub enum Opcode {
     Async,
     /// The opcode is for a call operation (i.e. `call`).
     Call,
+    /// The opcode is for a dynamic call operation (i.e. `call.dynamic`).
+    CallDynamic,
     /// The opcode is for a cast operation (i.e. `cast`).
     Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
@@ -37,6 +39,8 @@ pub enum Opcode {
     Literal(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for external signature verification (i.e. `ecdsa.verify.secp256k1`).
+    Verify(&'static str),
 }
 
 impl Deref for Opcode {
@@ -48,6 +52,7 @@ impl Deref for Opcode {
             Opcode::Assert(opcode) => opcode,
             Opcode::Async => &"async",
             Opcode::Call => &"call",
+            Opcode::CallDynamic => &"call.dynamic",
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
@@ -55,6 +60,7 @@ impl Deref for Opcode {
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::Verify(opcode) => opcode,
         }
     }
 }
@@ -73,6 +79,7 @@ impl Display for Opcode {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Async => write!(f, "{}", self.deref()),
             Self::Call => write!(f, "{}", self.deref()),
+            Self::CallDynamic => write!(f, "{}", self.deref()),
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
@@ -80,6 +87,7 @@ impl Display for Opcode {
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::Verify(opcode) => write!(f, "{opcode}"),
         }
     }
 }
@@ -24,6 +24,9 @@ impl<N: Network> FromBytes for Operand<N> {
             4 => Ok(Self::Caller),
             5 => Ok(Self::BlockHeight),
             6 => Ok(Self::NetworkID),
+            7 => Ok(Self::BlockTimestamp),
+            8 => Ok(Self::BlockPreviousHash),
+            9 => Ok(Self::BlockEpoch),
             variant => Err(error(format!("Failed to deserialize operand variant {variant}"))),
         }
     }
@@ -48,6 +51,9 @@ impl<N: Network> ToBytes for Operand<N> {
             Self::Caller => 4u8.write_le(&mut writer),
             Self::BlockHeight => 5u8.write_le(&mut writer),
             Self::NetworkID => 6u8.write_le(&mut writer),
+            Self::BlockTimestamp => 7u8.write_le(&mut writer),
+            Self::BlockPreviousHash => 8u8.write_le(&mut writer),
+            Self::BlockEpoch => 9u8.write_le(&mut writer),
         }
     }
 }
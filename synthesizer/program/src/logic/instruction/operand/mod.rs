@@ -43,6 +43,15 @@ pub enum Operand<N: Network> {
     /// The operand is the network ID.
     /// Note: This variant is only accessible in the `finalize` scope.
     NetworkID,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the previous block hash.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockPreviousHash,
+    /// The operand is the puzzle epoch number of the block.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockEpoch,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
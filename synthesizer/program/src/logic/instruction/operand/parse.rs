@@ -27,6 +27,9 @@ impl<N: Network> Parser for Operand<N> {
             map(tag("self.caller"), |_| Self::Caller),
             map(tag("block.height"), |_| Self::BlockHeight),
             map(tag("network.id"), |_| Self::NetworkID),
+            map(tag("block.timestamp"), |_| Self::BlockTimestamp),
+            map(tag("block.previous_hash"), |_| Self::BlockPreviousHash),
+            map(tag("block.epoch"), |_| Self::BlockEpoch),
             // Note that `Operand::ProgramID`s must be parsed before `Operand::Literal`s, since a program ID can be implicitly parsed as a literal address.
             // This ensures that the string representation of a program uses the `Operand::ProgramID` variant.
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -79,6 +82,12 @@ impl<N: Network> Display for Operand<N> {
             Self::BlockHeight => write!(f, "block.height"),
             // Prints the identifier for the network ID, i.e. network.id
             Self::NetworkID => write!(f, "network.id"),
+            // Prints the identifier for the block timestamp, i.e. block.timestamp
+            Self::BlockTimestamp => write!(f, "block.timestamp"),
+            // Prints the identifier for the previous block hash, i.e. block.previous_hash
+            Self::BlockPreviousHash => write!(f, "block.previous_hash"),
+            // Prints the identifier for the block epoch, i.e. block.epoch
+            Self::BlockEpoch => write!(f, "block.epoch"),
         }
     }
 }
@@ -116,6 +125,15 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("network.id").unwrap().1;
         assert_eq!(Operand::NetworkID, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(Operand::BlockTimestamp, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(Operand::BlockPreviousHash, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.epoch").unwrap().1;
+        assert_eq!(Operand::BlockEpoch, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
@@ -147,6 +165,15 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.timestamp");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.previous_hash");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.epoch").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.epoch");
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(
             format!("{operand}"),
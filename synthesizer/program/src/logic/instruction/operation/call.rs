@@ -227,7 +227,18 @@ impl<N: Network> Call<N> {
                 bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
             }
             // Return the output register types.
-            Ok(closure.outputs().iter().map(|output| output.register_type()).cloned().collect())
+            Ok(closure
+                .outputs()
+                .iter()
+                .map(|output| match (is_external, output.register_type()) {
+                    // If the output is a plaintext and the closure is external, qualify any struct types.
+                    (true, RegisterType::Plaintext(plaintext_type)) => {
+                        RegisterType::Plaintext(plaintext_type.qualify(program.id()))
+                    }
+                    // Else, return the register type.
+                    (_, register_type) => register_type.clone(),
+                })
+                .collect())
         }
         // If the operator is a function, retrieve the function and compute the output types.
         else if let Ok(function) = program.get_function(resource) {
@@ -252,6 +263,12 @@ impl<N: Network> Call<N> {
                     (true, ValueType::Record(record_name)) => Ok(RegisterType::ExternalRecord(Locator::from_str(
                         &format!("{}/{}", program.id(), record_name),
                     )?)),
+                    // If the output is a plaintext and the function is external, qualify any struct types.
+                    (true, ValueType::Constant(plaintext_type))
+                    | (true, ValueType::Public(plaintext_type))
+                    | (true, ValueType::Private(plaintext_type)) => {
+                        Ok(RegisterType::Plaintext(plaintext_type.qualify(program.id())))
+                    }
                     // Else, return the register type.
                     (_, output_type) => Ok(RegisterType::from(output_type)),
                 })
@@ -0,0 +1,494 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{
+        Identifier,
+        Literal,
+        LiteralType,
+        Locator,
+        Plaintext,
+        PlaintextType,
+        ProgramID,
+        Register,
+        RegisterType,
+        Value,
+        ValueType,
+    },
+    types::Field,
+};
+
+/// Calls a function, whose program name and function name are given as field elements at runtime,
+/// on the operands, with the declared input and output types.
+/// i.e. `call.dynamic r0 r1 with r2 r3 as address.public u64.public into r4 as boolean.public;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallDynamic<N: Network> {
+    /// The operands, starting with the program name and function name of the target.
+    operands: Vec<Operand<N>>,
+    /// The declared types of the inputs.
+    input_types: Vec<ValueType<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+    /// The declared types of the outputs.
+    output_types: Vec<ValueType<N>>,
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallDynamic
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the operand for the program name of the target.
+    #[inline]
+    pub fn program_name(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the operand for the function name of the target.
+    #[inline]
+    pub fn function_name(&self) -> &Operand<N> {
+        &self.operands[1]
+    }
+
+    /// Returns the operands for the inputs of the target.
+    #[inline]
+    pub fn inputs(&self) -> &[Operand<N>] {
+        &self.operands[2..]
+    }
+
+    /// Returns the declared types of the inputs.
+    #[inline]
+    pub fn input_types(&self) -> &[ValueType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the declared types of the outputs.
+    #[inline]
+    pub fn declared_output_types(&self) -> &[ValueType<N>] {
+        &self.output_types
+    }
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Returns the locator of the target, given the program name and function name as field elements.
+    pub fn to_locator(program_name: &Value<N>, function_name: &Value<N>) -> Result<Locator<N>> {
+        /// Returns the identifier encoded in the given value.
+        fn to_identifier<N: Network>(value: &Value<N>) -> Result<Identifier<N>> {
+            match value {
+                Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => Identifier::from_field(field),
+                _ => bail!("Expected a field element for the target of a dynamic call, found '{value}'"),
+            }
+        }
+
+        // Construct the program ID.
+        let program_id = ProgramID::try_from((to_identifier(program_name)?, Identifier::from_str("aleo")?))?;
+        // Return the locator.
+        Ok(Locator::new(program_id, to_identifier(function_name)?))
+    }
+
+    /// Returns the program name and function name of the given locator as field elements.
+    pub fn to_fields(locator: &Locator<N>) -> Result<(Field<N>, Field<N>)> {
+        // Ensure the program ID is on the expected network.
+        ensure!(
+            locator.program_id().network() == &Identifier::from_str("aleo")?,
+            "The target of a dynamic call must be an '.aleo' program, found '{locator}'"
+        );
+        Ok((locator.program_id().name().to_field()?, locator.resource().to_field()?))
+    }
+
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'call.dynamic' directly. Use 'call.dynamic' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'call.dynamic' directly. Use 'call.dynamic' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.dynamic'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types matches the number of operands.
+        if input_types.len() != self.operands.len() {
+            bail!("Expected {} input types, found {}", self.operands.len(), input_types.len())
+        }
+        // Ensure the program name and function name are field elements.
+        for input_type in &input_types[..2] {
+            if input_type != &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+                bail!("Instruction '{}' expects the target to be a 'field', found '{input_type}'", Self::opcode())
+            }
+        }
+        // Ensure the number of destinations matches the number of declared outputs.
+        if self.destinations.len() != self.output_types.len() {
+            bail!("Expected {} outputs, found {}", self.output_types.len(), self.destinations.len())
+        }
+
+        /// Returns the plaintext type of the given value type, if it is a public or private plaintext.
+        fn to_plaintext_type<N: Network>(value_type: &ValueType<N>) -> Result<&PlaintextType<N>> {
+            match value_type {
+                ValueType::Public(plaintext_type) | ValueType::Private(plaintext_type) => Ok(plaintext_type),
+                _ => bail!(
+                    "Instruction '{}' only supports public and private types, found '{value_type}'",
+                    Opcode::CallDynamic
+                ),
+            }
+        }
+
+        // Ensure the inputs match the declared input types.
+        for (input_type, declared_type) in input_types[2..].iter().zip_eq(&self.input_types) {
+            let expected_type = RegisterType::Plaintext(to_plaintext_type(declared_type)?.clone());
+            if input_type != &expected_type {
+                bail!(
+                    "Instruction '{}' expects an input of type '{expected_type}', found '{input_type}'",
+                    Self::opcode()
+                )
+            }
+        }
+        // Return the declared output types.
+        self.output_types
+            .iter()
+            .map(|output_type| Ok(RegisterType::Plaintext(to_plaintext_type(output_type)?.clone())))
+            .collect()
+    }
+}
+
+impl<N: Network> Parser for CallDynamic<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        /// Parses a list of declared types from the string.
+        fn parse_value_types<N: Network>(string: &str, expected: usize) -> ParserResult<Vec<ValueType<N>>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "as" from the string.
+            let (string, _) = tag("as")(string)?;
+            // Parse the declared types from the string.
+            map_res(
+                many1(complete(|string| {
+                    let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                    ValueType::parse(string)
+                })),
+                |value_types: Vec<ValueType<N>>| {
+                    // Ensure the number of declared types matches.
+                    match value_types.len() == expected {
+                        true => Ok(value_types),
+                        false => Err(error("Failed to parse 'call.dynamic' opcode: mismatched number of types")),
+                    }
+                },
+            )(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the program name and function name from the string.
+        let (string, program_name) = parse_operand(string)?;
+        let (string, function_name) = parse_operand(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "with" from the string.
+        let (string, (inputs, input_types)) = match opt(tag("with"))(string)? {
+            // If the "with" was not parsed, return the string and empty vectors.
+            (string, None) => (string, (vec![], vec![])),
+            // If the "with" was parsed, parse the inputs and their declared types from the string.
+            (string, Some(_)) => {
+                // Parse the inputs from the string.
+                let (string, inputs) = map_res(many1(complete(parse_operand)), |inputs: Vec<Operand<N>>| {
+                    // Ensure the number of inputs is within the bounds.
+                    match inputs.len() <= N::MAX_INPUTS {
+                        true => Ok(inputs),
+                        false => Err(error("Failed to parse 'call.dynamic' opcode: too many inputs")),
+                    }
+                })(string)?;
+                // Parse the declared input types from the string.
+                let (string, input_types) = parse_value_types(string, inputs.len())?;
+                (string, (inputs, input_types))
+            }
+        };
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, (destinations, output_types)) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and empty vectors.
+            (string, None) => (string, (vec![], vec![])),
+            // If the "into" was parsed, parse the destinations and their declared types from the string.
+            (string, Some(_)) => {
+                // Parse the destinations from the string.
+                let (string, destinations) =
+                    map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                        // Ensure the number of destinations is within the bounds.
+                        match destinations.len() <= N::MAX_OUTPUTS {
+                            true => Ok(destinations),
+                            false => Err(error("Failed to parse 'call.dynamic' opcode: too many destinations")),
+                        }
+                    })(string)?;
+                // Parse the declared output types from the string.
+                let (string, output_types) = parse_value_types(string, destinations.len())?;
+                (string, (destinations, output_types))
+            }
+        };
+
+        // Construct the operands.
+        let operands = [vec![program_name, function_name], inputs].concat();
+
+        Ok((string, Self { operands, input_types, destinations, output_types }))
+    }
+}
+
+impl<N: Network> FromStr for CallDynamic<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallDynamic<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallDynamic<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of inputs is within the bounds.
+        if self.operands.len() < 2 || self.inputs().len() > N::MAX_INPUTS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OUTPUTS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} {}", Self::opcode(), self.program_name(), self.function_name())?;
+        if !self.inputs().is_empty() {
+            write!(f, " with")?;
+            self.inputs().iter().try_for_each(|input| write!(f, " {input}"))?;
+            write!(f, " as")?;
+            self.input_types.iter().try_for_each(|input_type| write!(f, " {input_type}"))?;
+        }
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+            write!(f, " as")?;
+            self.output_types.iter().try_for_each(|output_type| write!(f, " {output_type}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CallDynamic<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program name and function name.
+        let program_name = Operand::read_le(&mut reader)?;
+        let function_name = Operand::read_le(&mut reader)?;
+
+        // Read the number of inputs.
+        let num_inputs = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of inputs is within the bounds.
+        if num_inputs > N::MAX_INPUTS {
+            return Err(error(format!("The number of inputs must be <= {}", N::MAX_INPUTS)));
+        }
+        // Read the inputs and their declared types.
+        let mut operands = Vec::with_capacity(2 + num_inputs);
+        operands.push(program_name);
+        operands.push(function_name);
+        for _ in 0..num_inputs {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        let input_types = (0..num_inputs).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OUTPUTS)));
+        }
+        // Read the destination registers and their declared types.
+        let destinations =
+            (0..num_destinations).map(|_| Register::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let output_types =
+            (0..num_destinations).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the operation.
+        Ok(Self { operands, input_types, destinations, output_types })
+    }
+}
+
+impl<N: Network> ToBytes for CallDynamic<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of inputs is within the bounds.
+        if self.operands.len() < 2 || self.inputs().len() > N::MAX_INPUTS {
+            return Err(error(format!("The number of inputs must be <= {}", N::MAX_INPUTS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OUTPUTS)));
+        }
+
+        // Write the program name and function name.
+        self.program_name().write_le(&mut writer)?;
+        self.function_name().write_le(&mut writer)?;
+        // Write the number of inputs.
+        u8::try_from(self.inputs().len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the inputs and their declared types.
+        self.inputs().iter().try_for_each(|input| input.write_le(&mut writer))?;
+        self.input_types.iter().try_for_each(|input_type| input_type.write_le(&mut writer))?;
+        // Write the number of destination registers.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers and their declared types.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))?;
+        self.output_types.iter().try_for_each(|output_type| output_type.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.dynamic r0 r1",
+        "call.dynamic r0 r1 with r2 as u64.public",
+        "call.dynamic r0 r1 into r2 as boolean.private",
+        "call.dynamic r0 r1 with r2 r3.owner as address.public u64.private into r4 r5 as boolean.public field.private",
+        "call.dynamic 1field 2field with self.caller 100u64 as address.public u64.public",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, call) = CallDynamic::<CurrentNetwork>::parse(
+            "call.dynamic r0 r1 with r2 100u64 as address.public u64.private into r3 as boolean.public",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(call.program_name(), &Operand::Register(Register::Locator(0)));
+        assert_eq!(call.function_name(), &Operand::Register(Register::Locator(1)));
+        assert_eq!(call.inputs().len(), 2);
+        assert_eq!(call.input_types(), &[
+            ValueType::from_str("address.public").unwrap(),
+            ValueType::from_str("u64.private").unwrap()
+        ]);
+        assert_eq!(call.destinations(), vec![Register::Locator(3)]);
+        assert_eq!(call.declared_output_types(), &[ValueType::from_str("boolean.public").unwrap()]);
+
+        // Ensure the number of declared types must match.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic r0 r1 with r2 r3 as u64.public").is_err());
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic r0 r1 into r2 as u64.public u64.public").is_err());
+        // Ensure the target is required.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call.dynamic r0").is_err());
+        // Ensure a static call is not parsed.
+        assert!(CallDynamic::<CurrentNetwork>::from_str("call foo r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_locator() {
+        let locator = Locator::<CurrentNetwork>::from_str("token.aleo/transfer_public").unwrap();
+        let (program_name, function_name) = CallDynamic::to_fields(&locator).unwrap();
+        let to_value = |field| Value::from(Literal::Field(field));
+        assert_eq!(CallDynamic::to_locator(&to_value(program_name), &to_value(function_name)).unwrap(), locator);
+        // Ensure a non-field target is rejected.
+        assert!(CallDynamic::to_locator(&Value::from_str("1u8").unwrap(), &to_value(function_name)).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallDynamic::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallDynamic::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallDynamic::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
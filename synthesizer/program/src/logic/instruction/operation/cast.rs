@@ -246,8 +246,8 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
             }
-            CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
-                self.cast_to_struct(stack, registers, *struct_name, inputs)
+            CastType::Plaintext(struct_type @ (PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..))) => {
+                self.cast_to_struct(stack, registers, struct_type, inputs)
             }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
@@ -395,32 +395,32 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     circuit::Value::Plaintext(circuit::Plaintext::from(value)),
                 )
             }
-            CastType::Plaintext(PlaintextType::Struct(struct_)) => {
+            CastType::Plaintext(struct_type @ (PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..))) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_STRUCT_ENTRIES {
                     bail!("Casting to a struct requires at least {} operand(s)", N::MIN_STRUCT_ENTRIES)
                 }
                 // Ensure the number of members does not exceed the maximum.
                 if inputs.len() > N::MAX_STRUCT_ENTRIES {
-                    bail!("Casting to struct '{struct_}' cannot exceed {} members", N::MAX_STRUCT_ENTRIES)
+                    bail!("Casting to struct '{struct_type}' cannot exceed {} members", N::MAX_STRUCT_ENTRIES)
                 }
 
-                // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(struct_)?;
+                // Retrieve the struct members and ensure the struct is defined.
+                let struct_members = struct_members(stack, struct_type)?;
 
                 // Ensure that the number of operands is equal to the number of struct members.
-                if inputs.len() != struct_.members().len() {
+                if inputs.len() != struct_members.len() {
                     bail!(
                         "Casting to the struct {} requires {} operands, but {} were provided",
-                        struct_.name(),
-                        struct_.members().len(),
+                        struct_type,
+                        struct_members.len(),
                         inputs.len()
                     )
                 }
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
-                for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
+                for (member, (member_name, member_type)) in inputs.iter().zip_eq(&struct_members) {
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         circuit::Value::Plaintext(plaintext) => {
@@ -628,8 +628,8 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
             }
-            CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
-                self.cast_to_struct(stack, registers, *struct_name, inputs)
+            CastType::Plaintext(struct_type @ (PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..))) => {
+                self.cast_to_struct(stack, registers, struct_type, inputs)
             }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
@@ -647,7 +647,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     #[inline]
     pub fn output_types(
         &self,
-        stack: &impl StackProgram<N>,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
         input_types: &[RegisterType<N>],
     ) -> Result<Vec<RegisterType<N>>> {
         // If the variant is `cast.lossy`, then check that the `cast_type` is a `PlaintextType::Literal`.
@@ -680,9 +680,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Literal(..)) => {
                 ensure!(input_types.len() == 1, "Casting to a literal requires exactly 1 operand");
             }
-            CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
-                // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(struct_name)?;
+            CastType::Plaintext(struct_name @ (PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..))) => {
+                // Retrieve the struct members and ensure the struct is defined.
+                let struct_members = struct_members(stack, struct_name)?;
 
                 // Ensure the input types length is at least the minimum.
                 if input_types.len() < N::MIN_STRUCT_ENTRIES {
@@ -690,26 +690,25 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 }
                 // Ensure the number of members does not exceed the maximum.
                 if input_types.len() > N::MAX_STRUCT_ENTRIES {
-                    bail!("Casting to struct '{struct_}' cannot exceed {} members", N::MAX_STRUCT_ENTRIES)
+                    bail!("Casting to struct '{struct_name}' cannot exceed {} members", N::MAX_STRUCT_ENTRIES)
                 }
 
                 // Ensure that the number of input types is equal to the number of struct members.
                 ensure!(
-                    input_types.len() == struct_.members().len(),
+                    input_types.len() == struct_members.len(),
                     "Casting to the struct {} requires {} operands, but {} were provided",
-                    struct_.name(),
-                    struct_.members().len(),
+                    struct_name,
+                    struct_members.len(),
                     input_types.len()
                 );
                 // Ensure the input types match the struct.
-                for ((_, member_type), input_type) in struct_.members().iter().zip_eq(input_types) {
+                for ((_, member_type), input_type) in struct_members.iter().zip_eq(input_types) {
                     match input_type {
                         // Ensure the plaintext type matches the member type.
                         RegisterType::Plaintext(plaintext_type) => {
-                            ensure!(
-                                member_type == plaintext_type,
-                                "Struct '{struct_name}' member type mismatch: expected '{member_type}', found '{plaintext_type}'"
-                            )
+                            stack.matches_plaintext_type(plaintext_type, member_type).map_err(|e| {
+                                anyhow!("Struct '{struct_name}' member type mismatch: expected '{member_type}', found '{plaintext_type}' - {e}")
+                            })?
                         }
                         // Ensure the input type cannot be a record (this is unsupported behavior).
                         RegisterType::Record(record_name) => bail!(
@@ -750,13 +749,14 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 for input_type in input_types {
                     match input_type {
                         // Ensure the plaintext type matches the member type.
-                        RegisterType::Plaintext(plaintext_type) => {
-                            ensure!(
-                                plaintext_type == array_type.next_element_type(),
-                                "Array element type mismatch: expected '{}', found '{plaintext_type}'",
-                                array_type.next_element_type()
-                            )
-                        }
+                        RegisterType::Plaintext(plaintext_type) => stack
+                            .matches_plaintext_type(plaintext_type, array_type.next_element_type())
+                            .map_err(|e| {
+                                anyhow!(
+                                    "Array element type mismatch: expected '{}', found '{plaintext_type}' - {e}",
+                                    array_type.next_element_type()
+                                )
+                            })?,
                         // Ensure the input type cannot be a record (this is unsupported behavior).
                         RegisterType::Record(record_name) => bail!(
                             "Array element type mismatch: expected '{}', found record '{record_name}'",
@@ -854,7 +854,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         &self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut impl RegistersStore<N>,
-        struct_name: Identifier<N>,
+        struct_type: &PlaintextType<N>,
         inputs: Vec<Value<N>>,
     ) -> Result<()> {
         // Ensure the operands length is at least the minimum.
@@ -862,27 +862,27 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             bail!("Casting to a struct requires at least {} operand", N::MIN_STRUCT_ENTRIES)
         }
 
-        // Retrieve the struct and ensure it is defined in the program.
-        let struct_ = stack.program().get_struct(&struct_name)?;
+        // Retrieve the struct members and ensure the struct is defined.
+        let struct_members = struct_members(stack, struct_type)?;
 
         // Ensure that the number of operands is equal to the number of struct members.
-        if inputs.len() != struct_.members().len() {
+        if inputs.len() != struct_members.len() {
             bail!(
                 "Casting to the struct {} requires {} operands, but {} were provided",
-                struct_.name(),
-                struct_.members().len(),
+                struct_type,
+                struct_members.len(),
                 inputs.len()
             )
         }
 
         // Initialize the struct members.
         let mut members = IndexMap::new();
-        for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
+        for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_members) {
             // Retrieve the plaintext value from the entry.
             let plaintext = match member {
                 Value::Plaintext(plaintext) => {
                     // Ensure the plaintext matches the member type.
-                    stack.matches_plaintext(plaintext, member_type)?;
+                    stack.matches_plaintext(plaintext, &member_type)?;
                     // Output the plaintext.
                     plaintext.clone()
                 }
@@ -892,7 +892,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 Value::Future(..) => bail!("Casting a future into a struct member is illegal"),
             };
             // Append the member to the struct members.
-            members.insert(*member_name, plaintext);
+            members.insert(member_name, plaintext);
         }
 
         // Construct the struct.
@@ -951,6 +951,24 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     }
 }
 
+/// Returns the members of the given struct type, with the member types expressed in the context of the given stack.
+/// In the case of an external struct, the member types are qualified by the program ID of the defining program.
+fn struct_members<N: Network>(
+    stack: &impl StackProgram<N>,
+    struct_type: &PlaintextType<N>,
+) -> Result<IndexMap<Identifier<N>, PlaintextType<N>>> {
+    match struct_type {
+        PlaintextType::Struct(struct_name) => Ok(stack.program().get_struct(struct_name)?.members().clone()),
+        PlaintextType::ExternalStruct(locator) => {
+            // Retrieve the struct from the external program.
+            let struct_ = stack.get_external_program(locator.program_id())?.get_struct(locator.resource())?;
+            // Qualify the member types by the external program ID.
+            Ok(struct_.members().iter().map(|(name, type_)| (*name, type_.qualify(locator.program_id()))).collect())
+        }
+        PlaintextType::Literal(..) | PlaintextType::Array(..) => bail!("'{struct_type}' is not a struct type"),
+    }
+}
+
 impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
@@ -988,7 +1006,7 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
-            CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
+            CastType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
@@ -1036,7 +1054,7 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
-            CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
+            CastType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
@@ -1081,7 +1099,7 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
-            CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
+            CastType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
@@ -1102,7 +1120,7 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
-            CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
+            CastType::Plaintext(PlaintextType::Struct(_) | PlaintextType::ExternalStruct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
@@ -1155,6 +1173,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_external_struct() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1 into r2 as token.aleo/metadata").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(cast.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(
+            cast.cast_type,
+            CastType::Plaintext(PlaintextType::ExternalStruct(Locator::from_str("token.aleo/metadata").unwrap())),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 r1 into r2 as token.aleo/metadata");
+
+        // Ensure the external record cast type is still parsed.
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1 into r2 as token.aleo/credits.record").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(
+            cast.cast_type,
+            CastType::ExternalRecord(Locator::from_str("token.aleo/credits").unwrap()),
+            "The value type is incorrect"
+        );
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -110,6 +110,7 @@ fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) ->
         PlaintextType::Literal(LiteralType::Boolean)
             | PlaintextType::Literal(LiteralType::String)
             | PlaintextType::Struct(..)
+            | PlaintextType::ExternalStruct(..)
             | PlaintextType::Array(..)
     )
 }
@@ -252,13 +253,13 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
-            (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
+            (_, PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
-            PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
+            PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
         };
         // Store the output.
@@ -323,13 +324,13 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
-            (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
+            (_, PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
-            PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
+            PlaintextType::Struct(..) | PlaintextType::ExternalStruct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
         };
         // Convert the output to a stack value.
@@ -21,6 +21,9 @@ pub use async_::*;
 mod call;
 pub use call::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod cast;
 pub use cast::*;
 
@@ -41,6 +44,9 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod verify;
+pub use verify::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
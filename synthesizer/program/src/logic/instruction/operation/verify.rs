@@ -0,0 +1,413 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether an ECDSA signature over secp256k1 is valid for the given Ethereum address and message.
+pub type ECDSAVerifySecp256k1<N> = VerifyInstruction<N, { Verifier::ECDSAVerifySecp256k1 as u8 }>;
+/// Computes whether an Ed25519 signature is valid for the given public key and message.
+pub type Ed25519Verify<N> = VerifyInstruction<N, { Verifier::Ed25519Verify as u8 }>;
+
+enum Verifier {
+    ECDSAVerifySecp256k1,
+    Ed25519Verify,
+}
+
+/// Returns the expected number of bytes in the signature and the key, given the variant.
+const fn expected_num_bytes(variant: u8) -> (u64, u64) {
+    match variant {
+        0 => (64, 20),
+        1 => (64, 32),
+        2.. => panic!("Invalid 'verify' instruction variant"),
+    }
+}
+
+/// Returns the number of bytes in the given plaintext type, if it is a `u8` or a (nested) array of `u8`s.
+fn num_bytes<N: Network>(plaintext_type: &PlaintextType<N>) -> Result<u64> {
+    match plaintext_type {
+        PlaintextType::Literal(LiteralType::U8) => Ok(1),
+        PlaintextType::Array(array_type) => {
+            Ok((**array_type.length() as u64).saturating_mul(num_bytes(array_type.next_element_type())?))
+        }
+        _ => bail!("Expected a 'u8' or an array of 'u8', found '{plaintext_type}'"),
+    }
+}
+
+/// Returns the bytes of the given value, if it is a `u8` or a (nested) array of `u8`s.
+fn to_bytes<N: Network>(value: &Value<N>) -> Result<Vec<u8>> {
+    /// Appends the bytes of the given plaintext to the given vector.
+    fn append_bytes<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), _) => bytes.push(**byte),
+            Plaintext::Array(elements, _) => elements.iter().try_for_each(|element| append_bytes(element, bytes))?,
+            _ => bail!("Expected a 'u8' or an array of 'u8', found '{plaintext}'"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::new();
+    match value {
+        Value::Plaintext(plaintext) => append_bytes(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' or an array of 'u8', found '{value}'"),
+    }
+    Ok(bytes)
+}
+
+/// Returns the bytes of the given circuit value, if it is a `u8` or a (nested) array of `u8`s.
+fn to_bytes_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::U8<A>>> {
+    /// Appends the bytes of the given plaintext to the given vector.
+    fn append_bytes<A: circuit::Aleo>(
+        plaintext: &circuit::Plaintext<A>,
+        bytes: &mut Vec<circuit::U8<A>>,
+    ) -> Result<()> {
+        match plaintext {
+            circuit::Plaintext::Literal(circuit::Literal::U8(byte), _) => bytes.push(byte.clone()),
+            circuit::Plaintext::Array(elements, _) => {
+                elements.iter().try_for_each(|element| append_bytes(element, bytes))?
+            }
+            _ => bail!("Expected a 'u8' or an array of 'u8'"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::new();
+    match value {
+        circuit::Value::Plaintext(plaintext) => append_bytes(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' or an array of 'u8'"),
+    }
+    Ok(bytes)
+}
+
+/// Computes whether `signature` is valid for the given `key` and `message`, where each operand is
+/// a `u8` or a (nested) array of `u8`s, for a signature scheme from outside of Aleo.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VerifyInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> VerifyInstruction<N, VARIANT> {
+    /// Initializes a new `verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Verify("ecdsa.verify.secp256k1"),
+            1 => Opcode::Verify("ed25519.verify"),
+            2.. => panic!("Invalid 'verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> VerifyInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes(&registers.load(stack, &self.operands[0])?)?;
+        let key = to_bytes(&registers.load(stack, &self.operands[1])?)?;
+        let message = to_bytes(&registers.load(stack, &self.operands[2])?)?;
+
+        // Verify the signature.
+        let is_valid = match VARIANT {
+            0 => N::verify_ecdsa_secp256k1(&signature, &key, &message)?,
+            1 => N::verify_ed25519(&signature, &key, &message)?,
+            2.. => bail!("Invalid 'verify' variant: {VARIANT}"),
+        };
+        let output = Literal::Boolean(Boolean::new(is_valid));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[0])?)?;
+        let key = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[1])?)?;
+        let message = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[2])?)?;
+
+        // Ensure the signature and key are well-formed.
+        let (num_signature_bytes, num_key_bytes) = expected_num_bytes(VARIANT);
+        ensure!(signature.len() as u64 == num_signature_bytes, "Expected a {num_signature_bytes}-byte signature");
+        ensure!(key.len() as u64 == num_key_bytes, "Expected a {num_key_bytes}-byte key");
+
+        // Verify the signature.
+        let output = match VARIANT {
+            0 => A::verify_ecdsa_secp256k1(&signature, &key, &message),
+            1 => A::verify_ed25519(&signature, &key, &message),
+            2.. => bail!("Invalid 'verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Retrieve the number of bytes in each input.
+        let num_bytes = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                RegisterType::Plaintext(plaintext_type) => num_bytes(plaintext_type),
+                _ => bail!("Instruction '{}' expects plaintext inputs, found '{input_type}'", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure the signature and key have the expected number of bytes.
+        let (num_signature_bytes, num_key_bytes) = expected_num_bytes(VARIANT);
+        if num_bytes[0] != num_signature_bytes {
+            bail!(
+                "Instruction '{}' expects the first input to have {num_signature_bytes} bytes. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        if num_bytes[1] != num_key_bytes {
+            bail!(
+                "Instruction '{}' expects the second input to have {num_key_bytes} bytes. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for VerifyInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for VerifyInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for VerifyInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for VerifyInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for VerifyInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for VerifyInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) =
+            ECDSAVerifySecp256k1::<CurrentNetwork>::parse("ecdsa.verify.secp256k1 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, is) = Ed25519Verify::<CurrentNetwork>::parse("ed25519.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_num_bytes() {
+        for (string, expected) in
+            [("u8", 1), ("[u8; 32u32]", 32), ("[[u8; 32u32]; 2u32]", 64), ("[[u8; 4u32]; 5u32]", 20)]
+        {
+            let plaintext_type = PlaintextType::<CurrentNetwork>::from_str(string).unwrap();
+            assert_eq!(num_bytes(&plaintext_type).unwrap(), expected);
+        }
+        for string in ["u16", "[u16; 32u32]", "[field; 2u32]", "message"] {
+            let plaintext_type = PlaintextType::<CurrentNetwork>::from_str(string).unwrap();
+            assert!(num_bytes(&plaintext_type).is_err());
+        }
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let value = Value::<CurrentNetwork>::from_str("[[0u8, 1u8], [2u8, 3u8]]").unwrap();
+        assert_eq!(to_bytes(&value).unwrap(), vec![0, 1, 2, 3]);
+        let value = Value::<CurrentNetwork>::from_str("7u8").unwrap();
+        assert_eq!(to_bytes(&value).unwrap(), vec![7]);
+        let value = Value::<CurrentNetwork>::from_str("[0u16, 1u16]").unwrap();
+        assert!(to_bytes(&value).is_err());
+    }
+}
@@ -43,6 +43,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
+        // Parse the upgrade policy from the string, if it is declared.
+        let (string, upgrade_policy) = opt(UpgradePolicy::parse)(string)?;
+        // Parse the constructor from the string, if it is declared.
+        let (string, constructor) = opt(ConstructorCore::parse)(string)?;
 
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
@@ -65,6 +69,24 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     return Err(error);
                 }
             };
+            // Add the imports (if any) to the program.
+            // Note: Imports are added first, as structs may reference structs declared in imported programs.
+            for import in imports.iter() {
+                match program.add_import(import.clone()) {
+                    Ok(_) => (),
+                    Err(error) => {
+                        eprintln!("{error}");
+                        return Err(error);
+                    }
+                }
+            }
+            // Add the constructor (if any) to the program.
+            if let Some(constructor) = &constructor {
+                if let Err(error) = program.add_constructor(constructor.clone()) {
+                    eprintln!("{error}");
+                    return Err(error);
+                }
+            }
             // Construct the program with the parsed components.
             for component in components.iter() {
                 let result = match component {
@@ -83,14 +105,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
-            // Lastly, add the imports (if any) to the program.
-            for import in imports.iter() {
-                match program.add_import(import.clone()) {
-                    Ok(_) => (),
-                    Err(error) => {
-                        eprintln!("{error}");
-                        return Err(error);
-                    }
+            // Set the upgrade policy (if any) of the program.
+            if let Some(upgrade_policy) = upgrade_policy {
+                if let Err(error) = program.set_upgrade_policy(upgrade_policy) {
+                    eprintln!("{error}");
+                    return Err(error);
                 }
             }
             // Output the program.
@@ -152,6 +171,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         // Print the program name.
         program += &format!("{} {};\n\n", Self::type_name(), self.id);
 
+        // Print the upgrade policy, if the program is upgradable.
+        if !self.upgrade_policy.is_immutable() {
+            program += &format!("{}\n\n", self.upgrade_policy);
+        }
+
+        // Print the constructor, if it exists.
+        if let Some(constructor) = &self.constructor {
+            program += &format!("{constructor}\n\n");
+        }
+
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
                 ProgramDefinition::Mapping => match self.mappings.get(identifier) {
@@ -259,6 +288,82 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_upgrade_policy() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+upgrade admin approve;
+
+function approve:
+    input r0 as u16.public;
+    input r1 as field.public;
+    async approve r0 r1 into r2;
+    output r2 as to_parse.aleo/approve.future;
+
+finalize approve:
+    input r0 as u16.public;
+    input r1 as field.public;
+    add r0 1u16 into r2;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(program.upgrade_policy(), &UpgradePolicy::Admin(Identifier::from_str("approve")?));
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure the admin function must exist.
+        let result = Program::<CurrentNetwork>::from_str(&expected.replace("admin approve", "admin missing"));
+        assert!(result.is_err());
+        // Ensure the admin function must finalize on the edition and checksum.
+        let result = Program::<CurrentNetwork>::from_str(
+            &expected.replace("input r1 as field.public;\n    add", "input r1 as u8.public;\n    add"),
+        );
+        assert!(result.is_err());
+
+        // Ensure an immutable policy is the default.
+        let program =
+            Program::<CurrentNetwork>::from_str(&expected.replace("upgrade admin approve;", "upgrade immutable;"))?;
+        assert!(program.upgrade_policy().is_immutable());
+        assert_eq!(program, Program::from_str(&expected.replace("upgrade admin approve;\n\n", ""))?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_constructor() -> Result<()> {
+        let expected = r"program to_parse.aleo;
+
+upgrade owner;
+
+constructor:
+    set 0u64 into counter[0u8];
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    output r0 as u64.public;
+";
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(program.constructor().map(|constructor| constructor.commands().len()), Some(1));
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
+        // Ensure a program may only declare one constructor.
+        let result = Program::<CurrentNetwork>::from_str(
+            &expected.replace("constructor:\n", "constructor:\n    add 1u64 1u64 into r0;\n\nconstructor:\n"),
+        );
+        assert!(result.is_err());
+        // Ensure a function cannot be named 'constructor'.
+        let result = Program::<CurrentNetwork>::from_str(&expected.replace("function compute", "function constructor"));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_size() {
         // Define variable name for easy experimentation with program sizes.
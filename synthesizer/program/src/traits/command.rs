@@ -25,6 +25,14 @@ pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn branch_to(&self) -> Option<&Identifier<N>>;
     /// Returns the position name, if the command is a position command.
     fn position(&self) -> Option<&Identifier<N>>;
+    /// Returns the iteration bound, if the command is a loop command.
+    fn loop_bound(&self) -> Option<u32>;
+    /// Returns `true` if the command is an end-of-loop command.
+    fn is_end_loop(&self) -> bool;
+    /// Returns `true` if the command is an await command.
+    fn is_await(&self) -> bool;
+    /// Returns `true` if the command is a key-by-index command.
+    fn is_key_at(&self) -> bool;
     /// Returns `true` if the command is a call instruction.
     fn is_call(&self) -> bool;
     /// Returns `true` if the command is a cast to record instruction.
@@ -54,6 +54,11 @@ pub trait StackMatches<N: Network> {
     /// Checks that the given plaintext matches the layout of the plaintext type.
     fn matches_plaintext(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<()>;
 
+    /// Checks that the candidate plaintext type is structurally equivalent to the expected plaintext type.
+    /// Struct types are equivalent if they share the same name and their members are equivalent, in order,
+    /// regardless of whether they are declared in this program or in an imported program.
+    fn matches_plaintext_type(&self, candidate: &PlaintextType<N>, expected: &PlaintextType<N>) -> Result<()>;
+
     /// Checks that the given future matches the layout of the future type.
     fn matches_future(&self, future: &Future<N>, locator: &Locator<N>) -> Result<()>;
 }
@@ -74,6 +79,9 @@ pub trait StackProgram<N: Network> {
     /// Returns the external stack for the given program ID.
     fn get_external_stack(&self, program_id: &ProgramID<N>) -> Result<&Arc<Self>>;
 
+    /// Returns the stack for the given program ID, as resolved at runtime by a dynamic call.
+    fn get_dynamic_stack(&self, program_id: &ProgramID<N>) -> Result<Arc<Self>>;
+
     /// Returns the external program for the given program ID.
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>>;
 
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{network::prelude::*, program::Identifier};
+
+/// An upgrade policy determines whether a deployed program may be replaced by a new edition,
+/// and is of the form `upgrade immutable;`, `upgrade owner;`, or `upgrade admin {function};`.
+/// If no upgrade policy is declared, the program is immutable.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UpgradePolicy<N: Network> {
+    /// The program can never be upgraded.
+    #[default]
+    Immutable,
+    /// The program can only be upgraded by the owner of the current edition.
+    Owner,
+    /// The program can be upgraded by anyone, if the `finalize` scope of the named admin function
+    /// succeeds on the new edition and the checksum of the new program.
+    Admin(Identifier<N>),
+}
+
+impl<N: Network> UpgradePolicy<N> {
+    /// Returns `true` if the program can never be upgraded.
+    #[inline]
+    pub const fn is_immutable(&self) -> bool {
+        matches!(self, Self::Immutable)
+    }
+
+    /// Returns the admin function name, if the policy is governed by an admin function.
+    #[inline]
+    pub const fn admin_function(&self) -> Option<&Identifier<N>> {
+        match self {
+            Self::Admin(function_name) => Some(function_name),
+            _ => None,
+        }
+    }
+}
+
+impl<N: Network> TypeName for UpgradePolicy<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "upgrade"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_upgrade_policy_default() -> Result<()> {
+        assert!(UpgradePolicy::<CurrentNetwork>::default().is_immutable());
+        assert!(!UpgradePolicy::<CurrentNetwork>::Owner.is_immutable());
+
+        let admin = Identifier::from_str("approve")?;
+        assert_eq!(UpgradePolicy::<CurrentNetwork>::Admin(admin).admin_function(), Some(&admin));
+        assert_eq!(UpgradePolicy::<CurrentNetwork>::Owner.admin_function(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for UpgradePolicy<N> {
+    /// Parses a string into an upgrade policy of the form `upgrade {policy};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a string into an admin policy of the form `admin {function}`.
+        fn parse_admin<N: Network>(string: &str) -> ParserResult<UpgradePolicy<N>> {
+            // Parse the 'admin' keyword from the string.
+            let (string, _) = tag("admin")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the admin function name from the string.
+            map(Identifier::parse, UpgradePolicy::Admin)(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the upgrade keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the policy from the string.
+        let (string, policy) =
+            alt((map(tag("immutable"), |_| Self::Immutable), map(tag("owner"), |_| Self::Owner), parse_admin))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the upgrade policy.
+        Ok((string, policy))
+    }
+}
+
+impl<N: Network> FromStr for UpgradePolicy<N> {
+    type Err = Error;
+
+    /// Parses a string into an upgrade policy.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for UpgradePolicy<N> {
+    /// Prints the upgrade policy as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for UpgradePolicy<N> {
+    /// Prints the upgrade policy as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Immutable => write!(f, "{} immutable;", Self::type_name()),
+            Self::Owner => write!(f, "{} owner;", Self::type_name()),
+            Self::Admin(function_name) => write!(f, "{} admin {function_name};", Self::type_name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_upgrade_policy_parse() -> Result<()> {
+        let policy = UpgradePolicy::<CurrentNetwork>::parse("upgrade immutable;").unwrap().1;
+        assert_eq!(policy, UpgradePolicy::Immutable);
+
+        let policy = UpgradePolicy::<CurrentNetwork>::parse("upgrade owner;").unwrap().1;
+        assert_eq!(policy, UpgradePolicy::Owner);
+
+        let policy = UpgradePolicy::<CurrentNetwork>::parse("upgrade admin approve_upgrade;").unwrap().1;
+        assert_eq!(policy, UpgradePolicy::Admin(Identifier::from_str("approve_upgrade")?));
+
+        // Ensure invalid policies fail to parse.
+        assert!(UpgradePolicy::<CurrentNetwork>::parse("upgrade;").is_err());
+        assert!(UpgradePolicy::<CurrentNetwork>::parse("upgrade anyone;").is_err());
+        assert!(UpgradePolicy::<CurrentNetwork>::parse("upgrade admin;").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_policy_display() -> Result<()> {
+        for expected in ["upgrade immutable;", "upgrade owner;", "upgrade admin approve_upgrade;"] {
+            let policy = UpgradePolicy::<CurrentNetwork>::from_str(expected)?;
+            assert_eq!(expected, policy.to_string());
+        }
+        Ok(())
+    }
+}
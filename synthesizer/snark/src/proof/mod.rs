@@ -29,6 +29,13 @@ impl<N: Network> Proof<N> {
     pub const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns the size in bytes of a proof with the given batch sizes, without constructing the proof.
+    pub fn size_in_bytes_for(batch_sizes: &[usize]) -> usize {
+        // Add the version byte to the size of the Varuna proof.
+        let is_hiding = <varuna::VarunaHidingMode as varuna::SNARKMode>::ZK;
+        1 + varuna::Proof::<N::PairingCurve>::compressed_size_for(batch_sizes, is_hiding)
+    }
 }
 
 impl<N: Network> Deref for Proof<N> {
@@ -38,3 +45,20 @@ impl<N: Network> Deref for Proof<N> {
         &self.proof
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_in_bytes_for() -> Result<()> {
+        // Sample the proof.
+        let proof = crate::test_helpers::sample_proof();
+
+        // Check the predicted size of the proof.
+        let expected = Proof::<console::network::MainnetV0>::size_in_bytes_for(proof.batch_sizes());
+        assert_eq!(proof.to_bytes_le()?.len(), expected);
+
+        Ok(())
+    }
+}
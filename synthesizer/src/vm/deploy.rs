@@ -39,8 +39,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Construct the owner.
         let owner = ProgramOwner::new(private_key, deployment_id, rng)?;
 
-        // Compute the minimum deployment cost.
-        let (minimum_deployment_cost, _) = deployment_cost(&deployment)?;
+        // Verify the deployment, and compute the minimum deployment cost.
+        let minimum_deployment_cost = self.check_deployment_internal(&deployment, rng)?;
         // Authorize the fee.
         let fee_authorization = match fee_record {
             Some(record) => self.authorize_fee_private(
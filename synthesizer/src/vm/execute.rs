@@ -75,6 +75,55 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns a new bundle transaction for the given authorizations.
+    ///
+    /// Each authorization is executed independently, and the resulting executions are finalized atomically.
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the bundle fee.
+    pub fn execute_bundle<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        authorizations: impl IntoIterator<Item = Authorization<N>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the executions.
+        let executions = authorizations
+            .into_iter()
+            .map(|authorization| self.execute_authorization_raw(authorization, query.clone(), rng))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the minimum bundle cost.
+        let (minimum_bundle_cost, (_, _)) = bundle_cost(&self.process().read(), &executions)?;
+        // Compute the bundle ID.
+        let bundle_id = *Transaction::bundle_tree(&executions, None)?.root();
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                minimum_bundle_cost,
+                priority_fee_in_microcredits,
+                bundle_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                minimum_bundle_cost,
+                priority_fee_in_microcredits,
+                bundle_id,
+                rng,
+            )?,
+        };
+        // Execute the fee.
+        let fee = self.execute_fee_authorization_raw(authorization, query, rng)?;
+        // Return the bundle transaction.
+        Transaction::from_bundle(executions, fee)
+    }
+
     /// Returns a new execute transaction for the given authorization.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
@@ -94,6 +143,50 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns a new execute transaction for the given proving request, from a client that delegates proving.
+    ///
+    /// The proving request is checked before it is proven. It must be signed by the signer of its authorization,
+    /// and its fee must be within its constraints and cover the cost of the execution.
+    pub fn execute_proving_request<R: Rng + CryptoRng>(
+        &self,
+        proving_request: &ProvingRequest<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Check the proving request.
+        proving_request.check()?;
+
+        // Retrieve the authorization.
+        let authorization = proving_request.authorization();
+        // Ensure the fee covers the cost of the execution.
+        match proving_request.fee_amounts()? {
+            Some((base_fee_in_microcredits, _)) => {
+                // Retrieve the program ID of the root transition.
+                let program_id = match authorization.transitions().values().last() {
+                    Some(transition) => *transition.program_id(),
+                    None => bail!("The authorization does not contain any transitions"),
+                };
+                // Compute the minimum execution cost.
+                let (minimum_execution_cost, (_, _)) =
+                    authorization_cost(self.process.read().get_stack(program_id)?, authorization)?;
+                ensure!(
+                    base_fee_in_microcredits >= minimum_execution_cost,
+                    "The base fee ({base_fee_in_microcredits}) is below the execution cost ({minimum_execution_cost})"
+                );
+            }
+            None => ensure!(authorization.is_split(), "The proving request is missing a fee authorization"),
+        }
+
+        // Execute the authorization and the fee authorization, on replicas,
+        // so that the requests in the proving request are not consumed.
+        self.execute_authorization(
+            authorization.replicate(),
+            proving_request.fee_authorization().map(Authorization::replicate),
+            query,
+            rng,
+        )
+    }
+
     /// Returns a new fee for the given authorization.
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
@@ -215,7 +308,7 @@ mod tests {
     };
     use ledger_block::Transition;
     use ledger_store::helpers::memory::ConsensusMemory;
-    use synthesizer_process::cost_per_command;
+    use synthesizer_process::{cost_per_command, ProvingConstraints};
     use synthesizer_program::StackProgram;
 
     use indexmap::IndexMap;
@@ -353,6 +446,115 @@ mod tests {
         println!("Cost: {}", cost);
     }
 
+    #[test]
+    fn test_credits_authorization_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent records.
+        let mut records = records.values();
+        let record_1 = Value::<CurrentNetwork>::Record(records.next().unwrap().decrypt(&caller_view_key).unwrap());
+        let record_2 = Value::<CurrentNetwork>::Record(records.next().unwrap().decrypt(&caller_view_key).unwrap());
+        let address = Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap();
+        let amount = Value::<CurrentNetwork>::from_str("1u64").unwrap();
+
+        // Retrieve the stack.
+        let stack = vm.process().read().get_stack("credits.aleo").unwrap().clone();
+
+        // Prepare the functions and their inputs.
+        let calls = [
+            ("transfer_public", vec![address.clone(), amount.clone()]),
+            ("transfer_public_as_signer", vec![address.clone(), amount.clone()]),
+            ("transfer_private", vec![record_1.clone(), address.clone(), amount.clone()]),
+            ("transfer_private_to_public", vec![record_1.clone(), address.clone(), amount.clone()]),
+            ("transfer_public_to_private", vec![address.clone(), amount.clone()]),
+            ("join", vec![record_1.clone(), record_2]),
+            ("split", vec![record_1, amount]),
+        ];
+
+        for (function_name, inputs) in calls {
+            // Authorize the call.
+            let authorization = vm.authorize(&caller_private_key, "credits.aleo", function_name, inputs, rng).unwrap();
+
+            // Estimate the cost of the authorization.
+            let estimate = authorization_cost(&stack, &authorization).unwrap();
+
+            // Execute the authorization, and ensure the estimate matches the cost of the execution.
+            let execution = vm.execute_authorization_raw(authorization, None, rng).unwrap();
+            let cost = execution_cost(&vm.process().read(), &execution).unwrap();
+            assert_eq!(estimate, cost, "The estimated cost of '{function_name}' is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_execute_proving_request() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Authorize a transfer.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let authorization =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.clone(), rng).unwrap();
+        // Authorize the fee, for the estimated cost of the transfer.
+        let stack = vm.process().read().get_stack("credits.aleo").unwrap().clone();
+        let (base_fee, (_, _)) = authorization_cost(&stack, &authorization).unwrap();
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization = vm.authorize_fee_public(&caller_private_key, base_fee, 10, execution_id, rng).unwrap();
+
+        // Ensure a proving request whose fee exceeds its constraints is rejected.
+        let constraints = ProvingConstraints::new(base_fee + 9, 10);
+        let proving_request = ProvingRequest::new(
+            &caller_private_key,
+            authorization.clone(),
+            Some(fee_authorization.clone()),
+            constraints,
+            rng,
+        )
+        .unwrap();
+        assert!(vm.execute_proving_request(&proving_request, None, rng).is_err());
+
+        // Ensure a proving request without a fee is rejected.
+        let constraints = ProvingConstraints::new(base_fee + 10, 10);
+        let proving_request =
+            ProvingRequest::new(&caller_private_key, authorization.clone(), None, constraints, rng).unwrap();
+        assert!(vm.execute_proving_request(&proving_request, None, rng).is_err());
+
+        // Send the proving request to the prover.
+        let proving_request =
+            ProvingRequest::new(&caller_private_key, authorization, Some(fee_authorization), constraints, rng).unwrap();
+        let proving_request = ProvingRequest::<CurrentNetwork>::from_str(&proving_request.to_string()).unwrap();
+
+        // Prove the request.
+        let transaction = vm.execute_proving_request(&proving_request, None, rng).unwrap();
+        // Ensure the transaction proves the signed requests, and is valid.
+        proving_request.verify_transaction(&transaction).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        // Ensure the proving request can be proven again, as its requests are not consumed.
+        assert_eq!(proving_request.authorization().len(), 1);
+
+        // Ensure a transaction for other requests does not match the proving request.
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        assert!(proving_request.verify_transaction(&transaction).is_err());
+    }
+
     #[test]
     fn test_unbond_public_transaction_size() {
         let rng = &mut TestRng::default();
@@ -380,7 +380,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // and update the respective leaves of the finalize tree.
                     Transaction::Execute(_, execution, fee) => {
                         // Determine if the transaction is safe for execution, and proceed to execute it.
-                        match Self::prepare_for_execution(store, execution)
+                        match Self::prepare_for_execution(store, transaction)
                             .and_then(|_| process.finalize_execution(state, store, execution, fee.as_ref()))
                         {
                             // Construct the accepted execute transaction.
@@ -419,6 +419,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             },
                         }
                     }
+                    // The finalize operation here involves finalizing each execution in order, such that
+                    // the bundle is accepted only if every execution succeeds.
+                    Transaction::Bundle(_, executions, fee) => {
+                        // Determine if the transaction is safe for execution, and proceed to execute it.
+                        match Self::prepare_for_execution(store, transaction)
+                            .and_then(|_| process.finalize_bundle(state, store, executions, fee))
+                        {
+                            // Construct the accepted execute transaction.
+                            Ok(finalize) => {
+                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
+                                    .map_err(|e| e.to_string())
+                            }
+                            // Construct the rejected execute transaction.
+                            Err(_error) => match process.finalize_fee(state, store, fee).and_then(|finalize| {
+                                Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                            }) {
+                                Ok((fee_tx, finalize)) => {
+                                    // Construct the rejected bundle.
+                                    let rejected = Rejected::new_bundle(executions.clone());
+                                    // Construct the rejected execute transaction.
+                                    ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
+                                        .map_err(|e| e.to_string())
+                                }
+                                Err(error) => {
+                                    // Note: On failure, skip this transaction, and continue speculation.
+                                    #[cfg(debug_assertions)]
+                                    eprintln!("Failed to finalize the fee in a rejected bundle - {error}");
+                                    // Store the aborted transaction.
+                                    aborted.push((transaction.clone(), error.to_string()));
+                                    // Continue to the next transaction.
+                                    continue 'outer;
+                                }
+                            },
+                        }
+                    }
                     // There are no finalize operations here.
                     // Note: This will abort the entire atomic batch.
                     Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
@@ -556,6 +591,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
+            // Begin recording the mapping history for the block, if it is enabled.
+            if let Err(e) = store.begin_history_block(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the mapping history - {e}"));
+            }
+            // Begin recording the mapping updates for the block events, if there are subscribers.
+            // Note: The pending subscribers are activated here, so a subscriber receives the events of a block
+            // only if the mapping updates of the block are recorded for it.
+            // Note: This is done within the atomic batch, so the updates are discarded if the batch is aborted.
+            if self.activate_subscribers() {
+                store.start_recording();
+            }
+
             /* Perform the ratifications before finalize. */
 
             match Self::atomic_pre_ratify(store, state, pre_ratifications) {
@@ -617,15 +665,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         Ok(())
                     }
                     ConfirmedTransaction::AcceptedExecute(_, transaction, finalize) => {
-                        // Extract the execution and fee from the transaction.
-                        let (execution, fee) = match transaction {
-                            Transaction::Execute(_, execution, fee) => (execution, fee),
+                        // The finalize operation here involves calling 'update_key_value',
+                        // and update the respective leaves of the finalize tree.
+                        let result = match transaction {
+                            Transaction::Execute(_, execution, fee) => {
+                                process.finalize_execution(state, store, execution, fee.as_ref())
+                            }
+                            Transaction::Bundle(_, executions, fee) => {
+                                process.finalize_bundle(state, store, executions, fee)
+                            }
                             // Note: This will abort the entire atomic batch.
                             _ => return Err("Expected execute transaction".to_string()),
                         };
-                        // The finalize operation here involves calling 'update_key_value',
-                        // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        match result {
                             // Ensure the finalize operations match the expected.
                             Ok(finalize_operations) => {
                                 if finalize != &finalize_operations {
@@ -682,13 +734,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         Ok(())
                     }
                     ConfirmedTransaction::RejectedExecute(_, Transaction::Fee(_, fee), rejected, finalize) => {
-                        // Extract the rejected execution.
-                        let Some(execution) = rejected.execution() else {
+                        // Ensure the rejected object is an execution or a bundle.
+                        if !rejected.is_execution() && !rejected.is_bundle() {
                             // Note: This will abort the entire atomic batch.
                             return Err("Expected rejected execution".to_string());
-                        };
+                        }
                         // Compute the expected execution ID.
-                        let Ok(expected_execution_id) = execution.to_execution_id() else {
+                        let Ok(expected_execution_id) = rejected.to_id() else {
                             // Note: This will abort the entire atomic batch.
                             return Err("Failed to compute the execution ID for a rejected execution".to_string());
                         };
@@ -750,7 +802,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Commit all of the stacks to the process.
             if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+                for stack in stacks {
+                    // Note: This will abort the entire atomic batch.
+                    process.add_stack(stack).map_err(|e| format!("Failed to commit the stack - {e}"))?;
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -937,16 +992,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// - If the transaction contains a `credits.aleo/bond_public` transition,
     ///   then the outcome should not exceed the maximum committee size.
     #[inline]
-    fn prepare_for_execution(store: &FinalizeStore<N, C::FinalizeStorage>, execution: &Execution<N>) -> Result<()> {
+    fn prepare_for_execution(store: &FinalizeStore<N, C::FinalizeStorage>, transaction: &Transaction<N>) -> Result<()> {
         // Construct the program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
         // Construct the committee mapping name.
         let committee_mapping = Identifier::from_str("committee")?;
 
-        // Check if the execution has any `bond_validator` transitions, and collect
+        // Check if the transaction has any `bond_validator` transitions, and collect
         // the unique validator addresses if so.
         // Note: This does not dedup for existing and new validator addresses.
-        let bond_validator_addresses: HashSet<_> = execution
+        let bond_validator_addresses: HashSet<_> = transaction
             .transitions()
             .filter_map(|transition| match transition.is_bond_validator() {
                 // Check the first input of the transition for the validator address.
@@ -2139,6 +2194,117 @@ finalize compute:
         assert_eq!(value, expected);
     }
 
+    #[test]
+    fn test_bundle_is_finalized_atomically() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct the deployment block.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program testing.aleo;
+
+mapping entries:
+    key as address.public;
+    value as u8.public;
+
+function compute:
+    input r0 as u8.public;
+    async compute self.caller r0 into r1;
+    output r1 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u8.public;
+    get.or_use entries[r0] r1 into r2;
+    add r1 r2 into r3;
+    set r3 into entries[r0];
+    get entries[r0] into r4;
+    add r4 r1 into r5;
+    set r5 into entries[r0];
+",
+        )
+        .unwrap();
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&view_key).unwrap());
+        let transaction = vm.deploy(&private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &private_key, &[transaction], &splits_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Prepares a bundle calling `compute` once per given input.
+        let create_bundle = |inputs: &[&str],
+                             unspent_records: &mut Vec<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>>,
+                             rng: &mut TestRng| {
+            let authorizations = inputs
+                .iter()
+                .map(|input| {
+                    let r0 = Value::<CurrentNetwork>::from_str(input).unwrap();
+                    vm.authorize(&private_key, "testing.aleo", "compute", [r0], rng).unwrap()
+                })
+                .collect::<Vec<_>>();
+            let credits = Some(unspent_records.pop().unwrap().decrypt(&view_key).unwrap());
+            let transaction = vm.execute_bundle(&private_key, authorizations, credits, 1, None, rng).unwrap();
+            vm.check_transaction(&transaction, None, rng).unwrap();
+            transaction
+        };
+
+        // Create a bundle whose second execution overflows in finalize.
+        let bundle = create_bundle(&["1u8", "100u8"], &mut unspent_records, rng);
+        assert!(bundle.is_bundle());
+
+        // Construct the next block.
+        let next_block =
+            sample_next_block(&vm, &private_key, &[bundle], &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Check that the bundle was rejected as a whole.
+        let confirmed = next_block.transactions().iter().next().unwrap();
+        assert!(confirmed.is_rejected());
+        assert!(confirmed.to_rejected().unwrap().is_bundle());
+        vm.add_next_block(&next_block).unwrap();
+
+        // Check that the first execution in the bundle did not update storage.
+        let program_id = ProgramID::from_str("testing.aleo").unwrap();
+        let mapping_name = Identifier::from_str("entries").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        assert!(!vm.finalize_store().contains_key_confirmed(program_id, mapping_name, &key).unwrap());
+
+        // Create a bundle where both executions succeed.
+        let bundle = create_bundle(&["1u8", "1u8"], &mut unspent_records, rng);
+
+        // Construct the next block.
+        let next_block =
+            sample_next_block(&vm, &private_key, &[bundle], &next_block, &mut unspent_records, rng).unwrap();
+
+        // Check that the bundle was accepted.
+        assert!(next_block.transactions().iter().next().unwrap().is_accepted());
+        vm.add_next_block(&next_block).unwrap();
+
+        // Check that both executions updated storage.
+        let value = vm.finalize_store().get_value_speculative(program_id, mapping_name, &key).unwrap().unwrap();
+        assert_eq!(value, Value::<CurrentNetwork>::from_str("5u8").unwrap());
+    }
+
     #[test]
     fn test_excess_transactions_should_be_aborted() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use ledger_committee::Committee;
+
+/// An event emitted by the VM to its subscribers, upon adding a block.
+///
+/// The events for a block are emitted in order: the transaction outcomes, the programs deployed,
+/// the mapping updates (in the order they were finalized), the committee change (if any),
+/// and lastly, `BlockCommitted`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockEvent<N: Network> {
+    /// The transaction with the given (unconfirmed) ID was accepted.
+    TransactionAccepted { height: u32, transaction_id: N::TransactionID },
+    /// The transaction with the given (unconfirmed) ID was rejected, and only its fee was finalized.
+    TransactionRejected { height: u32, transaction_id: N::TransactionID },
+    /// The transaction with the given ID was aborted.
+    TransactionAborted { height: u32, transaction_id: N::TransactionID },
+    /// The given edition of the program was deployed.
+    ProgramDeployed { height: u32, program_id: ProgramID<N>, edition: u16 },
+    /// The key was set to the value, in the given mapping.
+    MappingKeySet {
+        height: u32,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    },
+    /// The key was removed, from the given mapping.
+    MappingKeyRemoved { height: u32, program_id: ProgramID<N>, mapping_name: Identifier<N>, key: Plaintext<N> },
+    /// The committee changed, starting from the block at the given height.
+    CommitteeChanged { height: u32, committee: Committee<N> },
+    /// The block was committed.
+    BlockCommitted { height: u32, block_hash: N::BlockHash },
+}
+
+impl<N: Network> BlockEvent<N> {
+    /// Returns the height of the block that emitted the event.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::TransactionAccepted { height, .. }
+            | Self::TransactionRejected { height, .. }
+            | Self::TransactionAborted { height, .. }
+            | Self::ProgramDeployed { height, .. }
+            | Self::MappingKeySet { height, .. }
+            | Self::MappingKeyRemoved { height, .. }
+            | Self::CommitteeChanged { height, .. }
+            | Self::BlockCommitted { height, .. } => *height,
+        }
+    }
+}
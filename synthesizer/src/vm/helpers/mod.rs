@@ -15,7 +15,13 @@
 pub(crate) mod committee;
 pub use committee::*;
 
+mod events;
+pub use events::*;
+
 mod macros;
 
 mod rewards;
 pub use rewards::*;
+
+mod simulation;
+pub use simulation::*;
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Value};
+use synthesizer_process::FinalizeFailure;
+use synthesizer_program::FinalizeOperation;
+
+/// The result of simulating an authorization with `VM::simulate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation<N: Network> {
+    /// The outputs of the function.
+    pub outputs: Vec<Value<N>>,
+    /// The finalize operations, or the failure of the `finalize` scope.
+    pub finalize: Result<Vec<FinalizeOperation<N>>, FinalizeFailure<N>>,
+    /// The storage cost in microcredits.
+    pub storage_cost: u64,
+    /// The finalize cost in microcredits.
+    pub finalize_cost: u64,
+}
+
+impl<N: Network> Simulation<N> {
+    /// Returns `true` if the `finalize` scope succeeded, in which case the execution would be accepted.
+    pub const fn is_accepted(&self) -> bool {
+        self.finalize.is_ok()
+    }
+
+    /// Returns the finalize operations, if the `finalize` scope succeeded.
+    pub fn finalize_operations(&self) -> Option<&[FinalizeOperation<N>]> {
+        self.finalize.as_deref().ok()
+    }
+
+    /// Returns the failure of the `finalize` scope, if it failed.
+    pub fn failure(&self) -> Option<&FinalizeFailure<N>> {
+        self.finalize.as_ref().err()
+    }
+
+    /// Returns the fee in microcredits, which is the sum of the storage cost and the finalize cost.
+    pub fn fee(&self) -> Result<u64> {
+        self.storage_cost
+            .checked_add(self.finalize_cost)
+            .ok_or_else(|| anyhow!("The total cost computation overflowed for a simulation"))
+    }
+}
@@ -19,9 +19,13 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod rollback;
+mod simulate;
+mod subscribe;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, convert, process};
+use subscribe::Subscribers;
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -47,18 +51,29 @@ use ledger_committee::Committee;
 use ledger_puzzle::Puzzle;
 use ledger_query::Query;
 use ledger_store::{
+    atomic_batch_scope,
     atomic_finalize,
     BlockStore,
     ConsensusStorage,
     ConsensusStore,
     FinalizeMode,
     FinalizeStore,
+    Inconsistency,
+    MappingUpdate,
     TransactionStorage,
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
-use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_process::{
+    authorization_cost,
+    bundle_cost,
+    execution_cost,
+    Authorization,
+    Process,
+    ProvingRequest,
+    Trace,
+};
+use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program, UpgradePolicy};
 use utilities::try_vm_runtime;
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -67,7 +82,14 @@ use itertools::Either;
 use lru::LruCache;
 use parking_lot::{Mutex, RwLock};
 use rand::{rngs::StdRng, SeedableRng};
-use std::{collections::HashSet, num::NonZeroUsize, sync::Arc};
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    sync::{
+        mpsc::{sync_channel, Receiver, SyncSender},
+        Arc,
+    },
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -86,6 +108,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     block_lock: Arc<Mutex<()>>,
     /// A cache containing the list of recent partially-verified transactions.
     partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, ()>>>,
+    /// The subscribers to the block events.
+    subscribers: Arc<Mutex<Subscribers<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -125,6 +149,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             if process.contains_program(program_id) {
                 return Ok(vec![]);
             }
+            // Return early if the deployment is not the latest edition of the program.
+            // Note: The latest edition is loaded from its own deployment transaction.
+            if transaction_store.deployment_store().get_edition(program_id)? != Some(deployment.edition()) {
+                return Ok(vec![]);
+            }
 
             // Prepare a vector for the deployments.
             let mut deployments = vec![];
@@ -192,6 +221,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
+            subscribers: Default::default(),
         })
     }
 
@@ -244,6 +274,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Checks the consistency of the consensus storage, repairing the repairable inconsistencies if `repair` is `true`.
+    #[inline]
+    pub fn check_integrity(&self, repair: bool) -> Result<Vec<Inconsistency<N>>> {
+        self.store.check_integrity(repair)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -388,27 +424,33 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
         )?;
 
         // Pause the atomic writes, so that both the insertion and finalization belong to a single batch.
-        #[cfg(feature = "rocks")]
+        #[cfg(any(feature = "rocks", feature = "redb"))]
         self.block_store().pause_atomic_writes()?;
 
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
+        let result = self.finalize(state, block.ratifications(), block.solutions(), block.transactions());
+        // Retrieve the mapping updates recorded during finalize, if there are subscribers.
+        let mapping_updates = self.finalize_store().stop_recording();
+        match result {
             Ok(_ratified_finalize_operations) => {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
-                #[cfg(feature = "rocks")]
+                #[cfg(any(feature = "rocks", feature = "redb"))]
                 self.block_store().unpause_atomic_writes::<false>()?;
+                // Emit the events for the block to the subscribers.
+                self.emit_block_events(block, mapping_updates);
                 Ok(())
             }
             Err(finalize_error) => {
-                if cfg!(feature = "rocks") {
+                if cfg!(any(feature = "rocks", feature = "redb")) {
                     // Clear all pending atomic operations so that unpausing the atomic writes
                     // doesn't execute any of the queued storage operations.
                     self.block_store().abort_atomic();
@@ -462,7 +504,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -1164,6 +1206,228 @@ function check:
         assert!(vm.contains_program(&ProgramID::from_str("parent_program.aleo").unwrap()));
     }
 
+    #[test]
+    fn test_deployment_with_constructor() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Deploy a program whose constructor initializes a mapping.
+        let program = Program::from_str(
+            r"
+program constructed.aleo;
+
+constructor:
+    set 100u64 into supply[0u8];
+
+mapping supply:
+    key as u8.public;
+    value as u64.public;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Check that the constructor initialized the mapping.
+        let value = vm
+            .finalize_store()
+            .get_value_confirmed(
+                ProgramID::from_str("constructed.aleo").unwrap(),
+                Identifier::from_str("supply").unwrap(),
+                &Plaintext::from_str("0u8").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(value, Some(Value::from_str("100u64").unwrap()));
+
+        // Deploy a program whose constructor fails.
+        let program = Program::from_str(
+            r"
+program failed_constructor.aleo;
+
+constructor:
+    assert.eq 1u8 2u8;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        let block = sample_next_block(&vm, &private_key, &[deployment], rng).unwrap();
+
+        // Check that the deployment was rejected.
+        assert_eq!(block.transactions().num_rejected(), 1);
+        vm.add_next_block(&block).unwrap();
+        assert!(!vm.contains_program(&ProgramID::from_str("failed_constructor.aleo").unwrap()));
+    }
+
+    #[test]
+    fn test_deployment_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Deploy the first edition of an upgradable program.
+        let program = Program::from_str(
+            r"
+program upgradable.aleo;
+
+upgrade owner;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Check that the first edition is deployed.
+        let program_id = ProgramID::from_str("upgradable.aleo").unwrap();
+        assert_eq!(vm.process().read().get_stack(program_id).unwrap().edition(), 0);
+
+        // Construct the second edition of the program.
+        let program = Program::from_str(
+            r"
+program upgradable.aleo;
+
+upgrade owner;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+
+function bar:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+        ",
+        )
+        .unwrap();
+
+        // Ensure the upgrade is rejected if it is not deployed by the owner.
+        let other_private_key = PrivateKey::new(rng).unwrap();
+        let deployment = vm.deploy(&other_private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_err());
+
+        // Upgrade the program.
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Check that the second edition is deployed.
+        assert_eq!(vm.process().read().get_stack(program_id).unwrap().edition(), 1);
+        assert!(
+            vm.process()
+                .read()
+                .get_program(program_id)
+                .unwrap()
+                .contains_function(&Identifier::from_str("bar").unwrap())
+        );
+
+        // Ensure an immutable program cannot be upgraded.
+        let program = Program::from_str(
+            r"
+program immutable_program.aleo;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+        assert!(vm.deploy(&private_key, &program, None, 0, None, rng).is_err());
+    }
+
+    #[test]
+    fn test_deployment_upgrade_skips_constructor() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Deploy an upgradable program whose constructor increments a mapping.
+        let program = Program::from_str(
+            r"
+program counted.aleo;
+
+upgrade owner;
+
+constructor:
+    get.or_use count[0u8] 0u64 into r0;
+    add r0 1u64 into r1;
+    set r1 into count[0u8];
+
+mapping count:
+    key as u8.public;
+    value as u64.public;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Retrieves the count.
+        let get_count = || {
+            vm.finalize_store()
+                .get_value_confirmed(
+                    ProgramID::from_str("counted.aleo").unwrap(),
+                    Identifier::from_str("count").unwrap(),
+                    &Plaintext::from_str("0u8").unwrap(),
+                )
+                .unwrap()
+        };
+        // Check that the constructor ran on the initial deployment.
+        assert_eq!(get_count(), Some(Value::from_str("1u64").unwrap()));
+
+        // Upgrade the program.
+        let program = Program::from_str(&format!(
+            "{program}
+function bar:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+"
+        ))
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Check that the upgrade is deployed, and that the constructor did not run again.
+        let program_id = ProgramID::from_str("counted.aleo").unwrap();
+        assert_eq!(vm.process().read().get_stack(program_id).unwrap().edition(), 1);
+        assert_eq!(get_count(), Some(Value::from_str("1u64").unwrap()));
+    }
+
     #[test]
     fn test_deployment_with_external_records() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Rolls back the VM to the given block height, removing the subsequent blocks and reverting their state.
+    ///
+    /// The mapping history must be enabled, and cover the given block height, as it records the previous values
+    /// of the mapping entries modified by each block, including by the fees and the ratifications. The finalize
+    /// operations of a block only contain the hashes of the mapping entries, so they are not enough to revert it.
+    ///
+    /// The committees, and the programs deployed or upgraded in the removed blocks, are reverted as well.
+    pub fn rollback_to(&self, block_height: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+
+        // Retrieve the latest block height.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+            bail!("Cannot roll back to block {block_height}, as there are no blocks in storage")
+        };
+        // Ensure the block height precedes the latest block height.
+        ensure!(
+            block_height < latest_height,
+            "Cannot roll back to block {block_height}, as the latest block is {latest_height}"
+        );
+        // Ensure the block at the given height has not been pruned.
+        if let Some(pruned_height) = self.block_store().pruned_height()? {
+            ensure!(block_height > pruned_height, "Cannot roll back to block {block_height}, as it has been pruned");
+        }
+        // Ensure the mapping history covers the blocks to revert.
+        let Some((start, end)) = self.finalize_store().get_history_range()? else {
+            bail!("Cannot roll back to block {block_height}, as the mapping history is not enabled")
+        };
+        ensure!(end == latest_height, "Cannot roll back, as the mapping history ends at block {end}");
+        ensure!(
+            start <= block_height,
+            "Cannot roll back to block {block_height}, as the mapping history starts at block {start}"
+        );
+
+        // Collect the programs deployed in the blocks to remove, along with their earliest removed edition.
+        // Note: The programs are ordered from the latest deployment, so that dependent programs are reverted first.
+        let mut programs = IndexMap::new();
+        for height in (block_height + 1..=latest_height).rev() {
+            // Retrieve the transactions of the block.
+            let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                bail!("Failed to roll back: missing block hash for block {height}")
+            };
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Failed to roll back: missing transactions for block {height}")
+            };
+            // Collect the accepted deployments.
+            let deployments = transactions
+                .iter()
+                .filter(|transaction| transaction.is_accepted())
+                .filter_map(|transaction| transaction.transaction().deployment())
+                .collect::<Vec<_>>();
+            for deployment in deployments.into_iter().rev() {
+                let edition = programs.entry(*deployment.program_id()).or_insert(deployment.edition());
+                *edition = deployment.edition().min(*edition);
+            }
+        }
+
+        // Retrieve the deployment of the previous edition of each program, which is `None` for new programs.
+        let deployment_store = self.transaction_store().deployment_store();
+        let mut reverts = Vec::with_capacity(programs.len());
+        for (program_id, edition) in programs {
+            let previous_deployment = match edition.checked_sub(1) {
+                Some(previous_edition) => {
+                    let transaction_id = deployment_store
+                        .find_transaction_id_from_program_id_and_edition(&program_id, previous_edition)?
+                        .ok_or_else(|| anyhow!("Missing edition {previous_edition} of program '{program_id}'"))?;
+                    let deployment = self
+                        .transaction_store()
+                        .get_deployment(&transaction_id)?
+                        .ok_or_else(|| anyhow!("Missing the deployment in transaction '{transaction_id}'"))?;
+                    Some(deployment)
+                }
+                None => None,
+            };
+            reverts.push((program_id, previous_deployment));
+        }
+
+        // Reverts the programs in the given process.
+        let revert_programs = |process: &mut Process<N>| -> Result<()> {
+            for (program_id, previous_deployment) in &reverts {
+                match previous_deployment {
+                    Some(deployment) => process.revert_deployment(deployment)?,
+                    None => process.remove_program(program_id)?,
+                }
+            }
+            Ok(())
+        };
+        // Ensure the programs can be reverted, before modifying storage.
+        revert_programs(&mut self.process.read().clone())?;
+
+        // Revert the finalize state and remove the blocks.
+        let finalize_store = self.finalize_store();
+        atomic_batch_scope!(finalize_store, {
+            // Revert the mapping entries from the mapping history.
+            finalize_store.rollback_history(block_height)?;
+            // Remove the mappings initialized by the removed deployments.
+            for (program_id, previous_deployment) in &reverts {
+                match previous_deployment {
+                    Some(deployment) => {
+                        let mapping_names =
+                            finalize_store.get_mapping_names_speculative(program_id)?.unwrap_or_default();
+                        for mapping_name in mapping_names {
+                            if !deployment.program().contains_mapping(&mapping_name) {
+                                finalize_store.remove_mapping(*program_id, mapping_name)?;
+                            }
+                        }
+                    }
+                    // Note: A program is only in the finalize storage once it initializes a mapping.
+                    None => {
+                        if finalize_store.contains_program_speculative(program_id)? {
+                            finalize_store.remove_program(program_id)?;
+                        }
+                    }
+                }
+            }
+            // Revert the committees.
+            finalize_store.committee_store().rollback_to(block_height)?;
+            // Remove the blocks.
+            self.block_store().remove_last_n(latest_height - block_height)
+        })?;
+
+        // Revert the programs.
+        revert_programs(&mut self.process.write())
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates the given authorization, without generating a proof.
+    ///
+    /// The function is evaluated, and its `finalize` scope is run in a dry run against the finalize store,
+    /// so that the store is left unchanged. The simulation returns the outputs of the function,
+    /// the finalize operations (or the command at which the `finalize` scope failed), and the fee.
+    /// The fee is the same as the fee for the proven execution of the authorization.
+    pub fn simulate(&self, state: FinalizeGlobalState, authorization: &Authorization<N>) -> Result<Simulation<N>> {
+        let timer = timer!("VM::simulate");
+
+        // Evaluate the function.
+        let outputs = self.simulate_evaluate(authorization)?;
+        lap!(timer, "Evaluate the function");
+
+        // Retrieve the program ID of the root transition.
+        let program_id = match authorization.transitions().values().last() {
+            Some(transition) => *transition.program_id(),
+            None => bail!("The authorization does not contain any transitions"),
+        };
+        // Compute the storage cost and finalize cost in microcredits.
+        let (_, (storage_cost, finalize_cost)) =
+            authorization_cost(self.process.read().get_stack(program_id)?, authorization)?;
+        lap!(timer, "Compute the fee");
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Finalize the authorization in a dry run, which discards all of the changes to the finalize store.
+        let finalize = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            self.process
+                .read()
+                .finalize_authorization(state, self.finalize_store(), authorization)
+                .map_err(|error| error.to_string())
+        })?;
+        finish!(timer, "Finalize the authorization");
+
+        Ok(Simulation { outputs, finalize, storage_cost, finalize_cost })
+    }
+
+    /// Evaluates the function in the given authorization, and returns its outputs.
+    fn simulate_evaluate(&self, authorization: &Authorization<N>) -> Result<Vec<Value<N>>> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Evaluate the function on a replica, so that the requests in the authorization are not consumed.
+                let authorization = cast_ref!(&authorization as Authorization<$network>).replicate();
+                let outputs = $process.evaluate::<$aleo>(authorization)?.outputs().to_vec();
+                // Prepare the outputs.
+                Ok(cast_ref!(outputs as Vec<Value<N>>).clone())
+            }};
+        }
+
+        // Evaluate the function.
+        process!(self, logic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::sample_finalize_state;
+    use console::{account::Address, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_simulate_transfer_public() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+        // Initialize a recipient.
+        let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Retrieve the balance of the given address.
+        let credits_program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let account_mapping_name = Identifier::from_str("account").unwrap();
+        let get_balance = |address: Address<CurrentNetwork>| {
+            let key = Plaintext::from(Literal::Address(address));
+            vm.finalize_store().get_value_confirmed(credits_program_id, account_mapping_name, &key).unwrap()
+        };
+        let caller_balance = get_balance(caller_address);
+
+        // Authorize a transfer.
+        let authorization = vm
+            .authorize(
+                &caller_private_key,
+                "credits.aleo",
+                "transfer_public",
+                [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("1u64").unwrap()],
+                rng,
+            )
+            .unwrap();
+
+        // Simulate the transfer.
+        let simulation = vm.simulate(sample_finalize_state(1), &authorization).unwrap();
+        assert!(simulation.is_accepted());
+        assert_eq!(simulation.outputs.len(), 1);
+        assert_eq!(simulation.finalize_operations().unwrap().len(), 2);
+
+        // Ensure the finalize store is unchanged.
+        assert_eq!(get_balance(caller_address), caller_balance);
+        assert_eq!(get_balance(recipient_address), None);
+        // Ensure the authorization can still be executed.
+        assert_eq!(authorization.len(), 1);
+        let transaction = vm.execute_authorization(authorization, None, None, rng).unwrap();
+
+        // Ensure the fee matches the cost of the proven execution.
+        let execution = transaction.execution().unwrap();
+        let (cost, (storage_cost, finalize_cost)) = execution_cost(&vm.process().read(), execution).unwrap();
+        assert_eq!(simulation.storage_cost, storage_cost);
+        assert_eq!(simulation.finalize_cost, finalize_cost);
+        assert_eq!(simulation.fee().unwrap(), cost);
+    }
+
+    #[test]
+    fn test_simulate_failed_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize a recipient.
+        let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Authorize a transfer of more credits than the caller has.
+        let authorization = vm
+            .authorize(
+                &caller_private_key,
+                "credits.aleo",
+                "transfer_public",
+                [
+                    Value::from_str(&format!("{recipient_address}")).unwrap(),
+                    Value::from_str(&format!("{}u64", u64::MAX)).unwrap(),
+                ],
+                rng,
+            )
+            .unwrap();
+
+        // Simulate the transfer.
+        let simulation = vm.simulate(sample_finalize_state(1), &authorization).unwrap();
+        assert!(!simulation.is_accepted());
+        assert!(simulation.finalize_operations().is_none());
+        // Ensure the failure points to the `sub` command, which underflows.
+        let failure = simulation.failure().unwrap();
+        assert_eq!(failure.program_id, ProgramID::from_str("credits.aleo").unwrap());
+        assert_eq!(failure.function_name, Identifier::from_str("transfer_public").unwrap());
+        assert_eq!(failure.command_index, 1);
+    }
+}
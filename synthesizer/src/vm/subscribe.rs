@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The subscribers to the block events.
+pub(crate) struct Subscribers<N: Network> {
+    /// The subscribers that receive the events of the block being added.
+    active: Vec<SyncSender<BlockEvent<N>>>,
+    /// The subscribers that receive the events from the next block that begins finalizing.
+    pending: Vec<SyncSender<BlockEvent<N>>>,
+}
+
+impl<N: Network> Default for Subscribers<N> {
+    fn default() -> Self {
+        Self { active: Vec::new(), pending: Vec::new() }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The maximum number of events that may be queued for a subscriber, before it is unsubscribed.
+    pub const MAX_QUEUED_BLOCK_EVENTS: usize = 1 << 16;
+
+    /// Subscribes to the events emitted by the VM, upon adding each subsequent block.
+    ///
+    /// The events are emitted from the next block that begins finalizing, so the events of a block are
+    /// either all received or not at all. The subscription ends when the returned receiver is dropped,
+    /// or when the subscriber lags behind by more than `MAX_QUEUED_BLOCK_EVENTS` events, in which case
+    /// the receiver is disconnected, and a new subscription must be made.
+    pub fn subscribe(&self) -> Receiver<BlockEvent<N>> {
+        let (sender, receiver) = sync_channel(Self::MAX_QUEUED_BLOCK_EVENTS);
+        self.subscribers.lock().pending.push(sender);
+        receiver
+    }
+
+    /// Activates the pending subscribers, which receive the events of the block that begins finalizing,
+    /// and returns `true` if the VM has any subscribers for the block.
+    pub(crate) fn activate_subscribers(&self) -> bool {
+        let mut subscribers = self.subscribers.lock();
+        let pending = std::mem::take(&mut subscribers.pending);
+        subscribers.active.extend(pending);
+        !subscribers.active.is_empty()
+    }
+
+    /// Emits the events for the given block, which was just added, to the subscribers.
+    pub(crate) fn emit_block_events(&self, block: &Block<N>, mapping_updates: Vec<MappingUpdate<N>>) {
+        // Acquire the lock on the subscribers.
+        let mut subscribers = self.subscribers.lock();
+        // If there are no subscribers for the block, return early.
+        if subscribers.active.is_empty() {
+            return;
+        }
+
+        let height = block.height();
+        let mut events = Vec::new();
+
+        // Emit the transaction outcomes, and the programs deployed.
+        for transaction in block.transactions().iter() {
+            let transaction_id = match transaction.to_unconfirmed_transaction_id() {
+                Ok(transaction_id) => transaction_id,
+                Err(error) => {
+                    warn!("Failed to retrieve the unconfirmed transaction ID in block {height} - {error}");
+                    continue;
+                }
+            };
+            match transaction {
+                ConfirmedTransaction::AcceptedDeploy(_, transaction, _) => {
+                    events.push(BlockEvent::TransactionAccepted { height, transaction_id });
+                    if let Some(deployment) = transaction.deployment() {
+                        let (program_id, edition) = (*deployment.program_id(), deployment.edition());
+                        events.push(BlockEvent::ProgramDeployed { height, program_id, edition });
+                    }
+                }
+                ConfirmedTransaction::AcceptedExecute(..) => {
+                    events.push(BlockEvent::TransactionAccepted { height, transaction_id })
+                }
+                ConfirmedTransaction::RejectedDeploy(..) | ConfirmedTransaction::RejectedExecute(..) => {
+                    events.push(BlockEvent::TransactionRejected { height, transaction_id })
+                }
+            }
+        }
+        for transaction_id in block.aborted_transaction_ids() {
+            events.push(BlockEvent::TransactionAborted { height, transaction_id: *transaction_id });
+        }
+
+        // Emit the mapping updates.
+        for update in mapping_updates {
+            events.push(match update {
+                MappingUpdate::Set(program_id, mapping_name, key, value) => {
+                    BlockEvent::MappingKeySet { height, program_id, mapping_name, key, value }
+                }
+                MappingUpdate::Remove(program_id, mapping_name, key) => {
+                    BlockEvent::MappingKeyRemoved { height, program_id, mapping_name, key }
+                }
+            });
+        }
+
+        // Emit the committee, if it changed in this block.
+        let committee_store = self.finalize_store().committee_store();
+        match committee_store.get_committee(height) {
+            Ok(Some(committee)) => {
+                let previous =
+                    height.checked_sub(1).and_then(|height| committee_store.get_committee(height).ok().flatten());
+                if previous.as_ref() != Some(&committee) {
+                    events.push(BlockEvent::CommitteeChanged { height, committee });
+                }
+            }
+            Ok(None) => (),
+            Err(error) => warn!("Failed to retrieve the committee for block {height} - {error}"),
+        }
+
+        events.push(BlockEvent::BlockCommitted { height, block_hash: block.hash() });
+
+        // Send the events to the subscribers, removing those that have unsubscribed or are lagging behind.
+        // Note: A lagging subscriber is removed without blocking, so that it cannot stall the VM.
+        subscribers.active.retain(|subscriber| events.iter().all(|event| subscriber.try_send(event.clone()).is_ok()));
+    }
+}
@@ -139,31 +139,44 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Next, verify the deployment or execution.
         match transaction {
-            Transaction::Deploy(id, owner, deployment, _) => {
+            Transaction::Deploy(id, owner, deployment, fee) => {
                 // Compute the deployment ID.
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
                     bail!("Failed to compute the Merkle root for a deployment transaction '{id}'")
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
+                // Ensure the edition is correct, and that an upgrade is permitted by the upgrade policy.
+                match self.contains_program(program_id) {
+                    // If the program does not exist, ensure this is the initial edition.
+                    false => {
+                        // Ensure the edition is correct.
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program ID does not already exist in the store.
+                        if self.transaction_store().contains_program_id(program_id)? {
+                            bail!("Program ID '{program_id}' is already deployed")
+                        }
+                    }
+                    // If the program exists, ensure this is an upgrade to the next edition.
+                    true => self.check_upgrade_policy(id, owner, deployment)?,
                 }
                 // Verify the deployment if it has not been verified before.
+                // Note: A partially-verified deployment has already been checked against its minimum deployment cost.
                 if !is_partially_verified {
-                    // Verify the deployment.
-                    match try_vm_runtime!(|| self.check_deployment_internal(deployment, rng)) {
+                    // Verify the deployment, and compute the minimum deployment cost.
+                    let cost = match try_vm_runtime!(|| self.check_deployment_internal(deployment, rng)) {
                         Ok(result) => result?,
                         Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
+                    };
+                    // Ensure the fee is sufficient to cover the cost.
+                    if *fee.base_amount()? < cost {
+                        bail!(
+                            "Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits"
+                        )
                     }
                 }
             }
@@ -182,6 +195,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
                 }
             }
+            Transaction::Bundle(id, executions, _) => {
+                // Compute the bundle ID.
+                let Ok(bundle_id) = Transaction::bundle_tree(executions, None).map(|tree| *tree.root()) else {
+                    bail!("Failed to compute the Merkle root for a bundle transaction '{id}'")
+                };
+                // Ensure the bundle was not previously rejected (replay attack prevention).
+                if self.block_store().contains_rejected_deployment_or_execution_id(&bundle_id)? {
+                    bail!("Transaction '{id}' contains a previously rejected bundle")
+                }
+                // Verify each execution in the bundle.
+                for execution in executions {
+                    match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified)) {
+                        Ok(result) => result?,
+                        Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
+                    }
+                }
+            }
             Transaction::Fee(..) => { /* no-op */ }
         }
 
@@ -206,12 +236,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
                     bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
                 };
-                // Compute the minimum deployment cost.
-                let (cost, _) = deployment_cost(deployment)?;
-                // Ensure the fee is sufficient to cover the cost.
-                if *fee.base_amount()? < cost {
-                    bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
-                }
+                // Note: The base fee is checked against the minimum deployment cost when the deployment is verified,
+                // as the cost is computed from the stack that verification builds.
                 // Verify the fee.
                 self.check_fee_internal(fee, deployment_id)?;
             }
@@ -247,6 +273,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
                 }
             }
+            Transaction::Bundle(id, executions, fee) => {
+                // Ensure the rejected ID is not present.
+                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (bundle)");
+                // Compute the bundle ID.
+                let Ok(bundle_id) = Transaction::bundle_tree(executions, None).map(|tree| *tree.root()) else {
+                    bail!("Failed to compute the Merkle root for bundle transaction '{id}'")
+                };
+                // Compute the bundle cost.
+                let (cost, _) = bundle_cost(&self.process().read(), executions)?;
+                // Ensure the fee is sufficient to cover the cost.
+                if *fee.base_amount()? < cost {
+                    bail!("Transaction '{id}' has an insufficient base fee (bundle) - requires {cost} microcredits")
+                }
+                // Verify the fee.
+                self.check_fee_internal(fee, bundle_id)?;
+            }
             // Note: This transaction type does not need to check the fee amount, because:
             //  1. The fee is guaranteed to be non-zero by the constructor of `Transaction::Fee`.
             //  2. The fee may be less that the deployment or execution cost, as this is a valid reason it was rejected.
@@ -263,12 +305,64 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Verifies the given deployment. On failure, returns an error.
+    /// Verifies that the given deployment is a permitted upgrade of an existing program. On failure, returns an error.
+    ///
+    /// Note: The compatibility of the upgrade with the previous edition is checked when verifying the deployment,
+    /// and an upgrade governed by an admin function is approved or rejected in `finalize`.
+    #[inline]
+    fn check_upgrade_policy(
+        &self,
+        id: &N::TransactionID,
+        owner: &ProgramOwner<N>,
+        deployment: &Deployment<N>,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the current edition of the program.
+        let Some(edition) = self.transaction_store().deployment_store().get_edition(program_id)? else {
+            bail!("Invalid deployment transaction '{id}' - program '{program_id}' cannot be upgraded")
+        };
+        // Ensure the deployment is the next edition.
+        match edition.checked_add(1) {
+            Some(next_edition) if next_edition == deployment.edition() => (),
+            Some(next_edition) => {
+                bail!("Invalid deployment transaction '{id}' - expected edition {next_edition} for '{program_id}'")
+            }
+            None => bail!("Invalid deployment transaction '{id}' - edition overflow for '{program_id}'"),
+        }
+        // Retrieve the upgrade policy of the current edition.
+        let upgrade_policy = *self.process.read().get_program(program_id)?.upgrade_policy();
+        // Ensure the upgrade is permitted.
+        match upgrade_policy {
+            UpgradePolicy::Immutable => {
+                bail!("Invalid deployment transaction '{id}' - program '{program_id}' is immutable")
+            }
+            UpgradePolicy::Owner => {
+                // Retrieve the owner of the current edition.
+                let Some(previous_owner) = self.transaction_store().deployment_store().get_owner(program_id)? else {
+                    bail!("Failed to find the owner of program '{program_id}'")
+                };
+                // Ensure the upgrade is signed by the same owner.
+                if previous_owner.address() != owner.address() {
+                    bail!("Invalid deployment transaction '{id}' - only the owner of '{program_id}' may upgrade it")
+                }
+            }
+            // Note: The admin function is finalized when the deployment is finalized.
+            UpgradePolicy::Admin(_) => (),
+        }
+        Ok(())
+    }
+
+    /// Verifies the given deployment, and returns its minimum deployment cost. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the deployment are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_deployment_internal<R: CryptoRng + Rng>(&self, deployment: &Deployment<N>, rng: &mut R) -> Result<()> {
+    pub(super) fn check_deployment_internal<R: CryptoRng + Rng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<u64> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
@@ -17,6 +17,7 @@ mod clean;
 mod deploy;
 mod execute;
 mod is_build_required;
+mod profile;
 mod run;
 
 pub use build::{BuildRequest, BuildResponse};
@@ -32,7 +33,7 @@ use crate::{
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
-        process::{Assignments, CallMetrics, CallStack, Process, StackExecute},
+        process::{Assignments, CallMetrics, CallStack, ConstraintProfile, Process, StackExecute},
         program::{CallOperator, Instruction, Program},
         snark::{ProvingKey, VerifyingKey},
     },
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Synthesizes the circuit of each function in the program, with sampled inputs,
+    /// and returns the constraint profile of each of their instructions.
+    pub fn profile<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(&self) -> Result<ConstraintProfile<N>> {
+        // Retrieve the main program.
+        let program = self.program();
+        // Retrieve the program ID.
+        let program_id = program.id();
+
+        #[cfg(feature = "aleo-cli")]
+        println!("⏳ Profiling '{}'...\n", program_id.to_string().bold());
+
+        // Construct the process.
+        let process = self.get_process()?;
+
+        // Profile each function circuit.
+        let mut profile = ConstraintProfile::new();
+        for function_name in program.functions().keys() {
+            profile.extend(process.profile_function::<A, _>(program_id, function_name, &mut rand::thread_rng())?);
+        }
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{profile}");
+
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_profile() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Profile the package.
+        let profile = package.profile::<CurrentAleo>().unwrap();
+
+        // Ensure each function is profiled.
+        let function_names = profile.functions().iter().map(|function| function.function_name).collect::<Vec<_>>();
+        assert_eq!(function_names, package.program().functions().keys().copied().collect::<Vec<_>>());
+        for function in profile.functions() {
+            // Ensure each instruction is profiled.
+            let function_name = &function.function_name;
+            let num_instructions = package.program().get_function(function_name).unwrap().instructions().len();
+            assert_eq!(function.instructions.len(), num_instructions);
+            // Ensure the instructions do not exceed the function circuit.
+            let num_constraints =
+                function.instructions.iter().map(|profile| profile.counts.num_constraints).sum::<u64>();
+            assert!(function.request.num_constraints + num_constraints <= function.total.num_constraints);
+        }
+
+        // Ensure the flat profile is sorted by the number of constraints.
+        let flat = profile.flat();
+        assert!(!flat.is_empty());
+        assert!(flat.windows(2).all(|pair| pair[0].counts.num_constraints >= pair[1].counts.num_constraints));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}